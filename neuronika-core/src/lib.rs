@@ -87,6 +87,42 @@ pub trait Stack<Rhs> {
     fn stack(self, other: Rhs, axis: usize) -> Self::Output;
 }
 
+/// Element-wise maximum.
+pub trait Maximum<Rhs> {
+    /// The type of the element-wise maximum's result. See the [*differentiability arithmetic*]
+    /// for more details.
+    ///
+    /// [*differentiability arithmetic*]: index.html#differentiability-arithmetic
+    type Output;
+
+    /// Computes the element-wise maximum between `self` and `other`.
+    fn maximum(self, other: Rhs) -> Self::Output;
+}
+
+/// Element-wise minimum.
+pub trait Minimum<Rhs> {
+    /// The type of the element-wise minimum's result. See the [*differentiability arithmetic*]
+    /// for more details.
+    ///
+    /// [*differentiability arithmetic*]: index.html#differentiability-arithmetic
+    type Output;
+
+    /// Computes the element-wise minimum between `self` and `other`.
+    fn minimum(self, other: Rhs) -> Self::Output;
+}
+
+/// Element-wise conditional selection.
+pub trait Select<Rhs, Condition> {
+    /// The type of the selection's result. See the [*differentiability arithmetic*] for more
+    /// details.
+    ///
+    /// [*differentiability arithmetic*]: index.html#differentiability-arithmetic
+    type Output;
+
+    /// Picks the elements of `self` where `condition` holds and those of `other` elsewhere.
+    fn select(self, other: Rhs, condition: Condition) -> Self::Output;
+}
+
 /// Convolution.
 pub trait Convolution<Rhs, D>
 where
@@ -104,3 +140,28 @@ where
     where
         T: Copy + IntoDimension<Dim = D>;
 }
+
+/// Transposed convolution.
+pub trait ConvolutionTranspose<Rhs, D>
+where
+    D: Dimension,
+{
+    /// The type of the transposed convolution's result. See the [*differentiability arithmetic*]
+    /// for more details.
+    ///
+    /// [*differentiability arithmetic*]: index.html#differentiability-arithmetic
+    type Output;
+
+    /// Applies a *n*-dimensional transposed convolution with the given parameters. *n* can be
+    /// either 1, 2 or 3.
+    fn convolution_transpose<T>(
+        self,
+        input: Rhs,
+        stride: T,
+        dilation: T,
+        output_padding: T,
+        groups: usize,
+    ) -> Self::Output
+    where
+        T: Copy + IntoDimension<Dim = D>;
+}
@@ -381,22 +381,76 @@
 // //! * [`nn::GroupedConv3d`](struct@GroupedConv3d) - Applies a grouped volumetric convolution over an
 // //! input signal composed of several input planes.
 // //!
+// //! * [`nn::DepthwiseSeparableConv1d`](struct@DepthwiseSeparableConv1d) - Applies a depthwise
+// //! separable temporal convolution over an input signal composed of several input planes.
+// //!
+// //! * [`nn::DepthwiseSeparableConv2d`](struct@DepthwiseSeparableConv2d) - Applies a depthwise
+// //! separable spatial convolution over an input signal composed of several input planes.
+// //!
+// //! * [`nn::DepthwiseSeparableConv3d`](struct@DepthwiseSeparableConv3d) - Applies a depthwise
+// //! separable volumetric convolution over an input signal composed of several input planes.
+// //!
+// //! * [`nn::ConvTranspose1d`](struct@ConvTranspose1d) - Applies a temporal transposed convolution
+// //! over an input signal composed of several input planes.
+// //!
+// //! * [`nn::ConvTranspose2d`](struct@ConvTranspose2d) - Applies a spatial transposed convolution
+// //! over an input signal composed of several input planes.
+// //!
+// //! * [`nn::ConvTranspose3d`](struct@ConvTranspose3d) - Applies a volumetric transposed
+// //! convolution over an input signal composed of several input planes.
+// //!
+// //! ## Upsampling Layers
+// //!
+// //! * [`nn::Upsample`](struct@Upsample) - Upsamples an input signal composed of several input
+// //! planes with nearest, linear or cubic interpolation.
+// //!
 // //! ## Dropout Layers
 // //!
 // //! * [`nn::Dropout`](struct@Dropout) - During training, randomly zeroes some of the elements of
 // //! the input variable with probability *p* using samples from a Bernoulli distribution.
 
-use ndarray::{Ix1, Ix2, Ix3, Ix4, Ix5};
+use ndarray::{Dimension, IntoDimension, Ix1, Ix2, Ix3, Ix4, Ix5, RemoveAxis};
 
-use neuronika_core::{Convolution, MatMatMulT};
+use neuronika_core::{Convolution, ConvolutionTranspose, MatMatMulT};
 
-use neuronika_variable::{PaddingMode, VarDiff};
+use neuronika_variable::{Interpolation, PaddingMode, ParameterDict, Var, VarDiff, Zero};
 
 pub mod init;
 
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
+/// Implements `register_parameters` for a layer made of a weight and a bias, which are registered
+/// under the names `{name}.weight` and `{name}.bias` used by PyTorch.
+macro_rules! register_weight_and_bias {
+    () => {
+        /// Registers the weight and the bias of the layer in `parameters`, under the names
+        /// `{name}.weight` and `{name}.bias` used by PyTorch.
+        pub fn register_parameters(&self, name: &str, parameters: &mut ParameterDict) {
+            parameters.insert(format!("{}.weight", name), self.weight.clone());
+            parameters.insert(format!("{}.bias", name), self.bias.clone());
+        }
+    };
+}
+
+/// Implements `register_parameters` for a layer made of other layers, whose parameters are
+/// registered under the name of the layer followed by the name of the field holding them.
+macro_rules! register_sublayers {
+    ($($layer:ident),+) => {
+        /// Registers the parameters of the layer in `parameters`, under the names of the
+        /// parameters of each sub-layer prefixed by `{name}.` and the name of the sub-layer, such
+        /// as `{name}.depthwise.weight`.
+        pub fn register_parameters(&self, name: &str, parameters: &mut ParameterDict) {
+            $(
+                self.$layer.register_parameters(
+                    &format!(concat!("{}.", stringify!($layer)), name),
+                    parameters,
+                );
+            )+
+        }
+    };
+}
+
 /// Applies a **linear transformation** to the incoming data.
 ///
 /// ```text
@@ -432,6 +486,8 @@ impl Linear {
         Self { weight, bias }
     }
 
+    register_weight_and_bias!();
+
     /// Applies the linear transformation *y = xA^T + b* to the incoming data.
     ///
     /// # Arguments
@@ -625,13 +681,185 @@ impl GRUCell {
     }
 }
 
+/// A **parametric rectified linear unit (PReLU)**.
+///
+/// ```text
+/// PReLU(x) = max(0, x) + a * min(0, x)
+/// ```
+///
+/// Where *a* is a learnable slope.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[allow(clippy::upper_case_acronyms)]
+pub struct PReLU {
+    pub weight: VarDiff<Ix1>,
+}
+
+impl PReLU {
+    /// Creates a new PReLU.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_parameters` - number of learnable slopes. It can either be `1`, in which case the
+    ///   slope is shared by all the elements of the input, or the number of channels of the input,
+    ///   i.e. the length of its second dimension, in which case each channel has its own slope.
+    ///
+    /// * `init` - initial value of the slopes, `0.25` is a sensible default.
+    pub fn new(num_parameters: usize, init: f32) -> Self {
+        let weight = neuronika_variable::full(num_parameters, init).requires_grad();
+
+        Self { weight }
+    }
+
+    /// Applies the parametric rectified linear unit element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - a variable of shape *(N, C, ...)* where *C* is the number of slopes, unless
+    /// these are shared.
+    ///
+    /// # Panics
+    ///
+    /// If the slopes are not shared and their number differs from that of the input's channels.
+    pub fn forward<D>(&self, input: VarDiff<D>) -> VarDiff<D>
+    where
+        D: 'static + Dimension,
+    {
+        // The slopes are laid along the channel dimension, the second one, so that they
+        // broadcast over the batch and spatial dimensions.
+        let mut shape = D::zeros(input.data().ndim());
+        shape.slice_mut().fill(1);
+        if shape.ndim() > 1 {
+            shape[1] = self.weight.data().len();
+        }
+        let weight = self.weight.clone().reshape(shape);
+
+        input.clone().relu() - weight * (-input).relu()
+    }
+}
+
+/// Variables that can be padded over their spatial dimensions, whether differentiable or not.
+///
+/// It allows the convolution layers to accept both kinds of input.
+pub trait Pad<D>
+where
+    D: Dimension,
+    D::Smaller: RemoveAxis,
+{
+    /// Applies distinct amounts of padding before and after each spatial dimension of the
+    /// variable. A zero padding leaves the variable untouched.
+    fn pad_asymmetric<T, E>(self, before: E, after: E, mode: T) -> Self
+    where
+        T: 'static + PaddingMode<D>,
+        E: IntoDimension<Dim = <D::Smaller as Dimension>::Smaller>;
+}
+
+impl<D> Pad<D> for Var<D>
+where
+    D: 'static + Dimension,
+    D::Smaller: RemoveAxis,
+    <D::Smaller as Dimension>::Smaller: Copy,
+{
+    fn pad_asymmetric<T, E>(self, before: E, after: E, mode: T) -> Self
+    where
+        T: 'static + PaddingMode<D>,
+        E: IntoDimension<Dim = <D::Smaller as Dimension>::Smaller>,
+    {
+        let (before, after) = (before.into_dimension(), after.into_dimension());
+        if before
+            .slice()
+            .iter()
+            .chain(after.slice())
+            .all(|&el| el == 0)
+        {
+            return self;
+        }
+
+        Var::pad_asymmetric(self, before, after, mode)
+    }
+}
+
+impl<D> Pad<D> for VarDiff<D>
+where
+    D: 'static + Dimension,
+    D::Smaller: RemoveAxis,
+    <D::Smaller as Dimension>::Smaller: Copy,
+{
+    fn pad_asymmetric<T, E>(self, before: E, after: E, mode: T) -> Self
+    where
+        T: 'static + PaddingMode<D>,
+        E: IntoDimension<Dim = <D::Smaller as Dimension>::Smaller>,
+    {
+        let (before, after) = (before.into_dimension(), after.into_dimension());
+        if before
+            .slice()
+            .iter()
+            .chain(after.slice())
+            .all(|&el| el == 0)
+        {
+            return self;
+        }
+
+        VarDiff::pad_asymmetric(self, before, after, mode)
+    }
+}
+
+/// Padding of the input of a convolution layer.
+///
+/// Any explicit amount of padding can be converted into it, so that the layers accept it directly.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConvPadding<E> {
+    /// The same amount of padding before and after each spatial dimension.
+    Explicit(E),
+    /// The padding needed for the output to be as large as the input, which requires a unit
+    /// stride. When the dilated kernel has an even size along a dimension, the extra element of
+    /// padding goes after it.
+    Same,
+}
+
+impl<E> From<E> for ConvPadding<E> {
+    fn from(padding: E) -> Self {
+        Self::Explicit(padding)
+    }
+}
+
+impl<E> ConvPadding<E>
+where
+    E: Copy + IntoDimension,
+{
+    /// Returns the padding before and after each spatial dimension for a kernel whose spatial
+    /// shape is `kernel_shape`, with the given `dilation`.
+    fn sides(self, kernel_shape: &[usize], dilation: E) -> (E::Dim, E::Dim) {
+        match self {
+            Self::Explicit(padding) => (padding.into_dimension(), padding.into_dimension()),
+            Self::Same => {
+                let dilation = dilation.into_dimension();
+                let (mut before, mut after) = (dilation.clone(), dilation.clone());
+                kernel_shape
+                    .iter()
+                    .zip(dilation.slice())
+                    .zip(before.slice_mut().iter_mut().zip(after.slice_mut()))
+                    .for_each(|((kernel_len, dilation), (before, after))| {
+                        let total = dilation * (kernel_len - 1);
+                        *before = total / 2;
+                        *after = total - *before;
+                    });
+
+                (before, after)
+            }
+        }
+    }
+}
+
 /// Applies a temporal convolution over an input signal composed of several input planes.
+///
+/// See also [`GroupedConv1d`].
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub struct Conv1d<T>
 where
     T: PaddingMode<Ix3>,
 {
-    pub padding: usize,
+    pub padding: ConvPadding<usize>,
     pub padding_mode: T,
     pub stride: usize,
     pub dilation: usize,
@@ -653,10 +881,11 @@ where
     ///
     /// * `kernel_size` - size of the kernel, a number for this one-dimensional case.
     ///
-    /// * `padding` - padding to be applied to the input, a number for this one-dimensional case.
+    /// * `padding` - padding to be applied to the input, a number for this one-dimensional case, or
+    ///   [`ConvPadding::Same`].
     ///
-    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`] or
-    /// [`Replicative`].
+    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`],
+    /// [`Replicative`] or [`Circular`](neuronika_variable::Circular).
     ///
     /// * `stride` - stride of the convolution, a number for this one-dimensional case.
     ///
@@ -665,15 +894,24 @@ where
     ///
     /// The weight and the bias of the layer are initialized from *U(-k, k)* where
     /// `k = (1. /(in_channels * kernel_size) as f32).sqrt()`.
-    pub fn new(
+    pub fn new<P>(
         in_channels: usize,
         out_channels: usize,
         kernel_size: usize,
-        padding: usize,
+        padding: P,
         padding_mode: T,
         stride: usize,
         dilation: usize,
-    ) -> Self {
+    ) -> Self
+    where
+        P: Into<ConvPadding<usize>>,
+    {
+        let padding = padding.into();
+        assert!(
+            padding != ConvPadding::Same || stride == 1,
+            "Same padding requires a unit stride, got {:?}",
+            stride
+        );
         let weight =
             neuronika_variable::zeros((out_channels, in_channels, kernel_size)).requires_grad();
         let bias = neuronika_variable::zeros((out_channels, 1)).requires_grad();
@@ -692,6 +930,8 @@ where
         }
     }
 
+    register_weight_and_bias!();
+
     /// Computes a 1-dimensional convolution *(cross correlation)*.
     ///
     /// # Arguments
@@ -711,9 +951,161 @@ where
     /// The resulting output shape will be *(N, Cout, Lout)*
     pub fn forward<I>(&self, input: I) -> VarDiff<Ix3>
     where
-        VarDiff<Ix3>: Convolution<I, Ix3>,
+        T: 'static,
+        I: Pad<Ix3>,
+        VarDiff<Ix3>: Convolution<I, Ix1, Output = VarDiff<Ix3>>,
+    {
+        let (before, after) = self
+            .padding
+            .sides(&self.weight.data().shape()[2..], self.dilation);
+
+        self.weight.clone().convolution(
+            input.pad_asymmetric(before, after, self.padding_mode),
+            self.stride,
+            self.dilation,
+            1,
+        ) + self.bias.clone()
+    }
+}
+
+/// Applies a grouped temporal convolution over an input signal composed of several input planes.
+///
+/// The input channels and the output channels are split into `groups` blocks, each input block
+/// being convolved with its own set of kernels. With as many groups as input channels, this is a
+/// **depthwise convolution**.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct GroupedConv1d<T>
+where
+    T: PaddingMode<Ix3>,
+{
+    pub padding: ConvPadding<usize>,
+    pub padding_mode: T,
+    pub stride: usize,
+    pub dilation: usize,
+    pub groups: usize,
+    pub weight: VarDiff<Ix3>,
+    pub bias: VarDiff<Ix2>,
+}
+
+impl<T> GroupedConv1d<T>
+where
+    T: PaddingMode<Ix3>,
+{
+    /// Creates a new GroupedConv1d.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_channels` - number of planes in the input signal.
+    ///
+    /// * `out_channels` - number of planes in the output signal.
+    ///
+    /// * `kernel_size` - size of the kernel, a number for this one-dimensional case.
+    ///
+    /// * `padding` - padding to be applied to the input, a number for this one-dimensional case, or
+    ///   [`ConvPadding::Same`].
+    ///
+    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`],
+    ///   [`Replicative`] or [`Circular`](neuronika_variable::Circular).
+    ///
+    /// * `stride` - stride of the convolution, a number for this one-dimensional case.
+    ///
+    /// * `dilation` - controls the spacing between the kernel points, a number for this
+    ///   one-dimensional case.
+    ///
+    /// * `groups` - number of blocked connections from input channels to output channels. Both
+    ///   `in_channels` and `out_channels` must be divisible by it.
+    ///
+    /// The weight and the bias of the layer are initialized from *U(-k, k)* where
+    /// `k = (groups /(in_channels * kernel_size) as f32).sqrt()`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<P>(
+        in_channels: usize,
+        out_channels: usize,
+        kernel_size: usize,
+        padding: P,
+        padding_mode: T,
+        stride: usize,
+        dilation: usize,
+        groups: usize,
+    ) -> Self
+    where
+        P: Into<ConvPadding<usize>>,
+    {
+        let padding = padding.into();
+        assert!(
+            padding != ConvPadding::Same || stride == 1,
+            "Same padding requires a unit stride, got {:?}",
+            stride
+        );
+        assert_eq!(
+            in_channels % groups,
+            0,
+            "In channels {} is not divisible by groups {}",
+            in_channels,
+            groups
+        );
+        assert_eq!(
+            out_channels % groups,
+            0,
+            "Out channels {} is not divisible by groups {}",
+            out_channels,
+            groups
+        );
+
+        let weight = neuronika_variable::zeros((out_channels, in_channels / groups, kernel_size))
+            .requires_grad();
+        let bias = neuronika_variable::zeros((out_channels, 1)).requires_grad();
+
+        let k = (groups as f32 / (in_channels * kernel_size) as f32).sqrt();
+        init::uniform(&weight, -k, k);
+        init::uniform(&bias, -k, k);
+
+        Self {
+            padding,
+            padding_mode,
+            stride,
+            dilation,
+            groups,
+            weight,
+            bias,
+        }
+    }
+
+    register_weight_and_bias!();
+
+    /// Computes a 1-dimensional grouped convolution *(cross correlation)*.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - signal to convolve.
+    ///
+    /// The **input** must be of shape *(N, Cin, L)*
+    /// * **N** is the batch size
+    /// * **Cin** is the number of input channels
+    /// * **L** is the **length** of the input
+    ///
+    /// The **kernel** must be of shape *(Cout, Cin / groups, Lk)*
+    /// * **Cout** is the number of output channels
+    /// * **Cin** is the number of input channels
+    /// * **Lk** is the **length** of the kernel
+    ///
+    /// The resulting output shape will be *(N, Cout, Lout)*
+    pub fn forward<I>(&self, input: I) -> VarDiff<Ix3>
+    where
+        T: 'static,
+        I: Pad<Ix3>,
+        VarDiff<Ix3>: Convolution<I, Ix1, Output = VarDiff<Ix3>>,
     {
-        todo!()
+        let (before, after) = self
+            .padding
+            .sides(&self.weight.data().shape()[2..], self.dilation);
+
+        self.weight.clone().convolution(
+            input.pad_asymmetric(before, after, self.padding_mode),
+            self.stride,
+            self.dilation,
+            self.groups,
+        ) + self.bias.clone()
     }
 }
 
@@ -725,7 +1117,7 @@ pub struct Conv2d<T>
 where
     T: PaddingMode<Ix4>,
 {
-    pub padding: (usize, usize),
+    pub padding: ConvPadding<(usize, usize)>,
     pub padding_mode: T,
     pub stride: (usize, usize),
     pub dilation: (usize, usize),
@@ -747,10 +1139,11 @@ where
     ///
     /// * `kernel_size` - size of the kernel, a 2-tuple for this two-dimensional case.
     ///
-    /// * `padding` - padding to be applied to the input, a 2-tuple for this two-dimensional case.
+    /// * `padding` - padding to be applied to the input, a 2-tuple for this two-dimensional case,
+    ///   or [`ConvPadding::Same`].
     ///
-    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`] or
-    /// [`Replicative`].
+    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`],
+    /// [`Replicative`] or [`Circular`](neuronika_variable::Circular).
     ///
     /// * `stride` - stride of the convolution, a 2-tuple for this two-dimensional case.
     ///
@@ -759,15 +1152,24 @@ where
     ///
     /// The weight and the bias are initialized from *U(-k, k)* where
     /// `k = (1. /(in_channels * kernel_w * kernel_h) as f32).sqrt()`.
-    pub fn new(
+    pub fn new<P>(
         in_channels: usize,
         out_channels: usize,
         kernel_size: (usize, usize),
-        padding: (usize, usize),
+        padding: P,
         padding_mode: T,
         stride: (usize, usize),
         dilation: (usize, usize),
-    ) -> Self {
+    ) -> Self
+    where
+        P: Into<ConvPadding<(usize, usize)>>,
+    {
+        let padding = padding.into();
+        assert!(
+            padding != ConvPadding::Same || stride == (1, 1),
+            "Same padding requires a unit stride, got {:?}",
+            stride
+        );
         let (kernel_h, kernel_w) = kernel_size;
         let weight = neuronika_variable::zeros((out_channels, in_channels, kernel_h, kernel_w))
             .requires_grad();
@@ -787,6 +1189,8 @@ where
         }
     }
 
+    register_weight_and_bias!();
+
     /// Computes a 2-dimensional convolution *(cross correlation)*.
     ///
     /// # Arguments
@@ -808,70 +1212,114 @@ where
     /// The resulting output shape will be *(N, Cout, Hout, Wout)*
     pub fn forward<I>(&self, input: I) -> VarDiff<Ix4>
     where
-        VarDiff<Ix4>: Convolution<I, Ix4>,
+        T: 'static,
+        I: Pad<Ix4>,
+        VarDiff<Ix4>: Convolution<I, Ix2, Output = VarDiff<Ix4>>,
     {
-        todo!()
+        let (before, after) = self
+            .padding
+            .sides(&self.weight.data().shape()[2..], self.dilation);
+
+        self.weight.clone().convolution(
+            input.pad_asymmetric(before, after, self.padding_mode),
+            self.stride,
+            self.dilation,
+            1,
+        ) + self.bias.clone()
     }
 }
 
-/// Applies a **volumetric convolution** over an input signal composed of several input planes.
+/// Applies a grouped spatial convolution over an input signal composed of several input planes.
 ///
-/// See also [`GroupedConv3d`].
+/// The input channels and the output channels are split into `groups` blocks, each input block
+/// being convolved with its own set of kernels. With as many groups as input channels, this is a
+/// **depthwise convolution**.
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
-pub struct Conv3d<T>
+pub struct GroupedConv2d<T>
 where
-    T: PaddingMode<Ix5>,
+    T: PaddingMode<Ix4>,
 {
-    pub padding: (usize, usize, usize),
+    pub padding: ConvPadding<(usize, usize)>,
     pub padding_mode: T,
-    pub stride: (usize, usize, usize),
-    pub dilation: (usize, usize, usize),
-    pub weight: VarDiff<Ix5>,
-    pub bias: VarDiff<Ix4>,
+    pub stride: (usize, usize),
+    pub dilation: (usize, usize),
+    pub groups: usize,
+    pub weight: VarDiff<Ix4>,
+    pub bias: VarDiff<Ix3>,
 }
 
-impl<T> Conv3d<T>
+impl<T> GroupedConv2d<T>
 where
-    T: PaddingMode<Ix5>,
+    T: PaddingMode<Ix4>,
 {
-    /// Creates a new Conv3d.
+    /// Creates a new GroupedConv2d.
     ///
     /// # Arguments
     ///
-    /// * `in_channels` - number of planes in the input signal.
+    /// * `in_channels` - number of planes in the input signal.
     ///
-    /// * `out_channels` - number of planes in the output signal.
+    /// * `out_channels` - number of planes in the output signal.
     ///
-    /// * `kernel_size` - size of the kernel, a 3-tuple for this three-dimensional case.
+    /// * `kernel_size` - size of the kernel, a 2-tuple for this two-dimensional case.
     ///
-    /// * `padding` - padding to be applied to the input, a 3-tuple for this three-dimensional case.
+    /// * `padding` - padding to be applied to the input, a 2-tuple for this two-dimensional case,
+    ///   or [`ConvPadding::Same`].
     ///
-    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`] or
-    /// [`Replicative`].
+    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`],
+    ///   [`Replicative`] or [`Circular`](neuronika_variable::Circular).
     ///
-    /// * `stride` - stride of the convolution, a 3-tuple for this three-dimensional case.
+    /// * `stride` - stride of the convolution, a 2-tuple for this two-dimensional case.
     ///
-    /// * `dilation` - controls the spacing between the kernel points, a 3-tuple for this
-    /// three-dimensional case.
+    /// * `dilation` - controls the spacing between the kernel points, a 2-tuple for this
+    ///   two-dimensional case.
+    ///
+    /// * `groups` - number of blocked connections from input channels to output channels. Both
+    ///   `in_channels` and `out_channels` must be divisible by it.
     ///
     /// The weight and the bias of the layer are initialized from *U(-k, k)* where
-    /// `k = (1. /(in_channels * kernel_d * kernel_w * kernel_h) as f32).sqrt()`.
-    pub fn new(
+    /// `k = (groups /(in_channels * kernel_w * kernel_h) as f32).sqrt()`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<P>(
         in_channels: usize,
         out_channels: usize,
-        kernel_size: (usize, usize, usize),
-        padding: (usize, usize, usize),
+        kernel_size: (usize, usize),
+        padding: P,
         padding_mode: T,
-        stride: (usize, usize, usize),
-        dilation: (usize, usize, usize),
-    ) -> Self {
-        let (kernel_d, kernel_h, kernel_w) = kernel_size;
+        stride: (usize, usize),
+        dilation: (usize, usize),
+        groups: usize,
+    ) -> Self
+    where
+        P: Into<ConvPadding<(usize, usize)>>,
+    {
+        let padding = padding.into();
+        assert!(
+            padding != ConvPadding::Same || stride == (1, 1),
+            "Same padding requires a unit stride, got {:?}",
+            stride
+        );
+        assert_eq!(
+            in_channels % groups,
+            0,
+            "In channels {} is not divisible by groups {}",
+            in_channels,
+            groups
+        );
+        assert_eq!(
+            out_channels % groups,
+            0,
+            "Out channels {} is not divisible by groups {}",
+            out_channels,
+            groups
+        );
+
+        let (kernel_h, kernel_w) = kernel_size;
         let weight =
-            neuronika_variable::zeros((out_channels, in_channels, kernel_d, kernel_h, kernel_w))
+            neuronika_variable::zeros((out_channels, in_channels / groups, kernel_h, kernel_w))
                 .requires_grad();
-        let bias = neuronika_variable::zeros((out_channels, 1, 1, 1)).requires_grad();
+        let bias = neuronika_variable::zeros((out_channels, 1, 1)).requires_grad();
 
-        let k = (1. / (in_channels * kernel_d * kernel_h * kernel_w) as f32).sqrt();
+        let k = (groups as f32 / (in_channels * kernel_h * kernel_w) as f32).sqrt();
         init::uniform(&weight, -k, k);
         init::uniform(&bias, -k, k);
 
@@ -880,37 +1328,1039 @@ where
             padding_mode,
             stride,
             dilation,
+            groups,
             weight,
             bias,
         }
     }
 
-    /// Computes a 3-dimensional convolution *(cross correlation)*.
+    register_weight_and_bias!();
+
+    /// Computes a 2-dimensional grouped convolution *(cross correlation)*.
     ///
     /// # Arguments
     ///
     /// `input` - signal to convolve.
     ///
-    /// The **input** must be of shape *(N, Cin, D, H, W)*
+    /// The **input** must be of shape *(N, Cin, H, W)*
     /// * **N** is the batch size
     /// * **Cin** is the number of input channels
-    /// * **D** is the **depth** of the input
     /// * **H** is the **height** of the input
     /// * **W** is the **width** of the input
     ///
-    /// The **kernel** must be of shape *(Cout, Cin, Dk,  Hk, Wk)*
+    /// The **kernel** must be of shape *(Cout, Cin / groups, Hk, Wk)*
     /// * **Cout** is the number of output channels
     /// * **Cin** is the number of input channels
-    /// * **Dk** is the **depth** of the kernel
     /// * **Hk** is the **height** of the kernel
     /// * **Wk** is the **width** of the kernel
     ///
-    /// The resulting output shape will be *(N, Cout, Dout, Hout, Wout)*
-    pub fn forward<I>(&self, input: I) -> VarDiff<Ix5>
+    /// The resulting output shape will be *(N, Cout, Hout, Wout)*
+    pub fn forward<I>(&self, input: I) -> VarDiff<Ix4>
     where
-        VarDiff<Ix5>: Convolution<I, Ix5>,
-        <VarDiff<Ix5> as Convolution<I, Ix5>>::Output: Into<VarDiff<Ix5>>,
+        T: 'static,
+        I: Pad<Ix4>,
+        VarDiff<Ix4>: Convolution<I, Ix2, Output = VarDiff<Ix4>>,
     {
-        todo!()
+        let (before, after) = self
+            .padding
+            .sides(&self.weight.data().shape()[2..], self.dilation);
+
+        self.weight.clone().convolution(
+            input.pad_asymmetric(before, after, self.padding_mode),
+            self.stride,
+            self.dilation,
+            self.groups,
+        ) + self.bias.clone()
     }
 }
+
+/// Applies a **volumetric convolution** over an input signal composed of several input planes.
+///
+/// See also [`GroupedConv3d`].
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct Conv3d<T>
+where
+    T: PaddingMode<Ix5>,
+{
+    pub padding: ConvPadding<(usize, usize, usize)>,
+    pub padding_mode: T,
+    pub stride: (usize, usize, usize),
+    pub dilation: (usize, usize, usize),
+    pub weight: VarDiff<Ix5>,
+    pub bias: VarDiff<Ix4>,
+}
+
+impl<T> Conv3d<T>
+where
+    T: PaddingMode<Ix5>,
+{
+    /// Creates a new Conv3d.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_channels` - number of planes in the input signal.
+    ///
+    /// * `out_channels` - number of planes in the output signal.
+    ///
+    /// * `kernel_size` - size of the kernel, a 3-tuple for this three-dimensional case.
+    ///
+    /// * `padding` - padding to be applied to the input, a 3-tuple for this three-dimensional
+    ///   case, or [`ConvPadding::Same`].
+    ///
+    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`],
+    /// [`Replicative`] or [`Circular`](neuronika_variable::Circular).
+    ///
+    /// * `stride` - stride of the convolution, a 3-tuple for this three-dimensional case.
+    ///
+    /// * `dilation` - controls the spacing between the kernel points, a 3-tuple for this
+    /// three-dimensional case.
+    ///
+    /// The weight and the bias of the layer are initialized from *U(-k, k)* where
+    /// `k = (1. /(in_channels * kernel_d * kernel_w * kernel_h) as f32).sqrt()`.
+    pub fn new<P>(
+        in_channels: usize,
+        out_channels: usize,
+        kernel_size: (usize, usize, usize),
+        padding: P,
+        padding_mode: T,
+        stride: (usize, usize, usize),
+        dilation: (usize, usize, usize),
+    ) -> Self
+    where
+        P: Into<ConvPadding<(usize, usize, usize)>>,
+    {
+        let padding = padding.into();
+        assert!(
+            padding != ConvPadding::Same || stride == (1, 1, 1),
+            "Same padding requires a unit stride, got {:?}",
+            stride
+        );
+        let (kernel_d, kernel_h, kernel_w) = kernel_size;
+        let weight =
+            neuronika_variable::zeros((out_channels, in_channels, kernel_d, kernel_h, kernel_w))
+                .requires_grad();
+        let bias = neuronika_variable::zeros((out_channels, 1, 1, 1)).requires_grad();
+
+        let k = (1. / (in_channels * kernel_d * kernel_h * kernel_w) as f32).sqrt();
+        init::uniform(&weight, -k, k);
+        init::uniform(&bias, -k, k);
+
+        Self {
+            padding,
+            padding_mode,
+            stride,
+            dilation,
+            weight,
+            bias,
+        }
+    }
+
+    register_weight_and_bias!();
+
+    /// Computes a 3-dimensional convolution *(cross correlation)*.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - signal to convolve.
+    ///
+    /// The **input** must be of shape *(N, Cin, D, H, W)*
+    /// * **N** is the batch size
+    /// * **Cin** is the number of input channels
+    /// * **D** is the **depth** of the input
+    /// * **H** is the **height** of the input
+    /// * **W** is the **width** of the input
+    ///
+    /// The **kernel** must be of shape *(Cout, Cin, Dk,  Hk, Wk)*
+    /// * **Cout** is the number of output channels
+    /// * **Cin** is the number of input channels
+    /// * **Dk** is the **depth** of the kernel
+    /// * **Hk** is the **height** of the kernel
+    /// * **Wk** is the **width** of the kernel
+    ///
+    /// The resulting output shape will be *(N, Cout, Dout, Hout, Wout)*
+    pub fn forward<I>(&self, input: I) -> VarDiff<Ix5>
+    where
+        T: 'static,
+        I: Pad<Ix5>,
+        VarDiff<Ix5>: Convolution<I, Ix3, Output = VarDiff<Ix5>>,
+    {
+        let (before, after) = self
+            .padding
+            .sides(&self.weight.data().shape()[2..], self.dilation);
+
+        self.weight.clone().convolution(
+            input.pad_asymmetric(before, after, self.padding_mode),
+            self.stride,
+            self.dilation,
+            1,
+        ) + self.bias.clone()
+    }
+}
+
+/// Applies a grouped volumetric convolution over an input signal composed of several input planes.
+///
+/// The input channels and the output channels are split into `groups` blocks, each input block
+/// being convolved with its own set of kernels. With as many groups as input channels, this is a
+/// **depthwise convolution**.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct GroupedConv3d<T>
+where
+    T: PaddingMode<Ix5>,
+{
+    pub padding: ConvPadding<(usize, usize, usize)>,
+    pub padding_mode: T,
+    pub stride: (usize, usize, usize),
+    pub dilation: (usize, usize, usize),
+    pub groups: usize,
+    pub weight: VarDiff<Ix5>,
+    pub bias: VarDiff<Ix4>,
+}
+
+impl<T> GroupedConv3d<T>
+where
+    T: PaddingMode<Ix5>,
+{
+    /// Creates a new GroupedConv3d.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_channels` - number of planes in the input signal.
+    ///
+    /// * `out_channels` - number of planes in the output signal.
+    ///
+    /// * `kernel_size` - size of the kernel, a 3-tuple for this three-dimensional case.
+    ///
+    /// * `padding` - padding to be applied to the input, a 3-tuple for this three-dimensional
+    ///   case, or [`ConvPadding::Same`].
+    ///
+    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`],
+    ///   [`Replicative`] or [`Circular`](neuronika_variable::Circular).
+    ///
+    /// * `stride` - stride of the convolution, a 3-tuple for this three-dimensional case.
+    ///
+    /// * `dilation` - controls the spacing between the kernel points, a 3-tuple for this
+    ///   three-dimensional case.
+    ///
+    /// * `groups` - number of blocked connections from input channels to output channels. Both
+    ///   `in_channels` and `out_channels` must be divisible by it.
+    ///
+    /// The weight and the bias of the layer are initialized from *U(-k, k)* where
+    /// `k = (groups /(in_channels * kernel_d * kernel_w * kernel_h) as f32).sqrt()`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<P>(
+        in_channels: usize,
+        out_channels: usize,
+        kernel_size: (usize, usize, usize),
+        padding: P,
+        padding_mode: T,
+        stride: (usize, usize, usize),
+        dilation: (usize, usize, usize),
+        groups: usize,
+    ) -> Self
+    where
+        P: Into<ConvPadding<(usize, usize, usize)>>,
+    {
+        let padding = padding.into();
+        assert!(
+            padding != ConvPadding::Same || stride == (1, 1, 1),
+            "Same padding requires a unit stride, got {:?}",
+            stride
+        );
+        assert_eq!(
+            in_channels % groups,
+            0,
+            "In channels {} is not divisible by groups {}",
+            in_channels,
+            groups
+        );
+        assert_eq!(
+            out_channels % groups,
+            0,
+            "Out channels {} is not divisible by groups {}",
+            out_channels,
+            groups
+        );
+
+        let (kernel_d, kernel_h, kernel_w) = kernel_size;
+        let weight = neuronika_variable::zeros((
+            out_channels,
+            in_channels / groups,
+            kernel_d,
+            kernel_h,
+            kernel_w,
+        ))
+        .requires_grad();
+        let bias = neuronika_variable::zeros((out_channels, 1, 1, 1)).requires_grad();
+
+        let k = (groups as f32 / (in_channels * kernel_d * kernel_h * kernel_w) as f32).sqrt();
+        init::uniform(&weight, -k, k);
+        init::uniform(&bias, -k, k);
+
+        Self {
+            padding,
+            padding_mode,
+            stride,
+            dilation,
+            groups,
+            weight,
+            bias,
+        }
+    }
+
+    register_weight_and_bias!();
+
+    /// Computes a 3-dimensional grouped convolution *(cross correlation)*.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - signal to convolve.
+    ///
+    /// The **input** must be of shape *(N, Cin, D, H, W)*
+    /// * **N** is the batch size
+    /// * **Cin** is the number of input channels
+    /// * **D** is the **depth** of the input
+    /// * **H** is the **height** of the input
+    /// * **W** is the **width** of the input
+    ///
+    /// The **kernel** must be of shape *(Cout, Cin / groups, Dk, Hk, Wk)*
+    /// * **Cout** is the number of output channels
+    /// * **Cin** is the number of input channels
+    /// * **Dk** is the **depth** of the kernel
+    /// * **Hk** is the **height** of the kernel
+    /// * **Wk** is the **width** of the kernel
+    ///
+    /// The resulting output shape will be *(N, Cout, Dout, Hout, Wout)*
+    pub fn forward<I>(&self, input: I) -> VarDiff<Ix5>
+    where
+        T: 'static,
+        I: Pad<Ix5>,
+        VarDiff<Ix5>: Convolution<I, Ix3, Output = VarDiff<Ix5>>,
+    {
+        let (before, after) = self
+            .padding
+            .sides(&self.weight.data().shape()[2..], self.dilation);
+
+        self.weight.clone().convolution(
+            input.pad_asymmetric(before, after, self.padding_mode),
+            self.stride,
+            self.dilation,
+            self.groups,
+        ) + self.bias.clone()
+    }
+}
+
+/// Applies a depthwise separable temporal convolution over an input signal composed of several
+/// input planes.
+///
+/// The layer is made of a **depthwise convolution**, which convolves each input channel with its
+/// own kernels, followed by a **pointwise convolution**, which mixes the channels with kernels of
+/// size one.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct DepthwiseSeparableConv1d<T>
+where
+    T: PaddingMode<Ix3>,
+{
+    pub depthwise: GroupedConv1d<T>,
+    pub pointwise: Conv1d<Zero>,
+}
+
+impl<T> DepthwiseSeparableConv1d<T>
+where
+    T: PaddingMode<Ix3>,
+{
+    /// Creates a new DepthwiseSeparableConv1d.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_channels` - number of planes in the input signal.
+    ///
+    /// * `out_channels` - number of planes in the output signal.
+    ///
+    /// * `kernel_size` - size of the depthwise kernel, a number for this one-dimensional case.
+    ///
+    /// * `padding` - padding to be applied to the input, a number for this one-dimensional case, or
+    ///   [`ConvPadding::Same`].
+    ///
+    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`],
+    ///   [`Replicative`] or [`Circular`](neuronika_variable::Circular).
+    ///
+    /// * `stride` - stride of the depthwise convolution, a number for this one-dimensional case.
+    ///
+    /// * `dilation` - controls the spacing between the depthwise kernel points, a number for this
+    ///   one-dimensional case.
+    ///
+    /// * `depth_multiplier` - number of output channels of the depthwise convolution for each
+    ///   input channel.
+    ///
+    /// Both convolutions are initialized as described in [`GroupedConv1d::new`] and
+    /// [`Conv1d::new`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<P>(
+        in_channels: usize,
+        out_channels: usize,
+        kernel_size: usize,
+        padding: P,
+        padding_mode: T,
+        stride: usize,
+        dilation: usize,
+        depth_multiplier: usize,
+    ) -> Self
+    where
+        P: Into<ConvPadding<usize>>,
+    {
+        let hidden_channels = in_channels * depth_multiplier;
+        let depthwise = GroupedConv1d::new(
+            in_channels,
+            hidden_channels,
+            kernel_size,
+            padding,
+            padding_mode,
+            stride,
+            dilation,
+            in_channels,
+        );
+        let pointwise = Conv1d::new(hidden_channels, out_channels, 1, 0, Zero, 1, 1);
+
+        Self {
+            depthwise,
+            pointwise,
+        }
+    }
+
+    register_sublayers!(depthwise, pointwise);
+
+    /// Computes a 1-dimensional depthwise separable convolution.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - signal to convolve, see [`GroupedConv1d::forward`] for the expected shape.
+    pub fn forward<I>(&self, input: I) -> VarDiff<Ix3>
+    where
+        T: 'static,
+        I: Pad<Ix3>,
+        VarDiff<Ix3>: Convolution<I, Ix1, Output = VarDiff<Ix3>>,
+    {
+        self.pointwise
+            .forward::<VarDiff<Ix3>>(self.depthwise.forward(input))
+    }
+}
+
+/// Applies a depthwise separable spatial convolution over an input signal composed of several
+/// input planes.
+///
+/// The layer is made of a **depthwise convolution**, which convolves each input channel with its
+/// own kernels, followed by a **pointwise convolution**, which mixes the channels with kernels of
+/// size one.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct DepthwiseSeparableConv2d<T>
+where
+    T: PaddingMode<Ix4>,
+{
+    pub depthwise: GroupedConv2d<T>,
+    pub pointwise: Conv2d<Zero>,
+}
+
+impl<T> DepthwiseSeparableConv2d<T>
+where
+    T: PaddingMode<Ix4>,
+{
+    /// Creates a new DepthwiseSeparableConv2d.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_channels` - number of planes in the input signal.
+    ///
+    /// * `out_channels` - number of planes in the output signal.
+    ///
+    /// * `kernel_size` - size of the depthwise kernel, a 2-tuple for this two-dimensional case.
+    ///
+    /// * `padding` - padding to be applied to the input, a 2-tuple for this two-dimensional case,
+    ///   or [`ConvPadding::Same`].
+    ///
+    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`],
+    ///   [`Replicative`] or [`Circular`](neuronika_variable::Circular).
+    ///
+    /// * `stride` - stride of the depthwise convolution, a 2-tuple for this two-dimensional case.
+    ///
+    /// * `dilation` - controls the spacing between the depthwise kernel points, a 2-tuple for this
+    ///   two-dimensional case.
+    ///
+    /// * `depth_multiplier` - number of output channels of the depthwise convolution for each
+    ///   input channel.
+    ///
+    /// Both convolutions are initialized as described in [`GroupedConv2d::new`] and
+    /// [`Conv2d::new`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<P>(
+        in_channels: usize,
+        out_channels: usize,
+        kernel_size: (usize, usize),
+        padding: P,
+        padding_mode: T,
+        stride: (usize, usize),
+        dilation: (usize, usize),
+        depth_multiplier: usize,
+    ) -> Self
+    where
+        P: Into<ConvPadding<(usize, usize)>>,
+    {
+        let hidden_channels = in_channels * depth_multiplier;
+        let depthwise = GroupedConv2d::new(
+            in_channels,
+            hidden_channels,
+            kernel_size,
+            padding,
+            padding_mode,
+            stride,
+            dilation,
+            in_channels,
+        );
+        let pointwise = Conv2d::new(
+            hidden_channels,
+            out_channels,
+            (1, 1),
+            (0, 0),
+            Zero,
+            (1, 1),
+            (1, 1),
+        );
+
+        Self {
+            depthwise,
+            pointwise,
+        }
+    }
+
+    register_sublayers!(depthwise, pointwise);
+
+    /// Computes a 2-dimensional depthwise separable convolution.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - signal to convolve, see [`GroupedConv2d::forward`] for the expected shape.
+    pub fn forward<I>(&self, input: I) -> VarDiff<Ix4>
+    where
+        T: 'static,
+        I: Pad<Ix4>,
+        VarDiff<Ix4>: Convolution<I, Ix2, Output = VarDiff<Ix4>>,
+    {
+        self.pointwise
+            .forward::<VarDiff<Ix4>>(self.depthwise.forward(input))
+    }
+}
+
+/// Applies a depthwise separable volumetric convolution over an input signal composed of several
+/// input planes.
+///
+/// The layer is made of a **depthwise convolution**, which convolves each input channel with its
+/// own kernels, followed by a **pointwise convolution**, which mixes the channels with kernels of
+/// size one.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct DepthwiseSeparableConv3d<T>
+where
+    T: PaddingMode<Ix5>,
+{
+    pub depthwise: GroupedConv3d<T>,
+    pub pointwise: Conv3d<Zero>,
+}
+
+impl<T> DepthwiseSeparableConv3d<T>
+where
+    T: PaddingMode<Ix5>,
+{
+    /// Creates a new DepthwiseSeparableConv3d.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_channels` - number of planes in the input signal.
+    ///
+    /// * `out_channels` - number of planes in the output signal.
+    ///
+    /// * `kernel_size` - size of the depthwise kernel, a 3-tuple for this three-dimensional case.
+    ///
+    /// * `padding` - padding to be applied to the input, a 3-tuple for this three-dimensional
+    ///   case, or [`ConvPadding::Same`].
+    ///
+    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`],
+    ///   [`Replicative`] or [`Circular`](neuronika_variable::Circular).
+    ///
+    /// * `stride` - stride of the depthwise convolution, a 3-tuple for this three-dimensional case.
+    ///
+    /// * `dilation` - controls the spacing between the depthwise kernel points, a 3-tuple for this
+    ///   three-dimensional case.
+    ///
+    /// * `depth_multiplier` - number of output channels of the depthwise convolution for each
+    ///   input channel.
+    ///
+    /// Both convolutions are initialized as described in [`GroupedConv3d::new`] and
+    /// [`Conv3d::new`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<P>(
+        in_channels: usize,
+        out_channels: usize,
+        kernel_size: (usize, usize, usize),
+        padding: P,
+        padding_mode: T,
+        stride: (usize, usize, usize),
+        dilation: (usize, usize, usize),
+        depth_multiplier: usize,
+    ) -> Self
+    where
+        P: Into<ConvPadding<(usize, usize, usize)>>,
+    {
+        let hidden_channels = in_channels * depth_multiplier;
+        let depthwise = GroupedConv3d::new(
+            in_channels,
+            hidden_channels,
+            kernel_size,
+            padding,
+            padding_mode,
+            stride,
+            dilation,
+            in_channels,
+        );
+        let pointwise = Conv3d::new(
+            hidden_channels,
+            out_channels,
+            (1, 1, 1),
+            (0, 0, 0),
+            Zero,
+            (1, 1, 1),
+            (1, 1, 1),
+        );
+
+        Self {
+            depthwise,
+            pointwise,
+        }
+    }
+
+    register_sublayers!(depthwise, pointwise);
+
+    /// Computes a 3-dimensional depthwise separable convolution.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - signal to convolve, see [`GroupedConv3d::forward`] for the expected shape.
+    pub fn forward<I>(&self, input: I) -> VarDiff<Ix5>
+    where
+        T: 'static,
+        I: Pad<Ix5>,
+        VarDiff<Ix5>: Convolution<I, Ix3, Output = VarDiff<Ix5>>,
+    {
+        self.pointwise
+            .forward::<VarDiff<Ix5>>(self.depthwise.forward(input))
+    }
+}
+
+/// Applies a temporal transposed convolution over an input signal composed of several input
+/// planes.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct ConvTranspose1d {
+    pub stride: usize,
+    pub dilation: usize,
+    pub output_padding: usize,
+    pub groups: usize,
+    pub weight: VarDiff<Ix3>,
+    pub bias: VarDiff<Ix2>,
+}
+
+impl ConvTranspose1d {
+    /// Creates a new ConvTranspose1d.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_channels` - number of planes in the input signal.
+    ///
+    /// * `out_channels` - number of planes in the output signal.
+    ///
+    /// * `kernel_size` - size of the kernel, a number for this one-dimensional case.
+    ///
+    /// * `stride` - stride of the convolution, a number for this one-dimensional case.
+    ///
+    /// * `dilation` - controls the spacing between the kernel points, a number for this
+    ///   one-dimensional case.
+    ///
+    /// * `output_padding` - additional size added to one side of the output, a number for this
+    ///   one-dimensional case. It must be smaller than the stride.
+    ///
+    /// * `groups` - number of blocked connections from input channels to output channels. Both
+    ///   `in_channels` and `out_channels` must be divisible by it.
+    ///
+    /// The weight and the bias of the layer are initialized from *U(-k, k)* where
+    /// `k = (groups /(out_channels * kernel_size) as f32).sqrt()`.
+    ///
+    /// # Panics
+    ///
+    /// If `in_channels` or `out_channels` is not divisible by `groups`.
+    pub fn new(
+        in_channels: usize,
+        out_channels: usize,
+        kernel_size: usize,
+        stride: usize,
+        dilation: usize,
+        output_padding: usize,
+        groups: usize,
+    ) -> Self {
+        assert_eq!(
+            in_channels % groups,
+            0,
+            "In channels {} is not divisible by groups {}",
+            in_channels,
+            groups
+        );
+        assert_eq!(
+            out_channels % groups,
+            0,
+            "Out channels {} is not divisible by groups {}",
+            out_channels,
+            groups
+        );
+
+        let weight = neuronika_variable::zeros((in_channels, out_channels / groups, kernel_size))
+            .requires_grad();
+        let bias = neuronika_variable::zeros((out_channels, 1)).requires_grad();
+
+        let k = (groups as f32 / (out_channels * kernel_size) as f32).sqrt();
+        init::uniform(&weight, -k, k);
+        init::uniform(&bias, -k, k);
+
+        Self {
+            stride,
+            dilation,
+            output_padding,
+            groups,
+            weight,
+            bias,
+        }
+    }
+
+    register_weight_and_bias!();
+
+    /// Computes a 1-dimensional transposed convolution.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - signal to convolve.
+    ///
+    /// The **input** must be of shape *(N, Cin, L)*
+    /// * **N** is the batch size
+    /// * **Cin** is the number of input channels
+    /// * **L** is the **length** of the input
+    ///
+    /// The **kernel** must be of shape *(Cin, Cout / groups, Lk)*
+    /// * **Cin** is the number of input channels
+    /// * **Cout** is the number of output channels
+    /// * **Lk** is the **length** of the kernel
+    ///
+    /// The resulting output shape will be *(N, Cout, Lout)*
+    pub fn forward<I>(&self, input: I) -> VarDiff<Ix3>
+    where
+        VarDiff<Ix3>: ConvolutionTranspose<I, Ix1, Output = VarDiff<Ix3>>,
+    {
+        self.weight.clone().convolution_transpose(
+            input,
+            self.stride,
+            self.dilation,
+            self.output_padding,
+            self.groups,
+        ) + self.bias.clone()
+    }
+}
+
+/// Applies a spatial transposed convolution over an input signal composed of several input
+/// planes.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct ConvTranspose2d {
+    pub stride: (usize, usize),
+    pub dilation: (usize, usize),
+    pub output_padding: (usize, usize),
+    pub groups: usize,
+    pub weight: VarDiff<Ix4>,
+    pub bias: VarDiff<Ix3>,
+}
+
+impl ConvTranspose2d {
+    /// Creates a new ConvTranspose2d.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_channels` - number of planes in the input signal.
+    ///
+    /// * `out_channels` - number of planes in the output signal.
+    ///
+    /// * `kernel_size` - size of the kernel, a 2-tuple for this two-dimensional case.
+    ///
+    /// * `stride` - stride of the convolution, a 2-tuple for this two-dimensional case.
+    ///
+    /// * `dilation` - controls the spacing between the kernel points, a 2-tuple for this
+    ///   two-dimensional case.
+    ///
+    /// * `output_padding` - additional size added to one side of each dimension of the output, a
+    ///   2-tuple for this two-dimensional case. It must be smaller than the stride.
+    ///
+    /// * `groups` - number of blocked connections from input channels to output channels. Both
+    ///   `in_channels` and `out_channels` must be divisible by it.
+    ///
+    /// The weight and the bias of the layer are initialized from *U(-k, k)* where
+    /// `k = (groups /(out_channels * kernel_w * kernel_h) as f32).sqrt()`.
+    ///
+    /// # Panics
+    ///
+    /// If `in_channels` or `out_channels` is not divisible by `groups`.
+    pub fn new(
+        in_channels: usize,
+        out_channels: usize,
+        kernel_size: (usize, usize),
+        stride: (usize, usize),
+        dilation: (usize, usize),
+        output_padding: (usize, usize),
+        groups: usize,
+    ) -> Self {
+        assert_eq!(
+            in_channels % groups,
+            0,
+            "In channels {} is not divisible by groups {}",
+            in_channels,
+            groups
+        );
+        assert_eq!(
+            out_channels % groups,
+            0,
+            "Out channels {} is not divisible by groups {}",
+            out_channels,
+            groups
+        );
+
+        let (kernel_h, kernel_w) = kernel_size;
+        let weight =
+            neuronika_variable::zeros((in_channels, out_channels / groups, kernel_h, kernel_w))
+                .requires_grad();
+        let bias = neuronika_variable::zeros((out_channels, 1, 1)).requires_grad();
+
+        let k = (groups as f32 / (out_channels * kernel_h * kernel_w) as f32).sqrt();
+        init::uniform(&weight, -k, k);
+        init::uniform(&bias, -k, k);
+
+        Self {
+            stride,
+            dilation,
+            output_padding,
+            groups,
+            weight,
+            bias,
+        }
+    }
+
+    register_weight_and_bias!();
+
+    /// Computes a 2-dimensional transposed convolution.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - signal to convolve.
+    ///
+    /// The **input** must be of shape *(N, Cin, H, W)*
+    /// * **N** is the batch size
+    /// * **Cin** is the number of input channels
+    /// * **H** is the **height** of the input
+    /// * **W** is the **width** of the input
+    ///
+    /// The **kernel** must be of shape *(Cin, Cout / groups, Hk, Wk)*
+    /// * **Cin** is the number of input channels
+    /// * **Cout** is the number of output channels
+    /// * **Hk** is the **height** of the kernel
+    /// * **Wk** is the **width** of the kernel
+    ///
+    /// The resulting output shape will be *(N, Cout, Hout, Wout)*
+    pub fn forward<I>(&self, input: I) -> VarDiff<Ix4>
+    where
+        VarDiff<Ix4>: ConvolutionTranspose<I, Ix2, Output = VarDiff<Ix4>>,
+    {
+        self.weight.clone().convolution_transpose(
+            input,
+            self.stride,
+            self.dilation,
+            self.output_padding,
+            self.groups,
+        ) + self.bias.clone()
+    }
+}
+
+/// Applies a volumetric transposed convolution over an input signal composed of several input
+/// planes.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct ConvTranspose3d {
+    pub stride: (usize, usize, usize),
+    pub dilation: (usize, usize, usize),
+    pub output_padding: (usize, usize, usize),
+    pub groups: usize,
+    pub weight: VarDiff<Ix5>,
+    pub bias: VarDiff<Ix4>,
+}
+
+impl ConvTranspose3d {
+    /// Creates a new ConvTranspose3d.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_channels` - number of planes in the input signal.
+    ///
+    /// * `out_channels` - number of planes in the output signal.
+    ///
+    /// * `kernel_size` - size of the kernel, a 3-tuple for this three-dimensional case.
+    ///
+    /// * `stride` - stride of the convolution, a 3-tuple for this three-dimensional case.
+    ///
+    /// * `dilation` - controls the spacing between the kernel points, a 3-tuple for this
+    ///   three-dimensional case.
+    ///
+    /// * `output_padding` - additional size added to one side of each dimension of the output, a
+    ///   3-tuple for this three-dimensional case. It must be smaller than the stride.
+    ///
+    /// * `groups` - number of blocked connections from input channels to output channels. Both
+    ///   `in_channels` and `out_channels` must be divisible by it.
+    ///
+    /// The weight and the bias of the layer are initialized from *U(-k, k)* where
+    /// `k = (groups /(out_channels * kernel_d * kernel_w * kernel_h) as f32).sqrt()`.
+    ///
+    /// # Panics
+    ///
+    /// If `in_channels` or `out_channels` is not divisible by `groups`.
+    pub fn new(
+        in_channels: usize,
+        out_channels: usize,
+        kernel_size: (usize, usize, usize),
+        stride: (usize, usize, usize),
+        dilation: (usize, usize, usize),
+        output_padding: (usize, usize, usize),
+        groups: usize,
+    ) -> Self {
+        assert_eq!(
+            in_channels % groups,
+            0,
+            "In channels {} is not divisible by groups {}",
+            in_channels,
+            groups
+        );
+        assert_eq!(
+            out_channels % groups,
+            0,
+            "Out channels {} is not divisible by groups {}",
+            out_channels,
+            groups
+        );
+
+        let (kernel_d, kernel_h, kernel_w) = kernel_size;
+        let weight = neuronika_variable::zeros((
+            in_channels,
+            out_channels / groups,
+            kernel_d,
+            kernel_h,
+            kernel_w,
+        ))
+        .requires_grad();
+        let bias = neuronika_variable::zeros((out_channels, 1, 1, 1)).requires_grad();
+
+        let k = (groups as f32 / (out_channels * kernel_d * kernel_h * kernel_w) as f32).sqrt();
+        init::uniform(&weight, -k, k);
+        init::uniform(&bias, -k, k);
+
+        Self {
+            stride,
+            dilation,
+            output_padding,
+            groups,
+            weight,
+            bias,
+        }
+    }
+
+    register_weight_and_bias!();
+
+    /// Computes a 3-dimensional transposed convolution.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - signal to convolve.
+    ///
+    /// The **input** must be of shape *(N, Cin, D, H, W)*
+    /// * **N** is the batch size
+    /// * **Cin** is the number of input channels
+    /// * **D** is the **depth** of the input
+    /// * **H** is the **height** of the input
+    /// * **W** is the **width** of the input
+    ///
+    /// The **kernel** must be of shape *(Cin, Cout / groups, Dk, Hk, Wk)*
+    /// * **Cin** is the number of input channels
+    /// * **Cout** is the number of output channels
+    /// * **Dk** is the **depth** of the kernel
+    /// * **Hk** is the **height** of the kernel
+    /// * **Wk** is the **width** of the kernel
+    ///
+    /// The resulting output shape will be *(N, Cout, Dout, Hout, Wout)*
+    pub fn forward<I>(&self, input: I) -> VarDiff<Ix5>
+    where
+        VarDiff<Ix5>: ConvolutionTranspose<I, Ix3, Output = VarDiff<Ix5>>,
+    {
+        self.weight.clone().convolution_transpose(
+            input,
+            self.stride,
+            self.dilation,
+            self.output_padding,
+            self.groups,
+        ) + self.bias.clone()
+    }
+}
+
+/// Upsamples the spatial dimensions of an input signal composed of several input planes.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct Upsample {
+    pub scale_factor: f32,
+    pub mode: Interpolation,
+    pub align_corners: bool,
+}
+
+impl Upsample {
+    /// Creates a new Upsample.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale_factor` - multiplier of the length of each spatial dimension.
+    ///
+    /// * `mode` - interpolation algorithm, either [`Interpolation::Nearest`],
+    ///   [`Interpolation::Linear`] or [`Interpolation::Cubic`].
+    ///
+    /// * `align_corners` - whether the centers of the corner elements of the input and of the
+    ///   output are aligned. It doesn't affect [`Interpolation::Nearest`].
+    pub fn new(scale_factor: f32, mode: Interpolation, align_corners: bool) -> Self {
+        Self {
+            scale_factor,
+            mode,
+            align_corners,
+        }
+    }
+
+    /// Upsamples the input.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - signal to upsample.
+    ///
+    /// The **input** must be of shape *(N, C, ...)*, where **N** is the batch size, **C** is the
+    /// number of channels and the remaining one, two or three dimensions are the spatial ones.
+    ///
+    /// The resulting output shape will be *(N, C, ...)*, where the length of each spatial dimension
+    /// is multiplied by the scale factor and rounded down.
+    pub fn forward<D>(&self, input: VarDiff<D>) -> VarDiff<D>
+    where
+        D: 'static + Dimension,
+        D::Smaller: RemoveAxis,
+        <D::Smaller as Dimension>::Smaller: Copy,
+    {
+        input.upsample(self.scale_factor, self.mode, self.align_corners)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,501 @@
+use ndarray::Array;
+
+use super::*;
+
+mod conv_transpose {
+    use super::*;
+
+    #[test]
+    fn conv_transpose1d() {
+        let layer = ConvTranspose1d::new(4, 6, 3, 2, 1, 1, 2);
+        assert_eq!(layer.weight.data().shape(), &[4, 3, 3]);
+
+        let output = layer.forward(neuronika_variable::rand((2, 4, 5)));
+        output.forward();
+        assert_eq!(output.data().shape(), &[2, 6, 12]);
+
+        output.backward(1.);
+        assert_eq!(layer.weight.grad().shape(), &[4, 3, 3]);
+        assert_eq!(
+            *layer.bias.grad(),
+            Array::<f32, Ix2>::from_elem((6, 1), 24.)
+        );
+    }
+
+    #[test]
+    fn conv_transpose2d() {
+        let layer = ConvTranspose2d::new(2, 4, (3, 2), (1, 2), (1, 1), (0, 1), 1);
+        assert_eq!(layer.weight.data().shape(), &[2, 4, 3, 2]);
+
+        let output = layer.forward(neuronika_variable::rand((1, 2, 4, 3)));
+        output.forward();
+        assert_eq!(output.data().shape(), &[1, 4, 6, 7]);
+
+        output.backward(1.);
+        assert_eq!(layer.weight.grad().shape(), &[2, 4, 3, 2]);
+        assert_eq!(
+            *layer.bias.grad(),
+            Array::<f32, Ix3>::from_elem((4, 1, 1), 42.)
+        );
+    }
+
+    #[test]
+    fn conv_transpose3d() {
+        let layer = ConvTranspose3d::new(2, 2, (2, 2, 2), (2, 2, 2), (1, 1, 1), (0, 0, 0), 2);
+        assert_eq!(layer.weight.data().shape(), &[2, 1, 2, 2, 2]);
+
+        let output = layer.forward(neuronika_variable::rand((1, 2, 3, 3, 3)));
+        output.forward();
+        assert_eq!(output.data().shape(), &[1, 2, 6, 6, 6]);
+
+        output.backward(1.);
+        assert_eq!(layer.weight.grad().shape(), &[2, 1, 2, 2, 2]);
+        assert_eq!(
+            *layer.bias.grad(),
+            Array::<f32, Ix4>::from_elem((2, 1, 1, 1), 216.)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "In channels 3 is not divisible by groups 2")]
+    fn conv_transpose_in_channels_groups() {
+        ConvTranspose1d::new(3, 4, 3, 1, 1, 0, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Out channels 3 is not divisible by groups 2")]
+    fn conv_transpose_out_channels_groups() {
+        ConvTranspose2d::new(4, 3, (3, 3), (1, 1), (1, 1), (0, 0), 2);
+    }
+}
+
+mod grouped_conv {
+    use super::*;
+
+    #[test]
+    fn grouped_conv1d() {
+        let layer = GroupedConv1d::new(4, 6, 3, 1, Zero, 1, 1, 2);
+        assert_eq!(layer.weight.data().shape(), &[6, 2, 3]);
+
+        let output = layer.forward(neuronika_variable::rand((2, 4, 7)));
+        output.forward();
+        assert_eq!(output.data().shape(), &[2, 6, 7]);
+
+        output.backward(1.);
+        assert_eq!(layer.weight.grad().shape(), &[6, 2, 3]);
+        assert_eq!(
+            *layer.bias.grad(),
+            Array::<f32, Ix2>::from_elem((6, 1), 14.)
+        );
+    }
+
+    #[test]
+    fn grouped_conv2d() {
+        let layer = GroupedConv2d::new(4, 4, (3, 3), (0, 0), Zero, (2, 2), (1, 1), 4);
+        assert_eq!(layer.weight.data().shape(), &[4, 1, 3, 3]);
+
+        let output = layer.forward(neuronika_variable::rand((1, 4, 7, 7)));
+        output.forward();
+        assert_eq!(output.data().shape(), &[1, 4, 3, 3]);
+
+        output.backward(1.);
+        assert_eq!(layer.weight.grad().shape(), &[4, 1, 3, 3]);
+        assert_eq!(
+            *layer.bias.grad(),
+            Array::<f32, Ix3>::from_elem((4, 1, 1), 9.)
+        );
+    }
+
+    #[test]
+    fn grouped_conv3d() {
+        let layer = GroupedConv3d::new(2, 4, (2, 2, 2), (0, 0, 0), Zero, (1, 1, 1), (1, 1, 1), 2);
+        assert_eq!(layer.weight.data().shape(), &[4, 1, 2, 2, 2]);
+
+        let output = layer.forward(neuronika_variable::rand((1, 2, 3, 3, 3)));
+        output.forward();
+        assert_eq!(output.data().shape(), &[1, 4, 2, 2, 2]);
+
+        output.backward(1.);
+        assert_eq!(layer.weight.grad().shape(), &[4, 1, 2, 2, 2]);
+        assert_eq!(
+            *layer.bias.grad(),
+            Array::<f32, Ix4>::from_elem((4, 1, 1, 1), 8.)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "In channels 3 is not divisible by groups 2")]
+    fn grouped_conv_in_channels_groups() {
+        GroupedConv1d::new(3, 4, 3, 0, Zero, 1, 1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Out channels 3 is not divisible by groups 2")]
+    fn grouped_conv_out_channels_groups() {
+        GroupedConv2d::new(4, 3, (3, 3), (0, 0), Zero, (1, 1), (1, 1), 2);
+    }
+}
+
+mod depthwise_separable_conv {
+    use super::*;
+
+    #[test]
+    fn depthwise_separable_conv1d() {
+        let layer = DepthwiseSeparableConv1d::new(3, 5, 3, 1, Zero, 1, 1, 2);
+        assert_eq!(layer.depthwise.weight.data().shape(), &[6, 1, 3]);
+        assert_eq!(layer.pointwise.weight.data().shape(), &[5, 6, 1]);
+
+        let output = layer.forward(neuronika_variable::rand((2, 3, 8)));
+        output.forward();
+        assert_eq!(output.data().shape(), &[2, 5, 8]);
+
+        output.backward(1.);
+        assert_eq!(layer.depthwise.weight.grad().shape(), &[6, 1, 3]);
+        assert_eq!(
+            *layer.pointwise.bias.grad(),
+            Array::<f32, Ix2>::from_elem((5, 1), 16.)
+        );
+    }
+
+    #[test]
+    fn depthwise_separable_conv2d() {
+        let layer = DepthwiseSeparableConv2d::new(2, 4, (3, 3), (1, 1), Zero, (1, 1), (1, 1), 1);
+        assert_eq!(layer.depthwise.weight.data().shape(), &[2, 1, 3, 3]);
+        assert_eq!(layer.pointwise.weight.data().shape(), &[4, 2, 1, 1]);
+
+        let output = layer.forward(neuronika_variable::rand((1, 2, 5, 5)));
+        output.forward();
+        assert_eq!(output.data().shape(), &[1, 4, 5, 5]);
+
+        output.backward(1.);
+        assert_eq!(layer.depthwise.weight.grad().shape(), &[2, 1, 3, 3]);
+        assert_eq!(
+            *layer.pointwise.bias.grad(),
+            Array::<f32, Ix3>::from_elem((4, 1, 1), 25.)
+        );
+    }
+
+    #[test]
+    fn depthwise_separable_conv3d() {
+        let layer = DepthwiseSeparableConv3d::new(
+            2,
+            3,
+            (3, 3, 3),
+            (0, 0, 0),
+            Zero,
+            (1, 1, 1),
+            (1, 1, 1),
+            1,
+        );
+        assert_eq!(layer.depthwise.weight.data().shape(), &[2, 1, 3, 3, 3]);
+        assert_eq!(layer.pointwise.weight.data().shape(), &[3, 2, 1, 1, 1]);
+
+        let output = layer.forward(neuronika_variable::rand((1, 2, 4, 4, 4)));
+        output.forward();
+        assert_eq!(output.data().shape(), &[1, 3, 2, 2, 2]);
+
+        output.backward(1.);
+        assert_eq!(layer.depthwise.weight.grad().shape(), &[2, 1, 3, 3, 3]);
+        assert_eq!(
+            *layer.pointwise.bias.grad(),
+            Array::<f32, Ix4>::from_elem((3, 1, 1, 1), 8.)
+        );
+    }
+
+    #[test]
+    fn register_parameters() {
+        let layer = DepthwiseSeparableConv1d::new(2, 4, 3, 0, Zero, 1, 1, 1);
+        let mut parameters = ParameterDict::new();
+        layer.register_parameters("block", &mut parameters);
+
+        let state = parameters.state_dict();
+        let names: Vec<&str> = state.keys().map(String::as_str).collect();
+        assert_eq!(
+            names,
+            [
+                "block.depthwise.bias",
+                "block.depthwise.weight",
+                "block.pointwise.bias",
+                "block.pointwise.weight",
+            ]
+        );
+    }
+}
+
+mod upsample {
+    use super::*;
+
+    #[test]
+    fn nearest() {
+        let input = neuronika_variable::from_ndarray(
+            Array::from_shape_vec((1, 1, 2, 2), vec![1., 2., 3., 4.]).unwrap(),
+        )
+        .requires_grad();
+        let output = Upsample::new(2., Interpolation::Nearest, false).forward(input.clone());
+        output.forward();
+        assert_eq!(
+            *output.data(),
+            Array::from_shape_vec(
+                (1, 1, 4, 4),
+                vec![
+                    1., 1., 2., 2., //
+                    1., 1., 2., 2., //
+                    3., 3., 4., 4., //
+                    3., 3., 4., 4.,
+                ]
+            )
+            .unwrap()
+        );
+
+        output.backward(1.);
+        assert_eq!(*input.grad(), Array::from_elem((1, 1, 2, 2), 4.));
+    }
+
+    #[test]
+    fn linear() {
+        let input = neuronika_variable::rand((1, 2, 4)).requires_grad();
+        let output = Upsample::new(1.5, Interpolation::Linear, true).forward(input.clone());
+        output.forward();
+        assert_eq!(output.data().shape(), &[1, 2, 6]);
+
+        output.backward(1.);
+        assert!((input.grad().sum() - 12.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn trilinear() {
+        let input = neuronika_variable::rand((1, 1, 2, 2, 2)).requires_grad();
+        let output = Upsample::new(2., Interpolation::Linear, false).forward(input.clone());
+        output.forward();
+        assert_eq!(output.data().shape(), &[1, 1, 4, 4, 4]);
+
+        output.backward(1.);
+        assert!((input.grad().sum() - 64.).abs() < 1e-4);
+    }
+
+    #[test]
+    fn bicubic() {
+        let input = neuronika_variable::rand((2, 1, 3, 3)).requires_grad();
+        let output = Upsample::new(2., Interpolation::Cubic, false).forward(input.clone());
+        output.forward();
+        assert_eq!(output.data().shape(), &[2, 1, 6, 6]);
+
+        output.backward(1.);
+        assert!((input.grad().sum() - 72.).abs() < 1e-4);
+    }
+}
+
+mod conv_padding {
+    use super::*;
+
+    #[test]
+    fn explicit_sides() {
+        let (before, after) = ConvPadding::from((1, 2)).sides(&[3, 3], (1, 1));
+        assert_eq!(before, ndarray::Dim([1, 2]));
+        assert_eq!(after, ndarray::Dim([1, 2]));
+    }
+
+    #[test]
+    fn same_sides() {
+        let (before, after) = ConvPadding::<usize>::Same.sides(&[3], 1);
+        assert_eq!((before, after), (ndarray::Dim(1), ndarray::Dim(1)));
+
+        // The extra element of an even dilated kernel goes after.
+        let (before, after) = ConvPadding::<usize>::Same.sides(&[4], 1);
+        assert_eq!((before, after), (ndarray::Dim(1), ndarray::Dim(2)));
+
+        let (before, after) = ConvPadding::<(usize, usize)>::Same.sides(&[4, 3], (1, 2));
+        assert_eq!(before, ndarray::Dim([1, 2]));
+        assert_eq!(after, ndarray::Dim([2, 2]));
+    }
+
+    #[test]
+    fn same_even_kernel() {
+        let layer = Conv1d::new(1, 1, 2, ConvPadding::Same, Zero, 1, 1);
+        layer.weight.data_mut().fill(1.);
+        layer.bias.data_mut().fill(0.);
+
+        let input = neuronika_variable::from_ndarray(
+            Array::from_shape_vec((1, 1, 3), vec![1., 2., 3.]).unwrap(),
+        );
+        let output = layer.forward(input);
+        output.forward();
+        assert_eq!(
+            *output.data(),
+            Array::from_shape_vec((1, 1, 3), vec![3., 5., 3.]).unwrap()
+        );
+    }
+
+    #[test]
+    fn same_conv1d() {
+        let layer = Conv1d::new(2, 3, 4, ConvPadding::Same, Zero, 1, 2);
+
+        let output = layer.forward(neuronika_variable::rand((2, 2, 7)));
+        output.forward();
+        assert_eq!(output.data().shape(), &[2, 3, 7]);
+
+        output.backward(1.);
+        assert_eq!(layer.weight.grad().shape(), &[3, 2, 4]);
+        assert_eq!(
+            *layer.bias.grad(),
+            Array::<f32, Ix2>::from_elem((3, 1), 14.)
+        );
+    }
+
+    #[test]
+    fn same_conv2d() {
+        let layer = GroupedConv2d::new(
+            2,
+            4,
+            (2, 3),
+            ConvPadding::Same,
+            neuronika_variable::Reflective,
+            (1, 1),
+            (1, 2),
+            2,
+        );
+
+        let output = layer.forward(neuronika_variable::rand((1, 2, 5, 6)));
+        output.forward();
+        assert_eq!(output.data().shape(), &[1, 4, 5, 6]);
+
+        output.backward(1.);
+        assert_eq!(
+            *layer.bias.grad(),
+            Array::<f32, Ix3>::from_elem((4, 1, 1), 30.)
+        );
+    }
+
+    #[test]
+    fn same_conv3d() {
+        let layer = Conv3d::new(
+            1,
+            2,
+            (2, 2, 3),
+            ConvPadding::Same,
+            neuronika_variable::Circular,
+            (1, 1, 1),
+            (1, 1, 1),
+        );
+
+        let output = layer.forward(neuronika_variable::rand((1, 1, 3, 4, 5)));
+        output.forward();
+        assert_eq!(output.data().shape(), &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Same padding requires a unit stride, got 2")]
+    fn same_stride() {
+        Conv1d::new(1, 1, 3, ConvPadding::Same, Zero, 2, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Same padding requires a unit stride, got (1, 2)")]
+    fn same_stride_grouped() {
+        GroupedConv2d::new(2, 2, (3, 3), ConvPadding::Same, Zero, (1, 2), (1, 1), 2);
+    }
+}
+
+mod prelu {
+    use super::*;
+
+    #[test]
+    fn shared_slope() {
+        let layer = PReLU::new(1, 0.25);
+        let input =
+            neuronika_variable::from_ndarray(ndarray::array![-2., -1., 0.5, 3.]).requires_grad();
+
+        let output = layer.forward(input.clone());
+        output.forward();
+        assert_eq!(*output.data(), ndarray::array![-0.5, -0.25, 0.5, 3.]);
+
+        output.backward(1.);
+        assert_eq!(*layer.weight.grad(), ndarray::array![-3.]);
+        assert_eq!(*input.grad(), ndarray::array![0.25, 0.25, 1., 1.]);
+    }
+
+    #[test]
+    fn per_feature_slope() {
+        let layer = PReLU::new(3, 0.1);
+        layer
+            .weight
+            .data_mut()
+            .assign(&ndarray::array![0.1, 0.2, 0.3]);
+        let input =
+            neuronika_variable::from_ndarray(ndarray::array![[-1., 2., -3.], [-4., -5., 6.]])
+                .requires_grad();
+
+        let output = layer.forward(input.clone());
+        output.forward();
+        assert!(output
+            .data()
+            .iter()
+            .zip(&[-0.1, 2., -0.9, -0.4, -1., 6.])
+            .all(|(a, b)| (a - b).abs() < 1e-6));
+
+        output.backward(1.);
+        assert_eq!(*layer.weight.grad(), ndarray::array![-5., -5., -3.]);
+        assert!(input
+            .grad()
+            .iter()
+            .zip(&[0.1, 1., 0.3, 0.1, 0.2, 1.])
+            .all(|(a, b)| (a - b).abs() < 1e-6));
+    }
+
+    #[test]
+    fn per_channel_slope() {
+        let layer = PReLU::new(2, 0.1);
+        layer.weight.data_mut().assign(&ndarray::array![0.1, 0.2]);
+        // The width equals the number of channels, so that slopes laid along the last dimension
+        // would go unnoticed by the shapes.
+        let input = neuronika_variable::from_ndarray(-Array::ones((2, 2, 3, 2))).requires_grad();
+
+        let output = layer.forward(input.clone());
+        output.forward();
+        assert_eq!(output.data().shape(), &[2, 2, 3, 2]);
+        for (channel, slope) in [(0, 0.1), (1, 0.2)] {
+            assert!(output
+                .data()
+                .index_axis(ndarray::Axis(1), channel)
+                .iter()
+                .all(|el| (el + slope).abs() < 1e-6));
+        }
+
+        output.backward(1.);
+        assert_eq!(*layer.weight.grad(), ndarray::array![-12., -12.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn wrong_number_of_channels() {
+        let layer = PReLU::new(4, 0.1);
+        layer.forward(neuronika_variable::zeros((2, 3, 4)).requires_grad());
+    }
+}
+
+mod register_parameters {
+    use super::*;
+
+    #[test]
+    fn weight_and_bias() {
+        let mut parameters = ParameterDict::new();
+        Linear::new(3, 2).register_parameters("fc", &mut parameters);
+        ConvTranspose2d::new(2, 4, (3, 3), (1, 1), (1, 1), (0, 0), 1)
+            .register_parameters("decoder.0", &mut parameters);
+
+        let state = parameters.state_dict();
+        let names: Vec<(&str, &[usize])> = state
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.shape()))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("decoder.0.bias", &[4, 1, 1][..]),
+                ("decoder.0.weight", &[2, 4, 3, 3][..]),
+                ("fc.bias", &[2][..]),
+                ("fc.weight", &[2, 3][..]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use std::{cell::Cell, rc::Rc};
+
+use ndarray::{Array, Dimension, Zip};
+
+use neuronika_variable::VarDiff;
+
+use super::{
+    GroupOverrides, GroupStatus, IntoParam, Optimize, Optimizer, OptimizerStatus, Penalty,
+};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::StateDict;
+
+#[cfg(feature = "serialize")]
+use super::assign;
+
+/// Adadelta optimizer.
+///
+/// It has been proposed in
+/// [ADADELTA: An Adaptive Learning Rate Method](https://arxiv.org/abs/1212.5701).
+#[derive(Clone)]
+pub struct Adadelta<T>
+where
+    T: Penalty,
+{
+    lr: Cell<f32>,
+    penalty: T,
+    rho: Cell<f32>,
+    eps: Cell<f32>,
+}
+
+impl<T> OptimizerStatus for Adadelta<T>
+where
+    T: Penalty,
+{
+    fn get_lr(&self) -> f32 {
+        self.lr.get()
+    }
+
+    fn set_lr(&self, lr: f32) {
+        self.lr.set(lr)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn hyperparameters(&self) -> Vec<(&'static str, f32)> {
+        vec![
+            ("lr", self.lr.get()),
+            ("rho", self.rho.get()),
+            ("eps", self.eps.get()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn set_hyperparameter(&self, name: &str, value: f32) {
+        match name {
+            "lr" => self.lr.set(value),
+            "rho" => self.rho.set(value),
+            "eps" => self.eps.set(value),
+            _ => (),
+        }
+    }
+}
+
+impl<T> GroupStatus for Adadelta<T>
+where
+    T: Penalty,
+{
+    fn with_overrides(&self, overrides: &GroupOverrides) -> Self {
+        overrides.reject_momentum("Adadelta");
+
+        let mut status = self.clone();
+        if let Some(lr) = overrides.get_lr() {
+            status.lr.set(lr);
+        }
+        if let Some(weight_decay) = overrides.get_weight_decay() {
+            status.penalty = status.penalty.with_weight_decay(weight_decay);
+        }
+
+        status
+    }
+}
+
+impl<T> Adadelta<T>
+where
+    T: Penalty,
+{
+    /// Creates a new Adadelta optimizer.
+    ///
+    /// # Arguments
+    ///
+    /// * `lr` - coefficient that scales the delta before it is applied to the parameters. A good
+    ///   default is 1.0.
+    ///
+    /// * `rho` - coefficient for computing the running averages of the squared gradient and of
+    ///   the squared delta. A good default is 0.9.
+    ///
+    /// * `penalty` - penalty regularization.
+    ///
+    /// * `eps` - small constant for numerical stability. A good default value is *1e-6*.
+    pub fn new(lr: f32, rho: f32, penalty: T, eps: f32) -> Optimizer<Self> {
+        let lr = Cell::new(lr);
+        let rho = Cell::new(rho);
+        let eps = Cell::new(eps);
+
+        let status = Self {
+            lr,
+            penalty,
+            rho,
+            eps,
+        };
+
+        Optimizer::new(status)
+    }
+
+    /// Return the current learning rate.
+    pub fn get_lr(&self) -> f32 {
+        OptimizerStatus::get_lr(self)
+    }
+
+    /// Sets a new value for the learning rate.
+    pub fn set_lr(&self, lr: f32) {
+        OptimizerStatus::set_lr(self, lr);
+    }
+
+    /// Return the current running averages coefficient.
+    pub fn get_rho(&self) -> f32 {
+        self.rho.get()
+    }
+
+    /// Sets a new value for the running averages coefficient.
+    pub fn set_rho(&self, rho: f32) {
+        self.rho.set(rho)
+    }
+
+    /// Return the current epsilon constant.
+    pub fn get_eps(&self) -> f32 {
+        self.eps.get()
+    }
+
+    /// Sets a new value for the epsilon constant.
+    pub fn set_eps(&self, eps: f32) {
+        self.eps.set(eps)
+    }
+}
+
+/// A parameter used by the Adadelta optimizer.
+pub struct AdadeltaParam<D, T>
+where
+    D: Dimension,
+    T: Penalty,
+{
+    variable: VarDiff<D>,
+    square_avg: Array<f32, D>,
+    acc_delta: Array<f32, D>,
+    status: Rc<Adadelta<T>>,
+}
+
+impl<D, T> IntoParam<Adadelta<T>> for VarDiff<D>
+where
+    D: 'static + Dimension,
+    T: 'static + Penalty,
+{
+    type Param = AdadeltaParam<D, T>;
+
+    fn into_param(self, status: Rc<Adadelta<T>>) -> Self::Param {
+        let variable = self;
+        let square_avg = Array::zeros(variable.data().raw_dim());
+        let acc_delta = square_avg.clone();
+
+        Self::Param {
+            variable,
+            square_avg,
+            acc_delta,
+            status,
+        }
+    }
+}
+
+impl<D, T> Optimize for AdadeltaParam<D, T>
+where
+    D: Dimension,
+    T: Penalty,
+{
+    fn optimize(&mut self) {
+        let lr = self.status.get_lr();
+        let rho = self.status.get_rho();
+        let penalty = self.status.penalty;
+        let eps = self.status.get_eps();
+
+        let mut data = self.variable.data_mut();
+        let mut grad = self.variable.grad_mut();
+
+        Zip::from(&mut *grad)
+            .and(&*data)
+            .for_each(|grad_el, data_el| *grad_el += penalty.penalize(data_el));
+
+        Zip::from(&mut self.square_avg)
+            .and(&*grad)
+            .for_each(|square_avg_el, grad_el| {
+                *square_avg_el = *square_avg_el * rho + grad_el * grad_el * (1.0 - rho)
+            });
+
+        Zip::from(&mut *data)
+            .and(&*grad)
+            .and(&self.square_avg)
+            .and(&mut self.acc_delta)
+            .for_each(|data_el, grad_el, square_avg_el, acc_delta_el| {
+                let delta = (*acc_delta_el + eps).sqrt() / (square_avg_el + eps).sqrt() * grad_el;
+                *acc_delta_el = *acc_delta_el * rho + delta * delta * (1.0 - rho);
+                *data_el -= delta * lr;
+            });
+    }
+
+    fn zero_grad(&mut self) {
+        self.variable.zero_grad()
+    }
+
+    fn for_each_grad(&mut self, f: &mut dyn FnMut(&mut f32)) {
+        self.variable.grad_mut().iter_mut().for_each(f)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(&'static str, ArrayD<f32>)> {
+        vec![
+            ("square_avg", self.square_avg.clone().into_dyn()),
+            ("acc_delta", self.acc_delta.clone().into_dyn()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&mut self, state: &StateDict) {
+        assign(&mut self.square_avg, &state["square_avg"]);
+        assign(&mut self.acc_delta, &state["acc_delta"]);
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -4,11 +4,23 @@ use ndarray::{Array, Dimension, Zip};
 
 use neuronika_variable::VarDiff;
 
-use super::{IntoParam, Optimize, Optimizer, OptimizerStatus, Penalty};
+use super::{
+    GroupOverrides, GroupStatus, IntoParam, Optimize, Optimizer, OptimizerStatus, Penalty,
+};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::StateDict;
+
+#[cfg(feature = "serialize")]
+use super::{assign, counter, to_counter};
 
 /// Adagrad optimizer.
 ///
 /// The algorithm has been proposed in [this paper](http://jmlr.org/papers/v12/duchi11a.html).
+#[derive(Clone)]
 pub struct Adagrad<T>
 where
     T: Penalty,
@@ -30,6 +42,44 @@ where
     fn set_lr(&self, lr: f32) {
         self.lr.set(lr)
     }
+
+    #[cfg(feature = "serialize")]
+    fn hyperparameters(&self) -> Vec<(&'static str, f32)> {
+        vec![
+            ("lr", self.lr.get()),
+            ("lr_decay", self.lr_decay.get()),
+            ("eps", self.eps.get()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn set_hyperparameter(&self, name: &str, value: f32) {
+        match name {
+            "lr" => self.lr.set(value),
+            "lr_decay" => self.lr_decay.set(value),
+            "eps" => self.eps.set(value),
+            _ => (),
+        }
+    }
+}
+
+impl<T> GroupStatus for Adagrad<T>
+where
+    T: Penalty,
+{
+    fn with_overrides(&self, overrides: &GroupOverrides) -> Self {
+        overrides.reject_momentum("Adagrad");
+
+        let mut status = self.clone();
+        if let Some(lr) = overrides.get_lr() {
+            status.lr.set(lr);
+        }
+        if let Some(weight_decay) = overrides.get_weight_decay() {
+            status.penalty = status.penalty.with_weight_decay(weight_decay);
+        }
+
+        status
+    }
 }
 
 impl<T> Adagrad<T>
@@ -142,6 +192,24 @@ where
     fn zero_grad(&mut self) {
         self.variable.zero_grad()
     }
+
+    fn for_each_grad(&mut self, f: &mut dyn FnMut(&mut f32)) {
+        self.variable.grad_mut().iter_mut().for_each(f)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(&'static str, ArrayD<f32>)> {
+        vec![
+            ("step", counter(self.step)),
+            ("grad_sq", self.grad_sq.clone().into_dyn()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&mut self, state: &StateDict) {
+        self.step = to_counter(&state["step"]);
+        assign(&mut self.grad_sq, &state["grad_sq"]);
+    }
 }
 
 impl<D, T> IntoParam<Adagrad<T>> for VarDiff<D>
@@ -4,12 +4,24 @@ use ndarray::{Array, Dimension, Zip};
 
 use neuronika_variable::VarDiff;
 
-use super::{IntoParam, Optimize, Optimizer, OptimizerStatus, Penalty};
+use super::{
+    GroupOverrides, GroupStatus, IntoParam, Optimize, Optimizer, OptimizerStatus, Penalty,
+};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::StateDict;
+
+#[cfg(feature = "serialize")]
+use super::{assign, counter, to_counter};
 
 /// Adam optimizer.
 ///
 /// It has been proposed in
 /// [Adam: A Method for Stochastic Optimization](https://arxiv.org/abs/1412.6980).
+#[derive(Clone)]
 pub struct Adam<T>
 where
     T: Penalty,
@@ -32,6 +44,54 @@ where
     fn set_lr(&self, lr: f32) {
         self.lr.set(lr)
     }
+
+    fn get_cycle_momentum(&self) -> Option<f32> {
+        Some(self.beta1.get())
+    }
+
+    fn set_cycle_momentum(&self, momentum: f32) {
+        self.beta1.set(momentum)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn hyperparameters(&self) -> Vec<(&'static str, f32)> {
+        vec![
+            ("lr", self.lr.get()),
+            ("beta1", self.beta1.get()),
+            ("beta2", self.beta2.get()),
+            ("eps", self.eps.get()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn set_hyperparameter(&self, name: &str, value: f32) {
+        match name {
+            "lr" => self.lr.set(value),
+            "beta1" => self.beta1.set(value),
+            "beta2" => self.beta2.set(value),
+            "eps" => self.eps.set(value),
+            _ => (),
+        }
+    }
+}
+
+impl<T> GroupStatus for Adam<T>
+where
+    T: Penalty,
+{
+    fn with_overrides(&self, overrides: &GroupOverrides) -> Self {
+        overrides.reject_momentum("Adam");
+
+        let mut status = self.clone();
+        if let Some(lr) = overrides.get_lr() {
+            status.lr.set(lr);
+        }
+        if let Some(weight_decay) = overrides.get_weight_decay() {
+            status.penalty = status.penalty.with_weight_decay(weight_decay);
+        }
+
+        status
+    }
 }
 
 impl<T> Adam<T>
@@ -171,6 +231,26 @@ where
     fn zero_grad(&mut self) {
         self.variable.zero_grad()
     }
+
+    fn for_each_grad(&mut self, f: &mut dyn FnMut(&mut f32)) {
+        self.variable.grad_mut().iter_mut().for_each(f)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(&'static str, ArrayD<f32>)> {
+        vec![
+            ("step", counter(self.step)),
+            ("exp_avg", self.exp_avg.clone().into_dyn()),
+            ("exp_avg_sq", self.exp_avg_sq.clone().into_dyn()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&mut self, state: &StateDict) {
+        self.step = to_counter(&state["step"]);
+        assign(&mut self.exp_avg, &state["exp_avg"]);
+        assign(&mut self.exp_avg_sq, &state["exp_avg_sq"]);
+    }
 }
 
 impl<T, D> IntoParam<Adam<T>> for VarDiff<D>
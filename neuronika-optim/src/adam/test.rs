@@ -1,4 +1,7 @@
-use super::{super::L2, Adam};
+use super::{
+    super::{GroupOverrides, L2},
+    Adam,
+};
 
 #[test]
 fn creation() {
@@ -36,6 +39,28 @@ fn set_eps() {
     assert!((optim.status().get_eps() - 1e-9).abs() <= f32::EPSILON);
 }
 
+#[test]
+fn group_overrides() {
+    let optim = Adam::new(1e-2, 0.9, 0.999, L2::new(1e-2), 1e-8);
+    let group = optim.register_group(
+        vec![neuronika_variable::rand(3).requires_grad()],
+        GroupOverrides::new().lr(1e-3).weight_decay(0.0),
+    );
+
+    assert!((optim.group(group).get_lr() - 1e-3).abs() <= f32::EPSILON);
+    assert!((optim.group(group).get_beta1() - 0.9).abs() <= f32::EPSILON);
+}
+
+#[test]
+#[should_panic]
+fn group_overrides_momentum() {
+    let optim = Adam::new(1e-2, 0.9, 0.999, L2::new(1e-2), 1e-8);
+    optim.register_group(
+        vec![neuronika_variable::rand(3).requires_grad()],
+        GroupOverrides::new().momentum(0.9),
+    );
+}
+
 const EPOCHS: usize = 10;
 
 #[test]
@@ -62,3 +87,118 @@ fn step() {
 
     assert!(loss.item() < first_value);
 }
+
+#[cfg(feature = "serialize")]
+#[test]
+fn state_dict() {
+    use ndarray::Ix2;
+
+    use neuronika_variable::VarDiff;
+
+    let y = neuronika_variable::rand((3, 3));
+    let z = neuronika_variable::rand((3, 3));
+    let train = |optim: &super::super::Optimizer<Adam<L2>>, x: &VarDiff<Ix2>| {
+        for _ in 0..3 {
+            let loss = (x.clone().mm(y.clone()) - z.clone()).pow(2).sum();
+            loss.forward();
+            loss.backward(1.0);
+
+            optim.step();
+            optim.zero_grad();
+        }
+    };
+
+    let x = neuronika_variable::rand((3, 3)).requires_grad();
+    let optim = Adam::new(1e-2, 0.9, 0.999, L2::new(0.0), 1e-8);
+    optim.register(x.clone());
+    train(&optim, &x);
+
+    let state = optim.state_dict();
+    assert_eq!(state["groups.0.beta2"].shape(), &[] as &[usize]);
+    assert_eq!(state["params.0.exp_avg"].shape(), &[3, 3]);
+    assert_eq!(
+        state["params.0.step"],
+        ndarray::arr1(&[3., 0., 0., 0.]).into_dyn()
+    );
+
+    // The training resumed from the saved state matches the uninterrupted one.
+    let resumed = neuronika_variable::from_ndarray(x.data().clone()).requires_grad();
+    let resumed_optim = Adam::new(1., 0.5, 0.5, L2::new(0.0), 1.);
+    resumed_optim.register(resumed.clone());
+    resumed_optim.load_state_dict(&state).unwrap();
+    assert!((resumed_optim.status().get_beta1() - 0.9).abs() <= f32::EPSILON);
+
+    train(&optim, &x);
+    train(&resumed_optim, &resumed);
+    assert_eq!(*x.data(), *resumed.data());
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn state_dict_large_step() {
+    use crate::optimizer::{counter, to_counter};
+
+    let optim = Adam::new(1e-2, 0.9, 0.999, L2::new(0.0), 1e-8);
+    optim.register(neuronika_variable::rand(3).requires_grad());
+
+    // Steps beyond 2^24 aren't representable exactly by a single float.
+    let mut state = optim.state_dict();
+    for step in [(1 << 24) + 1, (1 << 40) + 3, usize::MAX] {
+        state.insert("params.0.step".to_string(), counter(step));
+        optim.load_state_dict(&state).unwrap();
+        assert_eq!(to_counter(&optim.state_dict()["params.0.step"]), step);
+    }
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn state_dict_invalid_step() {
+    use neuronika_variable::CheckpointError;
+
+    let optim = Adam::new(1e-2, 0.9, 0.999, L2::new(0.0), 1e-8);
+    optim.register(neuronika_variable::rand(3).requires_grad());
+    let state = optim.state_dict();
+
+    for digits in [[1.5, 0., 0., 0.], [65536., 0., 0., 0.], [-1., 0., 0., 0.]] {
+        let mut invalid = state.clone();
+        invalid.insert(
+            "params.0.step".to_string(),
+            ndarray::arr1(&digits).into_dyn(),
+        );
+        assert!(matches!(
+            optim.load_state_dict(&invalid),
+            Err(CheckpointError::InvalidFormat(_))
+        ));
+    }
+
+    let mut invalid = state.clone();
+    invalid.insert("params.0.step".to_string(), ndarray::arr1(&[3.]).into_dyn());
+    assert!(matches!(
+        optim.load_state_dict(&invalid),
+        Err(CheckpointError::ShapeMismatch { .. })
+    ));
+    assert_eq!(optim.state_dict(), state);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn state_dict_mismatch() {
+    use neuronika_variable::CheckpointError;
+
+    let optim = Adam::new(1e-2, 0.9, 0.999, L2::new(0.0), 1e-8);
+    optim.register(neuronika_variable::rand((3, 3)).requires_grad());
+    let state = optim.state_dict();
+
+    let other = Adam::new(1e-2, 0.9, 0.999, L2::new(0.0), 1e-8);
+    assert!(matches!(
+        other.load_state_dict(&state),
+        Err(CheckpointError::KeyMismatch { .. })
+    ));
+
+    other.register(neuronika_variable::rand((3, 2)).requires_grad());
+    assert!(matches!(
+        other.load_state_dict(&state),
+        Err(CheckpointError::ShapeMismatch { .. })
+    ));
+    assert!((other.status().get_lr() - 1e-2).abs() <= f32::EPSILON);
+}
@@ -0,0 +1,67 @@
+use ndarray::Array;
+
+use super::{super::NoDecay, AdamW};
+
+#[test]
+fn creation() {
+    let optim = AdamW::new(1e-2, 0.9, 0.999, 1e-2, 1e-8);
+
+    assert!((optim.get_lr() - 1e-2).abs() <= f32::EPSILON);
+    assert!((optim.status().get_beta1() - 0.9).abs() <= f32::EPSILON);
+    assert!((optim.status().get_beta2() - 0.999).abs() <= f32::EPSILON);
+    assert!((optim.status().get_weight_decay() - 1e-2).abs() <= f32::EPSILON);
+    assert!((optim.status().get_eps() - 1e-8).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_weight_decay() {
+    let optim = AdamW::new(1e-2, 0.9, 0.999, 1e-2, 1e-8);
+
+    optim.status().set_weight_decay(0.1);
+    assert!((optim.status().get_weight_decay() - 0.1).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn decoupled_weight_decay() {
+    let weight = neuronika_variable::full((2, 2), 2.).requires_grad();
+    let bias = neuronika_variable::full(2, 2.).requires_grad();
+
+    let optim = AdamW::new(0.1, 0.9, 0.999, 0.5, 1e-8);
+    optim.register(weight.clone());
+    optim.register(NoDecay(bias.clone()));
+
+    // With a null gradient only the weight decay changes the parameters.
+    optim.step();
+    assert_eq!(
+        *weight.data(),
+        Array::from_elem((2, 2), 2. * (1. - 0.1 * 0.5))
+    );
+    assert_eq!(*bias.data(), Array::from_elem(2, 2.));
+}
+
+const EPOCHS: usize = 10;
+
+#[test]
+fn step() {
+    let x = neuronika_variable::rand((3, 3)).requires_grad();
+    let y = neuronika_variable::rand((3, 3));
+    let z = neuronika_variable::rand((3, 3));
+
+    let loss = (x.clone().mm(y) - z).pow(2).sum();
+    loss.forward();
+
+    let first_value = loss.item();
+
+    let optim = AdamW::new(1e-4, 0.9, 0.999, 1e-2, 1e-8);
+    optim.register(x);
+
+    for _ in 0..EPOCHS {
+        loss.forward();
+        loss.backward(1.0);
+
+        optim.step();
+        optim.zero_grad();
+    }
+
+    assert!(loss.item() < first_value);
+}
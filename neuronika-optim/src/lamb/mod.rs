@@ -0,0 +1,316 @@
+use std::{cell::Cell, rc::Rc};
+
+use ndarray::{Array, Dimension, Zip};
+
+use neuronika_variable::VarDiff;
+
+use super::{
+    GroupOverrides, GroupStatus, IntoParam, NoDecay, Optimize, Optimizer, OptimizerStatus,
+};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::StateDict;
+
+#[cfg(feature = "serialize")]
+use super::{assign, counter, to_counter};
+
+/// LAMB optimizer.
+///
+/// It is a layer-wise adaptive variant of the *AdamW* algorithm meant for large-batch training,
+/// proposed in [Large Batch Optimization for Deep Learning: Training BERT in 76 minutes](https://arxiv.org/abs/1904.00962).
+///
+/// The update of each registered parameter, weight decay included, is rescaled by the trust ratio
+/// between the norm of the parameter and the norm of the update itself. Parameters registered
+/// wrapped in [`NoDecay`] are excluded from the weight decay.
+#[derive(Clone)]
+#[allow(clippy::upper_case_acronyms)]
+pub struct LAMB {
+    lr: Cell<f32>,
+    beta1: Cell<f32>,
+    beta2: Cell<f32>,
+    weight_decay: Cell<f32>,
+    eps: Cell<f32>,
+}
+
+impl OptimizerStatus for LAMB {
+    fn get_lr(&self) -> f32 {
+        self.lr.get()
+    }
+
+    fn set_lr(&self, lr: f32) {
+        self.lr.set(lr)
+    }
+
+    fn get_cycle_momentum(&self) -> Option<f32> {
+        Some(self.beta1.get())
+    }
+
+    fn set_cycle_momentum(&self, momentum: f32) {
+        self.beta1.set(momentum)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn hyperparameters(&self) -> Vec<(&'static str, f32)> {
+        vec![
+            ("lr", self.lr.get()),
+            ("beta1", self.beta1.get()),
+            ("beta2", self.beta2.get()),
+            ("weight_decay", self.weight_decay.get()),
+            ("eps", self.eps.get()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn set_hyperparameter(&self, name: &str, value: f32) {
+        match name {
+            "lr" => self.lr.set(value),
+            "beta1" => self.beta1.set(value),
+            "beta2" => self.beta2.set(value),
+            "weight_decay" => self.weight_decay.set(value),
+            "eps" => self.eps.set(value),
+            _ => (),
+        }
+    }
+}
+
+impl GroupStatus for LAMB {
+    fn with_overrides(&self, overrides: &GroupOverrides) -> Self {
+        overrides.reject_momentum("LAMB");
+
+        let status = self.clone();
+        if let Some(lr) = overrides.get_lr() {
+            status.lr.set(lr);
+        }
+        if let Some(weight_decay) = overrides.get_weight_decay() {
+            status.weight_decay.set(weight_decay);
+        }
+
+        status
+    }
+}
+
+impl LAMB {
+    /// Creates a new LAMB optimizer.
+    ///
+    /// # Arguments
+    ///
+    /// * `lr` - learning rate.
+    ///
+    /// * `beta1` - coefficient for computing running average of the gradient. A good default is 0.9.
+    ///
+    /// * `beta2` - coefficient for computing running average of the squared gradient. A good
+    ///   default is 0.999.
+    ///
+    /// * `weight_decay` - weight decay coefficient. A good default is 1e-2.
+    ///
+    /// * `eps` - small constant for numerical stability. A good default value is *1e-6*.
+    pub fn new(lr: f32, beta1: f32, beta2: f32, weight_decay: f32, eps: f32) -> Optimizer<Self> {
+        let lr = Cell::new(lr);
+        let beta1 = Cell::new(beta1);
+        let beta2 = Cell::new(beta2);
+        let weight_decay = Cell::new(weight_decay);
+        let eps = Cell::new(eps);
+
+        let status = Self {
+            lr,
+            beta1,
+            beta2,
+            weight_decay,
+            eps,
+        };
+
+        Optimizer::new(status)
+    }
+
+    /// Return the current learning rate.
+    pub fn get_lr(&self) -> f32 {
+        OptimizerStatus::get_lr(self)
+    }
+
+    /// Sets a new value for the learning rate.
+    pub fn set_lr(&self, lr: f32) {
+        OptimizerStatus::set_lr(self, lr);
+    }
+
+    /// Return the current values for the first exponential decay rate.
+    pub fn get_beta1(&self) -> f32 {
+        self.beta1.get()
+    }
+
+    /// Return the current values for the second exponential decay rate.
+    pub fn get_beta2(&self) -> f32 {
+        self.beta2.get()
+    }
+
+    /// Sets a new value for the first exponential decay rate.
+    pub fn set_beta1(&self, beta1: f32) {
+        self.beta1.set(beta1)
+    }
+
+    /// Sets a new value for the second exponential decay rate.
+    pub fn set_beta2(&self, beta2: f32) {
+        self.beta2.set(beta2)
+    }
+
+    /// Returns the current weight decay coefficient.
+    pub fn get_weight_decay(&self) -> f32 {
+        self.weight_decay.get()
+    }
+
+    /// Sets a new value for the weight decay coefficient.
+    pub fn set_weight_decay(&self, weight_decay: f32) {
+        self.weight_decay.set(weight_decay)
+    }
+
+    /// Return the current epsilon constant.
+    pub fn get_eps(&self) -> f32 {
+        self.eps.get()
+    }
+
+    /// Sets a new value for the epsilon constant.
+    pub fn set_eps(&self, eps: f32) {
+        self.eps.set(eps)
+    }
+}
+
+/// A parameter used by the LAMB optimizer.
+#[allow(clippy::upper_case_acronyms)]
+pub struct LAMBParam<D>
+where
+    D: Dimension,
+{
+    variable: VarDiff<D>,
+    decay: bool,
+    step: usize,
+    exp_avg: Array<f32, D>,
+    exp_avg_sq: Array<f32, D>,
+    status: Rc<LAMB>,
+}
+
+impl<D> LAMBParam<D>
+where
+    D: Dimension,
+{
+    fn new(variable: VarDiff<D>, decay: bool, status: Rc<LAMB>) -> Self {
+        let exp_avg = Array::zeros(variable.data().raw_dim());
+        let exp_avg_sq = exp_avg.clone();
+
+        Self {
+            variable,
+            decay,
+            step: 0,
+            exp_avg,
+            exp_avg_sq,
+            status,
+        }
+    }
+}
+
+impl<D> IntoParam<LAMB> for VarDiff<D>
+where
+    D: 'static + Dimension,
+{
+    type Param = LAMBParam<D>;
+
+    fn into_param(self, status: Rc<LAMB>) -> Self::Param {
+        LAMBParam::new(self, true, status)
+    }
+}
+
+impl<D> IntoParam<LAMB> for NoDecay<D>
+where
+    D: 'static + Dimension,
+{
+    type Param = LAMBParam<D>;
+
+    fn into_param(self, status: Rc<LAMB>) -> Self::Param {
+        LAMBParam::new(self.0, false, status)
+    }
+}
+
+impl<D> Optimize for LAMBParam<D>
+where
+    D: Dimension,
+{
+    fn optimize(&mut self) {
+        self.step += 1;
+
+        let lr = self.status.get_lr();
+        let beta1 = self.status.get_beta1();
+        let beta2 = self.status.get_beta2();
+        let eps = self.status.get_eps();
+        let weight_decay = if self.decay {
+            self.status.get_weight_decay()
+        } else {
+            0.0
+        };
+
+        let bias_correction1 = 1.0 - beta1.powi(self.step as i32);
+        let bias_correction2 = 1.0 - beta2.powi(self.step as i32);
+
+        let mut data = self.variable.data_mut();
+        let grad = self.variable.grad();
+
+        Zip::from(&mut self.exp_avg)
+            .and(&*grad)
+            .for_each(|exp_avg_el, grad_el| {
+                *exp_avg_el = *exp_avg_el * beta1 + grad_el * (1.0 - beta1)
+            });
+
+        Zip::from(&mut self.exp_avg_sq)
+            .and(&*grad)
+            .for_each(|exp_avg_sq_el, grad_el| {
+                *exp_avg_sq_el = *exp_avg_sq_el * beta2 + grad_el * grad_el * (1.0 - beta2)
+            });
+
+        let update = Zip::from(&*data)
+            .and(&self.exp_avg)
+            .and(&self.exp_avg_sq)
+            .map_collect(|data_el, exp_avg_el, exp_avg_sq_el| {
+                exp_avg_el / bias_correction1 / ((exp_avg_sq_el / bias_correction2).sqrt() + eps)
+                    + data_el * weight_decay
+            });
+
+        let data_norm = data.iter().map(|el| el * el).sum::<f32>().sqrt();
+        let update_norm = update.iter().map(|el| el * el).sum::<f32>().sqrt();
+        let trust_ratio = if data_norm > 0.0 && update_norm > 0.0 {
+            data_norm / update_norm
+        } else {
+            1.0
+        };
+
+        Zip::from(&mut *data)
+            .and(&update)
+            .for_each(|data_el, update_el| *data_el -= update_el * trust_ratio * lr);
+    }
+
+    fn zero_grad(&mut self) {
+        self.variable.zero_grad()
+    }
+
+    fn for_each_grad(&mut self, f: &mut dyn FnMut(&mut f32)) {
+        self.variable.grad_mut().iter_mut().for_each(f)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(&'static str, ArrayD<f32>)> {
+        vec![
+            ("step", counter(self.step)),
+            ("exp_avg", self.exp_avg.clone().into_dyn()),
+            ("exp_avg_sq", self.exp_avg_sq.clone().into_dyn()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&mut self, state: &StateDict) {
+        self.step = to_counter(&state["step"]);
+        assign(&mut self.exp_avg, &state["exp_avg"]);
+        assign(&mut self.exp_avg_sq, &state["exp_avg_sq"]);
+    }
+}
+
+#[cfg(test)]
+mod test;
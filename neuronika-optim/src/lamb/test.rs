@@ -0,0 +1,97 @@
+use super::LAMB;
+
+#[test]
+fn creation() {
+    let optim = LAMB::new(1e-2, 0.9, 0.999, 1e-2, 1e-6);
+
+    assert!((optim.get_lr() - 1e-2).abs() <= f32::EPSILON);
+    assert!((optim.status().get_beta1() - 0.9).abs() <= f32::EPSILON);
+    assert!((optim.status().get_beta2() - 0.999).abs() <= f32::EPSILON);
+    assert!((optim.status().get_weight_decay() - 1e-2).abs() <= f32::EPSILON);
+    assert!((optim.status().get_eps() - 1e-6).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_lr() {
+    let optim = LAMB::new(1e-2, 0.9, 0.999, 1e-2, 1e-6);
+
+    optim.set_lr(1e-3);
+    assert!((optim.get_lr() - 1e-3).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_betas() {
+    let optim = LAMB::new(1e-2, 0.9, 0.999, 1e-2, 1e-6);
+
+    optim.status().set_beta1(0.91);
+    optim.status().set_beta2(0.9991);
+    assert!((optim.status().get_beta1() - 0.91).abs() <= f32::EPSILON);
+    assert!((optim.status().get_beta2() - 0.9991).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_weight_decay() {
+    let optim = LAMB::new(1e-2, 0.9, 0.999, 1e-2, 1e-6);
+
+    optim.status().set_weight_decay(0.1);
+    assert!((optim.status().get_weight_decay() - 0.1).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_eps() {
+    let optim = LAMB::new(1e-2, 0.9, 0.999, 1e-2, 1e-6);
+
+    optim.status().set_eps(1e-7);
+    assert!((optim.status().get_eps() - 1e-7).abs() <= f32::EPSILON);
+}
+
+const EPOCHS: usize = 10;
+
+#[test]
+fn step() {
+    let x = neuronika_variable::rand((3, 3)).requires_grad();
+    let y = neuronika_variable::rand((3, 3));
+    let z = neuronika_variable::rand((3, 3));
+
+    let loss = (x.clone().mm(y) - z).pow(2).sum();
+    loss.forward();
+
+    let first_value = loss.item();
+
+    let optim = LAMB::new(1e-4, 0.9, 0.999, 0.0, 1e-6);
+    optim.register(x);
+
+    for _ in 0..EPOCHS {
+        loss.forward();
+        loss.backward(1.0);
+
+        optim.step();
+        optim.zero_grad();
+    }
+
+    assert!(loss.item() < first_value);
+}
+
+#[test]
+fn reference() {
+    let x = neuronika_variable::from_ndarray(ndarray::array![1., -2., 0.]).requires_grad();
+
+    let optim = LAMB::new(0.1, 0.9, 0.999, 0.01, 1e-6);
+    optim.register(x.clone());
+
+    for _ in 0..EPOCHS {
+        let loss = x.clone().pow(2).sum();
+        loss.forward();
+        loss.backward(1.0);
+
+        optim.step();
+        optim.zero_grad();
+    }
+
+    // Reference values computed with a double precision implementation of the update rule.
+    let expected = [-0.0186397, -0.8882651, 0.0];
+    x.data()
+        .iter()
+        .zip(expected)
+        .for_each(|(value, expected)| assert!((value - expected).abs() <= 1e-5));
+}
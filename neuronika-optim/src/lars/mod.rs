@@ -0,0 +1,294 @@
+use std::{cell::Cell, rc::Rc};
+
+use ndarray::{Array, Dimension, Zip};
+
+use neuronika_variable::VarDiff;
+
+use super::{
+    GroupOverrides, GroupStatus, IntoParam, NoDecay, Optimize, Optimizer, OptimizerStatus,
+};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::StateDict;
+
+#[cfg(feature = "serialize")]
+use super::assign;
+
+/// LARS optimizer.
+///
+/// It is a layer-wise adaptive variant of the momentum *SGD* algorithm meant for large-batch
+/// training, proposed in
+/// [Large Batch Training of Convolutional Networks](https://arxiv.org/abs/1708.03888).
+///
+/// The learning rate of each registered parameter is scaled by a local learning rate, computed
+/// from the ratio between the norm of the parameter and the norm of its gradient. Parameters
+/// registered wrapped in [`NoDecay`] are excluded from the weight decay.
+#[derive(Clone)]
+#[allow(clippy::upper_case_acronyms)]
+pub struct LARS {
+    lr: Cell<f32>,
+    momentum: Cell<f32>,
+    weight_decay: Cell<f32>,
+    trust_coefficient: Cell<f32>,
+    eps: Cell<f32>,
+}
+
+impl OptimizerStatus for LARS {
+    fn get_lr(&self) -> f32 {
+        self.lr.get()
+    }
+
+    fn set_lr(&self, lr: f32) {
+        self.lr.set(lr)
+    }
+
+    fn get_cycle_momentum(&self) -> Option<f32> {
+        Some(self.momentum.get())
+    }
+
+    fn set_cycle_momentum(&self, momentum: f32) {
+        self.momentum.set(momentum)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn hyperparameters(&self) -> Vec<(&'static str, f32)> {
+        vec![
+            ("lr", self.lr.get()),
+            ("momentum", self.momentum.get()),
+            ("weight_decay", self.weight_decay.get()),
+            ("trust_coefficient", self.trust_coefficient.get()),
+            ("eps", self.eps.get()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn set_hyperparameter(&self, name: &str, value: f32) {
+        match name {
+            "lr" => self.lr.set(value),
+            "momentum" => self.momentum.set(value),
+            "weight_decay" => self.weight_decay.set(value),
+            "trust_coefficient" => self.trust_coefficient.set(value),
+            "eps" => self.eps.set(value),
+            _ => (),
+        }
+    }
+}
+
+impl GroupStatus for LARS {
+    fn with_overrides(&self, overrides: &GroupOverrides) -> Self {
+        let status = self.clone();
+        if let Some(lr) = overrides.get_lr() {
+            status.lr.set(lr);
+        }
+        if let Some(weight_decay) = overrides.get_weight_decay() {
+            status.weight_decay.set(weight_decay);
+        }
+        if let Some(momentum) = overrides.get_momentum() {
+            status.momentum.set(momentum);
+        }
+
+        status
+    }
+}
+
+impl LARS {
+    /// Creates a new LARS optimizer.
+    ///
+    /// # Arguments
+    ///
+    /// * `lr` - global learning rate.
+    ///
+    /// * `momentum` - momentum factor. A good default is 0.9.
+    ///
+    /// * `weight_decay` - weight decay coefficient.
+    ///
+    /// * `trust_coefficient` - confidence in the local learning rate. A good default is 1e-3.
+    ///
+    /// * `eps` - small constant for numerical stability. A good default value is *1e-8*.
+    pub fn new(
+        lr: f32,
+        momentum: f32,
+        weight_decay: f32,
+        trust_coefficient: f32,
+        eps: f32,
+    ) -> Optimizer<Self> {
+        let lr = Cell::new(lr);
+        let momentum = Cell::new(momentum);
+        let weight_decay = Cell::new(weight_decay);
+        let trust_coefficient = Cell::new(trust_coefficient);
+        let eps = Cell::new(eps);
+
+        let status = Self {
+            lr,
+            momentum,
+            weight_decay,
+            trust_coefficient,
+            eps,
+        };
+
+        Optimizer::new(status)
+    }
+
+    /// Returns the current learning rate.
+    pub fn get_lr(&self) -> f32 {
+        OptimizerStatus::get_lr(self)
+    }
+
+    /// Sets a new value for the learning rate.
+    pub fn set_lr(&self, lr: f32) {
+        OptimizerStatus::set_lr(self, lr);
+    }
+
+    /// Returns the current momentum factor.
+    pub fn get_momentum(&self) -> f32 {
+        self.momentum.get()
+    }
+
+    /// Sets a new value for the momentum factor.
+    pub fn set_momentum(&self, momentum: f32) {
+        self.momentum.set(momentum)
+    }
+
+    /// Returns the current weight decay coefficient.
+    pub fn get_weight_decay(&self) -> f32 {
+        self.weight_decay.get()
+    }
+
+    /// Sets a new value for the weight decay coefficient.
+    pub fn set_weight_decay(&self, weight_decay: f32) {
+        self.weight_decay.set(weight_decay)
+    }
+
+    /// Returns the current trust coefficient.
+    pub fn get_trust_coefficient(&self) -> f32 {
+        self.trust_coefficient.get()
+    }
+
+    /// Sets a new value for the trust coefficient.
+    pub fn set_trust_coefficient(&self, trust_coefficient: f32) {
+        self.trust_coefficient.set(trust_coefficient)
+    }
+
+    /// Returns the current epsilon constant.
+    pub fn get_eps(&self) -> f32 {
+        self.eps.get()
+    }
+
+    /// Sets a new value for the epsilon constant.
+    pub fn set_eps(&self, eps: f32) {
+        self.eps.set(eps)
+    }
+}
+
+/// A parameter used by the LARS optimizer.
+#[allow(clippy::upper_case_acronyms)]
+pub struct LARSParam<D>
+where
+    D: Dimension,
+{
+    variable: VarDiff<D>,
+    decay: bool,
+    buffer: Array<f32, D>,
+    status: Rc<LARS>,
+}
+
+impl<D> LARSParam<D>
+where
+    D: Dimension,
+{
+    fn new(variable: VarDiff<D>, decay: bool, status: Rc<LARS>) -> Self {
+        let buffer = Array::zeros(variable.data().raw_dim());
+
+        Self {
+            variable,
+            decay,
+            buffer,
+            status,
+        }
+    }
+}
+
+impl<D> IntoParam<LARS> for VarDiff<D>
+where
+    D: 'static + Dimension,
+{
+    type Param = LARSParam<D>;
+
+    fn into_param(self, status: Rc<LARS>) -> Self::Param {
+        LARSParam::new(self, true, status)
+    }
+}
+
+impl<D> IntoParam<LARS> for NoDecay<D>
+where
+    D: 'static + Dimension,
+{
+    type Param = LARSParam<D>;
+
+    fn into_param(self, status: Rc<LARS>) -> Self::Param {
+        LARSParam::new(self.0, false, status)
+    }
+}
+
+impl<D> Optimize for LARSParam<D>
+where
+    D: Dimension,
+{
+    fn optimize(&mut self) {
+        let lr = self.status.get_lr();
+        let momentum = self.status.get_momentum();
+        let trust_coefficient = self.status.get_trust_coefficient();
+        let eps = self.status.get_eps();
+        let weight_decay = if self.decay {
+            self.status.get_weight_decay()
+        } else {
+            0.0
+        };
+
+        let mut data = self.variable.data_mut();
+        let grad = self.variable.grad();
+
+        let data_norm = data.iter().map(|el| el * el).sum::<f32>().sqrt();
+        let grad_norm = grad.iter().map(|el| el * el).sum::<f32>().sqrt();
+        let local_lr = if data_norm > 0.0 && grad_norm > 0.0 {
+            trust_coefficient * data_norm / (grad_norm + weight_decay * data_norm + eps)
+        } else {
+            1.0
+        };
+
+        Zip::from(&mut self.buffer)
+            .and(&*grad)
+            .and(&*data)
+            .for_each(|buffer_el, grad_el, data_el| {
+                *buffer_el = *buffer_el * momentum + (grad_el + data_el * weight_decay) * local_lr
+            });
+
+        Zip::from(&mut *data)
+            .and(&self.buffer)
+            .for_each(|data_el, buffer_el| *data_el -= buffer_el * lr);
+    }
+
+    fn zero_grad(&mut self) {
+        self.variable.zero_grad()
+    }
+
+    fn for_each_grad(&mut self, f: &mut dyn FnMut(&mut f32)) {
+        self.variable.grad_mut().iter_mut().for_each(f)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(&'static str, ArrayD<f32>)> {
+        vec![("momentum_buffer", self.buffer.clone().into_dyn())]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&mut self, state: &StateDict) {
+        assign(&mut self.buffer, &state["momentum_buffer"]);
+    }
+}
+
+#[cfg(test)]
+mod test;
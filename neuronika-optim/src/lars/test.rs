@@ -0,0 +1,103 @@
+use super::LARS;
+
+#[test]
+fn creation() {
+    let optim = LARS::new(1e-2, 0.9, 1e-4, 1e-3, 1e-8);
+
+    assert!((optim.get_lr() - 1e-2).abs() <= f32::EPSILON);
+    assert!((optim.status().get_momentum() - 0.9).abs() <= f32::EPSILON);
+    assert!((optim.status().get_weight_decay() - 1e-4).abs() <= f32::EPSILON);
+    assert!((optim.status().get_trust_coefficient() - 1e-3).abs() <= f32::EPSILON);
+    assert!((optim.status().get_eps() - 1e-8).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_lr() {
+    let optim = LARS::new(1e-2, 0.9, 1e-4, 1e-3, 1e-8);
+
+    optim.set_lr(1e-3);
+    assert!((optim.get_lr() - 1e-3).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_momentum() {
+    let optim = LARS::new(1e-2, 0.9, 1e-4, 1e-3, 1e-8);
+
+    optim.status().set_momentum(0.95);
+    assert!((optim.status().get_momentum() - 0.95).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_weight_decay() {
+    let optim = LARS::new(1e-2, 0.9, 1e-4, 1e-3, 1e-8);
+
+    optim.status().set_weight_decay(1e-3);
+    assert!((optim.status().get_weight_decay() - 1e-3).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_trust_coefficient() {
+    let optim = LARS::new(1e-2, 0.9, 1e-4, 1e-3, 1e-8);
+
+    optim.status().set_trust_coefficient(2e-2);
+    assert!((optim.status().get_trust_coefficient() - 2e-2).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_eps() {
+    let optim = LARS::new(1e-2, 0.9, 1e-4, 1e-3, 1e-8);
+
+    optim.status().set_eps(1e-9);
+    assert!((optim.status().get_eps() - 1e-9).abs() <= f32::EPSILON);
+}
+
+const EPOCHS: usize = 10;
+
+#[test]
+fn step() {
+    let x = neuronika_variable::rand((3, 3)).requires_grad();
+    let y = neuronika_variable::rand((3, 3));
+    let z = neuronika_variable::rand((3, 3));
+
+    let loss = (x.clone().mm(y) - z).pow(2).sum();
+    loss.forward();
+
+    let first_value = loss.item();
+
+    let optim = LARS::new(1e-2, 0.9, 0.0, 1e-3, 1e-8);
+    optim.register(x);
+
+    for _ in 0..EPOCHS {
+        loss.forward();
+        loss.backward(1.0);
+
+        optim.step();
+        optim.zero_grad();
+    }
+
+    assert!(loss.item() < first_value);
+}
+
+#[test]
+fn reference() {
+    let x = neuronika_variable::from_ndarray(ndarray::array![1., -2., 0.]).requires_grad();
+
+    let optim = LARS::new(0.1, 0.9, 0.01, 0.02, 1e-8);
+    optim.register(x.clone());
+
+    for _ in 0..EPOCHS {
+        let loss = x.clone().pow(2).sum();
+        loss.forward();
+        loss.backward(1.0);
+
+        optim.step();
+        optim.zero_grad();
+    }
+
+    // Reference values computed with a double precision implementation of the update rule.
+    let expected = [0.9186733, -1.8373466, 0.0];
+    x.data()
+        .iter()
+        .zip(expected)
+        .for_each(|(value, expected)| assert!((value - expected).abs() <= 1e-5));
+}
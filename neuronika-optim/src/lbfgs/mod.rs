@@ -0,0 +1,671 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+};
+
+use ndarray::Dimension;
+
+use neuronika_variable::VarDiff;
+
+use super::OptimizerStatus;
+
+/// Line search algorithms available to the [`LBFGS`] optimizer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LineSearch {
+    /// Line search satisfying the strong Wolfe conditions, from Algorithm 3.5 of *Numerical
+    /// Optimization* by Nocedal and Wright.
+    StrongWolfe,
+}
+
+/// L-BFGS optimizer.
+///
+/// It is a limited memory quasi-Newton method, see
+/// [On the limited memory BFGS method for large scale optimization](https://doi.org/10.1007/BF01589116).
+///
+/// Differently from the other optimizers, L-BFGS needs to re-evaluate the objective several times
+/// during a single step, hence [`.step()`](LBFGS::step()) takes a closure that clears the
+/// gradients, computes the loss, back-propagates it and returns its value. All the registered
+/// variables are treated as a single flattened parameter, so this optimizer is memory hungry: it
+/// stores `2 * history_size` vectors as large as the total number of parameters.
+///
+/// ```
+/// use neuronika_optim::{LineSearch, LBFGS};
+///
+/// let x = neuronika_variable::from_ndarray(ndarray::array![1.5, -0.5]).requires_grad();
+/// let target = neuronika_variable::from_ndarray(ndarray::array![0.5, 2.0]);
+///
+/// let optim = LBFGS::new(1.0, 20, 10, LineSearch::StrongWolfe);
+/// optim.register(x.clone());
+///
+/// optim.step(|| {
+///     optim.zero_grad();
+///
+///     let loss = (x.clone() - target.clone()).pow(2).sum();
+///     loss.forward();
+///     loss.backward(1.0);
+///
+///     loss.item()
+/// });
+///
+/// assert!((x.data()[0] - 0.5).abs() < 1e-4 && (x.data()[1] - 2.0).abs() < 1e-4);
+/// ```
+#[allow(clippy::upper_case_acronyms)]
+pub struct LBFGS {
+    lr: Cell<f32>,
+    max_iter: Cell<usize>,
+    max_eval: Cell<usize>,
+    tolerance_grad: Cell<f32>,
+    tolerance_change: Cell<f32>,
+    history_size: Cell<usize>,
+    line_search: Cell<Option<LineSearch>>,
+    params: RefCell<Vec<Box<dyn FlatParam>>>,
+    state: RefCell<State>,
+}
+
+impl OptimizerStatus for LBFGS {
+    fn get_lr(&self) -> f32 {
+        self.lr.get()
+    }
+
+    fn set_lr(&self, lr: f32) {
+        self.lr.set(lr)
+    }
+}
+
+impl LBFGS {
+    /// Creates a new L-BFGS optimizer.
+    ///
+    /// The maximal number of function evaluations per step defaults to `max_iter * 5 / 4`, the
+    /// first order optimality tolerance to *1e-7* and the tolerance on function value and
+    /// parameter changes to *1e-9*.
+    ///
+    /// # Arguments
+    ///
+    /// * `lr` - learning rate. A good default is 1.0.
+    ///
+    /// * `max_iter` - maximal number of iterations per step. A good default is 20.
+    ///
+    /// * `history_size` - number of past updates used to approximate the inverse Hessian. A good
+    ///   default is 100.
+    ///
+    /// * `line_search` - line search algorithm, if any. Without line search, each iteration
+    ///   moves by the learning rate along the search direction.
+    pub fn new(
+        lr: f32,
+        max_iter: usize,
+        history_size: usize,
+        line_search: impl Into<Option<LineSearch>>,
+    ) -> Self {
+        Self {
+            lr: Cell::new(lr),
+            max_iter: Cell::new(max_iter),
+            max_eval: Cell::new(max_iter * 5 / 4),
+            tolerance_grad: Cell::new(1e-7),
+            tolerance_change: Cell::new(1e-9),
+            history_size: Cell::new(history_size),
+            line_search: Cell::new(line_search.into()),
+            params: RefCell::default(),
+            state: RefCell::default(),
+        }
+    }
+
+    /// Returns the current learning rate.
+    pub fn get_lr(&self) -> f32 {
+        OptimizerStatus::get_lr(self)
+    }
+
+    /// Sets a new value for the learning rate.
+    pub fn set_lr(&self, lr: f32) {
+        OptimizerStatus::set_lr(self, lr);
+    }
+
+    /// Returns the maximal number of iterations per step.
+    pub fn get_max_iter(&self) -> usize {
+        self.max_iter.get()
+    }
+
+    /// Sets a new value for the maximal number of iterations per step.
+    pub fn set_max_iter(&self, max_iter: usize) {
+        self.max_iter.set(max_iter)
+    }
+
+    /// Returns the maximal number of function evaluations per step.
+    pub fn get_max_eval(&self) -> usize {
+        self.max_eval.get()
+    }
+
+    /// Sets a new value for the maximal number of function evaluations per step.
+    pub fn set_max_eval(&self, max_eval: usize) {
+        self.max_eval.set(max_eval)
+    }
+
+    /// Returns the termination tolerance on first order optimality.
+    pub fn get_tolerance_grad(&self) -> f32 {
+        self.tolerance_grad.get()
+    }
+
+    /// Sets a new value for the termination tolerance on first order optimality.
+    pub fn set_tolerance_grad(&self, tolerance_grad: f32) {
+        self.tolerance_grad.set(tolerance_grad)
+    }
+
+    /// Returns the termination tolerance on function value and parameter changes.
+    pub fn get_tolerance_change(&self) -> f32 {
+        self.tolerance_change.get()
+    }
+
+    /// Sets a new value for the termination tolerance on function value and parameter changes.
+    pub fn set_tolerance_change(&self, tolerance_change: f32) {
+        self.tolerance_change.set(tolerance_change)
+    }
+
+    /// Returns the history size.
+    pub fn get_history_size(&self) -> usize {
+        self.history_size.get()
+    }
+
+    /// Sets a new value for the history size.
+    pub fn set_history_size(&self, history_size: usize) {
+        self.history_size.set(history_size)
+    }
+
+    /// Returns the current line search algorithm.
+    pub fn get_line_search(&self) -> Option<LineSearch> {
+        self.line_search.get()
+    }
+
+    /// Sets a new line search algorithm.
+    pub fn set_line_search(&self, line_search: impl Into<Option<LineSearch>>) {
+        self.line_search.set(line_search.into())
+    }
+
+    /// Registers the variable to this optimizer.
+    ///
+    /// As the variables are optimized together, registering a new one discards the curvature
+    /// information gathered so far.
+    pub fn register<D>(&self, variable: VarDiff<D>)
+    where
+        D: 'static + Dimension,
+    {
+        self.params.borrow_mut().push(Box::new(variable));
+        *self.state.borrow_mut() = State::default();
+    }
+
+    /// Zeroes the gradients of all the parameters registered in this optimizer.
+    pub fn zero_grad(&self) {
+        self.params
+            .borrow()
+            .iter()
+            .for_each(|param| param.zero_grad());
+    }
+
+    /// Performs a single optimization step, made of up to `max_iter` iterations, and returns the
+    /// loss computed by the first evaluation of `closure`.
+    ///
+    /// # Arguments
+    ///
+    /// `closure` - closure that clears the gradients, computes the loss, back-propagates it and
+    /// returns its value.
+    pub fn step<F>(&self, mut closure: F) -> f32
+    where
+        F: FnMut() -> f32,
+    {
+        let lr = self.get_lr();
+        let max_iter = self.get_max_iter();
+        let max_eval = self.get_max_eval();
+        let tolerance_grad = self.get_tolerance_grad();
+        let tolerance_change = self.get_tolerance_change();
+        let history_size = self.get_history_size();
+
+        let orig_loss = closure();
+        let mut loss = orig_loss;
+        let mut flat_grad = self.gather_grad();
+        let mut current_evals = 1;
+
+        // The closure may reference the optimizer, hence the state isn't borrowed during its
+        // evaluations.
+        let mut state = self.state.take();
+        state.func_evals += 1;
+
+        if max_abs(&flat_grad) <= tolerance_grad {
+            *self.state.borrow_mut() = state;
+            return orig_loss;
+        }
+
+        let mut n_iter = 0;
+        while n_iter < max_iter {
+            n_iter += 1;
+            state.n_iter += 1;
+
+            // Computes the search direction.
+            if state.n_iter == 1 {
+                state.direction = flat_grad.iter().map(|el| -el).collect();
+                state.old_dirs.clear();
+                state.old_steps.clear();
+                state.ro.clear();
+                state.hessian_diag = 1.0;
+            } else {
+                let y: Vec<f32> = flat_grad
+                    .iter()
+                    .zip(&state.prev_flat_grad)
+                    .map(|(grad_el, prev_el)| grad_el - prev_el)
+                    .collect();
+                let s: Vec<f32> = state
+                    .direction
+                    .iter()
+                    .map(|el| el * state.step_size)
+                    .collect();
+                let ys = dot(&y, &s);
+
+                // Updates the memory only if the curvature condition holds.
+                if ys > 1e-10 {
+                    // The history size may have been lowered since the last step.
+                    while state.old_dirs.len() >= history_size.max(1) {
+                        state.old_dirs.pop_front();
+                        state.old_steps.pop_front();
+                        state.ro.pop_front();
+                    }
+
+                    state.hessian_diag = ys / dot(&y, &y);
+                    state.old_dirs.push_back(y);
+                    state.old_steps.push_back(s);
+                    state.ro.push_back(1.0 / ys);
+                }
+
+                // Two-loop recursion computing the product between the approximated inverse
+                // Hessian and the gradient.
+                let mut alphas = vec![0.0; state.old_dirs.len()];
+                let mut q: Vec<f32> = flat_grad.iter().map(|el| -el).collect();
+                for i in (0..state.old_dirs.len()).rev() {
+                    alphas[i] = dot(&state.old_steps[i], &q) * state.ro[i];
+                    axpy(-alphas[i], &state.old_dirs[i], &mut q);
+                }
+
+                q.iter_mut().for_each(|el| *el *= state.hessian_diag);
+                for (i, alpha) in alphas.iter().enumerate() {
+                    let beta = dot(&state.old_dirs[i], &q) * state.ro[i];
+                    axpy(alpha - beta, &state.old_steps[i], &mut q);
+                }
+                state.direction = q;
+            }
+
+            state.prev_flat_grad = flat_grad.clone();
+            state.prev_loss = loss;
+
+            state.step_size = if state.n_iter == 1 {
+                (1.0 / flat_grad.iter().map(|el| el.abs()).sum::<f32>()).min(1.0) * lr
+            } else {
+                lr
+            };
+
+            // Directional derivative, stops if the direction isn't a descent one.
+            let gtd = dot(&flat_grad, &state.direction);
+            if gtd > -tolerance_change {
+                break;
+            }
+
+            let mut ls_func_evals = 0;
+            match self.get_line_search() {
+                Some(LineSearch::StrongWolfe) => {
+                    let x_init = self.gather_data();
+                    let mut objective = |step_size: f32| {
+                        self.add_to_data(step_size, &state.direction);
+                        let loss = closure();
+                        let flat_grad = self.gather_grad();
+                        self.set_data(&x_init);
+                        (loss, flat_grad)
+                    };
+
+                    let (new_loss, new_flat_grad, step_size, evals) = strong_wolfe(
+                        &mut objective,
+                        &state.direction,
+                        state.step_size,
+                        loss,
+                        flat_grad,
+                        gtd,
+                        tolerance_change,
+                    );
+
+                    loss = new_loss;
+                    flat_grad = new_flat_grad;
+                    state.step_size = step_size;
+                    ls_func_evals = evals;
+                    self.add_to_data(state.step_size, &state.direction);
+                }
+                None => {
+                    self.add_to_data(state.step_size, &state.direction);
+                    if n_iter != max_iter {
+                        // Re-evaluates the function only when not at the last iteration, the
+                        // loss and the gradient are needed just by the termination tests.
+                        loss = closure();
+                        flat_grad = self.gather_grad();
+                        ls_func_evals = 1;
+                    }
+                }
+            }
+            current_evals += ls_func_evals;
+            state.func_evals += ls_func_evals;
+
+            // Termination conditions.
+            if n_iter == max_iter || current_evals >= max_eval {
+                break;
+            }
+
+            if max_abs(&flat_grad) <= tolerance_grad {
+                break;
+            }
+
+            if max_abs(&state.direction) * state.step_size.abs() <= tolerance_change {
+                break;
+            }
+
+            if (loss - state.prev_loss).abs() < tolerance_change {
+                break;
+            }
+        }
+
+        *self.state.borrow_mut() = state;
+
+        orig_loss
+    }
+
+    /// Returns the concatenation of the gradients of the registered variables.
+    fn gather_grad(&self) -> Vec<f32> {
+        let params = self.params.borrow();
+        let mut flat = Vec::with_capacity(params.iter().map(|param| param.len()).sum());
+        params.iter().for_each(|param| param.gather_grad(&mut flat));
+        flat
+    }
+
+    /// Returns the concatenation of the data of the registered variables.
+    fn gather_data(&self) -> Vec<f32> {
+        let params = self.params.borrow();
+        let mut flat = Vec::with_capacity(params.iter().map(|param| param.len()).sum());
+        params.iter().for_each(|param| param.gather_data(&mut flat));
+        flat
+    }
+
+    /// Adds `alpha * direction` to the data of the registered variables.
+    fn add_to_data(&self, alpha: f32, direction: &[f32]) {
+        let mut offset = 0;
+        for param in self.params.borrow().iter() {
+            let len = param.len();
+            param.add_to_data(alpha, &direction[offset..offset + len]);
+            offset += len;
+        }
+    }
+
+    /// Overwrites the data of the registered variables.
+    fn set_data(&self, flat: &[f32]) {
+        let mut offset = 0;
+        for param in self.params.borrow().iter() {
+            let len = param.len();
+            param.set_data(&flat[offset..offset + len]);
+            offset += len;
+        }
+    }
+}
+
+/// State of the L-BFGS optimizer, carried over between steps.
+#[derive(Default)]
+struct State {
+    n_iter: usize,
+    func_evals: usize,
+    direction: Vec<f32>,
+    step_size: f32,
+    old_dirs: VecDeque<Vec<f32>>,
+    old_steps: VecDeque<Vec<f32>>,
+    ro: VecDeque<f32>,
+    hessian_diag: f32,
+    prev_flat_grad: Vec<f32>,
+    prev_loss: f32,
+}
+
+/// Flattened view over a parameter of the L-BFGS optimizer.
+trait FlatParam {
+    /// Number of elements of the parameter.
+    fn len(&self) -> usize;
+
+    /// Appends the gradient elements, in logical order, to `flat`.
+    fn gather_grad(&self, flat: &mut Vec<f32>);
+
+    /// Appends the data elements, in logical order, to `flat`.
+    fn gather_data(&self, flat: &mut Vec<f32>);
+
+    /// Adds `alpha * direction` to the data.
+    fn add_to_data(&self, alpha: f32, direction: &[f32]);
+
+    /// Overwrites the data.
+    fn set_data(&self, flat: &[f32]);
+
+    /// Zeroes the gradient.
+    fn zero_grad(&self);
+}
+
+impl<D> FlatParam for VarDiff<D>
+where
+    D: Dimension,
+{
+    fn len(&self) -> usize {
+        self.data().len()
+    }
+
+    fn gather_grad(&self, flat: &mut Vec<f32>) {
+        flat.extend(self.grad().iter());
+    }
+
+    fn gather_data(&self, flat: &mut Vec<f32>) {
+        flat.extend(self.data().iter());
+    }
+
+    fn add_to_data(&self, alpha: f32, direction: &[f32]) {
+        self.data_mut()
+            .iter_mut()
+            .zip(direction)
+            .for_each(|(data_el, direction_el)| *data_el += alpha * direction_el);
+    }
+
+    fn set_data(&self, flat: &[f32]) {
+        self.data_mut()
+            .iter_mut()
+            .zip(flat)
+            .for_each(|(data_el, flat_el)| *data_el = *flat_el);
+    }
+
+    fn zero_grad(&self) {
+        VarDiff::zero_grad(self)
+    }
+}
+
+/// A point evaluated by the line search.
+#[derive(Clone)]
+struct Point {
+    step_size: f32,
+    loss: f32,
+    grad: Vec<f32>,
+    gtd: f32,
+}
+
+/// Strong Wolfe line search. Returns the loss, the gradient and the step size of the selected
+/// point, together with the number of function evaluations.
+fn strong_wolfe<F>(
+    objective: &mut F,
+    direction: &[f32],
+    step_size: f32,
+    loss: f32,
+    grad: Vec<f32>,
+    gtd: f32,
+    tolerance_change: f32,
+) -> (f32, Vec<f32>, f32, usize)
+where
+    F: FnMut(f32) -> (f32, Vec<f32>),
+{
+    const C1: f32 = 1e-4;
+    const C2: f32 = 0.9;
+    const MAX_LS: usize = 25;
+
+    let evaluate = |objective: &mut F, step_size: f32| {
+        let (loss, grad) = objective(step_size);
+        let gtd = dot(&grad, direction);
+        Point {
+            step_size,
+            loss,
+            grad,
+            gtd,
+        }
+    };
+
+    let direction_norm = max_abs(direction);
+    let start = Point {
+        step_size: 0.0,
+        loss,
+        grad,
+        gtd,
+    };
+
+    let mut new = evaluate(objective, step_size);
+    let mut func_evals = 1;
+    let mut prev = start.clone();
+    let mut ls_iter = 0;
+    let mut done = false;
+
+    // Bracketing phase.
+    let mut bracket = loop {
+        if ls_iter == MAX_LS {
+            break vec![start.clone(), new];
+        }
+
+        if new.loss > loss + C1 * new.step_size * gtd || (ls_iter > 1 && new.loss >= prev.loss) {
+            break vec![prev, new];
+        }
+
+        if new.gtd.abs() <= -C2 * gtd {
+            done = true;
+            break vec![new];
+        }
+
+        if new.gtd >= 0.0 {
+            break vec![prev, new];
+        }
+
+        // Interpolates within an extrapolation interval.
+        let min_step = new.step_size + 0.01 * (new.step_size - prev.step_size);
+        let max_step = new.step_size * 10.0;
+        let step_size = cubic_interpolate(&prev, &new, Some((min_step, max_step)));
+
+        prev = new;
+        new = evaluate(objective, step_size);
+        func_evals += 1;
+        ls_iter += 1;
+    };
+
+    // Zoom phase, refines the bracket until a point satisfying the strong Wolfe conditions is
+    // found.
+    let mut insufficient_progress = false;
+    let (mut low, mut high) = if bracket[0].loss <= bracket[bracket.len() - 1].loss {
+        (0, 1)
+    } else {
+        (1, 0)
+    };
+
+    while !done && ls_iter < MAX_LS {
+        let bracket_min = bracket[0].step_size.min(bracket[1].step_size);
+        let bracket_max = bracket[0].step_size.max(bracket[1].step_size);
+        if (bracket_max - bracket_min) * direction_norm < tolerance_change {
+            break;
+        }
+
+        let mut step_size = cubic_interpolate(&bracket[0], &bracket[1], None);
+
+        // Moves away from the boundaries if the interpolation makes insufficient progress.
+        let eps = 0.1 * (bracket_max - bracket_min);
+        if (bracket_max - step_size).min(step_size - bracket_min) < eps {
+            if insufficient_progress || step_size >= bracket_max || step_size <= bracket_min {
+                step_size = if (step_size - bracket_max).abs() < (step_size - bracket_min).abs() {
+                    bracket_max - eps
+                } else {
+                    bracket_min + eps
+                };
+                insufficient_progress = false;
+            } else {
+                insufficient_progress = true;
+            }
+        } else {
+            insufficient_progress = false;
+        }
+
+        let new = evaluate(objective, step_size);
+        func_evals += 1;
+        ls_iter += 1;
+
+        if new.loss > loss + C1 * step_size * gtd || new.loss >= bracket[low].loss {
+            bracket[high] = new;
+            (low, high) = if bracket[0].loss <= bracket[1].loss {
+                (0, 1)
+            } else {
+                (1, 0)
+            };
+        } else {
+            if new.gtd.abs() <= -C2 * gtd {
+                done = true;
+            } else if new.gtd * (bracket[high].step_size - bracket[low].step_size) >= 0.0 {
+                bracket[high] = bracket[low].clone();
+            }
+
+            bracket[low] = new;
+        }
+    }
+
+    let low = if bracket.len() == 1 { 0 } else { low };
+    let Point {
+        step_size,
+        loss,
+        grad,
+        ..
+    } = bracket.swap_remove(low);
+
+    (loss, grad, step_size, func_evals)
+}
+
+/// Minimizer of the cubic interpolating the two points, clamped in `bounds` if provided, or in
+/// the interval between the points otherwise.
+fn cubic_interpolate(first: &Point, second: &Point, bounds: Option<(f32, f32)>) -> f32 {
+    let (x1, f1, g1) = (first.step_size, first.loss, first.gtd);
+    let (x2, f2, g2) = (second.step_size, second.loss, second.gtd);
+    let (min_bound, max_bound) = bounds.unwrap_or((x1.min(x2), x1.max(x2)));
+
+    let d1 = g1 + g2 - 3.0 * (f1 - f2) / (x1 - x2);
+    let d2_square = d1 * d1 - g1 * g2;
+    if d2_square >= 0.0 {
+        let d2 = d2_square.sqrt();
+        let min_pos = if x1 <= x2 {
+            x2 - (x2 - x1) * ((g2 + d2 - d1) / (g2 - g1 + 2.0 * d2))
+        } else {
+            x1 - (x1 - x2) * ((g1 + d2 - d1) / (g1 - g2 + 2.0 * d2))
+        };
+
+        min_pos.max(min_bound).min(max_bound)
+    } else {
+        (min_bound + max_bound) / 2.0
+    }
+}
+
+fn dot(lhs: &[f32], rhs: &[f32]) -> f32 {
+    lhs.iter()
+        .zip(rhs)
+        .map(|(lhs_el, rhs_el)| lhs_el * rhs_el)
+        .sum()
+}
+
+fn axpy(alpha: f32, x: &[f32], y: &mut [f32]) {
+    y.iter_mut()
+        .zip(x)
+        .for_each(|(y_el, x_el)| *y_el += alpha * x_el);
+}
+
+fn max_abs(x: &[f32]) -> f32 {
+    x.iter().fold(0.0, |acc, el| acc.max(el.abs()))
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,167 @@
+use ndarray::array;
+
+use neuronika_variable::VarDiff;
+
+use super::{LineSearch, LBFGS};
+
+#[test]
+fn creation() {
+    let optim = LBFGS::new(1.0, 20, 100, LineSearch::StrongWolfe);
+
+    assert!((optim.get_lr() - 1.0).abs() <= f32::EPSILON);
+    assert_eq!(optim.get_max_iter(), 20);
+    assert_eq!(optim.get_max_eval(), 25);
+    assert!((optim.get_tolerance_grad() - 1e-7).abs() <= f32::EPSILON);
+    assert!((optim.get_tolerance_change() - 1e-9).abs() <= f32::EPSILON);
+    assert_eq!(optim.get_history_size(), 100);
+    assert_eq!(optim.get_line_search(), Some(LineSearch::StrongWolfe));
+}
+
+#[test]
+fn set_options() {
+    let optim = LBFGS::new(1.0, 20, 100, None);
+
+    optim.set_lr(0.5);
+    optim.set_max_iter(10);
+    optim.set_max_eval(15);
+    optim.set_tolerance_grad(1e-5);
+    optim.set_tolerance_change(1e-7);
+    optim.set_history_size(5);
+    optim.set_line_search(LineSearch::StrongWolfe);
+
+    assert!((optim.get_lr() - 0.5).abs() <= f32::EPSILON);
+    assert_eq!(optim.get_max_iter(), 10);
+    assert_eq!(optim.get_max_eval(), 15);
+    assert!((optim.get_tolerance_grad() - 1e-5).abs() <= f32::EPSILON);
+    assert!((optim.get_tolerance_change() - 1e-7).abs() <= f32::EPSILON);
+    assert_eq!(optim.get_history_size(), 5);
+    assert_eq!(optim.get_line_search(), Some(LineSearch::StrongWolfe));
+}
+
+/// Rosenbrock function, whose minimum is in *(1, 1)*.
+fn rosenbrock(x: VarDiff<ndarray::Ix1>, y: VarDiff<ndarray::Ix1>) -> f32 {
+    let one = neuronika_variable::ones(1);
+    let hundred = neuronika_variable::full(1, 100.);
+
+    let loss = ((one - x.clone()).pow(2) + (y - x.pow(2)).pow(2) * hundred).sum();
+    loss.forward();
+    loss.backward(1.0);
+
+    loss.item()
+}
+
+#[test]
+fn strong_wolfe() {
+    let x = neuronika_variable::from_ndarray(array![-1.5]).requires_grad();
+    let y = neuronika_variable::from_ndarray(array![2.0]).requires_grad();
+
+    let optim = LBFGS::new(1.0, 20, 10, LineSearch::StrongWolfe);
+    optim.register(x.clone());
+    optim.register(y.clone());
+
+    let first_value = optim.step(|| {
+        optim.zero_grad();
+        rosenbrock(x.clone(), y.clone())
+    });
+    for _ in 0..10 {
+        optim.step(|| {
+            optim.zero_grad();
+            rosenbrock(x.clone(), y.clone())
+        });
+    }
+
+    assert!((first_value - 12.5).abs() <= 1e-4);
+    assert!((x.data()[0] - 1.0).abs() <= 1e-2);
+    assert!((y.data()[0] - 1.0).abs() <= 1e-2);
+}
+
+#[test]
+fn fixed_step() {
+    let x = neuronika_variable::from_ndarray(array![[1.5, -0.5], [0.0, 3.0]]).requires_grad();
+    let target = neuronika_variable::from_ndarray(array![[0.5, 2.0], [-1.0, 1.0]]);
+
+    let optim = LBFGS::new(1.0, 20, 10, None);
+    optim.register(x.clone());
+
+    optim.step(|| {
+        optim.zero_grad();
+
+        let loss = (x.clone() - target.clone()).pow(2).sum();
+        loss.forward();
+        loss.backward(1.0);
+
+        loss.item()
+    });
+
+    x.data()
+        .iter()
+        .zip(target.data().iter())
+        .for_each(|(value, expected)| assert!((value - expected).abs() <= 1e-4));
+}
+
+#[test]
+fn history_size() {
+    let x = neuronika_variable::from_ndarray(array![-1.5]).requires_grad();
+    let y = neuronika_variable::from_ndarray(array![2.0]).requires_grad();
+
+    let optim = LBFGS::new(1.0, 20, 2, LineSearch::StrongWolfe);
+    optim.register(x.clone());
+    optim.register(y.clone());
+
+    optim.step(|| {
+        optim.zero_grad();
+        rosenbrock(x.clone(), y.clone())
+    });
+
+    let state = optim.state.borrow();
+    assert!(state.n_iter > 2);
+    assert_eq!(state.old_dirs.len(), 2);
+    assert_eq!(state.old_steps.len(), 2);
+}
+
+#[test]
+fn shrink_history_size() {
+    let x = neuronika_variable::from_ndarray(array![-1.5]).requires_grad();
+    let y = neuronika_variable::from_ndarray(array![2.0]).requires_grad();
+
+    let optim = LBFGS::new(1.0, 10, 5, LineSearch::StrongWolfe);
+    optim.register(x.clone());
+    optim.register(y.clone());
+
+    optim.step(|| {
+        optim.zero_grad();
+        rosenbrock(x.clone(), y.clone())
+    });
+    assert!(optim.state.borrow().old_dirs.len() > 2);
+
+    optim.set_history_size(2);
+    optim.step(|| {
+        optim.zero_grad();
+        rosenbrock(x.clone(), y.clone())
+    });
+
+    let state = optim.state.borrow();
+    assert_eq!(state.old_dirs.len(), 2);
+    assert_eq!(state.old_steps.len(), 2);
+    assert_eq!(state.ro.len(), 2);
+}
+
+#[test]
+fn optimal_start() {
+    let x = neuronika_variable::from_ndarray(array![1.0]).requires_grad();
+    let y = neuronika_variable::from_ndarray(array![1.0]).requires_grad();
+
+    let optim = LBFGS::new(1.0, 20, 10, LineSearch::StrongWolfe);
+    optim.register(x.clone());
+    optim.register(y.clone());
+
+    let value = optim.step(|| {
+        optim.zero_grad();
+        rosenbrock(x.clone(), y.clone())
+    });
+
+    assert!(value.abs() <= f32::EPSILON);
+    assert_eq!(optim.state.borrow().func_evals, 1);
+    assert_eq!(*x.data(), array![1.0]);
+    assert_eq!(*y.data(), array![1.0]);
+}
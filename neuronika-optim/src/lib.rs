@@ -1,16 +1,37 @@
+mod adadelta;
 mod adagrad;
 mod adam;
+mod adamax;
+mod adamw;
 mod amsgrad;
+mod lamb;
+mod lars;
+mod lbfgs;
+mod lion;
+mod nadam;
 mod optimizer;
 mod penalty;
+mod radam;
 mod rmsprop;
+mod rprop;
 mod sgd;
 
 pub mod lr_scheduler;
 
+pub use adadelta::*;
 pub use adagrad::*;
 pub use adam::*;
+pub use adamax::*;
+pub use adamw::*;
+pub use amsgrad::*;
+pub use lamb::*;
+pub use lars::*;
+pub use lbfgs::*;
+pub use lion::*;
+pub use nadam::*;
 pub use optimizer::*;
 pub use penalty::*;
+pub use radam::*;
 pub use rmsprop::*;
+pub use rprop::*;
 pub use sgd::*;
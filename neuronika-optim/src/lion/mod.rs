@@ -0,0 +1,262 @@
+use std::{cell::Cell, rc::Rc};
+
+use ndarray::{Array, Dimension, Zip};
+
+use neuronika_variable::VarDiff;
+
+use super::{
+    GroupOverrides, GroupStatus, IntoParam, NoDecay, Optimize, Optimizer, OptimizerStatus,
+};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::StateDict;
+
+#[cfg(feature = "serialize")]
+use super::assign;
+
+/// Lion optimizer.
+///
+/// It has been discovered in
+/// [Symbolic Discovery of Optimization Algorithms](https://arxiv.org/abs/2302.06675) and updates
+/// the parameters by the sign of an interpolation between the momentum and the gradient, so that
+/// every element moves by the learning rate. A learning rate 3-10 times smaller than the one used
+/// with *AdamW*, together with a proportionally larger weight decay, is usually a good choice.
+///
+/// The weight decay is decoupled, parameters registered wrapped in [`NoDecay`] are excluded from
+/// it.
+#[derive(Clone)]
+pub struct Lion {
+    lr: Cell<f32>,
+    beta1: Cell<f32>,
+    beta2: Cell<f32>,
+    weight_decay: Cell<f32>,
+}
+
+impl OptimizerStatus for Lion {
+    fn get_lr(&self) -> f32 {
+        self.lr.get()
+    }
+
+    fn set_lr(&self, lr: f32) {
+        self.lr.set(lr)
+    }
+
+    fn get_cycle_momentum(&self) -> Option<f32> {
+        Some(self.beta1.get())
+    }
+
+    fn set_cycle_momentum(&self, momentum: f32) {
+        self.beta1.set(momentum)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn hyperparameters(&self) -> Vec<(&'static str, f32)> {
+        vec![
+            ("lr", self.lr.get()),
+            ("beta1", self.beta1.get()),
+            ("beta2", self.beta2.get()),
+            ("weight_decay", self.weight_decay.get()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn set_hyperparameter(&self, name: &str, value: f32) {
+        match name {
+            "lr" => self.lr.set(value),
+            "beta1" => self.beta1.set(value),
+            "beta2" => self.beta2.set(value),
+            "weight_decay" => self.weight_decay.set(value),
+            _ => (),
+        }
+    }
+}
+
+impl GroupStatus for Lion {
+    fn with_overrides(&self, overrides: &GroupOverrides) -> Self {
+        overrides.reject_momentum("Lion");
+
+        let status = self.clone();
+        if let Some(lr) = overrides.get_lr() {
+            status.lr.set(lr);
+        }
+        if let Some(weight_decay) = overrides.get_weight_decay() {
+            status.weight_decay.set(weight_decay);
+        }
+
+        status
+    }
+}
+
+impl Lion {
+    /// Creates a new Lion optimizer.
+    ///
+    /// # Arguments
+    ///
+    /// * `lr` - learning rate.
+    ///
+    /// * `beta1` - coefficient for interpolating the momentum and the gradient in the update. A
+    ///   good default is 0.9.
+    ///
+    /// * `beta2` - coefficient for computing the running average of the gradient. A good default
+    ///   is 0.99.
+    ///
+    /// * `weight_decay` - decoupled weight decay coefficient.
+    pub fn new(lr: f32, beta1: f32, beta2: f32, weight_decay: f32) -> Optimizer<Self> {
+        let lr = Cell::new(lr);
+        let beta1 = Cell::new(beta1);
+        let beta2 = Cell::new(beta2);
+        let weight_decay = Cell::new(weight_decay);
+
+        let status = Self {
+            lr,
+            beta1,
+            beta2,
+            weight_decay,
+        };
+
+        Optimizer::new(status)
+    }
+
+    /// Return the current learning rate.
+    pub fn get_lr(&self) -> f32 {
+        OptimizerStatus::get_lr(self)
+    }
+
+    /// Sets a new value for the learning rate.
+    pub fn set_lr(&self, lr: f32) {
+        OptimizerStatus::set_lr(self, lr);
+    }
+
+    /// Return the current interpolation coefficient of the update.
+    pub fn get_beta1(&self) -> f32 {
+        self.beta1.get()
+    }
+
+    /// Return the current running average coefficient.
+    pub fn get_beta2(&self) -> f32 {
+        self.beta2.get()
+    }
+
+    /// Sets a new value for the interpolation coefficient of the update.
+    pub fn set_beta1(&self, beta1: f32) {
+        self.beta1.set(beta1)
+    }
+
+    /// Sets a new value for the running average coefficient.
+    pub fn set_beta2(&self, beta2: f32) {
+        self.beta2.set(beta2)
+    }
+
+    /// Returns the current weight decay coefficient.
+    pub fn get_weight_decay(&self) -> f32 {
+        self.weight_decay.get()
+    }
+
+    /// Sets a new value for the weight decay coefficient.
+    pub fn set_weight_decay(&self, weight_decay: f32) {
+        self.weight_decay.set(weight_decay)
+    }
+}
+
+/// A parameter used by the Lion optimizer.
+pub struct LionParam<D>
+where
+    D: Dimension,
+{
+    variable: VarDiff<D>,
+    decay: bool,
+    exp_avg: Array<f32, D>,
+    status: Rc<Lion>,
+}
+
+impl<D> LionParam<D>
+where
+    D: Dimension,
+{
+    fn new(variable: VarDiff<D>, decay: bool, status: Rc<Lion>) -> Self {
+        let exp_avg = Array::zeros(variable.data().raw_dim());
+
+        Self {
+            variable,
+            decay,
+            exp_avg,
+            status,
+        }
+    }
+}
+
+impl<D> IntoParam<Lion> for VarDiff<D>
+where
+    D: 'static + Dimension,
+{
+    type Param = LionParam<D>;
+
+    fn into_param(self, status: Rc<Lion>) -> Self::Param {
+        LionParam::new(self, true, status)
+    }
+}
+
+impl<D> IntoParam<Lion> for NoDecay<D>
+where
+    D: 'static + Dimension,
+{
+    type Param = LionParam<D>;
+
+    fn into_param(self, status: Rc<Lion>) -> Self::Param {
+        LionParam::new(self.0, false, status)
+    }
+}
+
+impl<D> Optimize for LionParam<D>
+where
+    D: Dimension,
+{
+    fn optimize(&mut self) {
+        let lr = self.status.get_lr();
+        let beta1 = self.status.get_beta1();
+        let beta2 = self.status.get_beta2();
+        let weight_decay = self.status.get_weight_decay();
+
+        let mut data = self.variable.data_mut();
+        let grad = self.variable.grad();
+
+        if self.decay {
+            data.mapv_inplace(|data_el| data_el * (1.0 - lr * weight_decay));
+        }
+
+        Zip::from(&mut *data)
+            .and(&*grad)
+            .and(&mut self.exp_avg)
+            .for_each(|data_el, grad_el, exp_avg_el| {
+                let update = *exp_avg_el * beta1 + grad_el * (1.0 - beta1);
+                if update != 0.0 {
+                    *data_el -= update.signum() * lr;
+                }
+                *exp_avg_el = *exp_avg_el * beta2 + grad_el * (1.0 - beta2);
+            });
+    }
+
+    fn zero_grad(&mut self) {
+        self.variable.zero_grad()
+    }
+
+    fn for_each_grad(&mut self, f: &mut dyn FnMut(&mut f32)) {
+        self.variable.grad_mut().iter_mut().for_each(f)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(&'static str, ArrayD<f32>)> {
+        vec![("exp_avg", self.exp_avg.clone().into_dyn())]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&mut self, state: &StateDict) {
+        assign(&mut self.exp_avg, &state["exp_avg"]);
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,88 @@
+use super::Lion;
+
+#[test]
+fn creation() {
+    let optim = Lion::new(1e-2, 0.9, 0.99, 1e-2);
+
+    assert!((optim.get_lr() - 1e-2).abs() <= f32::EPSILON);
+    assert!((optim.status().get_beta1() - 0.9).abs() <= f32::EPSILON);
+    assert!((optim.status().get_beta2() - 0.99).abs() <= f32::EPSILON);
+    assert!((optim.status().get_weight_decay() - 1e-2).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_lr() {
+    let optim = Lion::new(1e-2, 0.9, 0.99, 1e-2);
+
+    optim.set_lr(1e-3);
+    assert!((optim.get_lr() - 1e-3).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_betas() {
+    let optim = Lion::new(1e-2, 0.9, 0.99, 1e-2);
+
+    optim.status().set_beta1(0.95);
+    optim.status().set_beta2(0.98);
+    assert!((optim.status().get_beta1() - 0.95).abs() <= f32::EPSILON);
+    assert!((optim.status().get_beta2() - 0.98).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_weight_decay() {
+    let optim = Lion::new(1e-2, 0.9, 0.99, 1e-2);
+
+    optim.status().set_weight_decay(0.1);
+    assert!((optim.status().get_weight_decay() - 0.1).abs() <= f32::EPSILON);
+}
+
+const EPOCHS: usize = 10;
+
+#[test]
+fn step() {
+    let x = neuronika_variable::rand((3, 3)).requires_grad();
+    let y = neuronika_variable::rand((3, 3));
+    let z = neuronika_variable::rand((3, 3));
+
+    let loss = (x.clone().mm(y) - z).pow(2).sum();
+    loss.forward();
+
+    let first_value = loss.item();
+
+    let optim = Lion::new(1e-4, 0.9, 0.99, 0.0);
+    optim.register(x);
+
+    for _ in 0..EPOCHS {
+        loss.forward();
+        loss.backward(1.0);
+
+        optim.step();
+        optim.zero_grad();
+    }
+
+    assert!(loss.item() < first_value);
+}
+
+#[test]
+fn reference() {
+    let x = neuronika_variable::from_ndarray(ndarray::array![1., -2., 0.]).requires_grad();
+
+    let optim = Lion::new(0.01, 0.9, 0.99, 0.1);
+    optim.register(x.clone());
+
+    for _ in 0..EPOCHS {
+        let loss = x.clone().pow(2).sum();
+        loss.forward();
+        loss.backward(1.0);
+
+        optim.step();
+        optim.zero_grad();
+    }
+
+    // Reference values computed with a double precision implementation of the update rule.
+    let expected = [0.8904937, -1.8805386, 0.0];
+    x.data()
+        .iter()
+        .zip(expected)
+        .for_each(|(value, expected)| assert!((value - expected).abs() <= 1e-5));
+}
@@ -0,0 +1,146 @@
+use std::cell::Cell;
+
+use super::{prepare_step, LRScheduler};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::{CheckpointError, StateDict};
+
+#[cfg(feature = "serialize")]
+use super::{epoch_state, load_epoch_state, load_nested_state, nested_state};
+
+/// Steps a list of learning rate schedulers together, in order, as one.
+///
+/// This is equivalent to calling `.step()` on each of them after every epoch. The current
+/// learning rate is the one computed by the last scheduler.
+pub struct ChainedScheduler {
+    schedulers: Vec<Box<dyn LRScheduler>>,
+    current_epoch: Cell<usize>,
+    current_lr: Cell<f32>,
+    last_lr: Cell<f32>,
+}
+
+impl ChainedScheduler {
+    /// Creates a new ChainedScheduler.
+    ///
+    /// # Arguments
+    ///
+    /// * `schedulers` - list of schedulers to step, in order.
+    ///
+    /// # Panics
+    ///
+    /// If `schedulers` is empty.
+    pub fn new(schedulers: Vec<Box<dyn LRScheduler>>) -> Self {
+        let current_lr = schedulers
+            .last()
+            .expect("error: at least one scheduler is needed.")
+            .get_current_lr();
+
+        Self {
+            schedulers,
+            current_epoch: Cell::new(0),
+            current_lr: Cell::new(current_lr),
+            last_lr: Cell::new(0.0),
+        }
+    }
+
+    /// Steps all the chained schedulers.
+    pub fn step(&self) {
+        LRScheduler::step(self);
+    }
+
+    /// Returns the last learning rate value computed by this learning rate scheduler.
+    pub fn get_last_lr(&self) -> f32 {
+        LRScheduler::get_last_lr(self)
+    }
+
+    /// Returns the current learning rate value computed by this learning rate scheduler.
+    pub fn get_current_lr(&self) -> f32 {
+        LRScheduler::get_current_lr(self)
+    }
+
+    /// Sets the current epoch for this learning rate scheduler.
+    pub fn set_current_epoch(&self, epoch: usize) {
+        LRScheduler::set_current_epoch(self, epoch);
+    }
+
+    /// Returns the current epoch for this learning rate scheduler.
+    pub fn get_current_epoch(&self) -> usize {
+        LRScheduler::get_current_epoch(self)
+    }
+
+    /// Prints the learning rate update together with the epoch.
+    pub fn print_lr(&self) {
+        LRScheduler::print_lr(self);
+    }
+
+    /// Returns the state of this learning rate scheduler.
+    #[cfg(feature = "serialize")]
+    pub fn state_dict(&self) -> StateDict {
+        LRScheduler::state_dict(self)
+    }
+
+    /// Restores the state of this learning rate scheduler from `state`.
+    ///
+    /// # Errors
+    ///
+    /// See [`LRScheduler::load_state_dict()`].
+    #[cfg(feature = "serialize")]
+    pub fn load_state_dict(&self, state: &StateDict) -> Result<(), CheckpointError> {
+        LRScheduler::load_state_dict(self, state)
+    }
+}
+
+impl LRScheduler for ChainedScheduler {
+    fn step(&self) {
+        prepare_step(&self.last_lr, &self.current_lr, &self.current_epoch);
+        self.schedulers
+            .iter()
+            .for_each(|scheduler| scheduler.step());
+        self.current_lr
+            .set(self.schedulers.last().unwrap().get_current_lr());
+    }
+
+    fn get_last_lr(&self) -> f32 {
+        self.last_lr.get()
+    }
+
+    fn get_current_lr(&self) -> f32 {
+        self.current_lr.get()
+    }
+
+    fn set_current_epoch(&self, epoch: usize) {
+        self.current_epoch.replace(epoch);
+        self.schedulers
+            .iter()
+            .for_each(|scheduler| scheduler.set_current_epoch(epoch));
+    }
+
+    fn get_current_epoch(&self) -> usize {
+        self.current_epoch.get()
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(String, ArrayD<f32>)> {
+        let mut state = epoch_state(&self.current_epoch, &self.current_lr, &self.last_lr);
+        state.extend(nested_state(&self.schedulers));
+        state
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&self, name: &str, value: &ArrayD<f32>) {
+        load_epoch_state(
+            &self.current_epoch,
+            &self.current_lr,
+            &self.last_lr,
+            name,
+            value,
+        );
+        load_nested_state(&self.schedulers, name, value);
+    }
+}
+
+#[cfg(test)]
+mod test;
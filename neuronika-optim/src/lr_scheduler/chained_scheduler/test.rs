@@ -0,0 +1,77 @@
+use crate::{StochasticGD, L2};
+
+use super::{
+    super::{ExponentialLR, StepLR},
+    ChainedScheduler,
+};
+
+#[test]
+fn chained_scheduler() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let step = StepLR::new(&optim, 2, 0.5);
+    let exponential = ExponentialLR::new(&optim, 2.0);
+    let scheduler = ChainedScheduler::new(vec![Box::new(step), Box::new(exponential)]);
+
+    for epoch in 0..4 {
+        assert_eq!(scheduler.get_current_epoch(), epoch);
+        scheduler.step();
+        scheduler.print_lr();
+
+        let expected = 2_f32.powi(epoch as i32 + 1);
+        assert!((scheduler.get_current_lr() - expected).abs() <= f32::EPSILON);
+        assert!((optim.get_lr() - expected).abs() <= f32::EPSILON);
+    }
+
+    scheduler.set_current_epoch(0);
+    assert_eq!(scheduler.get_current_epoch(), 0);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn chained_scheduler_state_dict_without_nested_state() {
+    use std::cell::Cell;
+
+    use super::super::LRScheduler;
+
+    /// A scheduler that implements only the required methods of [`LRScheduler`].
+    #[derive(Default)]
+    struct ConstantLR {
+        current_epoch: Cell<usize>,
+    }
+
+    impl LRScheduler for ConstantLR {
+        fn step(&self) {
+            self.current_epoch.set(self.current_epoch.get() + 1);
+        }
+
+        fn get_last_lr(&self) -> f32 {
+            1.0
+        }
+
+        fn get_current_lr(&self) -> f32 {
+            1.0
+        }
+
+        fn get_current_epoch(&self) -> usize {
+            self.current_epoch.get()
+        }
+
+        fn set_current_epoch(&self, epoch: usize) {
+            self.current_epoch.set(epoch);
+        }
+    }
+
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let step = StepLR::new(&optim, 2, 0.5);
+    let scheduler = ChainedScheduler::new(vec![Box::new(ConstantLR::default()), Box::new(step)]);
+    scheduler.step();
+
+    // The state of a scheduler without one is empty.
+    let state = scheduler.state_dict();
+    assert!(!state.keys().any(|name| name.starts_with("schedulers.0.")));
+    assert!(state.contains_key("schedulers.1.current_epoch"));
+
+    scheduler.step();
+    scheduler.load_state_dict(&state).unwrap();
+    assert_eq!(scheduler.get_current_epoch(), 1);
+}
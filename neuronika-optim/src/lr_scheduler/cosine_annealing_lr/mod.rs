@@ -0,0 +1,186 @@
+use std::cell::Cell;
+
+use crate::{Optimizer, OptimizerHandle, OptimizerStatus};
+
+use super::{cosine_annealing, prepare_step, LRScheduler};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::{CheckpointError, StateDict};
+
+#[cfg(feature = "serialize")]
+use super::{epoch_state, load_epoch_state, scalar, to_scalar};
+
+/// Anneals the learning rate from its initial value to `eta_min` following a cosine curve that
+/// completes half a period every `t_max` epochs.
+///
+///```text
+/// lrₜ = eta_min + (lr₀ - eta_min) * (1 + cos(π * t / t_max)) / 2
+///```
+pub struct CosineAnnealingLR<T>
+where
+    T: OptimizerStatus,
+{
+    optimizer: OptimizerHandle<T>,
+    t_max: Cell<usize>,
+    eta_min: Cell<f32>,
+    current_epoch: Cell<usize>,
+    current_lr: Cell<f32>,
+    last_lr: Cell<f32>,
+    initial_lr: Cell<f32>,
+}
+
+impl<T> CosineAnnealingLR<T>
+where
+    T: OptimizerStatus,
+{
+    /// Creates a new CosineAnnealingLR scheduler.
+    ///
+    /// # Arguments
+    ///
+    /// * `optimizer` - wrapped optimizer.
+    ///
+    /// * `t_max` - number of epochs needed to reach the minimum learning rate.
+    ///
+    /// * `eta_min` - minimum learning rate.
+    ///
+    /// # Panics
+    ///
+    /// If `t_max` is zero.
+    pub fn new(optimizer: &Optimizer<T>, t_max: usize, eta_min: f32) -> Self {
+        assert!(t_max > 0, "error: t_max must be positive.");
+
+        let optimizer = optimizer.handle();
+        let initial_lr = optimizer.initial_lr();
+
+        Self {
+            optimizer,
+            t_max: Cell::new(t_max),
+            eta_min: Cell::new(eta_min),
+            current_epoch: Cell::new(0),
+            current_lr: Cell::new(initial_lr),
+            last_lr: Cell::new(0.0),
+            initial_lr: Cell::new(initial_lr),
+        }
+    }
+
+    /// Sets a new number of epochs needed to reach the minimum learning rate.
+    ///
+    /// # Panics
+    ///
+    /// If `t_max` is zero.
+    pub fn set_t_max(&self, t_max: usize) {
+        assert!(t_max > 0, "error: t_max must be positive.");
+
+        self.t_max.set(t_max)
+    }
+
+    /// Sets a new minimum learning rate.
+    pub fn set_eta_min(&self, eta_min: f32) {
+        self.eta_min.set(eta_min)
+    }
+
+    /// Anneals the learning rate following the cosine curve.
+    pub fn step(&self) {
+        LRScheduler::step(self);
+    }
+
+    /// Returns the last learning rate value computed by this learning rate scheduler.
+    pub fn get_last_lr(&self) -> f32 {
+        LRScheduler::get_last_lr(self)
+    }
+
+    /// Returns the current learning rate value computed by this learning rate scheduler.
+    pub fn get_current_lr(&self) -> f32 {
+        LRScheduler::get_current_lr(self)
+    }
+
+    /// Sets the current epoch for this learning rate scheduler.
+    pub fn set_current_epoch(&self, epoch: usize) {
+        LRScheduler::set_current_epoch(self, epoch);
+    }
+
+    /// Returns the current epoch for this learning rate scheduler.
+    pub fn get_current_epoch(&self) -> usize {
+        LRScheduler::get_current_epoch(self)
+    }
+
+    /// Prints the learning rate update together with the epoch.
+    pub fn print_lr(&self) {
+        LRScheduler::print_lr(self);
+    }
+
+    /// Returns the state of this learning rate scheduler.
+    #[cfg(feature = "serialize")]
+    pub fn state_dict(&self) -> StateDict {
+        LRScheduler::state_dict(self)
+    }
+
+    /// Restores the state of this learning rate scheduler from `state`.
+    ///
+    /// # Errors
+    ///
+    /// See [`LRScheduler::load_state_dict()`].
+    #[cfg(feature = "serialize")]
+    pub fn load_state_dict(&self, state: &StateDict) -> Result<(), CheckpointError> {
+        LRScheduler::load_state_dict(self, state)
+    }
+}
+
+impl<T> LRScheduler for CosineAnnealingLR<T>
+where
+    T: OptimizerStatus,
+{
+    fn step(&self) {
+        prepare_step(&self.last_lr, &self.current_lr, &self.current_epoch);
+        let pct = self.current_epoch.get() as f32 / self.t_max.get() as f32;
+        self.current_lr.set(cosine_annealing(
+            self.initial_lr.get(),
+            self.eta_min.get(),
+            pct,
+        ));
+        self.optimizer.set_lr(self.current_lr.get());
+    }
+
+    fn get_last_lr(&self) -> f32 {
+        self.last_lr.get()
+    }
+
+    fn get_current_lr(&self) -> f32 {
+        self.current_lr.get()
+    }
+
+    fn set_current_epoch(&self, epoch: usize) {
+        self.current_epoch.replace(epoch);
+    }
+
+    fn get_current_epoch(&self) -> usize {
+        self.current_epoch.get()
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(String, ArrayD<f32>)> {
+        let mut state = epoch_state(&self.current_epoch, &self.current_lr, &self.last_lr);
+        state.push(("initial_lr".to_string(), scalar(self.initial_lr.get())));
+        state
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&self, name: &str, value: &ArrayD<f32>) {
+        match name {
+            "initial_lr" => self.initial_lr.set(to_scalar(value)),
+            _ => load_epoch_state(
+                &self.current_epoch,
+                &self.current_lr,
+                &self.last_lr,
+                name,
+                value,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,89 @@
+use crate::{StochasticGD, L2};
+
+use super::CosineAnnealingLR;
+
+#[test]
+fn cosine_annealing_lr() {
+    const EPOCHS: usize = 10;
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = CosineAnnealingLR::new(&optim, EPOCHS, 0.1);
+
+    for epoch in 0..EPOCHS {
+        assert_eq!(scheduler.get_current_epoch(), epoch);
+        scheduler.step();
+        scheduler.print_lr();
+
+        let expected =
+            0.1 + 0.9 * (1. + (std::f32::consts::PI * (epoch + 1) as f32 / 10.).cos()) / 2.;
+        assert!((scheduler.get_current_lr() - expected).abs() <= 1e-6);
+        assert!((optim.get_lr() - expected).abs() <= 1e-6);
+    }
+    assert!((scheduler.get_current_lr() - 0.1).abs() <= 1e-6);
+}
+
+#[test]
+#[should_panic]
+fn cosine_annealing_lr_invalid_t_max() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    CosineAnnealingLR::new(&optim, 0, 0.1);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn cosine_annealing_lr_state_dict() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = CosineAnnealingLR::new(&optim, 10, 0.1);
+    for _ in 0..4 {
+        scheduler.step();
+    }
+
+    // The initial learning rate is restored, so the resumed optimizer can start from any value.
+    let resumed_optim = StochasticGD::new(0.5, L2::new(0.1), None, None, false);
+    let resumed = CosineAnnealingLR::new(&resumed_optim, 10, 0.1);
+    resumed.load_state_dict(&scheduler.state_dict()).unwrap();
+    assert_eq!(resumed.get_current_epoch(), 4);
+    assert!((resumed.get_last_lr() - scheduler.get_last_lr()).abs() <= f32::EPSILON);
+
+    for _ in 0..4 {
+        scheduler.step();
+        resumed.step();
+        assert!((resumed.get_current_lr() - scheduler.get_current_lr()).abs() <= f32::EPSILON);
+        assert!((resumed_optim.get_lr() - optim.get_lr()).abs() <= f32::EPSILON);
+    }
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn cosine_annealing_lr_state_dict_large_epoch() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = CosineAnnealingLR::new(&optim, 10, 0.1);
+    let resumed = CosineAnnealingLR::new(&optim, 10, 0.1);
+
+    // Epochs beyond 2^24 aren't representable exactly by a single float.
+    for epoch in [(1 << 24) + 1, (1 << 40) + 3, usize::MAX] {
+        scheduler.set_current_epoch(epoch);
+        resumed.load_state_dict(&scheduler.state_dict()).unwrap();
+        assert_eq!(resumed.get_current_epoch(), epoch);
+    }
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn cosine_annealing_lr_state_dict_invalid_epoch() {
+    use neuronika_variable::CheckpointError;
+
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = CosineAnnealingLR::new(&optim, 10, 0.1);
+    scheduler.step();
+
+    let mut state = scheduler.state_dict();
+    state.insert(
+        "current_epoch".to_string(),
+        ndarray::arr1(&[2.5, 0., 0., 0.]).into_dyn(),
+    );
+    assert!(matches!(
+        scheduler.load_state_dict(&state),
+        Err(CheckpointError::InvalidFormat(_))
+    ));
+    assert_eq!(scheduler.get_current_epoch(), 1);
+}
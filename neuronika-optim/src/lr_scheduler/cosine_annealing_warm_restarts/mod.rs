@@ -0,0 +1,214 @@
+use std::cell::Cell;
+
+use crate::{Optimizer, OptimizerHandle, OptimizerStatus};
+
+use super::{cosine_annealing, prepare_step, LRScheduler};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::{CheckpointError, StateDict};
+
+#[cfg(feature = "serialize")]
+use super::{epoch_state, load_epoch_state, scalar, to_scalar};
+
+/// Anneals the learning rate from its initial value to `eta_min` following a cosine curve, and
+/// restarts from the initial value at the end of each period. The first period lasts `t_0`
+/// epochs and each of the following is `t_mult` times longer than the one preceding it.
+///
+///```text
+/// lrₜ = eta_min + (lr₀ - eta_min) * (1 + cos(π * t_cur / t_i)) / 2
+///```
+///
+/// where `t_i` is the length of the current period and `t_cur` the number of epochs since the
+/// last restart.
+pub struct CosineAnnealingWarmRestarts<T>
+where
+    T: OptimizerStatus,
+{
+    optimizer: OptimizerHandle<T>,
+    t_0: Cell<usize>,
+    t_mult: Cell<usize>,
+    eta_min: Cell<f32>,
+    current_epoch: Cell<usize>,
+    current_lr: Cell<f32>,
+    last_lr: Cell<f32>,
+    initial_lr: Cell<f32>,
+}
+
+impl<T> CosineAnnealingWarmRestarts<T>
+where
+    T: OptimizerStatus,
+{
+    /// Creates a new CosineAnnealingWarmRestarts scheduler.
+    ///
+    /// # Arguments
+    ///
+    /// * `optimizer` - wrapped optimizer.
+    ///
+    /// * `t_0` - number of epochs before the first restart.
+    ///
+    /// * `t_mult` - growth factor of the periods after each restart.
+    ///
+    /// * `eta_min` - minimum learning rate.
+    ///
+    /// # Panics
+    ///
+    /// If either `t_0` or `t_mult` is zero.
+    pub fn new(optimizer: &Optimizer<T>, t_0: usize, t_mult: usize, eta_min: f32) -> Self {
+        assert!(t_0 > 0, "error: t_0 must be positive.");
+        assert!(t_mult > 0, "error: t_mult must be positive.");
+
+        let optimizer = optimizer.handle();
+        let initial_lr = optimizer.initial_lr();
+
+        Self {
+            optimizer,
+            t_0: Cell::new(t_0),
+            t_mult: Cell::new(t_mult),
+            eta_min: Cell::new(eta_min),
+            current_epoch: Cell::new(0),
+            current_lr: Cell::new(initial_lr),
+            last_lr: Cell::new(0.0),
+            initial_lr: Cell::new(initial_lr),
+        }
+    }
+
+    /// Sets a new number of epochs before the first restart.
+    ///
+    /// # Panics
+    ///
+    /// If `t_0` is zero.
+    pub fn set_t_0(&self, t_0: usize) {
+        assert!(t_0 > 0, "error: t_0 must be positive.");
+
+        self.t_0.set(t_0)
+    }
+
+    /// Sets a new growth factor for the periods.
+    ///
+    /// # Panics
+    ///
+    /// If `t_mult` is zero.
+    pub fn set_t_mult(&self, t_mult: usize) {
+        assert!(t_mult > 0, "error: t_mult must be positive.");
+
+        self.t_mult.set(t_mult)
+    }
+
+    /// Sets a new minimum learning rate.
+    pub fn set_eta_min(&self, eta_min: f32) {
+        self.eta_min.set(eta_min)
+    }
+
+    /// Anneals the learning rate following the cosine curve, restarting it at the end of each
+    /// period.
+    pub fn step(&self) {
+        LRScheduler::step(self);
+    }
+
+    /// Returns the last learning rate value computed by this learning rate scheduler.
+    pub fn get_last_lr(&self) -> f32 {
+        LRScheduler::get_last_lr(self)
+    }
+
+    /// Returns the current learning rate value computed by this learning rate scheduler.
+    pub fn get_current_lr(&self) -> f32 {
+        LRScheduler::get_current_lr(self)
+    }
+
+    /// Sets the current epoch for this learning rate scheduler.
+    pub fn set_current_epoch(&self, epoch: usize) {
+        LRScheduler::set_current_epoch(self, epoch);
+    }
+
+    /// Returns the current epoch for this learning rate scheduler.
+    pub fn get_current_epoch(&self) -> usize {
+        LRScheduler::get_current_epoch(self)
+    }
+
+    /// Prints the learning rate update together with the epoch.
+    pub fn print_lr(&self) {
+        LRScheduler::print_lr(self);
+    }
+
+    /// Returns the state of this learning rate scheduler.
+    #[cfg(feature = "serialize")]
+    pub fn state_dict(&self) -> StateDict {
+        LRScheduler::state_dict(self)
+    }
+
+    /// Restores the state of this learning rate scheduler from `state`.
+    ///
+    /// # Errors
+    ///
+    /// See [`LRScheduler::load_state_dict()`].
+    #[cfg(feature = "serialize")]
+    pub fn load_state_dict(&self, state: &StateDict) -> Result<(), CheckpointError> {
+        LRScheduler::load_state_dict(self, state)
+    }
+}
+
+impl<T> LRScheduler for CosineAnnealingWarmRestarts<T>
+where
+    T: OptimizerStatus,
+{
+    fn step(&self) {
+        prepare_step(&self.last_lr, &self.current_lr, &self.current_epoch);
+
+        let (mut t_cur, mut t_i) = (self.current_epoch.get(), self.t_0.get());
+        while t_cur >= t_i {
+            t_cur -= t_i;
+            t_i *= self.t_mult.get();
+        }
+
+        let pct = t_cur as f32 / t_i as f32;
+        self.current_lr.set(cosine_annealing(
+            self.initial_lr.get(),
+            self.eta_min.get(),
+            pct,
+        ));
+        self.optimizer.set_lr(self.current_lr.get());
+    }
+
+    fn get_last_lr(&self) -> f32 {
+        self.last_lr.get()
+    }
+
+    fn get_current_lr(&self) -> f32 {
+        self.current_lr.get()
+    }
+
+    fn set_current_epoch(&self, epoch: usize) {
+        self.current_epoch.replace(epoch);
+    }
+
+    fn get_current_epoch(&self) -> usize {
+        self.current_epoch.get()
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(String, ArrayD<f32>)> {
+        let mut state = epoch_state(&self.current_epoch, &self.current_lr, &self.last_lr);
+        state.push(("initial_lr".to_string(), scalar(self.initial_lr.get())));
+        state
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&self, name: &str, value: &ArrayD<f32>) {
+        match name {
+            "initial_lr" => self.initial_lr.set(to_scalar(value)),
+            _ => load_epoch_state(
+                &self.current_epoch,
+                &self.current_lr,
+                &self.last_lr,
+                name,
+                value,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,30 @@
+use crate::{StochasticGD, L2};
+
+use super::CosineAnnealingWarmRestarts;
+
+#[test]
+fn cosine_annealing_warm_restarts() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = CosineAnnealingWarmRestarts::new(&optim, 2, 2, 0.0);
+
+    // Restarts happen at epochs 2 and 6.
+    let expected = [0.5, 1.0, 0.853_553_4, 0.5, 0.146_446_6, 1.0];
+    for (epoch, expected) in expected.iter().enumerate() {
+        scheduler.step();
+        assert_eq!(scheduler.get_current_epoch(), epoch + 1);
+        assert!((scheduler.get_current_lr() - expected).abs() <= 1e-6);
+        assert!((optim.get_lr() - expected).abs() <= 1e-6);
+    }
+}
+
+#[test]
+fn cosine_annealing_warm_restarts_constant_period() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = CosineAnnealingWarmRestarts::new(&optim, 2, 1, 0.0);
+
+    let expected = [0.5, 1.0, 0.5, 1.0];
+    for expected in expected {
+        scheduler.step();
+        assert!((scheduler.get_current_lr() - expected).abs() <= 1e-6);
+    }
+}
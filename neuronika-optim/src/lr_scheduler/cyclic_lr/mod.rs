@@ -0,0 +1,256 @@
+use std::cell::Cell;
+
+use crate::{Optimizer, OptimizerHandle, OptimizerStatus};
+
+use super::{prepare_step, LRScheduler};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::{CheckpointError, StateDict};
+
+#[cfg(feature = "serialize")]
+use super::{epoch_state, load_epoch_state};
+
+/// Policy used by [`CyclicLR`] to scale the amplitude of the cycles.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CyclicMode {
+    /// Cycles with constant amplitude.
+    Triangular,
+    /// Halves the amplitude after each cycle.
+    Triangular2,
+    /// Scales the amplitude by `gammaᵗ` at step `t`.
+    ExpRange(f32),
+}
+
+/// Cycles the learning rate between `base_lr` and `max_lr` with a constant frequency, as
+/// presented in [Cyclical Learning Rates for Training Neural Networks](https://arxiv.org/abs/1506.01186).
+///
+/// Each cycle increases the learning rate for `step_size_up` steps and decreases it for
+/// `step_size_down` steps, which defaults to `step_size_up`. The amplitude of the cycles is
+/// scaled according to the [`CyclicMode`]. The scheduler is meant to be stepped after every
+/// batch.
+///
+/// The momentum, or the first moment decay rate for the optimizers of the Adam family, is cycled
+/// inversely to the learning rate between `max_momentum` and `base_momentum`. Optimizers that
+/// have no momentum are left untouched.
+///
+/// The learning rate of the optimizer is set to `base_lr` as soon as the scheduler is created.
+pub struct CyclicLR<T>
+where
+    T: OptimizerStatus,
+{
+    optimizer: OptimizerHandle<T>,
+    base_lr: f32,
+    max_lr: f32,
+    step_size_up: usize,
+    step_size_down: Cell<usize>,
+    mode: CyclicMode,
+    cycle_momentum: Cell<bool>,
+    base_momentum: Cell<f32>,
+    max_momentum: Cell<f32>,
+    current_epoch: Cell<usize>,
+    current_lr: Cell<f32>,
+    last_lr: Cell<f32>,
+}
+
+impl<T> CyclicLR<T>
+where
+    T: OptimizerStatus,
+{
+    /// Creates a new CyclicLR scheduler.
+    ///
+    /// # Arguments
+    ///
+    /// * `optimizer` - wrapped optimizer.
+    ///
+    /// * `base_lr` - lower bound of the learning rate.
+    ///
+    /// * `max_lr` - upper bound of the learning rate.
+    ///
+    /// * `step_size_up` - number of steps in the increasing half of a cycle.
+    ///
+    /// * `mode` - amplitude scaling policy.
+    ///
+    /// The scheduler starts cycling the momentum between 0.8 and 0.9.
+    ///
+    /// # Panics
+    ///
+    /// If `step_size_up` is zero.
+    pub fn new(
+        optimizer: &Optimizer<T>,
+        base_lr: f32,
+        max_lr: f32,
+        step_size_up: usize,
+        mode: CyclicMode,
+    ) -> Self {
+        assert!(step_size_up > 0, "error: step_size_up must be positive.");
+
+        let optimizer = optimizer.handle();
+        let scheduler = Self {
+            optimizer,
+            base_lr,
+            max_lr,
+            step_size_up,
+            step_size_down: Cell::new(step_size_up),
+            mode,
+            cycle_momentum: Cell::new(true),
+            base_momentum: Cell::new(0.8),
+            max_momentum: Cell::new(0.9),
+            current_epoch: Cell::new(0),
+            current_lr: Cell::new(base_lr),
+            last_lr: Cell::new(0.0),
+        };
+        scheduler.update();
+
+        scheduler
+    }
+
+    /// Sets a new number of steps for the decreasing half of a cycle. The learning rate for the
+    /// current step is updated accordingly.
+    pub fn set_step_size_down(&self, step_size_down: usize) {
+        self.step_size_down.set(step_size_down);
+        self.update();
+    }
+
+    /// Enables or disables the cycling of the momentum.
+    pub fn set_cycle_momentum(&self, cycle_momentum: bool) {
+        self.cycle_momentum.set(cycle_momentum);
+        self.update();
+    }
+
+    /// Sets new bounds for the momentum. The momentum for the current step is updated
+    /// accordingly.
+    pub fn set_momentum(&self, base_momentum: f32, max_momentum: f32) {
+        self.base_momentum.set(base_momentum);
+        self.max_momentum.set(max_momentum);
+        self.update();
+    }
+
+    /// Sets the learning rate and the momentum for the next step of the cycle.
+    pub fn step(&self) {
+        LRScheduler::step(self);
+    }
+
+    /// Returns the last learning rate value computed by this learning rate scheduler.
+    pub fn get_last_lr(&self) -> f32 {
+        LRScheduler::get_last_lr(self)
+    }
+
+    /// Returns the current learning rate value computed by this learning rate scheduler.
+    pub fn get_current_lr(&self) -> f32 {
+        LRScheduler::get_current_lr(self)
+    }
+
+    /// Sets the current epoch for this learning rate scheduler.
+    pub fn set_current_epoch(&self, epoch: usize) {
+        LRScheduler::set_current_epoch(self, epoch);
+    }
+
+    /// Returns the current epoch for this learning rate scheduler.
+    pub fn get_current_epoch(&self) -> usize {
+        LRScheduler::get_current_epoch(self)
+    }
+
+    /// Prints the learning rate update together with the epoch.
+    pub fn print_lr(&self) {
+        LRScheduler::print_lr(self);
+    }
+
+    /// Returns the state of this learning rate scheduler.
+    #[cfg(feature = "serialize")]
+    pub fn state_dict(&self) -> StateDict {
+        LRScheduler::state_dict(self)
+    }
+
+    /// Restores the state of this learning rate scheduler from `state`.
+    ///
+    /// # Errors
+    ///
+    /// See [`LRScheduler::load_state_dict()`].
+    #[cfg(feature = "serialize")]
+    pub fn load_state_dict(&self, state: &StateDict) -> Result<(), CheckpointError> {
+        LRScheduler::load_state_dict(self, state)
+    }
+}
+
+impl<T> CyclicLR<T>
+where
+    T: OptimizerStatus,
+{
+    /// Computes the learning rate and the momentum for the current step and sets them on the
+    /// optimizer.
+    fn update(&self) {
+        let step = self.current_epoch.get() as f32;
+        let cycle_size = (self.step_size_up + self.step_size_down.get()) as f32;
+        let step_ratio = self.step_size_up as f32 / cycle_size;
+
+        let cycle = (1. + step / cycle_size).floor();
+        let x = 1. + step / cycle_size - cycle;
+        let height = if x <= step_ratio {
+            x / step_ratio
+        } else {
+            (x - 1.) / (step_ratio - 1.)
+        };
+        let scale = match self.mode {
+            CyclicMode::Triangular => 1.,
+            CyclicMode::Triangular2 => 1. / 2_f32.powf(cycle - 1.),
+            CyclicMode::ExpRange(gamma) => gamma.powf(step),
+        };
+
+        self.current_lr
+            .set(self.base_lr + (self.max_lr - self.base_lr) * height * scale);
+        self.optimizer.set_lr(self.current_lr.get());
+        if self.cycle_momentum.get() && self.optimizer.status().get_cycle_momentum().is_some() {
+            let (base_momentum, max_momentum) = (self.base_momentum.get(), self.max_momentum.get());
+            self.optimizer
+                .set_cycle_momentum(max_momentum - (max_momentum - base_momentum) * height * scale);
+        }
+    }
+}
+
+impl<T> LRScheduler for CyclicLR<T>
+where
+    T: OptimizerStatus,
+{
+    fn step(&self) {
+        prepare_step(&self.last_lr, &self.current_lr, &self.current_epoch);
+        self.update();
+    }
+
+    fn get_last_lr(&self) -> f32 {
+        self.last_lr.get()
+    }
+
+    fn get_current_lr(&self) -> f32 {
+        self.current_lr.get()
+    }
+
+    fn set_current_epoch(&self, epoch: usize) {
+        self.current_epoch.replace(epoch);
+    }
+
+    fn get_current_epoch(&self) -> usize {
+        self.current_epoch.get()
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(String, ArrayD<f32>)> {
+        epoch_state(&self.current_epoch, &self.current_lr, &self.last_lr)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&self, name: &str, value: &ArrayD<f32>) {
+        load_epoch_state(
+            &self.current_epoch,
+            &self.current_lr,
+            &self.last_lr,
+            name,
+            value,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test;
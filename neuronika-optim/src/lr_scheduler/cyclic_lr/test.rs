@@ -0,0 +1,52 @@
+use crate::{StochasticGD, L2};
+
+use super::{CyclicLR, CyclicMode};
+
+#[test]
+fn cyclic_lr_triangular() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), 0.9, None, false);
+    let scheduler = CyclicLR::new(&optim, 0.1, 1.0, 2, CyclicMode::Triangular);
+    assert!((optim.get_lr() - 0.1).abs() <= f32::EPSILON);
+    assert!((optim.status().get_momentum().unwrap() - 0.9).abs() <= f32::EPSILON);
+
+    let expected = [
+        (0.55, 0.85),
+        (1.0, 0.8),
+        (0.55, 0.85),
+        (0.1, 0.9),
+        (0.55, 0.85),
+        (1.0, 0.8),
+    ];
+    for (lr, momentum) in expected {
+        scheduler.step();
+        scheduler.print_lr();
+        assert!((scheduler.get_current_lr() - lr).abs() <= 1e-6);
+        assert!((optim.get_lr() - lr).abs() <= 1e-6);
+        assert!((optim.status().get_momentum().unwrap() - momentum).abs() <= 1e-6);
+    }
+}
+
+#[test]
+fn cyclic_lr_triangular2() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = CyclicLR::new(&optim, 0.1, 1.0, 1, CyclicMode::Triangular2);
+    scheduler.set_step_size_down(3);
+
+    // The first peak is at step 1, the second at step 5 with half the amplitude.
+    let expected = [1.0, 0.7, 0.4, 0.1, 0.55];
+    for lr in expected {
+        scheduler.step();
+        assert!((optim.get_lr() - lr).abs() <= 1e-6);
+    }
+    assert!(optim.status().get_momentum().is_none());
+}
+
+#[test]
+fn cyclic_lr_exp_range() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = CyclicLR::new(&optim, 0.1, 1.0, 2, CyclicMode::ExpRange(0.5));
+
+    scheduler.step();
+    scheduler.step();
+    assert!((optim.get_lr() - 0.325).abs() <= 1e-6);
+}
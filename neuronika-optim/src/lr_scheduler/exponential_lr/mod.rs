@@ -1,7 +1,16 @@
-use crate::{Optimizer, OptimizerStatus};
+use crate::{Optimizer, OptimizerHandle, OptimizerStatus};
 
 use super::{prepare_step, LRScheduler};
 
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::{CheckpointError, StateDict};
+
+#[cfg(feature = "serialize")]
+use super::{epoch_state, load_epoch_state};
+
 use std::cell::Cell;
 
 /// Decays the learning rate by `gamma` every epoch.
@@ -9,18 +18,18 @@ use std::cell::Cell;
 ///```text
 /// lrₜ = lrₜ₋₁ * gamma
 ///```
-pub struct ExponentialLR<'a, T>
+pub struct ExponentialLR<T>
 where
     T: OptimizerStatus,
 {
-    optimizer: &'a Optimizer<T>,
+    optimizer: OptimizerHandle<T>,
     gamma: Cell<f32>,
     current_epoch: Cell<usize>,
     current_lr: Cell<f32>,
     last_lr: Cell<f32>,
 }
 
-impl<'a, T> ExponentialLR<'a, T>
+impl<T> ExponentialLR<T>
 where
     T: OptimizerStatus,
 {
@@ -31,7 +40,8 @@ where
     /// * `optimizer` - wrapped optimizer.
     ///
     /// * `gamma` - multiplicative factor for the learning rate decay.
-    pub fn new(optimizer: &'a Optimizer<T>, gamma: f32) -> Self {
+    pub fn new(optimizer: &Optimizer<T>, gamma: f32) -> Self {
+        let optimizer = optimizer.handle();
         let current_lr = optimizer.get_lr();
 
         Self {
@@ -77,9 +87,25 @@ where
     pub fn print_lr(&self) {
         LRScheduler::print_lr(self);
     }
+
+    /// Returns the state of this learning rate scheduler.
+    #[cfg(feature = "serialize")]
+    pub fn state_dict(&self) -> StateDict {
+        LRScheduler::state_dict(self)
+    }
+
+    /// Restores the state of this learning rate scheduler from `state`.
+    ///
+    /// # Errors
+    ///
+    /// See [`LRScheduler::load_state_dict()`].
+    #[cfg(feature = "serialize")]
+    pub fn load_state_dict(&self, state: &StateDict) -> Result<(), CheckpointError> {
+        LRScheduler::load_state_dict(self, state)
+    }
 }
 
-impl<'a, T> LRScheduler for ExponentialLR<'a, T>
+impl<T> LRScheduler for ExponentialLR<T>
 where
     T: OptimizerStatus,
 {
@@ -104,6 +130,22 @@ where
     fn get_current_epoch(&self) -> usize {
         self.current_epoch.get()
     }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(String, ArrayD<f32>)> {
+        epoch_state(&self.current_epoch, &self.current_lr, &self.last_lr)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&self, name: &str, value: &ArrayD<f32>) {
+        load_epoch_state(
+            &self.current_epoch,
+            &self.current_lr,
+            &self.last_lr,
+            name,
+            value,
+        )
+    }
 }
 
 #[cfg(test)]
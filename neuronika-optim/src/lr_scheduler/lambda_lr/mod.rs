@@ -1,7 +1,16 @@
-use crate::{Optimizer, OptimizerStatus};
+use crate::{Optimizer, OptimizerHandle, OptimizerStatus};
 
 use super::{prepare_step, LRScheduler};
 
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::{CheckpointError, StateDict};
+
+#[cfg(feature = "serialize")]
+use super::{epoch_state, load_epoch_state, scalar, to_scalar};
+
 use std::cell::Cell;
 
 /// Sets the learning rate to the initial lr times a given function.
@@ -9,12 +18,12 @@ use std::cell::Cell;
 ///```text
 /// lrₜ = lr₀ * lr_fn(t)
 ///```
-pub struct LambdaLR<'a, T, F>
+pub struct LambdaLR<T, F>
 where
     T: OptimizerStatus,
     F: Fn(usize) -> f32,
 {
-    optimizer: &'a Optimizer<T>,
+    optimizer: OptimizerHandle<T>,
     lr_fn: F,
     current_epoch: Cell<usize>,
     current_lr: Cell<f32>,
@@ -22,7 +31,7 @@ where
     initial_lr: Cell<f32>,
 }
 
-impl<'a, T, F> LambdaLR<'a, T, F>
+impl<T, F> LambdaLR<T, F>
 where
     T: OptimizerStatus,
     F: Fn(usize) -> f32,
@@ -35,7 +44,8 @@ where
     ///
     /// * `lr_fn` - function which computes a multiplicative factor given an `usize` parameter
     /// epoch.
-    pub fn new(optimizer: &'a Optimizer<T>, lr_fn: F) -> Self {
+    pub fn new(optimizer: &Optimizer<T>, lr_fn: F) -> Self {
+        let optimizer = optimizer.handle();
         let current_lr = optimizer.get_lr();
 
         Self {
@@ -77,9 +87,25 @@ where
     pub fn print_lr(&self) {
         LRScheduler::print_lr(self);
     }
+
+    /// Returns the state of this learning rate scheduler.
+    #[cfg(feature = "serialize")]
+    pub fn state_dict(&self) -> StateDict {
+        LRScheduler::state_dict(self)
+    }
+
+    /// Restores the state of this learning rate scheduler from `state`.
+    ///
+    /// # Errors
+    ///
+    /// See [`LRScheduler::load_state_dict()`].
+    #[cfg(feature = "serialize")]
+    pub fn load_state_dict(&self, state: &StateDict) -> Result<(), CheckpointError> {
+        LRScheduler::load_state_dict(self, state)
+    }
 }
 
-impl<'a, T, F> LRScheduler for LambdaLR<'a, T, F>
+impl<T, F> LRScheduler for LambdaLR<T, F>
 where
     T: OptimizerStatus,
     F: Fn(usize) -> f32,
@@ -106,6 +132,27 @@ where
     fn get_current_epoch(&self) -> usize {
         self.current_epoch.get()
     }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(String, ArrayD<f32>)> {
+        let mut state = epoch_state(&self.current_epoch, &self.current_lr, &self.last_lr);
+        state.push(("initial_lr".to_string(), scalar(self.initial_lr.get())));
+        state
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&self, name: &str, value: &ArrayD<f32>) {
+        match name {
+            "initial_lr" => self.initial_lr.set(to_scalar(value)),
+            _ => load_epoch_state(
+                &self.current_epoch,
+                &self.current_lr,
+                &self.last_lr,
+                name,
+                value,
+            ),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,181 @@
+use std::cell::Cell;
+
+use crate::{Optimizer, OptimizerHandle, OptimizerStatus};
+
+use super::{prepare_step, LRScheduler};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::{CheckpointError, StateDict};
+
+#[cfg(feature = "serialize")]
+use super::{epoch_state, load_epoch_state, scalar, to_scalar};
+
+/// Scales the learning rate by a factor that changes linearly from `start_factor` to
+/// `end_factor` in `total_iters` epochs, and stays at `end_factor` afterwards.
+///
+///```text
+/// lrₜ = lr₀ * (start_factor + (end_factor - start_factor) * min(t, total_iters) / total_iters)
+///```
+///
+/// The learning rate of the optimizer is set to `lr₀ * start_factor` as soon as the scheduler is
+/// created.
+pub struct LinearWarmup<T>
+where
+    T: OptimizerStatus,
+{
+    optimizer: OptimizerHandle<T>,
+    start_factor: f32,
+    end_factor: f32,
+    total_iters: usize,
+    current_epoch: Cell<usize>,
+    current_lr: Cell<f32>,
+    last_lr: Cell<f32>,
+    initial_lr: Cell<f32>,
+}
+
+impl<T> LinearWarmup<T>
+where
+    T: OptimizerStatus,
+{
+    /// Creates a new LinearWarmup scheduler.
+    ///
+    /// # Arguments
+    ///
+    /// * `optimizer` - wrapped optimizer.
+    ///
+    /// * `start_factor` - factor applied to the learning rate at the first epoch.
+    ///
+    /// * `end_factor` - factor applied to the learning rate at the end of the warmup.
+    ///
+    /// * `total_iters` - number of epochs the warmup lasts.
+    ///
+    /// # Panics
+    ///
+    /// If `total_iters` is zero.
+    pub fn new(
+        optimizer: &Optimizer<T>,
+        start_factor: f32,
+        end_factor: f32,
+        total_iters: usize,
+    ) -> Self {
+        assert!(total_iters > 0, "error: total_iters must be positive.");
+
+        let optimizer = optimizer.handle();
+        let initial_lr = optimizer.initial_lr();
+        let current_lr = initial_lr * start_factor;
+        optimizer.set_lr(current_lr);
+
+        Self {
+            optimizer,
+            start_factor,
+            end_factor,
+            total_iters,
+            current_epoch: Cell::new(0),
+            current_lr: Cell::new(current_lr),
+            last_lr: Cell::new(0.0),
+            initial_lr: Cell::new(initial_lr),
+        }
+    }
+
+    /// Moves the learning rate factor towards `end_factor`.
+    pub fn step(&self) {
+        LRScheduler::step(self);
+    }
+
+    /// Returns the last learning rate value computed by this learning rate scheduler.
+    pub fn get_last_lr(&self) -> f32 {
+        LRScheduler::get_last_lr(self)
+    }
+
+    /// Returns the current learning rate value computed by this learning rate scheduler.
+    pub fn get_current_lr(&self) -> f32 {
+        LRScheduler::get_current_lr(self)
+    }
+
+    /// Sets the current epoch for this learning rate scheduler.
+    pub fn set_current_epoch(&self, epoch: usize) {
+        LRScheduler::set_current_epoch(self, epoch);
+    }
+
+    /// Returns the current epoch for this learning rate scheduler.
+    pub fn get_current_epoch(&self) -> usize {
+        LRScheduler::get_current_epoch(self)
+    }
+
+    /// Prints the learning rate update together with the epoch.
+    pub fn print_lr(&self) {
+        LRScheduler::print_lr(self);
+    }
+
+    /// Returns the state of this learning rate scheduler.
+    #[cfg(feature = "serialize")]
+    pub fn state_dict(&self) -> StateDict {
+        LRScheduler::state_dict(self)
+    }
+
+    /// Restores the state of this learning rate scheduler from `state`.
+    ///
+    /// # Errors
+    ///
+    /// See [`LRScheduler::load_state_dict()`].
+    #[cfg(feature = "serialize")]
+    pub fn load_state_dict(&self, state: &StateDict) -> Result<(), CheckpointError> {
+        LRScheduler::load_state_dict(self, state)
+    }
+}
+
+impl<T> LRScheduler for LinearWarmup<T>
+where
+    T: OptimizerStatus,
+{
+    fn step(&self) {
+        prepare_step(&self.last_lr, &self.current_lr, &self.current_epoch);
+        let pct = self.current_epoch.get().min(self.total_iters) as f32 / self.total_iters as f32;
+        let factor = self.start_factor + (self.end_factor - self.start_factor) * pct;
+        self.current_lr.set(self.initial_lr.get() * factor);
+        self.optimizer.set_lr(self.current_lr.get());
+    }
+
+    fn get_last_lr(&self) -> f32 {
+        self.last_lr.get()
+    }
+
+    fn get_current_lr(&self) -> f32 {
+        self.current_lr.get()
+    }
+
+    fn set_current_epoch(&self, epoch: usize) {
+        self.current_epoch.replace(epoch);
+    }
+
+    fn get_current_epoch(&self) -> usize {
+        self.current_epoch.get()
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(String, ArrayD<f32>)> {
+        let mut state = epoch_state(&self.current_epoch, &self.current_lr, &self.last_lr);
+        state.push(("initial_lr".to_string(), scalar(self.initial_lr.get())));
+        state
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&self, name: &str, value: &ArrayD<f32>) {
+        match name {
+            "initial_lr" => self.initial_lr.set(to_scalar(value)),
+            _ => load_epoch_state(
+                &self.current_epoch,
+                &self.current_lr,
+                &self.last_lr,
+                name,
+                value,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,20 @@
+use crate::{StochasticGD, L2};
+
+use super::LinearWarmup;
+
+#[test]
+fn linear_warmup() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = LinearWarmup::new(&optim, 0.25, 1.0, 3);
+    assert!((scheduler.get_current_lr() - 0.25).abs() <= f32::EPSILON);
+    assert!((optim.get_lr() - 0.25).abs() <= f32::EPSILON);
+
+    let expected = [0.5, 0.75, 1.0, 1.0, 1.0];
+    for expected in expected {
+        scheduler.step();
+        scheduler.print_lr();
+        assert!((scheduler.get_current_lr() - expected).abs() <= f32::EPSILON);
+        assert!((optim.get_lr() - expected).abs() <= f32::EPSILON);
+    }
+    assert!((scheduler.get_last_lr() - 1.0).abs() <= f32::EPSILON);
+}
@@ -27,18 +27,48 @@
 //! }
 //! ```
 
+mod chained_scheduler;
+mod cosine_annealing_lr;
+mod cosine_annealing_warm_restarts;
+mod cyclic_lr;
 mod exponential_lr;
 mod lambda_lr;
+mod linear_warmup;
 mod multi_step_lr;
 mod multiplicative_lr;
+mod one_cycle_lr;
+mod polynomial_lr;
+mod reduce_lr_on_plateau;
+mod sequential_lr;
 mod step_lr;
 
+pub use chained_scheduler::*;
+pub use cosine_annealing_lr::*;
+pub use cosine_annealing_warm_restarts::*;
+pub use cyclic_lr::*;
 pub use exponential_lr::*;
 pub use lambda_lr::*;
+pub use linear_warmup::*;
 pub use multi_step_lr::*;
 pub use multiplicative_lr::*;
+pub use one_cycle_lr::*;
+pub use polynomial_lr::*;
+pub use reduce_lr_on_plateau::*;
+pub use sequential_lr::*;
 pub use step_lr::*;
 
+#[cfg(feature = "serialize")]
+use std::cell::Cell;
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::{CheckpointError, StateDict};
+
+#[cfg(feature = "serialize")]
+use crate::optimizer::{check_state, counter, scalar, to_counter, to_scalar};
+
 /// Learning rate scheduler trait, defines the scheduler's logic.
 pub trait LRScheduler {
     /// Updates the learning rate.
@@ -56,6 +86,51 @@ pub trait LRScheduler {
     /// Sets the current epoch.
     fn set_current_epoch(&self, epoch: usize);
 
+    /// Returns the state of the scheduler by name: the current epoch, the learning rates and the
+    /// internal counters. Learning rates are stored as scalars, while counters are stored as
+    /// arrays of digits so that they are restored exactly however large they grow.
+    ///
+    /// The default implementation returns an empty state.
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(String, ArrayD<f32>)> {
+        Vec::new()
+    }
+
+    /// Restores the entry of the state called `name`, which is one of those returned by
+    /// [`.state()`](LRScheduler::state()).
+    ///
+    /// The default implementation does nothing.
+    #[cfg(feature = "serialize")]
+    fn load_state(&self, _name: &str, _value: &ArrayD<f32>) {}
+
+    /// Returns the state of the scheduler, which can be stored in a
+    /// [`ModelCheckpoint`](neuronika_variable::ModelCheckpoint) together with the optimizer's one
+    /// to resume the training later on the same schedule. It holds the entries of
+    /// [`.state()`](LRScheduler::state()).
+    #[cfg(feature = "serialize")]
+    fn state_dict(&self) -> StateDict {
+        self.state().into_iter().collect()
+    }
+
+    /// Restores the state of the scheduler from `state`. Nothing is modified if an error is
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// [`CheckpointError::KeyMismatch`] if the names in `state` don't match exactly the ones of
+    /// the scheduler, [`CheckpointError::ShapeMismatch`] if an entry doesn't have the shape of
+    /// the corresponding one and [`CheckpointError::InvalidFormat`] if a counter, such as the
+    /// current epoch, isn't a valid one.
+    #[cfg(feature = "serialize")]
+    fn load_state_dict(&self, state: &StateDict) -> Result<(), CheckpointError> {
+        check_state(&self.state_dict(), state)?;
+        state
+            .iter()
+            .for_each(|(name, value)| self.load_state(name, value));
+
+        Ok(())
+    }
+
     /// Prints the update of the learning rate. It should be called after `.step()`.
     fn print_lr(&self) {
         println!(
@@ -81,3 +156,70 @@ fn prepare_step(
     // Increase current epoch.
     current_epoch.set(last_epoch + 1);
 }
+
+/// Anneals from `start` to `end` following half a cosine period, `pct` being the fraction of the
+/// annealing already completed.
+fn cosine_annealing(start: f32, end: f32, pct: f32) -> f32 {
+    end + (start - end) / 2. * (1. + (std::f32::consts::PI * pct).cos())
+}
+
+/// Returns the state shared by all the learning rate schedulers.
+#[cfg(feature = "serialize")]
+fn epoch_state(
+    current_epoch: &Cell<usize>,
+    current_lr: &Cell<f32>,
+    last_lr: &Cell<f32>,
+) -> Vec<(String, ArrayD<f32>)> {
+    vec![
+        ("current_epoch".to_string(), counter(current_epoch.get())),
+        ("current_lr".to_string(), scalar(current_lr.get())),
+        ("last_lr".to_string(), scalar(last_lr.get())),
+    ]
+}
+
+/// Restores the entry called `name` of the state shared by all the learning rate schedulers.
+#[cfg(feature = "serialize")]
+fn load_epoch_state(
+    current_epoch: &Cell<usize>,
+    current_lr: &Cell<f32>,
+    last_lr: &Cell<f32>,
+    name: &str,
+    value: &ArrayD<f32>,
+) {
+    match name {
+        "current_epoch" => current_epoch.set(to_counter(value)),
+        "current_lr" => current_lr.set(to_scalar(value)),
+        "last_lr" => last_lr.set(to_scalar(value)),
+        _ => {}
+    }
+}
+
+/// Returns the state of the schedulers wrapped by a combinator, the one of the `i`-th being
+/// stored under `schedulers.{i}.{name}`.
+#[cfg(feature = "serialize")]
+fn nested_state(schedulers: &[Box<dyn LRScheduler>]) -> Vec<(String, ArrayD<f32>)> {
+    schedulers
+        .iter()
+        .enumerate()
+        .flat_map(|(index, scheduler)| {
+            scheduler
+                .state()
+                .into_iter()
+                .map(move |(name, value)| (format!("schedulers.{}.{}", index, name), value))
+        })
+        .collect()
+}
+
+/// Restores the entry called `name` of the state of the schedulers wrapped by a combinator.
+#[cfg(feature = "serialize")]
+fn load_nested_state(schedulers: &[Box<dyn LRScheduler>], name: &str, value: &ArrayD<f32>) {
+    let nested = name
+        .strip_prefix("schedulers.")
+        .and_then(|name| name.split_once('.'))
+        .and_then(|(index, name)| Some((index.parse::<usize>().ok()?, name)));
+    if let Some((index, name)) = nested {
+        if let Some(scheduler) = schedulers.get(index) {
+            scheduler.load_state(name, value);
+        }
+    }
+}
@@ -1,20 +1,29 @@
 use std::cell::{Cell, RefCell};
 
-use crate::{Optimizer, OptimizerStatus};
+use crate::{Optimizer, OptimizerHandle, OptimizerStatus};
 
 use super::{prepare_step, LRScheduler};
 
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::{CheckpointError, StateDict};
+
+#[cfg(feature = "serialize")]
+use super::{epoch_state, load_epoch_state};
+
 /// Decays the learning rate by gamma once the number of epoch reaches one of the specified
 /// milestones.
 ///
 ///```text
 /// lrₜ = lrₜ₋₁ * gamma if t is a milestone else lrₜ₋₁
 ///```
-pub struct MultiStepLR<'a, T>
+pub struct MultiStepLR<T>
 where
     T: OptimizerStatus,
 {
-    optimizer: &'a Optimizer<T>,
+    optimizer: OptimizerHandle<T>,
     gamma: f32,
     milestones: RefCell<Vec<usize>>,
     current_epoch: Cell<usize>,
@@ -22,7 +31,7 @@ where
     last_lr: Cell<f32>,
 }
 
-impl<'a, T> MultiStepLR<'a, T>
+impl<T> MultiStepLR<T>
 where
     T: OptimizerStatus,
 {
@@ -35,7 +44,8 @@ where
     /// * `milestones` - list of epoch indices. Must be increasing.
     ///
     /// * `gamma` - multiplicative factor for the learning rate decay.
-    pub fn new(optimizer: &'a Optimizer<T>, milestones: Vec<usize>, gamma: f32) -> Self {
+    pub fn new(optimizer: &Optimizer<T>, milestones: Vec<usize>, gamma: f32) -> Self {
+        let optimizer = optimizer.handle();
         let current_lr = optimizer.get_lr();
 
         Self {
@@ -82,9 +92,25 @@ where
     pub fn print_lr(&self) {
         LRScheduler::print_lr(self);
     }
+
+    /// Returns the state of this learning rate scheduler.
+    #[cfg(feature = "serialize")]
+    pub fn state_dict(&self) -> StateDict {
+        LRScheduler::state_dict(self)
+    }
+
+    /// Restores the state of this learning rate scheduler from `state`.
+    ///
+    /// # Errors
+    ///
+    /// See [`LRScheduler::load_state_dict()`].
+    #[cfg(feature = "serialize")]
+    pub fn load_state_dict(&self, state: &StateDict) -> Result<(), CheckpointError> {
+        LRScheduler::load_state_dict(self, state)
+    }
 }
 
-impl<'a, T> LRScheduler for MultiStepLR<'a, T>
+impl<T> LRScheduler for MultiStepLR<T>
 where
     T: OptimizerStatus,
 {
@@ -116,6 +142,22 @@ where
     fn get_current_epoch(&self) -> usize {
         self.current_epoch.get()
     }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(String, ArrayD<f32>)> {
+        epoch_state(&self.current_epoch, &self.current_lr, &self.last_lr)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&self, name: &str, value: &ArrayD<f32>) {
+        load_epoch_state(
+            &self.current_epoch,
+            &self.current_lr,
+            &self.last_lr,
+            name,
+            value,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -1,27 +1,36 @@
 use std::cell::Cell;
 
-use crate::{Optimizer, OptimizerStatus};
+use crate::{Optimizer, OptimizerHandle, OptimizerStatus};
 
 use super::{prepare_step, LRScheduler};
 
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::{CheckpointError, StateDict};
+
+#[cfg(feature = "serialize")]
+use super::{epoch_state, load_epoch_state};
+
 /// Multiplies the learning rate by the factor given in the specified function.
 ///
 ///```text
 /// lrₜ = lrₜ₋₁ * lr_fn(t)
 ///```
-pub struct MultiplicativeLR<'a, T, F>
+pub struct MultiplicativeLR<T, F>
 where
     T: OptimizerStatus,
     F: Fn(usize) -> f32,
 {
-    optimizer: &'a Optimizer<T>,
+    optimizer: OptimizerHandle<T>,
     lr_fn: F,
     current_epoch: Cell<usize>,
     current_lr: Cell<f32>,
     last_lr: Cell<f32>,
 }
 
-impl<'a, T, F> MultiplicativeLR<'a, T, F>
+impl<T, F> MultiplicativeLR<T, F>
 where
     T: OptimizerStatus,
     F: Fn(usize) -> f32,
@@ -34,7 +43,8 @@ where
     ///
     /// * `lr_fn` - function which computes a multiplicative factor given an `usize` parameter
     /// epoch.
-    pub fn new(optimizer: &'a Optimizer<T>, lr_fn: F) -> Self {
+    pub fn new(optimizer: &Optimizer<T>, lr_fn: F) -> Self {
+        let optimizer = optimizer.handle();
         let current_lr = optimizer.get_lr();
         Self {
             optimizer,
@@ -74,9 +84,25 @@ where
     pub fn print_lr(&self) {
         LRScheduler::print_lr(self);
     }
+
+    /// Returns the state of this learning rate scheduler.
+    #[cfg(feature = "serialize")]
+    pub fn state_dict(&self) -> StateDict {
+        LRScheduler::state_dict(self)
+    }
+
+    /// Restores the state of this learning rate scheduler from `state`.
+    ///
+    /// # Errors
+    ///
+    /// See [`LRScheduler::load_state_dict()`].
+    #[cfg(feature = "serialize")]
+    pub fn load_state_dict(&self, state: &StateDict) -> Result<(), CheckpointError> {
+        LRScheduler::load_state_dict(self, state)
+    }
 }
 
-impl<'a, T, F> LRScheduler for MultiplicativeLR<'a, T, F>
+impl<T, F> LRScheduler for MultiplicativeLR<T, F>
 where
     T: OptimizerStatus,
     F: Fn(usize) -> f32,
@@ -103,6 +129,22 @@ where
     fn get_current_epoch(&self) -> usize {
         self.current_epoch.get()
     }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(String, ArrayD<f32>)> {
+        epoch_state(&self.current_epoch, &self.current_lr, &self.last_lr)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&self, name: &str, value: &ArrayD<f32>) {
+        load_epoch_state(
+            &self.current_epoch,
+            &self.current_lr,
+            &self.last_lr,
+            name,
+            value,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,332 @@
+use std::cell::Cell;
+
+use crate::{Optimizer, OptimizerHandle, OptimizerStatus};
+
+use super::{cosine_annealing, prepare_step, LRScheduler};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::{CheckpointError, StateDict};
+
+#[cfg(feature = "serialize")]
+use super::{epoch_state, load_epoch_state};
+
+/// Strategy used to anneal the learning rate and the momentum between two values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnnealStrategy {
+    /// Cosine annealing.
+    Cos,
+    /// Linear annealing.
+    Linear,
+}
+
+impl AnnealStrategy {
+    /// Anneals from `start` to `end`, `pct` being the fraction of the annealing already
+    /// completed.
+    fn anneal(self, start: f32, end: f32, pct: f32) -> f32 {
+        match self {
+            AnnealStrategy::Cos => cosine_annealing(start, end, pct),
+            AnnealStrategy::Linear => start + (end - start) * pct,
+        }
+    }
+}
+
+/// Sets the learning rate according to the 1cycle policy, presented in
+/// [Super-Convergence: Very Fast Training of Neural Networks Using Large Learning Rates](https://arxiv.org/abs/1708.07120).
+///
+/// The learning rate is annealed from `max_lr / div_factor` to `max_lr` in the first
+/// `pct_start * total_steps` steps, and from `max_lr` to `max_lr / (div_factor *
+/// final_div_factor)` in the remaining ones. With the three phase variant, it is first annealed
+/// back to `max_lr / div_factor` and then to the minimum in the last phase. The scheduler is
+/// meant to be stepped after every batch, and keeps the minimum learning rate past
+/// `total_steps`.
+///
+/// The momentum, or the first moment decay rate for the optimizers of the Adam family, is cycled
+/// inversely to the learning rate between `max_momentum` and `base_momentum`. Optimizers that
+/// have no momentum are left untouched.
+///
+/// The learning rate of the optimizer is set to `max_lr / div_factor` as soon as the scheduler
+/// is created.
+pub struct OneCycleLR<T>
+where
+    T: OptimizerStatus,
+{
+    optimizer: OptimizerHandle<T>,
+    max_lr: f32,
+    total_steps: usize,
+    pct_start: f32,
+    anneal_strategy: AnnealStrategy,
+    div_factor: Cell<f32>,
+    final_div_factor: Cell<f32>,
+    three_phase: Cell<bool>,
+    cycle_momentum: Cell<bool>,
+    base_momentum: Cell<f32>,
+    max_momentum: Cell<f32>,
+    current_epoch: Cell<usize>,
+    current_lr: Cell<f32>,
+    last_lr: Cell<f32>,
+}
+
+impl<T> OneCycleLR<T>
+where
+    T: OptimizerStatus,
+{
+    /// Creates a new OneCycleLR scheduler.
+    ///
+    /// # Arguments
+    ///
+    /// * `optimizer` - wrapped optimizer.
+    ///
+    /// * `max_lr` - peak learning rate.
+    ///
+    /// * `total_steps` - total number of steps in the cycle.
+    ///
+    /// * `pct_start` - fraction of the cycle spent increasing the learning rate.
+    ///
+    /// * `anneal_strategy` - annealing strategy.
+    ///
+    /// The scheduler starts with a `div_factor` of 25, a `final_div_factor` of 1e4, without the
+    /// third phase and cycling the momentum between 0.85 and 0.95.
+    ///
+    /// # Panics
+    ///
+    /// If `total_steps` is zero or `pct_start` is not in the open interval (0, 1).
+    pub fn new(
+        optimizer: &Optimizer<T>,
+        max_lr: f32,
+        total_steps: usize,
+        pct_start: f32,
+        anneal_strategy: AnnealStrategy,
+    ) -> Self {
+        assert!(total_steps > 0, "error: total_steps must be positive.");
+        assert!(
+            pct_start > 0.0 && pct_start < 1.0,
+            "error: pct_start must be between 0 and 1, found {}.",
+            pct_start
+        );
+
+        let optimizer = optimizer.handle();
+        let scheduler = Self {
+            optimizer,
+            max_lr,
+            total_steps,
+            pct_start,
+            anneal_strategy,
+            div_factor: Cell::new(25.0),
+            final_div_factor: Cell::new(1e4),
+            three_phase: Cell::new(false),
+            cycle_momentum: Cell::new(true),
+            base_momentum: Cell::new(0.85),
+            max_momentum: Cell::new(0.95),
+            current_epoch: Cell::new(0),
+            current_lr: Cell::new(0.0),
+            last_lr: Cell::new(0.0),
+        };
+        scheduler.update();
+
+        scheduler
+    }
+
+    /// Sets a new factor between `max_lr` and the initial learning rate. The learning rate for
+    /// the current step is updated accordingly.
+    pub fn set_div_factor(&self, div_factor: f32) {
+        self.div_factor.set(div_factor);
+        self.update();
+    }
+
+    /// Sets a new factor between the initial and the minimum learning rate. The learning rate
+    /// for the current step is updated accordingly.
+    pub fn set_final_div_factor(&self, final_div_factor: f32) {
+        self.final_div_factor.set(final_div_factor);
+        self.update();
+    }
+
+    /// Enables or disables the third phase of the cycle. The learning rate for the current step
+    /// is updated accordingly.
+    pub fn set_three_phase(&self, three_phase: bool) {
+        self.three_phase.set(three_phase);
+        self.update();
+    }
+
+    /// Enables or disables the cycling of the momentum.
+    pub fn set_cycle_momentum(&self, cycle_momentum: bool) {
+        self.cycle_momentum.set(cycle_momentum);
+        self.update();
+    }
+
+    /// Sets new bounds for the momentum. The momentum for the current step is updated
+    /// accordingly.
+    pub fn set_momentum(&self, base_momentum: f32, max_momentum: f32) {
+        self.base_momentum.set(base_momentum);
+        self.max_momentum.set(max_momentum);
+        self.update();
+    }
+
+    /// Sets the learning rate and the momentum for the next step of the cycle.
+    pub fn step(&self) {
+        LRScheduler::step(self);
+    }
+
+    /// Returns the last learning rate value computed by this learning rate scheduler.
+    pub fn get_last_lr(&self) -> f32 {
+        LRScheduler::get_last_lr(self)
+    }
+
+    /// Returns the current learning rate value computed by this learning rate scheduler.
+    pub fn get_current_lr(&self) -> f32 {
+        LRScheduler::get_current_lr(self)
+    }
+
+    /// Sets the current epoch for this learning rate scheduler.
+    pub fn set_current_epoch(&self, epoch: usize) {
+        LRScheduler::set_current_epoch(self, epoch);
+    }
+
+    /// Returns the current epoch for this learning rate scheduler.
+    pub fn get_current_epoch(&self) -> usize {
+        LRScheduler::get_current_epoch(self)
+    }
+
+    /// Prints the learning rate update together with the epoch.
+    pub fn print_lr(&self) {
+        LRScheduler::print_lr(self);
+    }
+
+    /// Returns the state of this learning rate scheduler.
+    #[cfg(feature = "serialize")]
+    pub fn state_dict(&self) -> StateDict {
+        LRScheduler::state_dict(self)
+    }
+
+    /// Restores the state of this learning rate scheduler from `state`.
+    ///
+    /// # Errors
+    ///
+    /// See [`LRScheduler::load_state_dict()`].
+    #[cfg(feature = "serialize")]
+    pub fn load_state_dict(&self, state: &StateDict) -> Result<(), CheckpointError> {
+        LRScheduler::load_state_dict(self, state)
+    }
+}
+
+impl<T> OneCycleLR<T>
+where
+    T: OptimizerStatus,
+{
+    /// Computes the learning rate and the momentum for the current step and sets them on the
+    /// optimizer.
+    fn update(&self) {
+        let total_steps = self.total_steps as f32;
+        let initial_lr = self.max_lr / self.div_factor.get();
+        let min_lr = initial_lr / self.final_div_factor.get();
+        let (base_momentum, max_momentum) = (self.base_momentum.get(), self.max_momentum.get());
+
+        // Each phase is described by its last step, the learning rates and the momenta at its
+        // boundaries.
+        let warmup_end = self.pct_start * total_steps - 1.;
+        let phases = if self.three_phase.get() {
+            vec![
+                (
+                    warmup_end,
+                    (initial_lr, self.max_lr),
+                    (max_momentum, base_momentum),
+                ),
+                (
+                    2. * self.pct_start * total_steps - 2.,
+                    (self.max_lr, initial_lr),
+                    (base_momentum, max_momentum),
+                ),
+                (
+                    total_steps - 1.,
+                    (initial_lr, min_lr),
+                    (max_momentum, max_momentum),
+                ),
+            ]
+        } else {
+            vec![
+                (
+                    warmup_end,
+                    (initial_lr, self.max_lr),
+                    (max_momentum, base_momentum),
+                ),
+                (
+                    total_steps - 1.,
+                    (self.max_lr, min_lr),
+                    (base_momentum, max_momentum),
+                ),
+            ]
+        };
+
+        let step = (self.current_epoch.get() as f32).min(total_steps - 1.);
+        let mut phase_start = 0.;
+        for (index, (phase_end, lr, momentum)) in phases.iter().enumerate() {
+            if step <= *phase_end || index == phases.len() - 1 {
+                let pct = if *phase_end > phase_start {
+                    (step - phase_start) / (phase_end - phase_start)
+                } else {
+                    1.
+                };
+
+                self.current_lr
+                    .set(self.anneal_strategy.anneal(lr.0, lr.1, pct));
+                self.optimizer.set_lr(self.current_lr.get());
+                if self.cycle_momentum.get()
+                    && self.optimizer.status().get_cycle_momentum().is_some()
+                {
+                    self.optimizer.set_cycle_momentum(
+                        self.anneal_strategy.anneal(momentum.0, momentum.1, pct),
+                    );
+                }
+                break;
+            }
+            phase_start = *phase_end;
+        }
+    }
+}
+
+impl<T> LRScheduler for OneCycleLR<T>
+where
+    T: OptimizerStatus,
+{
+    fn step(&self) {
+        prepare_step(&self.last_lr, &self.current_lr, &self.current_epoch);
+        self.update();
+    }
+
+    fn get_last_lr(&self) -> f32 {
+        self.last_lr.get()
+    }
+
+    fn get_current_lr(&self) -> f32 {
+        self.current_lr.get()
+    }
+
+    fn set_current_epoch(&self, epoch: usize) {
+        self.current_epoch.replace(epoch);
+    }
+
+    fn get_current_epoch(&self) -> usize {
+        self.current_epoch.get()
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(String, ArrayD<f32>)> {
+        epoch_state(&self.current_epoch, &self.current_lr, &self.last_lr)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&self, name: &str, value: &ArrayD<f32>) {
+        load_epoch_state(
+            &self.current_epoch,
+            &self.current_lr,
+            &self.last_lr,
+            name,
+            value,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,73 @@
+use crate::{Adagrad, Adam, StochasticGD, L2};
+
+use super::{AnnealStrategy, OneCycleLR};
+
+#[test]
+fn one_cycle_lr() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), 0.9, None, false);
+    let scheduler = OneCycleLR::new(&optim, 1.0, 10, 0.3, AnnealStrategy::Linear);
+    assert!((optim.get_lr() - 0.04).abs() <= f32::EPSILON);
+    assert!((optim.status().get_momentum().unwrap() - 0.95).abs() <= f32::EPSILON);
+
+    // Warmup ends at step 2, annealing at step 9.
+    let expected = [
+        (0.52, 0.9),
+        (1.0, 0.85),
+        (1.0 - (1.0 - 4e-6) / 7., 0.85 + 0.1 / 7.),
+    ];
+    for (lr, momentum) in expected {
+        scheduler.step();
+        assert!((scheduler.get_current_lr() - lr).abs() <= 1e-6);
+        assert!((optim.get_lr() - lr).abs() <= 1e-6);
+        assert!((optim.status().get_momentum().unwrap() - momentum).abs() <= 1e-6);
+    }
+
+    for _ in 3..12 {
+        scheduler.step();
+    }
+    assert!((optim.get_lr() - 4e-6).abs() <= 1e-7);
+    assert!((optim.status().get_momentum().unwrap() - 0.95).abs() <= 1e-6);
+}
+
+#[test]
+fn one_cycle_lr_three_phase() {
+    let optim = Adam::new(1.0, 0.9, 0.999, L2::new(0.0), 1e-8);
+    let scheduler = OneCycleLR::new(&optim, 1.0, 10, 0.3, AnnealStrategy::Cos);
+    scheduler.set_three_phase(true);
+
+    // The three phases end at steps 2, 4 and 9.
+    for _ in 0..2 {
+        scheduler.step();
+    }
+    assert!((optim.get_lr() - 1.0).abs() <= 1e-6);
+    assert!((optim.status().get_beta1() - 0.85).abs() <= 1e-6);
+
+    scheduler.step();
+    assert!((optim.get_lr() - 0.52).abs() <= 1e-6);
+    assert!((optim.status().get_beta1() - 0.9).abs() <= 1e-6);
+
+    scheduler.step();
+    assert!((optim.get_lr() - 0.04).abs() <= 1e-6);
+    assert!((optim.status().get_beta1() - 0.95).abs() <= 1e-6);
+
+    for _ in 4..9 {
+        scheduler.step();
+    }
+    assert!((optim.get_lr() - 4e-6).abs() <= 1e-7);
+    assert!((optim.status().get_beta1() - 0.95).abs() <= 1e-6);
+}
+
+#[test]
+fn one_cycle_lr_no_momentum() {
+    let optim = Adagrad::new(1.0, 0.0, L2::new(0.0), 1e-10);
+    let scheduler = OneCycleLR::new(&optim, 1.0, 10, 0.3, AnnealStrategy::Linear);
+    scheduler.step();
+    assert!((optim.get_lr() - 0.52).abs() <= 1e-6);
+}
+
+#[test]
+#[should_panic]
+fn one_cycle_lr_invalid_pct_start() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    OneCycleLR::new(&optim, 1.0, 10, 1.0, AnnealStrategy::Linear);
+}
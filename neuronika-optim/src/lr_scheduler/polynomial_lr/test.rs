@@ -0,0 +1,29 @@
+use crate::{StochasticGD, L2};
+
+use super::PolynomialLR;
+
+#[test]
+fn polynomial_lr() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = PolynomialLR::new(&optim, 4, 2.0);
+
+    let expected = [0.5625, 0.25, 0.0625, 0.0, 0.0];
+    for expected in expected {
+        scheduler.step();
+        scheduler.print_lr();
+        assert!((scheduler.get_current_lr() - expected).abs() <= f32::EPSILON);
+        assert!((optim.get_lr() - expected).abs() <= f32::EPSILON);
+    }
+}
+
+#[test]
+fn polynomial_lr_linear() {
+    let optim = StochasticGD::new(2.0, L2::new(0.1), None, None, false);
+    let scheduler = PolynomialLR::new(&optim, 4, 1.0);
+
+    for epoch in 1..=4 {
+        scheduler.step();
+        let expected = 2.0 * (1.0 - epoch as f32 / 4.0);
+        assert!((scheduler.get_current_lr() - expected).abs() <= f32::EPSILON);
+    }
+}
@@ -0,0 +1,305 @@
+use std::cell::Cell;
+
+use crate::{Optimizer, OptimizerHandle, OptimizerStatus};
+
+use super::prepare_step;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::{CheckpointError, StateDict};
+
+#[cfg(feature = "serialize")]
+use crate::optimizer::{check_state, counter, scalar, to_counter, to_scalar};
+
+#[cfg(feature = "serialize")]
+use super::{epoch_state, load_epoch_state};
+
+/// Whether the monitored metric should be minimized or maximized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlateauMode {
+    /// Lower values of the metric are better, as for a loss.
+    Min,
+    /// Higher values of the metric are better, as for an accuracy.
+    Max,
+}
+
+/// How the threshold for a significant improvement is measured.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThresholdMode {
+    /// The threshold is relative to the best value of the metric.
+    Rel,
+    /// The threshold is an absolute difference from the best value of the metric.
+    Abs,
+}
+
+/// Reduces the learning rate by `factor` when a monitored metric has stopped improving for more
+/// than `patience` epochs.
+///
+/// Unlike the other schedulers, this one is stepped with the value of the metric, usually
+/// computed on a validation set after each epoch.
+///
+///```text
+/// lrₜ = max(lrₜ₋₁ * factor, min_lr) if the metric didn't improve for more than patience epochs
+///       else lrₜ₋₁
+///```
+///
+/// An improvement is significant when it exceeds the threshold, which defaults to a relative
+/// 1e-4. After a reduction, the epochs without improvement aren't counted for `cooldown` epochs.
+pub struct ReduceLROnPlateau<T>
+where
+    T: OptimizerStatus,
+{
+    optimizer: OptimizerHandle<T>,
+    mode: PlateauMode,
+    factor: Cell<f32>,
+    patience: Cell<usize>,
+    threshold: Cell<f32>,
+    threshold_mode: Cell<ThresholdMode>,
+    cooldown: Cell<usize>,
+    min_lr: Cell<f32>,
+    eps: Cell<f32>,
+    best: Cell<f32>,
+    num_bad_epochs: Cell<usize>,
+    cooldown_counter: Cell<usize>,
+    current_epoch: Cell<usize>,
+    current_lr: Cell<f32>,
+    last_lr: Cell<f32>,
+}
+
+impl<T> ReduceLROnPlateau<T>
+where
+    T: OptimizerStatus,
+{
+    /// Creates a new ReduceLROnPlateau scheduler.
+    ///
+    /// # Arguments
+    ///
+    /// * `optimizer` - wrapped optimizer.
+    ///
+    /// * `mode` - whether the metric should be minimized or maximized.
+    ///
+    /// * `factor` - multiplicative factor for the learning rate reduction.
+    ///
+    /// * `patience` - number of epochs without improvement after which the learning rate is
+    ///   reduced.
+    ///
+    /// The scheduler starts with a relative threshold of 1e-4, no cooldown and a minimum learning
+    /// rate of 0.
+    ///
+    /// # Panics
+    ///
+    /// If `factor` is not in the open interval (0, 1).
+    pub fn new(optimizer: &Optimizer<T>, mode: PlateauMode, factor: f32, patience: usize) -> Self {
+        assert_factor(factor);
+
+        let optimizer = optimizer.handle();
+        let current_lr = optimizer.get_lr();
+
+        Self {
+            optimizer,
+            mode,
+            factor: Cell::new(factor),
+            patience: Cell::new(patience),
+            threshold: Cell::new(1e-4),
+            threshold_mode: Cell::new(ThresholdMode::Rel),
+            cooldown: Cell::new(0),
+            min_lr: Cell::new(0.0),
+            eps: Cell::new(1e-8),
+            best: Cell::new(worst(mode)),
+            num_bad_epochs: Cell::new(0),
+            cooldown_counter: Cell::new(0),
+            current_epoch: Cell::new(0),
+            current_lr: Cell::new(current_lr),
+            last_lr: Cell::new(0.0),
+        }
+    }
+
+    /// Sets a new factor for the learning rate reduction.
+    ///
+    /// # Panics
+    ///
+    /// If `factor` is not in the open interval (0, 1).
+    pub fn set_factor(&self, factor: f32) {
+        assert_factor(factor);
+
+        self.factor.set(factor)
+    }
+
+    /// Sets a new patience for the scheduler.
+    pub fn set_patience(&self, patience: usize) {
+        self.patience.set(patience)
+    }
+
+    /// Sets a new threshold for significant improvements, together with the way it is measured.
+    pub fn set_threshold(&self, threshold: f32, threshold_mode: ThresholdMode) {
+        self.threshold.set(threshold);
+        self.threshold_mode.set(threshold_mode);
+    }
+
+    /// Sets a new number of epochs to wait after a reduction before counting the epochs without
+    /// improvement again.
+    pub fn set_cooldown(&self, cooldown: usize) {
+        self.cooldown.set(cooldown)
+    }
+
+    /// Sets a new lower bound for the learning rate.
+    pub fn set_min_lr(&self, min_lr: f32) {
+        self.min_lr.set(min_lr)
+    }
+
+    /// Sets a new minimal reduction of the learning rate. Reductions smaller than `eps` are
+    /// ignored.
+    pub fn set_eps(&self, eps: f32) {
+        self.eps.set(eps)
+    }
+
+    /// Returns the best value of the metric seen so far.
+    pub fn get_best(&self) -> f32 {
+        self.best.get()
+    }
+
+    /// Returns the number of consecutive epochs without a significant improvement.
+    pub fn get_num_bad_epochs(&self) -> usize {
+        self.num_bad_epochs.get()
+    }
+
+    /// Records the value of the monitored metric for the current epoch and reduces the learning
+    /// rate if it has stopped improving.
+    pub fn step(&self, metric: f32) {
+        // The learning rate may have been changed by another scheduler or by hand.
+        self.current_lr.set(self.optimizer.get_lr());
+        prepare_step(&self.last_lr, &self.current_lr, &self.current_epoch);
+
+        if self.is_better(metric) {
+            self.best.set(metric);
+            self.num_bad_epochs.set(0);
+        } else {
+            self.num_bad_epochs.set(self.num_bad_epochs.get() + 1);
+        }
+
+        if self.cooldown_counter.get() > 0 {
+            self.cooldown_counter.set(self.cooldown_counter.get() - 1);
+            self.num_bad_epochs.set(0);
+        }
+
+        if self.num_bad_epochs.get() > self.patience.get() {
+            let lr = self.optimizer.get_lr();
+            let reduced_lr = (lr * self.factor.get()).max(self.min_lr.get());
+            if lr - reduced_lr > self.eps.get() {
+                self.current_lr.set(reduced_lr);
+                self.optimizer.set_lr(reduced_lr);
+            }
+            self.cooldown_counter.set(self.cooldown.get());
+            self.num_bad_epochs.set(0);
+        }
+    }
+
+    /// Returns the last learning rate value computed by this learning rate scheduler.
+    pub fn get_last_lr(&self) -> f32 {
+        self.last_lr.get()
+    }
+
+    /// Returns the current learning rate value computed by this learning rate scheduler.
+    pub fn get_current_lr(&self) -> f32 {
+        self.current_lr.get()
+    }
+
+    /// Sets the current epoch for this learning rate scheduler.
+    pub fn set_current_epoch(&self, epoch: usize) {
+        self.current_epoch.replace(epoch);
+    }
+
+    /// Returns the current epoch for this learning rate scheduler.
+    pub fn get_current_epoch(&self) -> usize {
+        self.current_epoch.get()
+    }
+
+    /// Prints the learning rate update together with the epoch. It should be called after
+    /// `.step()`.
+    pub fn print_lr(&self) {
+        println!(
+            "epoch {}: learning rate adjusted to [{}]",
+            self.get_current_epoch(),
+            self.get_current_lr()
+        );
+    }
+
+    /// Returns the state of this learning rate scheduler, made of the current epoch, the learning
+    /// rates, the best value of the metric and the counters of the epochs without improvement
+    /// and of the cooldown.
+    #[cfg(feature = "serialize")]
+    pub fn state_dict(&self) -> StateDict {
+        let mut state = epoch_state(&self.current_epoch, &self.current_lr, &self.last_lr);
+        state.extend([
+            ("best".to_string(), scalar(self.best.get())),
+            (
+                "num_bad_epochs".to_string(),
+                counter(self.num_bad_epochs.get()),
+            ),
+            (
+                "cooldown_counter".to_string(),
+                counter(self.cooldown_counter.get()),
+            ),
+        ]);
+
+        state.into_iter().collect()
+    }
+
+    /// Restores the state of this learning rate scheduler from `state`. Nothing is modified if an
+    /// error is returned.
+    ///
+    /// # Errors
+    ///
+    /// See [`LRScheduler::load_state_dict()`](super::LRScheduler::load_state_dict()).
+    #[cfg(feature = "serialize")]
+    pub fn load_state_dict(&self, state: &StateDict) -> Result<(), CheckpointError> {
+        check_state(&self.state_dict(), state)?;
+
+        for (name, value) in state {
+            match name.as_str() {
+                "best" => self.best.set(to_scalar(value)),
+                "num_bad_epochs" => self.num_bad_epochs.set(to_counter(value)),
+                "cooldown_counter" => self.cooldown_counter.set(to_counter(value)),
+                _ => load_epoch_state(
+                    &self.current_epoch,
+                    &self.current_lr,
+                    &self.last_lr,
+                    name,
+                    value,
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether `metric` is a significant improvement over the best value seen so far.
+    fn is_better(&self, metric: f32) -> bool {
+        let (best, threshold) = (self.best.get(), self.threshold.get());
+
+        match (self.mode, self.threshold_mode.get()) {
+            (PlateauMode::Min, ThresholdMode::Rel) => metric < best * (1. - threshold),
+            (PlateauMode::Min, ThresholdMode::Abs) => metric < best - threshold,
+            (PlateauMode::Max, ThresholdMode::Rel) => metric > best * (1. + threshold),
+            (PlateauMode::Max, ThresholdMode::Abs) => metric > best + threshold,
+        }
+    }
+}
+
+/// Returns the worst possible value of a metric for the given mode.
+fn worst(mode: PlateauMode) -> f32 {
+    match mode {
+        PlateauMode::Min => f32::INFINITY,
+        PlateauMode::Max => f32::NEG_INFINITY,
+    }
+}
+
+fn assert_factor(factor: f32) {
+    assert!(
+        factor > 0.0 && factor < 1.0,
+        "error: factor must be between 0 and 1, found {}.",
+        factor
+    );
+}
+
+#[cfg(test)]
+mod test;
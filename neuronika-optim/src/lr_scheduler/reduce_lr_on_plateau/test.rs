@@ -0,0 +1,122 @@
+use crate::{StochasticGD, L2};
+
+use super::{PlateauMode, ReduceLROnPlateau, ThresholdMode};
+
+#[test]
+fn reduce_lr_on_plateau() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = ReduceLROnPlateau::new(&optim, PlateauMode::Min, 0.5, 1);
+
+    // The learning rate is halved on the second consecutive epoch without improvement.
+    let metrics = [10., 9., 9., 9., 8., 8.5, 8.5, 8.5];
+    let expected = [1.0, 1.0, 1.0, 0.5, 0.5, 0.5, 0.25, 0.25];
+    for (epoch, (metric, lr)) in metrics.iter().zip(expected).enumerate() {
+        scheduler.step(*metric);
+        scheduler.print_lr();
+        assert_eq!(scheduler.get_current_epoch(), epoch + 1);
+        assert!((scheduler.get_current_lr() - lr).abs() <= f32::EPSILON);
+        assert!((optim.get_lr() - lr).abs() <= f32::EPSILON);
+    }
+    assert!((scheduler.get_best() - 8.).abs() <= f32::EPSILON);
+    assert!((scheduler.get_last_lr() - 0.25).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn reduce_lr_on_plateau_max_mode() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = ReduceLROnPlateau::new(&optim, PlateauMode::Max, 0.1, 0);
+
+    scheduler.step(0.5);
+    assert!((optim.get_lr() - 1.0).abs() <= f32::EPSILON);
+    scheduler.step(0.4);
+    assert!((optim.get_lr() - 0.1).abs() <= f32::EPSILON);
+    scheduler.step(0.6);
+    assert!((optim.get_lr() - 0.1).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn reduce_lr_on_plateau_threshold() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = ReduceLROnPlateau::new(&optim, PlateauMode::Min, 0.5, 0);
+    scheduler.set_threshold(0.5, ThresholdMode::Abs);
+
+    scheduler.step(10.);
+    // An improvement smaller than the threshold is not significant.
+    scheduler.step(9.6);
+    assert!((optim.get_lr() - 0.5).abs() <= f32::EPSILON);
+    assert!((scheduler.get_best() - 10.).abs() <= f32::EPSILON);
+
+    scheduler.set_threshold(0.1, ThresholdMode::Rel);
+    scheduler.step(8.9);
+    assert!((optim.get_lr() - 0.5).abs() <= f32::EPSILON);
+    assert!((scheduler.get_best() - 8.9).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn reduce_lr_on_plateau_cooldown_and_min_lr() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = ReduceLROnPlateau::new(&optim, PlateauMode::Min, 0.5, 0);
+    scheduler.set_cooldown(2);
+    scheduler.set_min_lr(0.3);
+
+    scheduler.step(1.);
+    let expected = [0.5, 0.5, 0.5, 0.3, 0.3, 0.3, 0.3];
+    for lr in expected {
+        scheduler.step(1.);
+        assert!((optim.get_lr() - lr).abs() <= f32::EPSILON);
+    }
+    assert_eq!(scheduler.get_num_bad_epochs(), 0);
+}
+
+#[test]
+fn reduce_lr_on_plateau_external_lr_change() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = ReduceLROnPlateau::new(&optim, PlateauMode::Min, 0.5, 0);
+
+    scheduler.step(1.);
+    // The reduction starts from the learning rate the optimizer currently has.
+    optim.set_lr(0.2);
+    scheduler.step(1.);
+    assert!((optim.get_lr() - 0.1).abs() <= f32::EPSILON);
+    assert!((scheduler.get_current_lr() - 0.1).abs() <= f32::EPSILON);
+    assert!((scheduler.get_last_lr() - 0.2).abs() <= f32::EPSILON);
+
+    // The current learning rate follows the optimizer when there is no reduction too.
+    optim.set_lr(0.05);
+    scheduler.step(0.5);
+    assert!((scheduler.get_current_lr() - 0.05).abs() <= f32::EPSILON);
+    assert!((scheduler.get_last_lr() - 0.05).abs() <= f32::EPSILON);
+}
+
+#[test]
+#[should_panic]
+fn reduce_lr_on_plateau_invalid_factor() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    ReduceLROnPlateau::new(&optim, PlateauMode::Min, 1.0, 0);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn reduce_lr_on_plateau_state_dict() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = ReduceLROnPlateau::new(&optim, PlateauMode::Min, 0.5, 1);
+    scheduler.set_cooldown(1);
+    for metric in [10., 9., 9.5, 9.5] {
+        scheduler.step(metric);
+    }
+    assert!((optim.get_lr() - 0.5).abs() <= f32::EPSILON);
+
+    let resumed_optim = StochasticGD::new(0.5, L2::new(0.1), None, None, false);
+    let resumed = ReduceLROnPlateau::new(&resumed_optim, PlateauMode::Min, 0.5, 1);
+    resumed.set_cooldown(1);
+    resumed.load_state_dict(&scheduler.state_dict()).unwrap();
+    assert_eq!(resumed.get_current_epoch(), 4);
+    assert!((resumed.get_best() - 9.).abs() <= f32::EPSILON);
+
+    for metric in [9.5, 9.5, 9.5, 8.] {
+        scheduler.step(metric);
+        resumed.step(metric);
+        assert!((resumed_optim.get_lr() - optim.get_lr()).abs() <= f32::EPSILON);
+        assert_eq!(resumed.get_num_bad_epochs(), scheduler.get_num_bad_epochs());
+    }
+}
@@ -0,0 +1,209 @@
+use std::cell::Cell;
+
+use crate::{Optimizer, OptimizerHandle, OptimizerStatus};
+
+use super::{prepare_step, LRScheduler};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::{CheckpointError, StateDict};
+
+#[cfg(feature = "serialize")]
+use super::{epoch_state, load_epoch_state, load_nested_state, nested_state};
+
+/// Runs a list of learning rate schedulers one after the other, switching to the next one every
+/// time the current epoch reaches a milestone.
+///
+/// Each scheduler starts from its own first epoch: when a milestone is reached the learning
+/// rate is set to the one the next scheduler had when created. Likewise, the learning rate of
+/// the optimizer is set to the one of the first scheduler as soon as this is created.
+///
+/// ```ignore
+/// let warmup = LinearWarmup::new(&optim, 0.1, 1.0, 5);
+/// let annealing = CosineAnnealingLR::new(&optim, 95, 0.0);
+/// let scheduler = SequentialLR::new(&optim, vec![Box::new(warmup), Box::new(annealing)], vec![5]);
+/// ```
+pub struct SequentialLR<T>
+where
+    T: OptimizerStatus,
+{
+    optimizer: OptimizerHandle<T>,
+    schedulers: Vec<Box<dyn LRScheduler>>,
+    milestones: Vec<usize>,
+    current_epoch: Cell<usize>,
+    current_lr: Cell<f32>,
+    last_lr: Cell<f32>,
+}
+
+impl<T> SequentialLR<T>
+where
+    T: OptimizerStatus,
+{
+    /// Creates a new SequentialLR scheduler.
+    ///
+    /// # Arguments
+    ///
+    /// * `optimizer` - wrapped optimizer.
+    ///
+    /// * `schedulers` - list of schedulers to run, in order.
+    ///
+    /// * `milestones` - list of epochs at which the next scheduler is switched to. Must be
+    ///   increasing.
+    ///
+    /// # Panics
+    ///
+    /// If the number of milestones is not one less than the number of schedulers.
+    pub fn new(
+        optimizer: &Optimizer<T>,
+        schedulers: Vec<Box<dyn LRScheduler>>,
+        milestones: Vec<usize>,
+    ) -> Self {
+        assert!(
+            !schedulers.is_empty() && milestones.len() == schedulers.len() - 1,
+            "error: expected {} milestones for {} schedulers, found {}.",
+            schedulers.len().saturating_sub(1),
+            schedulers.len(),
+            milestones.len()
+        );
+
+        let optimizer = optimizer.handle();
+        let current_lr = schedulers[0].get_current_lr();
+        optimizer.set_lr(current_lr);
+
+        Self {
+            optimizer,
+            schedulers,
+            milestones,
+            current_epoch: Cell::new(0),
+            current_lr: Cell::new(current_lr),
+            last_lr: Cell::new(0.0),
+        }
+    }
+
+    /// Steps the scheduler in charge of the current epoch.
+    pub fn step(&self) {
+        LRScheduler::step(self);
+    }
+
+    /// Returns the last learning rate value computed by this learning rate scheduler.
+    pub fn get_last_lr(&self) -> f32 {
+        LRScheduler::get_last_lr(self)
+    }
+
+    /// Returns the current learning rate value computed by this learning rate scheduler.
+    pub fn get_current_lr(&self) -> f32 {
+        LRScheduler::get_current_lr(self)
+    }
+
+    /// Sets the current epoch for this learning rate scheduler.
+    pub fn set_current_epoch(&self, epoch: usize) {
+        LRScheduler::set_current_epoch(self, epoch);
+    }
+
+    /// Returns the current epoch for this learning rate scheduler.
+    pub fn get_current_epoch(&self) -> usize {
+        LRScheduler::get_current_epoch(self)
+    }
+
+    /// Prints the learning rate update together with the epoch.
+    pub fn print_lr(&self) {
+        LRScheduler::print_lr(self);
+    }
+
+    /// Returns the state of this learning rate scheduler.
+    #[cfg(feature = "serialize")]
+    pub fn state_dict(&self) -> StateDict {
+        LRScheduler::state_dict(self)
+    }
+
+    /// Restores the state of this learning rate scheduler from `state`.
+    ///
+    /// # Errors
+    ///
+    /// See [`LRScheduler::load_state_dict()`].
+    #[cfg(feature = "serialize")]
+    pub fn load_state_dict(&self, state: &StateDict) -> Result<(), CheckpointError> {
+        LRScheduler::load_state_dict(self, state)
+    }
+}
+
+impl<T> SequentialLR<T>
+where
+    T: OptimizerStatus,
+{
+    /// Returns the index of the scheduler in charge of `epoch` together with the epoch it
+    /// started from.
+    fn scheduler_at(&self, epoch: usize) -> (usize, usize) {
+        let index = self
+            .milestones
+            .iter()
+            .take_while(|milestone| **milestone <= epoch)
+            .count();
+        let start = index.checked_sub(1).map_or(0, |prev| self.milestones[prev]);
+
+        (index, start)
+    }
+}
+
+impl<T> LRScheduler for SequentialLR<T>
+where
+    T: OptimizerStatus,
+{
+    fn step(&self) {
+        prepare_step(&self.last_lr, &self.current_lr, &self.current_epoch);
+
+        let epoch = self.current_epoch.get();
+        let (index, start) = self.scheduler_at(epoch);
+        let scheduler = &self.schedulers[index];
+        if index > 0 && start == epoch {
+            scheduler.set_current_epoch(0);
+            self.optimizer.set_lr(scheduler.get_current_lr());
+        } else {
+            scheduler.step();
+        }
+        self.current_lr.set(self.optimizer.get_lr());
+    }
+
+    fn get_last_lr(&self) -> f32 {
+        self.last_lr.get()
+    }
+
+    fn get_current_lr(&self) -> f32 {
+        self.current_lr.get()
+    }
+
+    fn set_current_epoch(&self, epoch: usize) {
+        self.current_epoch.replace(epoch);
+
+        let (index, start) = self.scheduler_at(epoch);
+        self.schedulers[index].set_current_epoch(epoch - start);
+    }
+
+    fn get_current_epoch(&self) -> usize {
+        self.current_epoch.get()
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(String, ArrayD<f32>)> {
+        let mut state = epoch_state(&self.current_epoch, &self.current_lr, &self.last_lr);
+        state.extend(nested_state(&self.schedulers));
+        state
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&self, name: &str, value: &ArrayD<f32>) {
+        load_epoch_state(
+            &self.current_epoch,
+            &self.current_lr,
+            &self.last_lr,
+            name,
+            value,
+        );
+        load_nested_state(&self.schedulers, name, value);
+    }
+}
+
+#[cfg(test)]
+mod test;
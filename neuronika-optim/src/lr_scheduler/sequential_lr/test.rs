@@ -0,0 +1,84 @@
+use crate::{StochasticGD, L2};
+
+use super::{
+    super::{CosineAnnealingLR, LinearWarmup},
+    SequentialLR,
+};
+
+#[test]
+fn sequential_lr() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let warmup = LinearWarmup::new(&optim, 0.5, 1.0, 2);
+    let annealing = CosineAnnealingLR::new(&optim, 2, 0.0);
+    let scheduler = SequentialLR::new(&optim, vec![Box::new(warmup), Box::new(annealing)], vec![2]);
+    assert!((optim.get_lr() - 0.5).abs() <= f32::EPSILON);
+
+    let expected = [0.75, 1.0, 0.5, 0.0];
+    for (epoch, lr) in expected.iter().enumerate() {
+        scheduler.step();
+        scheduler.print_lr();
+        assert_eq!(scheduler.get_current_epoch(), epoch + 1);
+        assert!((scheduler.get_current_lr() - lr).abs() <= 1e-6);
+        assert!((optim.get_lr() - lr).abs() <= 1e-6);
+    }
+}
+
+#[test]
+#[should_panic]
+fn sequential_lr_invalid_milestones() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let warmup = LinearWarmup::new(&optim, 0.5, 1.0, 2);
+    SequentialLR::new(&optim, vec![Box::new(warmup)], vec![2]);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn sequential_lr_state_dict() {
+    let new_scheduler = |optim| {
+        let warmup = LinearWarmup::new(optim, 0.5, 1.0, 2);
+        let annealing = CosineAnnealingLR::new(optim, 4, 0.0);
+        SequentialLR::new(optim, vec![Box::new(warmup), Box::new(annealing)], vec![2])
+    };
+
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = new_scheduler(&optim);
+    for _ in 0..3 {
+        scheduler.step();
+    }
+    let state = scheduler.state_dict();
+    assert!((state["schedulers.1.current_epoch"].first().unwrap() - 1.).abs() <= f32::EPSILON);
+
+    let resumed_optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let resumed = new_scheduler(&resumed_optim);
+    resumed.load_state_dict(&state).unwrap();
+    assert_eq!(resumed.state_dict(), state);
+
+    for _ in 0..3 {
+        scheduler.step();
+        resumed.step();
+        assert!((resumed_optim.get_lr() - optim.get_lr()).abs() <= f32::EPSILON);
+    }
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn sequential_lr_state_dict_mismatch() {
+    use neuronika_variable::CheckpointError;
+
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let warmup = LinearWarmup::new(&optim, 0.5, 1.0, 2);
+    let scheduler = SequentialLR::new(&optim, vec![Box::new(warmup)], vec![]);
+    let annealing = CosineAnnealingLR::new(&optim, 4, 0.0);
+
+    match annealing.load_state_dict(&scheduler.state_dict()) {
+        Err(CheckpointError::KeyMismatch {
+            missing,
+            unexpected,
+        }) => {
+            assert_eq!(missing, vec!["initial_lr".to_string()]);
+            assert_eq!(unexpected.len(), 4);
+        }
+        _ => panic!("expected a key mismatch"),
+    }
+    assert_eq!(annealing.get_current_epoch(), 0);
+}
@@ -1,19 +1,28 @@
 use std::cell::Cell;
 
-use crate::{Optimizer, OptimizerStatus};
+use crate::{Optimizer, OptimizerHandle, OptimizerStatus};
 
 use super::{prepare_step, LRScheduler};
 
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::{CheckpointError, StateDict};
+
+#[cfg(feature = "serialize")]
+use super::{epoch_state, load_epoch_state};
+
 /// Decays the learning rate by `gamma` every `step_size` epochs.
 ///
 ///```text
 /// lrₜ = lrₜ₋₁ * gamma if t mod step_size == 0 else lrₜ₋₁
 ///```
-pub struct StepLR<'a, T>
+pub struct StepLR<T>
 where
     T: OptimizerStatus,
 {
-    optimizer: &'a Optimizer<T>,
+    optimizer: OptimizerHandle<T>,
     gamma: Cell<f32>,
     step_size: Cell<usize>,
     current_epoch: Cell<usize>,
@@ -21,7 +30,7 @@ where
     last_lr: Cell<f32>,
 }
 
-impl<'a, T> StepLR<'a, T>
+impl<T> StepLR<T>
 where
     T: OptimizerStatus,
 {
@@ -34,7 +43,8 @@ where
     /// * `step_size` - period of learning rate decay.
     ///
     /// * `gamma` - multiplicative factor for the learning rate decay.
-    pub fn new(optimizer: &'a Optimizer<T>, step_size: usize, gamma: f32) -> Self {
+    pub fn new(optimizer: &Optimizer<T>, step_size: usize, gamma: f32) -> Self {
+        let optimizer = optimizer.handle();
         let current_lr = optimizer.get_lr();
 
         Self {
@@ -86,9 +96,25 @@ where
     pub fn print_lr(&self) {
         LRScheduler::print_lr(self);
     }
+
+    /// Returns the state of this learning rate scheduler.
+    #[cfg(feature = "serialize")]
+    pub fn state_dict(&self) -> StateDict {
+        LRScheduler::state_dict(self)
+    }
+
+    /// Restores the state of this learning rate scheduler from `state`.
+    ///
+    /// # Errors
+    ///
+    /// See [`LRScheduler::load_state_dict()`].
+    #[cfg(feature = "serialize")]
+    pub fn load_state_dict(&self, state: &StateDict) -> Result<(), CheckpointError> {
+        LRScheduler::load_state_dict(self, state)
+    }
 }
 
-impl<'a, T> LRScheduler for StepLR<'a, T>
+impl<T> LRScheduler for StepLR<T>
 where
     T: OptimizerStatus,
 {
@@ -115,6 +141,22 @@ where
     fn get_current_epoch(&self) -> usize {
         self.current_epoch.get()
     }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(String, ArrayD<f32>)> {
+        epoch_state(&self.current_epoch, &self.current_lr, &self.last_lr)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&self, name: &str, value: &ArrayD<f32>) {
+        load_epoch_state(
+            &self.current_epoch,
+            &self.current_lr,
+            &self.last_lr,
+            name,
+            value,
+        )
+    }
 }
 
 #[cfg(test)]
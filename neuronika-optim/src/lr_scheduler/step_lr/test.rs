@@ -1,4 +1,4 @@
-use crate::{StochasticGD, L2};
+use crate::{GroupOverrides, Optimizer, StochasticGD, L2};
 
 use super::StepLR;
 
@@ -23,3 +23,36 @@ fn step_lr() {
     }
     assert!((scheduler.get_last_lr() - 2_f32.powi(4)).abs() <= f32::EPSILON);
 }
+
+#[test]
+fn step_lr_groups() {
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let group = optim.register_group(
+        vec![neuronika_variable::rand(3).requires_grad()],
+        GroupOverrides::new().lr(0.1),
+    );
+    let scheduler = StepLR::new(&optim, 1, 0.5);
+
+    for epoch in 0..3 {
+        let factor = 0.5_f32.powi(epoch);
+        assert!((optim.group(0).get_lr() - factor).abs() <= f32::EPSILON);
+        assert!((optim.group(group).get_lr() - 0.1 * factor).abs() <= f32::EPSILON);
+        scheduler.step();
+    }
+}
+
+#[test]
+fn step_lr_owned() {
+    // The scheduler doesn't borrow the optimizer, so both can be stored in the same struct.
+    struct Trainer {
+        optim: Optimizer<StochasticGD<L2>>,
+        scheduler: StepLR<StochasticGD<L2>>,
+    }
+
+    let optim = StochasticGD::new(1.0, L2::new(0.1), None, None, false);
+    let scheduler = StepLR::new(&optim, 1, 0.5);
+    let trainer = Trainer { optim, scheduler };
+
+    trainer.scheduler.step();
+    assert!((trainer.optim.get_lr() - 0.5).abs() <= f32::EPSILON);
+}
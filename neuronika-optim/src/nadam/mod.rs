@@ -0,0 +1,316 @@
+use std::{cell::Cell, rc::Rc};
+
+use ndarray::{Array, Dimension, Zip};
+
+use neuronika_variable::VarDiff;
+
+use super::{
+    GroupOverrides, GroupStatus, IntoParam, Optimize, Optimizer, OptimizerStatus, Penalty,
+};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::StateDict;
+
+#[cfg(feature = "serialize")]
+use super::{assign, counter, scalar, to_counter, to_scalar};
+
+/// NAdam optimizer.
+///
+/// It is a variant of the *Adam* algorithm incorporating Nesterov momentum, proposed in
+/// [Incorporating Nesterov Momentum into Adam](https://openreview.net/forum?id=OM0jvwB8jIp57ZJjtNEZ).
+#[derive(Clone)]
+pub struct NAdam<T>
+where
+    T: Penalty,
+{
+    lr: Cell<f32>,
+    penalty: T,
+    beta1: Cell<f32>,
+    beta2: Cell<f32>,
+    momentum_decay: Cell<f32>,
+    eps: Cell<f32>,
+}
+
+impl<T> OptimizerStatus for NAdam<T>
+where
+    T: Penalty,
+{
+    fn get_lr(&self) -> f32 {
+        self.lr.get()
+    }
+
+    fn set_lr(&self, lr: f32) {
+        self.lr.set(lr)
+    }
+
+    fn get_cycle_momentum(&self) -> Option<f32> {
+        Some(self.beta1.get())
+    }
+
+    fn set_cycle_momentum(&self, momentum: f32) {
+        self.beta1.set(momentum)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn hyperparameters(&self) -> Vec<(&'static str, f32)> {
+        vec![
+            ("lr", self.lr.get()),
+            ("beta1", self.beta1.get()),
+            ("beta2", self.beta2.get()),
+            ("momentum_decay", self.momentum_decay.get()),
+            ("eps", self.eps.get()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn set_hyperparameter(&self, name: &str, value: f32) {
+        match name {
+            "lr" => self.lr.set(value),
+            "beta1" => self.beta1.set(value),
+            "beta2" => self.beta2.set(value),
+            "momentum_decay" => self.momentum_decay.set(value),
+            "eps" => self.eps.set(value),
+            _ => (),
+        }
+    }
+}
+
+impl<T> GroupStatus for NAdam<T>
+where
+    T: Penalty,
+{
+    fn with_overrides(&self, overrides: &GroupOverrides) -> Self {
+        overrides.reject_momentum("NAdam");
+
+        let mut status = self.clone();
+        if let Some(lr) = overrides.get_lr() {
+            status.lr.set(lr);
+        }
+        if let Some(weight_decay) = overrides.get_weight_decay() {
+            status.penalty = status.penalty.with_weight_decay(weight_decay);
+        }
+
+        status
+    }
+}
+
+impl<T> NAdam<T>
+where
+    T: Penalty,
+{
+    /// Creates a new NAdam optimizer.
+    ///
+    /// # Arguments
+    ///
+    /// * `lr` - learning rate.
+    ///
+    /// * `beta1` - coefficient for computing running average of the gradient. A good default is 0.9.
+    ///
+    /// * `beta2` - coefficient for computing running average of the squared gradient. A good
+    ///   default is 0.999.
+    ///
+    /// * `momentum_decay` - decay of the momentum schedule. A good default is 4e-3.
+    ///
+    /// * `penalty` - penalty regularization.
+    ///
+    /// * `eps` - small constant for numerical stability. A good default value is *1e-8*.
+    pub fn new(
+        lr: f32,
+        beta1: f32,
+        beta2: f32,
+        momentum_decay: f32,
+        penalty: T,
+        eps: f32,
+    ) -> Optimizer<Self> {
+        let lr = Cell::new(lr);
+        let beta1 = Cell::new(beta1);
+        let beta2 = Cell::new(beta2);
+        let momentum_decay = Cell::new(momentum_decay);
+        let eps = Cell::new(eps);
+
+        let status = Self {
+            lr,
+            penalty,
+            beta1,
+            beta2,
+            momentum_decay,
+            eps,
+        };
+
+        Optimizer::new(status)
+    }
+
+    /// Return the current learning rate.
+    pub fn get_lr(&self) -> f32 {
+        OptimizerStatus::get_lr(self)
+    }
+
+    /// Sets a new value for the learning rate.
+    pub fn set_lr(&self, lr: f32) {
+        OptimizerStatus::set_lr(self, lr);
+    }
+
+    /// Return the current values for the first exponential decay rate.
+    pub fn get_beta1(&self) -> f32 {
+        self.beta1.get()
+    }
+
+    /// Return the current values for the second exponential decay rate.
+    pub fn get_beta2(&self) -> f32 {
+        self.beta2.get()
+    }
+
+    /// Sets a new value for the first exponential decay rate.
+    pub fn set_beta1(&self, beta1: f32) {
+        self.beta1.set(beta1)
+    }
+
+    /// Sets a new value for the second exponential decay rate.
+    pub fn set_beta2(&self, beta2: f32) {
+        self.beta2.set(beta2)
+    }
+
+    /// Return the current momentum decay.
+    pub fn get_momentum_decay(&self) -> f32 {
+        self.momentum_decay.get()
+    }
+
+    /// Sets a new value for the momentum decay.
+    pub fn set_momentum_decay(&self, momentum_decay: f32) {
+        self.momentum_decay.set(momentum_decay)
+    }
+
+    /// Return the current epsilon constant.
+    pub fn get_eps(&self) -> f32 {
+        self.eps.get()
+    }
+
+    /// Sets a new value for the epsilon constant.
+    pub fn set_eps(&self, eps: f32) {
+        self.eps.set(eps)
+    }
+}
+
+/// A parameter used by the NAdam optimizer.
+pub struct NAdamParam<D, T>
+where
+    D: Dimension,
+    T: Penalty,
+{
+    variable: VarDiff<D>,
+    step: usize,
+    mu_product: f32,
+    exp_avg: Array<f32, D>,
+    exp_avg_sq: Array<f32, D>,
+    status: Rc<NAdam<T>>,
+}
+
+impl<D, T> IntoParam<NAdam<T>> for VarDiff<D>
+where
+    D: 'static + Dimension,
+    T: 'static + Penalty,
+{
+    type Param = NAdamParam<D, T>;
+
+    fn into_param(self, status: Rc<NAdam<T>>) -> Self::Param {
+        let variable = self;
+        let step = 0;
+        let mu_product = 1.0;
+        let exp_avg = Array::zeros(variable.data().raw_dim());
+        let exp_avg_sq = exp_avg.clone();
+
+        Self::Param {
+            variable,
+            step,
+            mu_product,
+            exp_avg,
+            exp_avg_sq,
+            status,
+        }
+    }
+}
+
+impl<D, T> Optimize for NAdamParam<D, T>
+where
+    D: Dimension,
+    T: Penalty,
+{
+    fn optimize(&mut self) {
+        self.step += 1;
+
+        let lr = self.status.get_lr();
+        let beta1 = self.status.get_beta1();
+        let beta2 = self.status.get_beta2();
+        let momentum_decay = self.status.get_momentum_decay();
+        let penalty = self.status.penalty;
+        let eps = self.status.get_eps();
+
+        let mu = beta1 * (1.0 - 0.5 * 0.96_f32.powf(self.step as f32 * momentum_decay));
+        let mu_next = beta1 * (1.0 - 0.5 * 0.96_f32.powf((self.step + 1) as f32 * momentum_decay));
+        self.mu_product *= mu;
+
+        let grad_coefficient = lr * (1.0 - mu) / (1.0 - self.mu_product);
+        let exp_avg_coefficient = lr * mu_next / (1.0 - self.mu_product * mu_next);
+        let bias_correction2 = 1.0 - beta2.powi(self.step as i32);
+
+        let mut data = self.variable.data_mut();
+        let mut grad = self.variable.grad_mut();
+
+        Zip::from(&mut *grad)
+            .and(&*data)
+            .for_each(|grad_el, data_el| *grad_el += penalty.penalize(data_el));
+
+        Zip::from(&mut self.exp_avg)
+            .and(&*grad)
+            .for_each(|exp_avg_el, grad_el| {
+                *exp_avg_el = *exp_avg_el * beta1 + grad_el * (1.0 - beta1)
+            });
+
+        Zip::from(&mut self.exp_avg_sq)
+            .and(&*grad)
+            .for_each(|exp_avg_sq_el, grad_el| {
+                *exp_avg_sq_el = *exp_avg_sq_el * beta2 + grad_el * grad_el * (1.0 - beta2)
+            });
+
+        Zip::from(&mut *data)
+            .and(&*grad)
+            .and(&self.exp_avg)
+            .and(&self.exp_avg_sq)
+            .for_each(|data_el, grad_el, exp_avg_el, exp_avg_sq_el| {
+                let denom = (exp_avg_sq_el / bias_correction2).sqrt() + eps;
+                *data_el -= (grad_el * grad_coefficient + exp_avg_el * exp_avg_coefficient) / denom
+            });
+    }
+
+    fn zero_grad(&mut self) {
+        self.variable.zero_grad()
+    }
+
+    fn for_each_grad(&mut self, f: &mut dyn FnMut(&mut f32)) {
+        self.variable.grad_mut().iter_mut().for_each(f)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(&'static str, ArrayD<f32>)> {
+        vec![
+            ("step", counter(self.step)),
+            ("mu_product", scalar(self.mu_product)),
+            ("exp_avg", self.exp_avg.clone().into_dyn()),
+            ("exp_avg_sq", self.exp_avg_sq.clone().into_dyn()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&mut self, state: &StateDict) {
+        self.step = to_counter(&state["step"]);
+        self.mu_product = to_scalar(&state["mu_product"]);
+        assign(&mut self.exp_avg, &state["exp_avg"]);
+        assign(&mut self.exp_avg_sq, &state["exp_avg_sq"]);
+    }
+}
+
+#[cfg(test)]
+mod test;
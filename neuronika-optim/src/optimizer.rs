@@ -1,4 +1,13 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+#[cfg(feature = "serialize")]
+use ndarray::{Array, ArrayD, Dimension, IxDyn};
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::{CheckpointError, StateDict};
 
 /// Parameter optimization logic trait.
 pub trait Optimize {
@@ -7,6 +16,24 @@ pub trait Optimize {
 
     /// Zeroes the gradient of this parameter.
     fn zero_grad(&mut self);
+
+    /// Applies `f` to every element of the gradient of this parameter. This is used to clip the
+    /// gradients.
+    ///
+    /// The default implementation does nothing, leaving the parameter out of gradient clipping.
+    fn for_each_grad(&mut self, _f: &mut dyn FnMut(&mut f32)) {}
+
+    /// Returns the buffers of this parameter, such as moment estimates and step counters, by
+    /// name.
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(&'static str, ArrayD<f32>)> {
+        Vec::new()
+    }
+
+    /// Restores the buffers of this parameter from `state`, whose names and shapes are the ones
+    /// returned by [`.state()`](Optimize::state()).
+    #[cfg(feature = "serialize")]
+    fn load_state(&mut self, _state: &StateDict) {}
 }
 
 /// Parameter creation trait.
@@ -27,14 +54,224 @@ pub trait OptimizerStatus {
 
     /// Sets the optimizer's learning rate.
     fn set_lr(&self, lr: f32);
+
+    /// Gets the optimizer's momentum, or its first moment decay rate for the optimizers of the
+    /// Adam family. Returns `None` if the optimizer has no such hyperparameter.
+    ///
+    /// This is the value cycled by [`OneCycleLR`](crate::lr_scheduler::OneCycleLR) and
+    /// [`CyclicLR`](crate::lr_scheduler::CyclicLR).
+    fn get_cycle_momentum(&self) -> Option<f32> {
+        None
+    }
+
+    /// Sets the optimizer's momentum, or its first moment decay rate for the optimizers of the
+    /// Adam family. Does nothing if the optimizer has no such hyperparameter.
+    fn set_cycle_momentum(&self, _momentum: f32) {}
+
+    /// Returns the optimizer's hyperparameters by name. Flags are stored as 0 or 1 and disabled
+    /// options as NaN.
+    #[cfg(feature = "serialize")]
+    fn hyperparameters(&self) -> Vec<(&'static str, f32)> {
+        vec![("lr", self.get_lr())]
+    }
+
+    /// Sets the optimizer's hyperparameter called `name`, which is one of those returned by
+    /// [`.hyperparameters()`](OptimizerStatus::hyperparameters()).
+    #[cfg(feature = "serialize")]
+    fn set_hyperparameter(&self, name: &str, value: f32) {
+        if name == "lr" {
+            self.set_lr(value)
+        }
+    }
+}
+
+/// Optimizer status that can be replicated for a group of parameters.
+pub trait GroupStatus: OptimizerStatus + Sized {
+    /// Returns a copy of this status with `overrides` applied.
+    ///
+    /// # Panics
+    ///
+    /// If an override isn't supported by the optimizer.
+    fn with_overrides(&self, overrides: &GroupOverrides) -> Self;
+}
+
+/// Hyperparameters of a group of parameters that differ from the ones of the optimizer.
+///
+/// For the optimizers regularizing through a [`Penalty`](crate::Penalty), the weight decay
+/// replaces the coefficient of the penalty, see [`Penalty::with_weight_decay`](crate::Penalty::with_weight_decay).
+///
+/// ```
+/// use neuronika_optim::{GroupOverrides, StochasticGD, L2};
+///
+/// let backbone = neuronika_variable::rand((3, 3)).requires_grad();
+/// let head = neuronika_variable::rand((3, 3)).requires_grad();
+///
+/// let optim = StochasticGD::new(1e-2, L2::new(1e-4), 0.9, None, false);
+/// optim.register(head);
+/// let group = optim.register_group(vec![backbone], GroupOverrides::new().lr(1e-4));
+///
+/// assert!((optim.group(group).get_lr() - 1e-4).abs() <= f32::EPSILON);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GroupOverrides {
+    lr: Option<f32>,
+    weight_decay: Option<f32>,
+    momentum: Option<f32>,
+}
+
+impl GroupOverrides {
+    /// Creates an empty set of overrides, the group will use the hyperparameters of the optimizer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the learning rate.
+    pub fn lr(mut self, lr: f32) -> Self {
+        self.lr = Some(lr);
+        self
+    }
+
+    /// Overrides the weight decay coefficient.
+    pub fn weight_decay(mut self, weight_decay: f32) -> Self {
+        self.weight_decay = Some(weight_decay);
+        self
+    }
+
+    /// Overrides the momentum factor.
+    pub fn momentum(mut self, momentum: f32) -> Self {
+        self.momentum = Some(momentum);
+        self
+    }
+
+    /// Returns the overridden learning rate, if any.
+    pub fn get_lr(&self) -> Option<f32> {
+        self.lr
+    }
+
+    /// Returns the overridden weight decay coefficient, if any.
+    pub fn get_weight_decay(&self) -> Option<f32> {
+        self.weight_decay
+    }
+
+    /// Returns the overridden momentum factor, if any.
+    pub fn get_momentum(&self) -> Option<f32> {
+        self.momentum
+    }
+
+    /// Panics if the momentum is overridden for an optimizer without it.
+    pub(crate) fn reject_momentum(&self, optimizer: &str) {
+        assert!(
+            self.momentum.is_none(),
+            "error: the {} optimizer has no momentum to override.",
+            optimizer
+        );
+    }
+}
+
+/// A group of parameters sharing the same status.
+struct Group<T> {
+    status: Rc<T>,
+    lr_scale: f32,
+    /// `None` if the momentum of the group is overridden while the one of the default group is
+    /// missing or zero, in which case it is left as it is.
+    momentum_scale: Option<f32>,
+}
+
+/// Shared handle to the learning rate and the momentum of an optimizer and of its parameter
+/// groups.
+///
+/// It is obtained with [`.handle()`](Optimizer::handle()) and is what learning rate schedulers
+/// hold, so that they don't borrow the optimizer they act on and can be stored next to it.
+pub struct OptimizerHandle<T>
+where
+    T: OptimizerStatus,
+{
+    status: Rc<T>,
+    groups: Rc<RefCell<Vec<Group<T>>>>,
+    initial_lr: Rc<Cell<Option<f32>>>,
+}
+
+impl<T> OptimizerHandle<T>
+where
+    T: OptimizerStatus,
+{
+    /// Returns the current learning rate.
+    pub fn get_lr(&self) -> f32 {
+        self.status.get_lr()
+    }
+
+    /// Sets a new value for the learning rate.
+    ///
+    /// The learning rates of the parameter groups are scaled accordingly, keeping the ratio with
+    /// the learning rate of the default group they had when created. This allows learning rate
+    /// schedulers to act on all the groups.
+    pub fn set_lr(&self, lr: f32) {
+        self.status.set_lr(lr);
+        self.groups
+            .borrow()
+            .iter()
+            .for_each(|group| group.status.set_lr(lr * group.lr_scale));
+    }
+
+    /// Returns the learning rate the optimizer had when the first learning rate scheduler was
+    /// attached to it. Schedulers that compute the learning rate in closed form start from this
+    /// value, so that creating one doesn't depend on the changes made by those created before.
+    pub(crate) fn initial_lr(&self) -> f32 {
+        match self.initial_lr.get() {
+            Some(lr) => lr,
+            None => {
+                let lr = self.get_lr();
+                self.initial_lr.set(Some(lr));
+                lr
+            }
+        }
+    }
+
+    /// Sets a new value for the momentum. Does nothing if the optimizer has no momentum.
+    ///
+    /// The momentums of the parameter groups are scaled accordingly, keeping the ratio with the
+    /// momentum of the default group they had when created.
+    ///
+    /// See [`OptimizerStatus::get_cycle_momentum()`] for the hyperparameter this refers to.
+    pub fn set_cycle_momentum(&self, momentum: f32) {
+        self.status.set_cycle_momentum(momentum);
+        self.groups.borrow().iter().for_each(|group| {
+            if let Some(momentum_scale) = group.momentum_scale {
+                group.status.set_cycle_momentum(momentum * momentum_scale)
+            }
+        });
+    }
+
+    /// Returns an immutable reference to the status of the default group.
+    pub fn status(&self) -> &T {
+        &self.status
+    }
+}
+
+impl<T> Clone for OptimizerHandle<T>
+where
+    T: OptimizerStatus,
+{
+    fn clone(&self) -> Self {
+        Self {
+            status: self.status.clone(),
+            groups: self.groups.clone(),
+            initial_lr: self.initial_lr.clone(),
+        }
+    }
 }
 
 /// Generic optimization algorithm template.
+///
+/// The variables registered with [`.register()`](Optimizer::register()) belong to the default
+/// group, whose hyperparameters are the ones of [`.status()`](Optimizer::status()). Additional
+/// groups with their own hyperparameters can be created with
+/// [`.register_group()`](Optimizer::register_group()).
 pub struct Optimizer<T>
 where
     T: OptimizerStatus,
 {
-    status: Rc<T>,
+    handle: OptimizerHandle<T>,
     params: RefCell<Vec<Box<dyn Optimize>>>,
 }
 
@@ -45,24 +282,47 @@ where
     /// Creates a new optimizer with the provided status.
     pub fn new(status: T) -> Self {
         let params = RefCell::default();
-        let status = Rc::new(status);
+        let handle = OptimizerHandle {
+            status: Rc::new(status),
+            groups: Rc::default(),
+            initial_lr: Rc::default(),
+        };
 
-        Self { status, params }
+        Self { handle, params }
+    }
+
+    /// Returns a shared handle to the learning rate and the momentum of this optimizer.
+    pub fn handle(&self) -> OptimizerHandle<T> {
+        self.handle.clone()
     }
 
     /// Returns the current learning rate.
     pub fn get_lr(&self) -> f32 {
-        self.status.get_lr()
+        self.handle.get_lr()
     }
 
     /// Sets a new value for the learning rate.
+    ///
+    /// The learning rates of the parameter groups are scaled accordingly, keeping the ratio with
+    /// the learning rate of the default group they had when created. This allows learning rate
+    /// schedulers to act on all the groups.
     pub fn set_lr(&self, lr: f32) {
-        self.status.set_lr(lr)
+        self.handle.set_lr(lr)
+    }
+
+    /// Sets a new value for the momentum. Does nothing if the optimizer has no momentum.
+    ///
+    /// The momentums of the parameter groups are scaled accordingly, keeping the ratio with the
+    /// momentum of the default group they had when created.
+    ///
+    /// See [`OptimizerStatus::get_cycle_momentum()`] for the hyperparameter this refers to.
+    pub fn set_cycle_momentum(&self, momentum: f32) {
+        self.handle.set_cycle_momentum(momentum)
     }
 
     /// Returns an immutable reference to the inner status.
     pub fn status(&self) -> &T {
-        &self.status
+        self.handle.status()
     }
 
     /// Registers the variable to this optimizer. Following calls to `.step()` will apply the
@@ -73,7 +333,46 @@ where
     {
         self.params
             .borrow_mut()
-            .push(Box::new(variable.into_param(self.status.clone())))
+            .push(Box::new(variable.into_param(self.handle.status.clone())))
+    }
+
+    /// Returns the number of parameter groups, the default one included.
+    pub fn groups(&self) -> usize {
+        self.handle.groups.borrow().len() + 1
+    }
+
+    /// Returns the status of the parameter group of index `group`. The default group has index
+    /// 0.
+    ///
+    /// # Panics
+    ///
+    /// If the group doesn't exist.
+    pub fn group(&self, group: usize) -> Rc<T> {
+        match group {
+            0 => self.handle.status.clone(),
+            _ => self
+                .handle
+                .groups
+                .borrow()
+                .get(group - 1)
+                .map(|group| group.status.clone())
+                .unwrap_or_else(|| panic!("error: parameter group {} does not exist.", group)),
+        }
+    }
+
+    /// Registers the variable to the parameter group of index `group`.
+    ///
+    /// # Panics
+    ///
+    /// If the group doesn't exist.
+    pub fn register_in_group<U>(&self, group: usize, variable: U)
+    where
+        U: IntoParam<T>,
+    {
+        let status = self.group(group);
+        self.params
+            .borrow_mut()
+            .push(Box::new(variable.into_param(status)))
     }
 
     /// Performs a single optimization step. It applies the provided learning rule to all the
@@ -92,6 +391,344 @@ where
             .iter_mut()
             .for_each(|param| param.zero_grad());
     }
+
+    /// Clips the gradients of all the parameters registered in this optimizer so that their
+    /// global norm doesn't exceed `max_norm`. The norm is computed over all the gradients
+    /// together, as if they were concatenated into a single vector.
+    ///
+    /// Returns the total norm of the gradients before clipping.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_norm` - maximum norm of the gradients.
+    ///
+    /// * `norm_type` - type of the p-norm used. Can be `f32::INFINITY` for the infinity norm.
+    ///
+    /// # Panics
+    ///
+    /// If `norm_type` is not positive.
+    pub fn clip_grad_norm(&self, max_norm: f32, norm_type: f32) -> f32 {
+        assert!(
+            norm_type > 0.0,
+            "error: norm type must be positive, found {}.",
+            norm_type
+        );
+
+        let mut params = self.params.borrow_mut();
+        let total_norm = if norm_type.is_infinite() {
+            let mut total_norm = 0.0_f32;
+            params.iter_mut().for_each(|param| {
+                param.for_each_grad(&mut |grad_el| total_norm = total_norm.max(grad_el.abs()))
+            });
+            total_norm
+        } else {
+            let mut total_norm = 0.0_f32;
+            params.iter_mut().for_each(|param| {
+                param.for_each_grad(&mut |grad_el| total_norm += grad_el.abs().powf(norm_type))
+            });
+            total_norm.powf(1.0 / norm_type)
+        };
+
+        let clip_coefficient = max_norm / (total_norm + 1e-6);
+        if clip_coefficient < 1.0 {
+            params
+                .iter_mut()
+                .for_each(|param| param.for_each_grad(&mut |grad_el| *grad_el *= clip_coefficient));
+        }
+
+        total_norm
+    }
+
+    /// Clips the gradients of all the parameters registered in this optimizer element-wise to
+    /// the range `[-clip_value, clip_value]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `clip_value` - maximum absolute value of the gradients' elements.
+    ///
+    /// # Panics
+    ///
+    /// If `clip_value` is negative.
+    pub fn clip_grad_value(&self, clip_value: f32) {
+        assert!(
+            clip_value >= 0.0,
+            "error: clip value must be non-negative, found {}.",
+            clip_value
+        );
+
+        self.params.borrow_mut().iter_mut().for_each(|param| {
+            param.for_each_grad(&mut |grad_el| *grad_el = grad_el.clamp(-clip_value, clip_value))
+        });
+    }
+}
+
+impl<T> Optimizer<T>
+where
+    T: GroupStatus,
+{
+    /// Creates a new parameter group whose hyperparameters are the ones of the default group
+    /// with `overrides` applied, registers `variables` to it and returns its index.
+    ///
+    /// Further variables, possibly of different dimensionality, can be added to the group with
+    /// [`.register_in_group()`](Optimizer::register_in_group()).
+    ///
+    /// # Panics
+    ///
+    /// If an override isn't supported by the optimizer, or if the learning rate is overridden
+    /// while the one of the optimizer is zero, as the ratio between the two, which the group
+    /// keeps when the learning rate changes, would be undefined.
+    pub fn register_group<I, U>(&self, variables: I, overrides: GroupOverrides) -> usize
+    where
+        I: IntoIterator<Item = U>,
+        U: IntoParam<T>,
+    {
+        let status = Rc::new(self.handle.status.with_overrides(&overrides));
+        let lr_scale = match overrides.get_lr() {
+            Some(group_lr) => {
+                let lr = self.handle.status.get_lr();
+                assert!(
+                    lr != 0.0,
+                    "error: cannot override the learning rate of a group when the one of the optimizer is zero."
+                );
+                group_lr / lr
+            }
+            None => 1.0,
+        };
+        let momentum_scale = match overrides.get_momentum() {
+            Some(group_momentum) => self
+                .handle
+                .status
+                .get_cycle_momentum()
+                .filter(|momentum| *momentum != 0.0)
+                .map(|momentum| group_momentum / momentum),
+            None => Some(1.0),
+        };
+
+        self.handle.groups.borrow_mut().push(Group {
+            status: status.clone(),
+            lr_scale,
+            momentum_scale,
+        });
+        self.params.borrow_mut().extend(
+            variables
+                .into_iter()
+                .map(|variable| Box::new(variable.into_param(status.clone())) as Box<dyn Optimize>),
+        );
+
+        self.groups() - 1
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<T> Optimizer<T>
+where
+    T: OptimizerStatus,
+{
+    /// Returns the state of the optimizer, which can be stored in a
+    /// [`ModelCheckpoint`](neuronika_variable::ModelCheckpoint) to resume the training later.
+    ///
+    /// The hyperparameters of the parameter group of index `g` are stored under
+    /// `groups.{g}.{hyperparameter}`, while the buffers of the parameter registered as the
+    /// `i`-th are stored under `params.{i}.{buffer}`. The penalty isn't part of the state.
+    pub fn state_dict(&self) -> StateDict {
+        let mut state = StateDict::new();
+
+        let groups = self.handle.groups.borrow();
+        let statuses = std::iter::once((self.handle.status.as_ref(), None)).chain(
+            groups
+                .iter()
+                .map(|group| (group.status.as_ref(), Some(group))),
+        );
+        for (index, (status, group)) in statuses.enumerate() {
+            for (name, value) in status.hyperparameters() {
+                state.insert(format!("groups.{}.{}", index, name), scalar(value));
+            }
+            if let Some(group) = group {
+                state.insert(format!("groups.{}.lr_scale", index), scalar(group.lr_scale));
+                state.insert(
+                    format!("groups.{}.momentum_scale", index),
+                    scalar(from_option(group.momentum_scale)),
+                );
+            }
+        }
+
+        for (index, param) in self.params.borrow().iter().enumerate() {
+            for (name, buffer) in param.state() {
+                state.insert(format!("params.{}.{}", index, name), buffer);
+            }
+        }
+
+        state
+    }
+
+    /// Restores the state of the optimizer from `state`.
+    ///
+    /// The optimizer must have the same parameter groups and the same parameters, registered in
+    /// the same order, as the one whose state was saved. Nothing is modified if an error is
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// [`CheckpointError::KeyMismatch`] if the names in `state` don't match exactly the ones of
+    /// the optimizer, [`CheckpointError::ShapeMismatch`] if a saved buffer doesn't have the
+    /// shape of the corresponding one and [`CheckpointError::InvalidFormat`] if a step counter
+    /// isn't a valid one.
+    pub fn load_state_dict(&self, state: &StateDict) -> Result<(), CheckpointError> {
+        check_state(&self.state_dict(), state)?;
+
+        let hyperparameter =
+            |group: usize, name: &str| to_scalar(&state[&format!("groups.{}.{}", group, name)]);
+        for (name, _) in self.handle.status.hyperparameters() {
+            self.handle
+                .status
+                .set_hyperparameter(name, hyperparameter(0, name));
+        }
+        for (index, group) in self.handle.groups.borrow_mut().iter_mut().enumerate() {
+            for (name, _) in group.status.hyperparameters() {
+                group
+                    .status
+                    .set_hyperparameter(name, hyperparameter(index + 1, name));
+            }
+            group.lr_scale = hyperparameter(index + 1, "lr_scale");
+            group.momentum_scale = to_option(hyperparameter(index + 1, "momentum_scale"));
+        }
+
+        for (index, param) in self.params.borrow_mut().iter_mut().enumerate() {
+            let prefix = format!("params.{}.", index);
+            let param_state = state
+                .iter()
+                .filter_map(|(name, array)| {
+                    name.strip_prefix(&prefix)
+                        .map(|name| (name.to_string(), array.clone()))
+                })
+                .collect();
+            param.load_state(&param_state);
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that `state` has exactly the names of `current`, that each of its arrays has the
+/// shape of the corresponding one and that its counters are valid.
+#[cfg(feature = "serialize")]
+pub(crate) fn check_state(current: &StateDict, state: &StateDict) -> Result<(), CheckpointError> {
+    let missing: Vec<String> = current
+        .keys()
+        .filter(|name| !state.contains_key(*name))
+        .cloned()
+        .collect();
+    let unexpected: Vec<String> = state
+        .keys()
+        .filter(|name| !current.contains_key(*name))
+        .cloned()
+        .collect();
+    if !missing.is_empty() || !unexpected.is_empty() {
+        return Err(CheckpointError::KeyMismatch {
+            missing,
+            unexpected,
+        });
+    }
+
+    for (name, array) in current {
+        let found = state[name].shape();
+        if found != array.shape() {
+            return Err(CheckpointError::ShapeMismatch {
+                name: name.clone(),
+                expected: array.shape().to_vec(),
+                found: found.to_vec(),
+            });
+        }
+    }
+
+    for (name, array) in state {
+        let entry = name.rsplit('.').next().unwrap_or(name);
+        if COUNTERS.contains(&entry) && !is_counter(array) {
+            return Err(CheckpointError::InvalidFormat(format!(
+                "{} is not a valid counter",
+                name
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Stores a scalar as a zero-dimensional array.
+#[cfg(feature = "serialize")]
+pub(crate) fn scalar(value: f32) -> ArrayD<f32> {
+    ArrayD::from_elem(IxDyn(&[]), value)
+}
+
+/// Reads a scalar stored as a zero-dimensional array.
+#[cfg(feature = "serialize")]
+pub(crate) fn to_scalar(array: &ArrayD<f32>) -> f32 {
+    array.first().copied().unwrap_or_default()
+}
+
+/// Stores a counter as a one-dimensional array of its 16-bit digits, least significant first.
+/// Unlike a single float, which is exact only up to 2^24, each digit is represented exactly.
+#[cfg(feature = "serialize")]
+pub(crate) fn counter(value: usize) -> ArrayD<f32> {
+    let value = value as u64;
+    ArrayD::from_shape_fn(IxDyn(&[COUNTER_DIGITS]), |index| {
+        ((value >> (16 * index[0])) & 0xFFFF) as f32
+    })
+}
+
+/// Reads a counter stored by [`counter()`], which must have been validated by [`check_state()`].
+#[cfg(feature = "serialize")]
+pub(crate) fn to_counter(array: &ArrayD<f32>) -> usize {
+    array
+        .iter()
+        .enumerate()
+        .fold(0_u64, |value, (index, digit)| {
+            value | (*digit as u64) << (16 * index)
+        }) as usize
+}
+
+/// Checks that `array` holds [`COUNTER_DIGITS`] whole numbers, each of them fitting in 16 bits.
+#[cfg(feature = "serialize")]
+fn is_counter(array: &ArrayD<f32>) -> bool {
+    array.len() == COUNTER_DIGITS
+        && array
+            .iter()
+            .all(|digit| digit.fract() == 0.0 && (0.0..65536.0).contains(digit))
+}
+
+/// Number of 16-bit digits of a stored counter.
+#[cfg(feature = "serialize")]
+const COUNTER_DIGITS: usize = 4;
+
+/// Names of the entries of the optimizers' and schedulers' states that are stored with
+/// [`counter()`].
+#[cfg(feature = "serialize")]
+const COUNTERS: [&str; 4] = [
+    "step",
+    "current_epoch",
+    "num_bad_epochs",
+    "cooldown_counter",
+];
+
+/// Stores an optional hyperparameter, [`None`] becomes NaN.
+#[cfg(feature = "serialize")]
+pub(crate) fn from_option(value: Option<f32>) -> f32 {
+    value.unwrap_or(f32::NAN)
+}
+
+/// Reads an optional hyperparameter, NaN becomes [`None`].
+#[cfg(feature = "serialize")]
+pub(crate) fn to_option(value: f32) -> Option<f32> {
+    (!value.is_nan()).then_some(value)
+}
+
+/// Copies a saved buffer into `buffer`, which has the same shape.
+#[cfg(feature = "serialize")]
+pub(crate) fn assign<D>(buffer: &mut Array<f32, D>, state: &ArrayD<f32>)
+where
+    D: Dimension,
+{
+    buffer.assign(&state.view().into_dimensionality::<D>().unwrap());
 }
 
 impl<T> Default for Optimizer<T>
@@ -1,9 +1,42 @@
+use ndarray::Dimension;
+
+use neuronika_variable::VarDiff;
+
 /// Penalty trait, defines the penalty regularization's logic.
 pub trait Penalty: Copy + Send + Sync {
     /// Applies the penalty to an element of the gradient.
     fn penalize(&self, w: &f32) -> f32;
+
+    /// Returns a copy of the penalty whose weight decay coefficient is `weight_decay`. This is
+    /// used by the parameter groups overriding the weight decay.
+    ///
+    /// # Panics
+    ///
+    /// The default implementation panics, as the penalty has no coefficient that can be replaced.
+    fn with_weight_decay(&self, _weight_decay: f32) -> Self {
+        panic!("error: the penalty has no weight decay coefficient to override.")
+    }
 }
 
+/// Excludes a parameter from the decoupled weight decay of the optimizers that support it, such as
+/// [`AdamW`](super::AdamW).
+///
+/// Biases and the parameters of normalization layers are commonly registered this way.
+///
+/// ```
+/// use neuronika_optim::{AdamW, NoDecay};
+///
+/// let weight = neuronika_variable::rand((3, 3)).requires_grad();
+/// let bias = neuronika_variable::zeros(3).requires_grad();
+///
+/// let optim = AdamW::new(1e-3, 0.9, 0.999, 1e-2, 1e-8);
+/// optim.register(weight);
+/// optim.register(NoDecay(bias));
+/// ```
+pub struct NoDecay<D>(pub VarDiff<D>)
+where
+    D: Dimension;
+
 /// L2 penalty, also known as *weight decay* or *Tichonov regularization*.
 #[derive(Copy, Clone)]
 pub struct L2 {
@@ -64,16 +97,29 @@ impl Penalty for L2 {
     fn penalize(&self, w: &f32) -> f32 {
         2. * self.lambda * w
     }
+
+    fn with_weight_decay(&self, weight_decay: f32) -> Self {
+        Self::new(weight_decay)
+    }
 }
 
 impl Penalty for L1 {
     fn penalize(&self, w: &f32) -> f32 {
         self.lambda * w.signum()
     }
+
+    fn with_weight_decay(&self, weight_decay: f32) -> Self {
+        Self::new(weight_decay)
+    }
 }
 
 impl Penalty for ElasticNet {
     fn penalize(&self, w: &f32) -> f32 {
         self.lambda_l1 * w.signum() + 2. * self.lambda_l2 * w
     }
+
+    /// The weight decay replaces the L2 coefficient, the L1 one is kept.
+    fn with_weight_decay(&self, weight_decay: f32) -> Self {
+        Self::new(self.lambda_l1, weight_decay)
+    }
 }
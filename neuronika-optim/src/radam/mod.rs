@@ -0,0 +1,302 @@
+use std::{cell::Cell, rc::Rc};
+
+use ndarray::{Array, Dimension, Zip};
+
+use neuronika_variable::VarDiff;
+
+use super::{
+    GroupOverrides, GroupStatus, IntoParam, Optimize, Optimizer, OptimizerStatus, Penalty,
+};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::StateDict;
+
+#[cfg(feature = "serialize")]
+use super::{assign, counter, to_counter};
+
+/// RAdam optimizer.
+///
+/// It is a variant of the *Adam* algorithm rectifying the variance of the adaptive learning rate,
+/// proposed in [On the Variance of the Adaptive Learning Rate and Beyond](https://arxiv.org/abs/1908.03265).
+///
+/// During the first steps, while the variance is intractable, the parameters are updated with
+/// the bias corrected momentum only.
+#[derive(Clone)]
+pub struct RAdam<T>
+where
+    T: Penalty,
+{
+    lr: Cell<f32>,
+    penalty: T,
+    beta1: Cell<f32>,
+    beta2: Cell<f32>,
+    eps: Cell<f32>,
+}
+
+impl<T> OptimizerStatus for RAdam<T>
+where
+    T: Penalty,
+{
+    fn get_lr(&self) -> f32 {
+        self.lr.get()
+    }
+
+    fn set_lr(&self, lr: f32) {
+        self.lr.set(lr)
+    }
+
+    fn get_cycle_momentum(&self) -> Option<f32> {
+        Some(self.beta1.get())
+    }
+
+    fn set_cycle_momentum(&self, momentum: f32) {
+        self.beta1.set(momentum)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn hyperparameters(&self) -> Vec<(&'static str, f32)> {
+        vec![
+            ("lr", self.lr.get()),
+            ("beta1", self.beta1.get()),
+            ("beta2", self.beta2.get()),
+            ("eps", self.eps.get()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn set_hyperparameter(&self, name: &str, value: f32) {
+        match name {
+            "lr" => self.lr.set(value),
+            "beta1" => self.beta1.set(value),
+            "beta2" => self.beta2.set(value),
+            "eps" => self.eps.set(value),
+            _ => (),
+        }
+    }
+}
+
+impl<T> GroupStatus for RAdam<T>
+where
+    T: Penalty,
+{
+    fn with_overrides(&self, overrides: &GroupOverrides) -> Self {
+        overrides.reject_momentum("RAdam");
+
+        let mut status = self.clone();
+        if let Some(lr) = overrides.get_lr() {
+            status.lr.set(lr);
+        }
+        if let Some(weight_decay) = overrides.get_weight_decay() {
+            status.penalty = status.penalty.with_weight_decay(weight_decay);
+        }
+
+        status
+    }
+}
+
+impl<T> RAdam<T>
+where
+    T: Penalty,
+{
+    /// Creates a new RAdam optimizer.
+    ///
+    /// # Arguments
+    ///
+    /// * `lr` - learning rate.
+    ///
+    /// * `beta1` - coefficient for computing running average of the gradient. A good default is 0.9.
+    ///
+    /// * `beta2` - coefficient for computing running average of the squared gradient. A good
+    ///   default is 0.999.
+    ///
+    /// * `penalty` - penalty regularization.
+    ///
+    /// * `eps` - small constant for numerical stability. A good default value is *1e-8*.
+    pub fn new(lr: f32, beta1: f32, beta2: f32, penalty: T, eps: f32) -> Optimizer<Self> {
+        let lr = Cell::new(lr);
+        let beta1 = Cell::new(beta1);
+        let beta2 = Cell::new(beta2);
+        let eps = Cell::new(eps);
+
+        let status = Self {
+            lr,
+            penalty,
+            beta1,
+            beta2,
+            eps,
+        };
+
+        Optimizer::new(status)
+    }
+
+    /// Return the current learning rate.
+    pub fn get_lr(&self) -> f32 {
+        OptimizerStatus::get_lr(self)
+    }
+
+    /// Sets a new value for the learning rate.
+    pub fn set_lr(&self, lr: f32) {
+        OptimizerStatus::set_lr(self, lr);
+    }
+
+    /// Return the current values for the first exponential decay rate.
+    pub fn get_beta1(&self) -> f32 {
+        self.beta1.get()
+    }
+
+    /// Return the current values for the second exponential decay rate.
+    pub fn get_beta2(&self) -> f32 {
+        self.beta2.get()
+    }
+
+    /// Sets a new value for the first exponential decay rate.
+    pub fn set_beta1(&self, beta1: f32) {
+        self.beta1.set(beta1)
+    }
+
+    /// Sets a new value for the second exponential decay rate.
+    pub fn set_beta2(&self, beta2: f32) {
+        self.beta2.set(beta2)
+    }
+
+    /// Return the current epsilon constant.
+    pub fn get_eps(&self) -> f32 {
+        self.eps.get()
+    }
+
+    /// Sets a new value for the epsilon constant.
+    pub fn set_eps(&self, eps: f32) {
+        self.eps.set(eps)
+    }
+}
+
+/// A parameter used by the RAdam optimizer.
+pub struct RAdamParam<D, T>
+where
+    D: Dimension,
+    T: Penalty,
+{
+    variable: VarDiff<D>,
+    step: usize,
+    exp_avg: Array<f32, D>,
+    exp_avg_sq: Array<f32, D>,
+    status: Rc<RAdam<T>>,
+}
+
+impl<D, T> IntoParam<RAdam<T>> for VarDiff<D>
+where
+    D: 'static + Dimension,
+    T: 'static + Penalty,
+{
+    type Param = RAdamParam<D, T>;
+
+    fn into_param(self, status: Rc<RAdam<T>>) -> Self::Param {
+        let variable = self;
+        let step = 0;
+        let exp_avg = Array::zeros(variable.data().raw_dim());
+        let exp_avg_sq = exp_avg.clone();
+
+        Self::Param {
+            variable,
+            step,
+            exp_avg,
+            exp_avg_sq,
+            status,
+        }
+    }
+}
+
+impl<D, T> Optimize for RAdamParam<D, T>
+where
+    D: Dimension,
+    T: Penalty,
+{
+    fn optimize(&mut self) {
+        self.step += 1;
+
+        let lr = self.status.get_lr();
+        let beta1 = self.status.get_beta1();
+        let beta2 = self.status.get_beta2();
+        let penalty = self.status.penalty;
+        let eps = self.status.get_eps();
+
+        let step = self.step as i32;
+        let bias_correction1 = 1.0 - beta1.powi(step);
+        let bias_correction2 = 1.0 - beta2.powi(step);
+
+        // Maximum and current length of the approximated simple moving average, computed in
+        // double precision as their difference is affected by cancellation.
+        let beta2_f64 = beta2 as f64;
+        let rho_inf = 2.0 / (1.0 - beta2_f64) - 1.0;
+        let rho = rho_inf - 2.0 * step as f64 * beta2_f64.powi(step) / (1.0 - beta2_f64.powi(step));
+
+        let mut data = self.variable.data_mut();
+        let mut grad = self.variable.grad_mut();
+
+        Zip::from(&mut *grad)
+            .and(&*data)
+            .for_each(|grad_el, data_el| *grad_el += penalty.penalize(data_el));
+
+        Zip::from(&mut self.exp_avg)
+            .and(&*grad)
+            .for_each(|exp_avg_el, grad_el| {
+                *exp_avg_el = *exp_avg_el * beta1 + grad_el * (1.0 - beta1)
+            });
+
+        Zip::from(&mut self.exp_avg_sq)
+            .and(&*grad)
+            .for_each(|exp_avg_sq_el, grad_el| {
+                *exp_avg_sq_el = *exp_avg_sq_el * beta2 + grad_el * grad_el * (1.0 - beta2)
+            });
+
+        let step_size = lr / bias_correction1;
+        if rho > 5.0 {
+            let rect = ((rho - 4.0) * (rho - 2.0) * rho_inf
+                / ((rho_inf - 4.0) * (rho_inf - 2.0) * rho))
+                .sqrt() as f32;
+
+            Zip::from(&mut *data)
+                .and(&self.exp_avg)
+                .and(&self.exp_avg_sq)
+                .for_each(|data_el, exp_avg_el, exp_avg_sq_el| {
+                    *data_el -= exp_avg_el * bias_correction2.sqrt() / (exp_avg_sq_el.sqrt() + eps)
+                        * step_size
+                        * rect
+                });
+        } else {
+            Zip::from(&mut *data)
+                .and(&self.exp_avg)
+                .for_each(|data_el, exp_avg_el| *data_el -= exp_avg_el * step_size);
+        }
+    }
+
+    fn zero_grad(&mut self) {
+        self.variable.zero_grad()
+    }
+
+    fn for_each_grad(&mut self, f: &mut dyn FnMut(&mut f32)) {
+        self.variable.grad_mut().iter_mut().for_each(f)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(&'static str, ArrayD<f32>)> {
+        vec![
+            ("step", counter(self.step)),
+            ("exp_avg", self.exp_avg.clone().into_dyn()),
+            ("exp_avg_sq", self.exp_avg_sq.clone().into_dyn()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&mut self, state: &StateDict) {
+        self.step = to_counter(&state["step"]);
+        assign(&mut self.exp_avg, &state["exp_avg"]);
+        assign(&mut self.exp_avg_sq, &state["exp_avg_sq"]);
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -4,7 +4,18 @@ use neuronika_variable::VarDiff;
 
 use ndarray::{Array, Dimension, Zip};
 
-use super::{IntoParam, Optimize, Optimizer, OptimizerStatus, Penalty};
+use super::{
+    GroupOverrides, GroupStatus, IntoParam, Optimize, Optimizer, OptimizerStatus, Penalty,
+};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::StateDict;
+
+#[cfg(feature = "serialize")]
+use super::{assign, from_option, to_option};
 
 /// RMSProp optimizer.
 ///
@@ -18,6 +29,7 @@ use super::{IntoParam, Optimize, Optimizer, OptimizerStatus, Penalty};
 /// epsilon. Do note that TensorFlow interchanges these two operations. The effective
 /// learning rate is thus *lr' / (v.sqrt() + eps)* where *lr'* is the scheduled
 /// learning rate and *v* is the weighted moving average of the square gradient.
+#[derive(Clone)]
 #[allow(clippy::upper_case_acronyms)]
 pub struct RMSProp<T>
 where
@@ -42,6 +54,57 @@ where
     fn set_lr(&self, lr: f32) {
         self.lr.set(lr)
     }
+
+    fn get_cycle_momentum(&self) -> Option<f32> {
+        self.momentum.get()
+    }
+
+    fn set_cycle_momentum(&self, momentum: f32) {
+        self.momentum.set(Some(momentum))
+    }
+
+    #[cfg(feature = "serialize")]
+    fn hyperparameters(&self) -> Vec<(&'static str, f32)> {
+        vec![
+            ("lr", self.lr.get()),
+            ("alpha", from_option(self.alpha.get())),
+            ("momentum", from_option(self.momentum.get())),
+            ("centered", f32::from(u8::from(self.centered.get()))),
+            ("eps", self.eps.get()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn set_hyperparameter(&self, name: &str, value: f32) {
+        match name {
+            "lr" => self.lr.set(value),
+            "alpha" => self.alpha.set(to_option(value)),
+            "momentum" => self.momentum.set(to_option(value)),
+            "centered" => self.centered.set(value != 0.0),
+            "eps" => self.eps.set(value),
+            _ => (),
+        }
+    }
+}
+
+impl<T> GroupStatus for RMSProp<T>
+where
+    T: Penalty,
+{
+    fn with_overrides(&self, overrides: &GroupOverrides) -> Self {
+        let mut status = self.clone();
+        if let Some(lr) = overrides.get_lr() {
+            status.lr.set(lr);
+        }
+        if let Some(weight_decay) = overrides.get_weight_decay() {
+            status.penalty = status.penalty.with_weight_decay(weight_decay);
+        }
+        if let Some(momentum) = overrides.get_momentum() {
+            status.momentum.set(Some(momentum));
+        }
+
+        status
+    }
 }
 
 impl<T> RMSProp<T>
@@ -299,6 +362,34 @@ where
     fn zero_grad(&mut self) {
         self.variable.zero_grad()
     }
+
+    fn for_each_grad(&mut self, f: &mut dyn FnMut(&mut f32)) {
+        self.variable.grad_mut().iter_mut().for_each(f)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(&'static str, ArrayD<f32>)> {
+        let mut state = vec![("square_avg", self.square_avg.clone().into_dyn())];
+        if let Some(buffer) = &self.buffer {
+            state.push(("momentum_buffer", buffer.clone().into_dyn()));
+        }
+        if let Some(grad_avg) = &self.grad_avg {
+            state.push(("grad_avg", grad_avg.clone().into_dyn()));
+        }
+
+        state
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&mut self, state: &StateDict) {
+        assign(&mut self.square_avg, &state["square_avg"]);
+        if let Some(buffer) = &mut self.buffer {
+            assign(buffer, &state["momentum_buffer"]);
+        }
+        if let Some(grad_avg) = &mut self.grad_avg {
+            assign(grad_avg, &state["grad_avg"]);
+        }
+    }
 }
 
 #[cfg(test)]
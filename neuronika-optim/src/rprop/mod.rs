@@ -0,0 +1,276 @@
+use std::{cell::Cell, rc::Rc};
+
+use ndarray::{Array, Dimension, Zip};
+
+use neuronika_variable::VarDiff;
+
+use super::{
+    GroupOverrides, GroupStatus, IntoParam, Optimize, Optimizer, OptimizerStatus, Penalty,
+};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::StateDict;
+
+#[cfg(feature = "serialize")]
+use super::assign;
+
+/// Resilient backpropagation optimizer.
+///
+/// It adapts a step size for each element of the parameters by looking only at the sign of the
+/// gradient, as described in
+/// [A Direct Adaptive Method for Faster Backpropagation Learning: The RPROP Algorithm](https://doi.org/10.1109/ICNN.1993.298623).
+///
+/// The learning rate is used as the initial step size of the parameters registered afterwards.
+#[derive(Clone)]
+pub struct Rprop<T>
+where
+    T: Penalty,
+{
+    lr: Cell<f32>,
+    penalty: T,
+    etas: Cell<(f32, f32)>,
+    step_sizes: Cell<(f32, f32)>,
+}
+
+impl<T> OptimizerStatus for Rprop<T>
+where
+    T: Penalty,
+{
+    fn get_lr(&self) -> f32 {
+        self.lr.get()
+    }
+
+    fn set_lr(&self, lr: f32) {
+        self.lr.set(lr)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn hyperparameters(&self) -> Vec<(&'static str, f32)> {
+        vec![
+            ("lr", self.lr.get()),
+            ("eta_minus", self.etas.get().0),
+            ("eta_plus", self.etas.get().1),
+            ("step_size_min", self.step_sizes.get().0),
+            ("step_size_max", self.step_sizes.get().1),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn set_hyperparameter(&self, name: &str, value: f32) {
+        match name {
+            "lr" => self.lr.set(value),
+            "eta_minus" => self.etas.set((value, self.etas.get().1)),
+            "eta_plus" => self.etas.set((self.etas.get().0, value)),
+            "step_size_min" => self.step_sizes.set((value, self.step_sizes.get().1)),
+            "step_size_max" => self.step_sizes.set((self.step_sizes.get().0, value)),
+            _ => (),
+        }
+    }
+}
+
+impl<T> GroupStatus for Rprop<T>
+where
+    T: Penalty,
+{
+    fn with_overrides(&self, overrides: &GroupOverrides) -> Self {
+        overrides.reject_momentum("Rprop");
+
+        let mut status = self.clone();
+        if let Some(lr) = overrides.get_lr() {
+            status.lr.set(lr);
+        }
+        if let Some(weight_decay) = overrides.get_weight_decay() {
+            status.penalty = status.penalty.with_weight_decay(weight_decay);
+        }
+
+        status
+    }
+}
+
+impl<T> Rprop<T>
+where
+    T: Penalty,
+{
+    /// Creates a new Rprop optimizer.
+    ///
+    /// # Arguments
+    ///
+    /// * `lr` - initial step size. A good default is 1e-2.
+    ///
+    /// * `etas` - pair of multiplicative decrease and increase factors of the step sizes. A good
+    ///   default is *(0.5, 1.2)*.
+    ///
+    /// * `step_sizes` - pair of minimal and maximal allowed step sizes. A good default is
+    ///   *(1e-6, 50.0)*.
+    ///
+    /// * `penalty` - penalty regularization.
+    ///
+    /// # Panics
+    ///
+    /// If the decrease factor doesn't lie in *(0.0, 1.0)* or if the increase factor isn't greater
+    /// than 1.0.
+    pub fn new(lr: f32, etas: (f32, f32), step_sizes: (f32, f32), penalty: T) -> Optimizer<Self> {
+        assert!(
+            0.0 < etas.0 && etas.0 < 1.0 && 1.0 < etas.1,
+            "Etas should satisfy 0.0 < eta_minus < 1.0 < eta_plus, got: {etas:?}"
+        );
+
+        let lr = Cell::new(lr);
+        let etas = Cell::new(etas);
+        let step_sizes = Cell::new(step_sizes);
+
+        let status = Self {
+            lr,
+            penalty,
+            etas,
+            step_sizes,
+        };
+
+        Optimizer::new(status)
+    }
+
+    /// Return the current learning rate.
+    pub fn get_lr(&self) -> f32 {
+        OptimizerStatus::get_lr(self)
+    }
+
+    /// Sets a new value for the learning rate.
+    pub fn set_lr(&self, lr: f32) {
+        OptimizerStatus::set_lr(self, lr);
+    }
+
+    /// Return the current decrease and increase factors.
+    pub fn get_etas(&self) -> (f32, f32) {
+        self.etas.get()
+    }
+
+    /// Sets new values for the decrease and increase factors.
+    pub fn set_etas(&self, etas: (f32, f32)) {
+        self.etas.set(etas)
+    }
+
+    /// Return the current minimal and maximal step sizes.
+    pub fn get_step_sizes(&self) -> (f32, f32) {
+        self.step_sizes.get()
+    }
+
+    /// Sets new values for the minimal and maximal step sizes.
+    pub fn set_step_sizes(&self, step_sizes: (f32, f32)) {
+        self.step_sizes.set(step_sizes)
+    }
+}
+
+/// A parameter used by the Rprop optimizer.
+pub struct RpropParam<D, T>
+where
+    D: Dimension,
+    T: Penalty,
+{
+    variable: VarDiff<D>,
+    prev_grad: Array<f32, D>,
+    step_size: Array<f32, D>,
+    status: Rc<Rprop<T>>,
+}
+
+impl<D, T> IntoParam<Rprop<T>> for VarDiff<D>
+where
+    D: 'static + Dimension,
+    T: 'static + Penalty,
+{
+    type Param = RpropParam<D, T>;
+
+    fn into_param(self, status: Rc<Rprop<T>>) -> Self::Param {
+        let variable = self;
+        let prev_grad = Array::zeros(variable.data().raw_dim());
+        let step_size = Array::from_elem(variable.data().raw_dim(), status.get_lr());
+
+        Self::Param {
+            variable,
+            prev_grad,
+            step_size,
+            status,
+        }
+    }
+}
+
+impl<D, T> Optimize for RpropParam<D, T>
+where
+    D: Dimension,
+    T: Penalty,
+{
+    fn optimize(&mut self) {
+        let (eta_minus, eta_plus) = self.status.get_etas();
+        let (step_size_min, step_size_max) = self.status.get_step_sizes();
+        let penalty = self.status.penalty;
+
+        let mut data = self.variable.data_mut();
+        let mut grad = self.variable.grad_mut();
+
+        Zip::from(&mut *grad)
+            .and(&*data)
+            .for_each(|grad_el, data_el| *grad_el += penalty.penalize(data_el));
+
+        Zip::from(&mut *data)
+            .and(&*grad)
+            .and(&mut self.prev_grad)
+            .and(&mut self.step_size)
+            .for_each(|data_el, grad_el, prev_grad_el, step_size_el| {
+                let product = grad_el * *prev_grad_el;
+                let mut grad_el = *grad_el;
+
+                let factor = if product > 0.0 {
+                    eta_plus
+                } else if product < 0.0 {
+                    // The sign has changed, the step is skipped and won't be considered in the
+                    // next comparison.
+                    grad_el = 0.0;
+                    eta_minus
+                } else {
+                    1.0
+                };
+                *step_size_el = (*step_size_el * factor)
+                    .max(step_size_min)
+                    .min(step_size_max);
+
+                *data_el -= sign(grad_el) * *step_size_el;
+                *prev_grad_el = grad_el;
+            });
+    }
+
+    fn zero_grad(&mut self) {
+        self.variable.zero_grad()
+    }
+
+    fn for_each_grad(&mut self, f: &mut dyn FnMut(&mut f32)) {
+        self.variable.grad_mut().iter_mut().for_each(f)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(&'static str, ArrayD<f32>)> {
+        vec![
+            ("prev_grad", self.prev_grad.clone().into_dyn()),
+            ("step_size", self.step_size.clone().into_dyn()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&mut self, state: &StateDict) {
+        assign(&mut self.prev_grad, &state["prev_grad"]);
+        assign(&mut self.step_size, &state["step_size"]);
+    }
+}
+
+/// Sign function mapping zero to zero.
+fn sign(x: f32) -> f32 {
+    if x == 0.0 {
+        0.0
+    } else {
+        x.signum()
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,91 @@
+use super::{super::L2, Rprop};
+
+#[test]
+fn creation() {
+    let optim = Rprop::new(1e-2, (0.5, 1.2), (1e-6, 50.0), L2::new(1e-2));
+
+    assert!((optim.get_lr() - 1e-2).abs() <= f32::EPSILON);
+    assert_eq!(optim.status().get_etas(), (0.5, 1.2));
+    assert_eq!(optim.status().get_step_sizes(), (1e-6, 50.0));
+}
+
+#[test]
+fn set_lr() {
+    let optim = Rprop::new(1e-2, (0.5, 1.2), (1e-6, 50.0), L2::new(1e-2));
+
+    optim.set_lr(1e-3);
+    assert!((optim.get_lr() - 1e-3).abs() <= f32::EPSILON);
+}
+
+#[test]
+fn set_etas() {
+    let optim = Rprop::new(1e-2, (0.5, 1.2), (1e-6, 50.0), L2::new(1e-2));
+
+    optim.status().set_etas((0.4, 1.3));
+    assert_eq!(optim.status().get_etas(), (0.4, 1.3));
+}
+
+#[test]
+fn set_step_sizes() {
+    let optim = Rprop::new(1e-2, (0.5, 1.2), (1e-6, 50.0), L2::new(1e-2));
+
+    optim.status().set_step_sizes((1e-5, 10.0));
+    assert_eq!(optim.status().get_step_sizes(), (1e-5, 10.0));
+}
+
+#[test]
+#[should_panic]
+fn creation_invalid_etas() {
+    Rprop::new(1e-2, (1.2, 0.5), (1e-6, 50.0), L2::new(0.0));
+}
+
+const EPOCHS: usize = 10;
+
+#[test]
+fn step() {
+    let x = neuronika_variable::rand((3, 3)).requires_grad();
+    let y = neuronika_variable::rand((3, 3));
+    let z = neuronika_variable::rand((3, 3));
+
+    let loss = (x.clone().mm(y) - z).pow(2).sum();
+    loss.forward();
+
+    let first_value = loss.item();
+
+    let optim = Rprop::new(1e-4, (0.5, 1.2), (1e-6, 50.0), L2::new(0.0));
+    optim.register(x);
+
+    for _ in 0..EPOCHS {
+        loss.forward();
+        loss.backward(1.0);
+
+        optim.step();
+        optim.zero_grad();
+    }
+
+    assert!(loss.item() < first_value);
+}
+
+#[test]
+fn reference() {
+    let x = neuronika_variable::from_ndarray(ndarray::array![1., -2., 0.]).requires_grad();
+
+    let optim = Rprop::new(0.1, (0.5, 1.2), (1e-6, 50.0), L2::new(0.0));
+    optim.register(x.clone());
+
+    for _ in 0..EPOCHS {
+        let loss = x.clone().pow(2).sum();
+        loss.forward();
+        loss.backward(1.0);
+
+        optim.step();
+        optim.zero_grad();
+    }
+
+    // Reference values computed with a double precision implementation of the update rule.
+    let expected = [0.0368678, 0.0798902, 0.0];
+    x.data()
+        .iter()
+        .zip(expected)
+        .for_each(|(value, expected)| assert!((value - expected).abs() <= 1e-5));
+}
@@ -4,9 +4,21 @@ use ndarray::{Array, Dimension, Zip};
 
 use neuronika_variable::VarDiff;
 
-use super::{IntoParam, Optimize, Optimizer, OptimizerStatus, Penalty};
+use super::{
+    GroupOverrides, GroupStatus, IntoParam, NoDecay, Optimize, Optimizer, OptimizerStatus, Penalty,
+};
+
+#[cfg(feature = "serialize")]
+use ndarray::ArrayD;
+
+#[cfg(feature = "serialize")]
+use neuronika_variable::StateDict;
+
+#[cfg(feature = "serialize")]
+use super::{assign, from_option, to_option};
 
 /// Stochastic gradient descent optimizer.
+#[derive(Clone)]
 #[allow(clippy::upper_case_acronyms)]
 pub struct StochasticGD<T>
 where
@@ -17,6 +29,7 @@ where
     momentum: Cell<Option<f32>>,
     dampening: Cell<Option<f32>>,
     nesterov: Cell<bool>,
+    weight_decay: Cell<f32>,
 }
 
 impl<T> OptimizerStatus for StochasticGD<T>
@@ -30,6 +43,62 @@ where
     fn set_lr(&self, lr: f32) {
         self.lr.set(lr)
     }
+
+    fn get_cycle_momentum(&self) -> Option<f32> {
+        self.momentum.get()
+    }
+
+    fn set_cycle_momentum(&self, momentum: f32) {
+        self.momentum.set(Some(momentum))
+    }
+
+    #[cfg(feature = "serialize")]
+    fn hyperparameters(&self) -> Vec<(&'static str, f32)> {
+        vec![
+            ("lr", self.lr.get()),
+            ("momentum", from_option(self.momentum.get())),
+            ("dampening", from_option(self.dampening.get())),
+            ("nesterov", f32::from(u8::from(self.nesterov.get()))),
+            ("weight_decay", self.weight_decay.get()),
+        ]
+    }
+
+    #[cfg(feature = "serialize")]
+    fn set_hyperparameter(&self, name: &str, value: f32) {
+        match name {
+            "lr" => self.lr.set(value),
+            "momentum" => self.momentum.set(to_option(value)),
+            "dampening" => self.dampening.set(to_option(value)),
+            "nesterov" => self.nesterov.set(value != 0.0),
+            "weight_decay" => self.weight_decay.set(value),
+            _ => (),
+        }
+    }
+}
+
+impl<T> GroupStatus for StochasticGD<T>
+where
+    T: Penalty,
+{
+    fn with_overrides(&self, overrides: &GroupOverrides) -> Self {
+        let mut status = self.clone();
+        if let Some(lr) = overrides.get_lr() {
+            status.lr.set(lr);
+        }
+        if let Some(weight_decay) = overrides.get_weight_decay() {
+            // Overrides the decoupled weight decay if in use, the penalty otherwise.
+            if status.weight_decay.get() > 0.0 {
+                status.weight_decay.set(weight_decay);
+            } else {
+                status.penalty = status.penalty.with_weight_decay(weight_decay);
+            }
+        }
+        if let Some(momentum) = overrides.get_momentum() {
+            status.momentum.set(Some(momentum));
+        }
+
+        status
+    }
 }
 
 impl<T> StochasticGD<T>
@@ -71,6 +140,9 @@ where
     /// v(t+1) = μ * v(t) + lr * g(t+1)
     /// p(t+1) = p(t) - v(t+1)
     /// ```
+    ///
+    /// The optimizer can also apply a decoupled weight decay, as in SGDW, by means of
+    /// [`set_weight_decay`](Self::set_weight_decay).
     pub fn new(
         lr: f32,
         penalty: T,
@@ -103,6 +175,7 @@ where
             momentum,
             dampening,
             nesterov,
+            weight_decay: Cell::new(0.0),
         };
 
         Optimizer::new(status)
@@ -147,6 +220,21 @@ where
     pub fn set_nesterov(&self, nesterov: bool) {
         self.nesterov.set(nesterov);
     }
+
+    /// Returns the current decoupled weight decay coefficient.
+    pub fn get_weight_decay(&self) -> f32 {
+        self.weight_decay.get()
+    }
+
+    /// Sets a new value for the decoupled weight decay coefficient.
+    ///
+    /// Differently from the penalty, the decay isn't added to the gradient, and thus isn't
+    /// accumulated in the momentum buffer, but directly shrinks the parameters as described in
+    /// [Decoupled Weight Decay Regularization](https://arxiv.org/abs/1711.05101). Parameters
+    /// registered wrapped in [`NoDecay`] are excluded from it. The default value is 0.0.
+    pub fn set_weight_decay(&self, weight_decay: f32) {
+        self.weight_decay.set(weight_decay);
+    }
 }
 
 /// A parameter used by the SDG optimizer.
@@ -157,32 +245,55 @@ where
     T: Penalty,
 {
     variable: VarDiff<D>,
+    decay: bool,
     buffer: Option<Array<f32, D>>,
     status: Rc<StochasticGD<T>>,
 }
 
-impl<D, T> IntoParam<StochasticGD<T>> for VarDiff<D>
+impl<D, T> SGDParam<D, T>
 where
-    D: 'static + Dimension,
-    T: 'static + Penalty,
+    D: Dimension,
+    T: Penalty,
 {
-    type Param = SGDParam<D, T>;
-
-    fn into_param(self, status: Rc<StochasticGD<T>>) -> Self::Param {
-        let variable = self;
+    fn new(variable: VarDiff<D>, decay: bool, status: Rc<StochasticGD<T>>) -> Self {
         let buffer = status
             .get_momentum()
             .filter(|val| *val > f32::EPSILON)
             .map(|_| Array::zeros(variable.grad().raw_dim()));
 
-        Self::Param {
+        Self {
             variable,
+            decay,
             buffer,
             status,
         }
     }
 }
 
+impl<D, T> IntoParam<StochasticGD<T>> for VarDiff<D>
+where
+    D: 'static + Dimension,
+    T: 'static + Penalty,
+{
+    type Param = SGDParam<D, T>;
+
+    fn into_param(self, status: Rc<StochasticGD<T>>) -> Self::Param {
+        SGDParam::new(self, true, status)
+    }
+}
+
+impl<D, T> IntoParam<StochasticGD<T>> for NoDecay<D>
+where
+    D: 'static + Dimension,
+    T: 'static + Penalty,
+{
+    type Param = SGDParam<D, T>;
+
+    fn into_param(self, status: Rc<StochasticGD<T>>) -> Self::Param {
+        SGDParam::new(self.0, false, status)
+    }
+}
+
 impl<D, T> Optimize for SGDParam<D, T>
 where
     D: Dimension,
@@ -191,10 +302,15 @@ where
     fn optimize(&mut self) {
         let lr = self.status.get_lr();
         let penalty = self.status.penalty;
+        let weight_decay = self.status.get_weight_decay();
 
         let mut data = self.variable.data_mut();
         let mut grad = self.variable.grad_mut();
 
+        if self.decay && weight_decay > 0.0 {
+            data.mapv_inplace(|data_el| data_el * (1.0 - lr * weight_decay));
+        }
+
         Zip::from(&mut *grad)
             .and(&*data)
             .for_each(|grad_el, data_el| *grad_el += penalty.penalize(data_el));
@@ -233,6 +349,27 @@ where
     fn zero_grad(&mut self) {
         self.variable.zero_grad()
     }
+
+    fn for_each_grad(&mut self, f: &mut dyn FnMut(&mut f32)) {
+        self.variable.grad_mut().iter_mut().for_each(f)
+    }
+
+    #[cfg(feature = "serialize")]
+    fn state(&self) -> Vec<(&'static str, ArrayD<f32>)> {
+        let mut state = vec![];
+        if let Some(buffer) = &self.buffer {
+            state.push(("momentum_buffer", buffer.clone().into_dyn()));
+        }
+
+        state
+    }
+
+    #[cfg(feature = "serialize")]
+    fn load_state(&mut self, state: &StateDict) {
+        if let Some(buffer) = &mut self.buffer {
+            assign(buffer, &state["momentum_buffer"]);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,9 @@
-use super::{super::L2, StochasticGD};
+use ndarray::Array;
+
+use super::{
+    super::{GroupOverrides, NoDecay, Penalty, L2},
+    StochasticGD,
+};
 
 #[test]
 fn creation() {
@@ -59,8 +64,268 @@ fn set_nesterov() {
     assert!(optim.status().get_nesterov());
 }
 
+#[test]
+fn decoupled_weight_decay() {
+    let weight = neuronika_variable::full((2, 2), 2.).requires_grad();
+    let bias = neuronika_variable::full(2, 2.).requires_grad();
+
+    let optim = StochasticGD::new(0.1, L2::new(0.0), 0.9, None, false);
+    optim.status().set_weight_decay(0.5);
+    assert!((optim.status().get_weight_decay() - 0.5).abs() <= f32::EPSILON);
+
+    optim.register(weight.clone());
+    optim.register(NoDecay(bias.clone()));
+
+    // With a null gradient only the weight decay changes the parameters.
+    optim.step();
+    assert_eq!(
+        *weight.data(),
+        Array::from_elem((2, 2), 2. * (1. - 0.1 * 0.5))
+    );
+    assert_eq!(*bias.data(), Array::from_elem(2, 2.));
+}
+
+#[test]
+fn groups() {
+    let head = neuronika_variable::full((2, 2), 1.).requires_grad();
+    let frozen = neuronika_variable::full((2, 2), 1.).requires_grad();
+    let bias = neuronika_variable::full(2, 1.).requires_grad();
+
+    let optim = StochasticGD::new(0.1, L2::new(0.5), None, None, false);
+    optim.register(head.clone());
+    let group = optim.register_group(
+        vec![frozen.clone()],
+        GroupOverrides::new().lr(0.0).momentum(0.9),
+    );
+    optim.register_in_group(group, bias.clone());
+
+    assert_eq!(group, 1);
+    assert_eq!(optim.groups(), 2);
+    assert!((optim.group(0).get_lr() - 0.1).abs() <= f32::EPSILON);
+    assert!(optim.group(0).get_momentum().is_none());
+    assert!(optim.group(group).get_lr().abs() <= f32::EPSILON);
+    assert!((optim.group(group).get_momentum().unwrap() - 0.9).abs() <= f32::EPSILON);
+
+    // With a null gradient only the penalty changes the parameters of the default group.
+    optim.step();
+    assert_eq!(*head.data(), Array::from_elem((2, 2), 1. - 0.1 * 2. * 0.5));
+    assert_eq!(*frozen.data(), Array::from_elem((2, 2), 1.));
+    assert_eq!(*bias.data(), Array::from_elem(2, 1.));
+}
+
+#[test]
+fn groups_weight_decay() {
+    let weight = neuronika_variable::full((2, 2), 1.).requires_grad();
+    let bias = neuronika_variable::full(2, 1.).requires_grad();
+
+    let optim = StochasticGD::new(0.1, L2::new(0.0), None, None, false);
+    optim.status().set_weight_decay(0.5);
+    optim.register(weight.clone());
+    optim.register_group(vec![bias.clone()], GroupOverrides::new().weight_decay(0.0));
+
+    optim.step();
+    assert_eq!(*weight.data(), Array::from_elem((2, 2), 1. - 0.1 * 0.5));
+    assert_eq!(*bias.data(), Array::from_elem(2, 1.));
+}
+
+#[test]
+fn groups_cycle_momentum() {
+    let optim = StochasticGD::new(0.1, L2::new(0.0), 0.9, None, false);
+    let slow = optim.register_group(
+        vec![neuronika_variable::rand(2).requires_grad()],
+        GroupOverrides::new().momentum(0.45),
+    );
+    let default = optim.register_group(
+        vec![neuronika_variable::rand(2).requires_grad()],
+        GroupOverrides::new(),
+    );
+
+    // Each group keeps the ratio with the default momentum it was created with.
+    optim.set_cycle_momentum(0.8);
+    assert!((optim.group(0).get_momentum().unwrap() - 0.8).abs() <= f32::EPSILON);
+    assert!((optim.group(slow).get_momentum().unwrap() - 0.4).abs() <= f32::EPSILON);
+    assert!((optim.group(default).get_momentum().unwrap() - 0.8).abs() <= f32::EPSILON);
+
+    // Without a default momentum the override is left as it is.
+    let optim = StochasticGD::new(0.1, L2::new(0.0), None, None, false);
+    let group = optim.register_group(
+        vec![neuronika_variable::rand(2).requires_grad()],
+        GroupOverrides::new().momentum(0.9),
+    );
+    optim.set_cycle_momentum(0.5);
+    assert!((optim.group(group).get_momentum().unwrap() - 0.9).abs() <= f32::EPSILON);
+}
+
+#[test]
+#[should_panic]
+fn groups_lr_override_with_zero_lr() {
+    let optim = StochasticGD::new(0.0, L2::new(0.0), None, None, false);
+    optim.register_group(
+        vec![neuronika_variable::rand(2).requires_grad()],
+        GroupOverrides::new().lr(0.1),
+    );
+}
+
+#[test]
+fn groups_zero_lr() {
+    let optim = StochasticGD::new(0.0, L2::new(0.0), None, None, false);
+    let group = optim.register_group(
+        vec![neuronika_variable::rand(2).requires_grad()],
+        GroupOverrides::new().momentum(0.9),
+    );
+
+    // Without an override the group follows the learning rate of the optimizer.
+    optim.set_lr(0.1);
+    assert!((optim.group(group).get_lr() - 0.1).abs() <= f32::EPSILON);
+}
+
+/// A penalty that implements only the required methods of [`Penalty`].
+#[derive(Copy, Clone)]
+struct Shrink;
+
+impl Penalty for Shrink {
+    fn penalize(&self, w: &f32) -> f32 {
+        *w
+    }
+}
+
+#[test]
+fn groups_custom_penalty() {
+    let weight = neuronika_variable::full(2, 1.).requires_grad();
+    let optim = StochasticGD::new(0.5, Shrink, None, None, false);
+    optim.register_group(vec![weight.clone()], GroupOverrides::new().lr(0.1));
+
+    optim.step();
+    assert_eq!(*weight.data(), Array::from_elem(2, 1. - 0.1));
+}
+
+#[test]
+#[should_panic]
+fn groups_custom_penalty_weight_decay() {
+    let optim = StochasticGD::new(0.5, Shrink, None, None, false);
+    optim.register_group(
+        vec![neuronika_variable::rand(2).requires_grad()],
+        GroupOverrides::new().weight_decay(0.1),
+    );
+}
+
+#[test]
+#[should_panic]
+fn groups_out_of_range() {
+    let optim = StochasticGD::new(0.1, L2::new(0.0), None, None, false);
+    optim.group(1);
+}
+
 const EPOCHS: usize = 10;
 
+#[test]
+fn clip_grad_norm() {
+    let x = neuronika_variable::from_ndarray(ndarray::array![3., 0.]).requires_grad();
+    let y = neuronika_variable::from_ndarray(ndarray::array![[0.], [4.]]).requires_grad();
+    x.grad_mut().assign(&ndarray::array![3., 0.]);
+    y.grad_mut().assign(&ndarray::array![[0.], [4.]]);
+
+    let optim = StochasticGD::new(1e-2, L2::new(0.0), None, None, false);
+    optim.register(x.clone());
+    optim.register(y.clone());
+
+    let total_norm = optim.clip_grad_norm(1.0, 2.0);
+    assert!((total_norm - 5.0).abs() <= f32::EPSILON);
+
+    let clipped = x
+        .grad()
+        .iter()
+        .chain(y.grad().iter())
+        .copied()
+        .collect::<Vec<_>>();
+    clipped
+        .iter()
+        .zip([0.6, 0., 0., 0.8])
+        .for_each(|(value, expected)| assert!((value - expected).abs() <= 1e-5));
+
+    // Gradients within the limit are left untouched.
+    let total_norm = optim.clip_grad_norm(2.0, 1.0);
+    assert!((total_norm - 1.4).abs() <= 1e-5);
+    assert_eq!(*x.grad(), ndarray::array![clipped[0], 0.]);
+}
+
+#[test]
+fn clip_grad_norm_infinity() {
+    let x = neuronika_variable::from_ndarray(ndarray::array![1., -4., 2.]).requires_grad();
+    x.grad_mut().assign(&ndarray::array![1., -4., 2.]);
+
+    let optim = StochasticGD::new(1e-2, L2::new(0.0), None, None, false);
+    optim.register(x.clone());
+
+    let total_norm = optim.clip_grad_norm(2.0, f32::INFINITY);
+    assert!((total_norm - 4.0).abs() <= f32::EPSILON);
+    x.grad()
+        .iter()
+        .zip([0.5, -2., 1.])
+        .for_each(|(value, expected)| assert!((value - expected).abs() <= 1e-5));
+}
+
+#[test]
+fn clip_grad_norm_custom_param() {
+    use std::rc::Rc;
+
+    use neuronika_variable::VarDiff;
+
+    use super::super::{IntoParam, Optimize};
+
+    /// A parameter that implements only the required methods of [`Optimize`].
+    struct Frozen(VarDiff<ndarray::Ix1>);
+
+    impl Optimize for Frozen {
+        fn optimize(&mut self) {}
+
+        fn zero_grad(&mut self) {
+            self.0.zero_grad()
+        }
+    }
+
+    impl IntoParam<StochasticGD<L2>> for Frozen {
+        type Param = Self;
+
+        fn into_param(self, _status: Rc<StochasticGD<L2>>) -> Self::Param {
+            self
+        }
+    }
+
+    let x = neuronika_variable::from_ndarray(ndarray::array![3., 4.]).requires_grad();
+    let frozen = neuronika_variable::from_ndarray(ndarray::array![12.]).requires_grad();
+    x.grad_mut().assign(&ndarray::array![3., 4.]);
+    frozen.grad_mut().assign(&ndarray::array![12.]);
+
+    let optim = StochasticGD::new(1e-2, L2::new(0.0), None, None, false);
+    optim.register(x);
+    optim.register(Frozen(frozen.clone()));
+
+    // The gradient of the custom parameter is left out of the clipping.
+    let total_norm = optim.clip_grad_norm(1.0, 2.0);
+    assert!((total_norm - 5.0).abs() <= f32::EPSILON);
+    assert_eq!(*frozen.grad(), ndarray::array![12.]);
+}
+
+#[test]
+#[should_panic]
+fn clip_grad_norm_invalid_norm_type() {
+    let optim = StochasticGD::new(1e-2, L2::new(0.0), None, None, false);
+    optim.clip_grad_norm(1.0, 0.0);
+}
+
+#[test]
+fn clip_grad_value() {
+    let x = neuronika_variable::from_ndarray(ndarray::array![1., -4., 0.5]).requires_grad();
+    x.grad_mut().assign(&ndarray::array![1., -4., 0.5]);
+
+    let optim = StochasticGD::new(1e-2, L2::new(0.0), None, None, false);
+    optim.register(x.clone());
+
+    optim.clip_grad_value(0.75);
+    assert_eq!(*x.grad(), ndarray::array![0.75, -0.75, 0.5]);
+}
+
 #[test]
 fn step() {
     let x = neuronika_variable::rand((3, 3)).requires_grad();
@@ -132,3 +397,49 @@ fn step_with_nesterov_momentum() {
 
     assert!(loss.item() < first_value);
 }
+
+#[cfg(feature = "serialize")]
+#[test]
+fn state_dict() {
+    let weight = neuronika_variable::full((2, 2), 1.).requires_grad();
+    let bias = neuronika_variable::full(2, 1.).requires_grad();
+
+    let optim = StochasticGD::new(0.1, L2::new(0.5), 0.9, None, true);
+    optim.register(weight);
+    optim.register_group(vec![bias], GroupOverrides::new().lr(0.01));
+    optim.set_lr(0.2);
+    optim.step();
+
+    let state = optim.state_dict();
+    assert!(state["groups.0.dampening"][[]].is_nan());
+    assert!((state["groups.0.nesterov"][[]] - 1.).abs() <= f32::EPSILON);
+    assert!((state["groups.1.lr"][[]] - 0.02).abs() <= f32::EPSILON);
+    assert!((state["groups.1.lr_scale"][[]] - 0.1).abs() <= f32::EPSILON);
+    assert!((state["groups.1.momentum_scale"][[]] - 1.).abs() <= f32::EPSILON);
+    assert_eq!(
+        state["params.1.momentum_buffer"],
+        Array::from_elem(2, 2. * 0.5).into_dyn()
+    );
+
+    let other = StochasticGD::new(1., L2::new(0.5), 0.5, 0.5, false);
+    other.register(neuronika_variable::zeros((2, 2)).requires_grad());
+    other.register_group(
+        vec![neuronika_variable::zeros(2).requires_grad()],
+        GroupOverrides::new(),
+    );
+    other.load_state_dict(&state).unwrap();
+
+    let loaded = other.state_dict();
+    assert!(loaded.keys().eq(state.keys()));
+    assert!(loaded.values().zip(state.values()).all(|(lhs, rhs)| {
+        lhs.iter()
+            .zip(rhs)
+            .all(|(lhs, rhs)| lhs == rhs || (lhs.is_nan() && rhs.is_nan()))
+    }));
+    assert!(other.status().get_dampening().is_none());
+    assert!(other.status().get_nesterov());
+
+    // The ratio between the learning rates of the groups is restored too.
+    other.set_lr(1.);
+    assert!((other.group(1).get_lr() - 0.1).abs() <= f32::EPSILON);
+}
@@ -1,3 +1,7 @@
+use std::cell::{Ref, RefMut};
+
+use crate::{fusion::Kernel, onnx::Operator, planner::Pool};
+
 /// Forward-propagation behavior.
 ///
 /// This trait is implemented by all the internal forward components of `Var` and `VarDiff`.
@@ -9,6 +13,70 @@ pub(crate) trait Forward {
     ///
     /// It also defines the logic for the computation of the node.
     fn forward(&self);
+
+    /// Returns the buffer the computation writes its result into.
+    fn data(&self) -> &dyn Buffer;
+
+    /// Returns the buffers the computation reads from.
+    fn operands(&self) -> Vec<&dyn Buffer>;
+
+    /// Returns `true` if the computation must be evaluated at each forward pass, even when its
+    /// operands haven't changed.
+    fn is_volatile(&self) -> bool {
+        false
+    }
+
+    /// Returns the scalar function applied to each element, if the computation is element-wise
+    /// and can thus be fused with the neighbouring ones.
+    fn kernel(&self) -> Option<Kernel> {
+        None
+    }
+
+    /// Returns the ONNX operator performing the same computation, if there's one. Its inputs are
+    /// the operands, in the same order, followed by its constants.
+    fn onnx(&self) -> Option<Operator> {
+        None
+    }
+}
+
+/// Storage behavior.
+///
+/// This trait is implemented by the shared buffers that the forward components read from and write
+/// into, and it is used by the memory planner to track and recycle them.
+pub(crate) trait Buffer {
+    /// Returns the address of the buffer, which identifies it in the graph.
+    fn address(&self) -> usize;
+
+    /// Returns the number of handles that keep the buffer alive.
+    fn owners(&self) -> usize;
+
+    /// Moves the memory of the buffer into `pool` and returns the shape it had, if the buffer can
+    /// be released.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - pool receiving the memory.
+    fn release(&self, pool: &mut Pool) -> Option<Vec<usize>>;
+
+    /// Allocates the buffer with the given shape, taking memory from `pool` when possible. The
+    /// content of the buffer is zeroed.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - shape of the buffer.
+    ///
+    /// * `pool` - pool providing the memory.
+    fn reserve(&self, shape: &[usize], pool: &mut Pool);
+
+    /// Returns the shape of the buffer.
+    fn shape(&self) -> Vec<usize>;
+
+    /// Returns the elements of the buffer in logical order, if they are contiguous in host memory.
+    fn flat(&self) -> Option<Ref<'_, [f32]>>;
+
+    /// Returns the elements of the buffer in logical order for writing, if they are contiguous in
+    /// host memory.
+    fn flat_mut(&self) -> Option<RefMut<'_, [f32]>>;
 }
 
 /// Back-propagation behavior.
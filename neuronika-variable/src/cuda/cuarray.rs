@@ -1,10 +1,15 @@
+use std::{
+    cell::{Ref, RefMut},
+    rc::Rc,
+};
+
 use ndarray::{Array, Dimension, Ix4, Ix5, ShapeBuilder};
 
 use cust::memory::{bytemuck::Zeroable, DeviceBuffer, DeviceCopy};
 
 use cudnn::{DataType, FilterDescriptor, ScalarC, TensorDescriptor};
 
-use crate::cuda::device::Device;
+use crate::{autograd::Buffer, cuda::device::Device, planner::Pool, utils::Shared};
 
 /// An array allocated on a CUDA capable device.
 pub struct CuArray<T, D>
@@ -169,3 +174,36 @@ where
         FilterDescriptor::new(&shape, ScalarC::Nchw).unwrap()
     }
 }
+
+impl<D> Buffer for Shared<CuArray<f32, D>>
+where
+    D: Dimension,
+{
+    fn address(&self) -> usize {
+        Rc::as_ptr(self) as *const () as usize
+    }
+
+    fn owners(&self) -> usize {
+        Rc::strong_count(self)
+    }
+
+    fn release(&self, _: &mut Pool) -> Option<Vec<usize>> {
+        // Device memory is never recycled.
+        None
+    }
+
+    fn reserve(&self, _: &[usize], _: &mut Pool) {}
+
+    fn shape(&self) -> Vec<usize> {
+        self.borrow().dimension().slice().to_vec()
+    }
+
+    fn flat(&self) -> Option<Ref<'_, [f32]>> {
+        // Device memory can't be accessed element-wise from the host.
+        None
+    }
+
+    fn flat_mut(&self) -> Option<RefMut<'_, [f32]>> {
+        None
+    }
+}
@@ -3,7 +3,7 @@ use ndarray::{DimMax, Dimension};
 use cudnn::{BinaryOp, BinaryOpTensorDescriptor, NanPropagation, TensorDescriptor};
 
 use crate::{
-    autograd::Forward,
+    autograd::{Buffer, Forward},
     cuda::cuarray::CuArray,
     utils::{Broadcast, Shared},
 };
@@ -79,4 +79,12 @@ where
             )
             .unwrap()
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.left_data, &self.right_data]
+    }
 }
@@ -0,0 +1,268 @@
+use std::{
+    cell::Cell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    gradient::NoGrad,
+    history::History,
+    node::{Chain, Fused, FusedBackward},
+};
+
+/// Scalar function computed by an element-wise operation.
+///
+/// The derivatives are expressed in terms of both the arguments and the result of the function,
+/// so that each operation can use the cheapest form, as its backward component does.
+#[derive(Clone, Copy)]
+pub(crate) enum Kernel {
+    /// A function of one argument *x* with its derivative, computed from *x* and *f(x)*.
+    Unary(fn(f32) -> f32, fn(f32, f32) -> f32),
+    /// A function of two arguments *l* and *r* with its partial derivatives, computed from *l*,
+    /// *r* and *f(l, r)*.
+    Binary(fn(f32, f32) -> f32, fn(f32, f32, f32) -> (f32, f32)),
+}
+
+impl Kernel {
+    /// Evaluates the function.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - arguments of the function.
+    pub(crate) fn eval(&self, args: [f32; 2]) -> f32 {
+        match self {
+            Self::Unary(f, _) => f(args[0]),
+            Self::Binary(f, _) => f(args[0], args[1]),
+        }
+    }
+
+    /// Evaluates the partial derivatives of the function.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - arguments of the function.
+    ///
+    /// * `result` - value of the function at `args`.
+    pub(crate) fn partials(&self, args: [f32; 2], result: f32) -> [f32; 2] {
+        match self {
+            Self::Unary(_, df) => [df(args[0], result), 0.],
+            Self::Binary(_, df) => {
+                let (dl, dr) = df(args[0], args[1], result);
+                [dl, dr]
+            }
+        }
+    }
+}
+
+/// Argument of a fused element-wise function.
+#[derive(Clone, Copy)]
+pub(crate) enum Arg {
+    /// The result of the previous function of the chain.
+    Previous,
+    /// The element of the input at the given index.
+    Input(usize),
+}
+
+/// A function of a chain of fused element-wise operations.
+pub(crate) struct Stage {
+    pub(crate) kernel: Kernel,
+    pub(crate) args: Vec<Arg>,
+}
+
+/// Returns the shape of a buffer, as it was before any release by the memory planner.
+///
+/// # Arguments
+///
+/// * `buffer` - buffer to inspect.
+///
+/// * `released` - shapes of the released buffers, indexed by address.
+fn shape(buffer: &dyn Buffer, released: &HashMap<usize, Vec<usize>>) -> Vec<usize> {
+    released
+        .get(&buffer.address())
+        .cloned()
+        .unwrap_or_else(|| buffer.shape())
+}
+
+/// Returns the chains of element-wise operations of `tape` that can be fused, as lists of
+/// positions in `tape`.
+///
+/// An operation can be fused if it is element-wise, all the buffers it touches have the same shape,
+/// it doesn't operate in place and it belongs to this graph only. Two such operations are chained
+/// if the result of the first one is read only once and only by the second one.
+///
+/// # Arguments
+///
+/// * `tape` - operations of the graph, in topological order.
+///
+/// * `data` - address of the result of the graph, which must stay available.
+///
+/// * `released` - shapes of the buffers released by the memory planner, indexed by address.
+fn chains(
+    tape: &[(Rc<dyn Forward>, Cell<bool>)],
+    data: usize,
+    released: &HashMap<usize, Vec<usize>>,
+) -> Vec<Vec<usize>> {
+    let mut consumers: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (position, (op, _)) in tape.iter().enumerate() {
+        op.operands().iter().for_each(|operand| {
+            consumers
+                .entry(operand.address())
+                .or_default()
+                .push(position)
+        });
+    }
+
+    let is_fusable = |op: &Rc<dyn Forward>| {
+        // The operation is held by the tape and by its copy only.
+        if op.kernel().is_none() || Rc::strong_count(op) != 2 {
+            return false;
+        }
+
+        let result = op.data();
+        let operands = op.operands();
+        let expected = shape(result, released);
+
+        operands.iter().all(|operand| {
+            operand.address() != result.address() && shape(*operand, released) == expected
+        })
+    };
+
+    let next: Vec<Option<usize>> = tape
+        .iter()
+        .map(|(op, _)| {
+            let address = op.data().address();
+            if !is_fusable(op) || address == data {
+                return None;
+            }
+
+            match consumers.get(&address).map(Vec::as_slice) {
+                Some([consumer]) if is_fusable(&tape[*consumer].0) => Some(*consumer),
+                _ => None,
+            }
+        })
+        .collect();
+    let has_previous: HashSet<usize> = next.iter().flatten().copied().collect();
+
+    (0..tape.len())
+        .filter(|position| !has_previous.contains(position) && next[*position].is_some())
+        .map(|start| {
+            let mut chain = vec![start];
+            while let Some(position) = next[*chain.last().unwrap()] {
+                chain.push(position);
+            }
+            chain
+        })
+        .collect()
+}
+
+/// Replaces each chain of element-wise operations of a graph with a single operation that
+/// evaluates it in one loop, without storing the intermediate results. When the graph is
+/// differentiable, the backward components of each chain are replaced as well.
+///
+/// # Arguments
+///
+/// * `history` - forward history of the graph.
+///
+/// * `data` - address of the result of the graph.
+///
+/// * `backward` - backward history of the graph, if it is differentiable.
+#[allow(clippy::type_complexity)]
+pub(crate) fn compile(
+    history: &mut History<(Rc<dyn Forward>, Cell<bool>)>,
+    data: usize,
+    mut backward: Option<&mut History<(Rc<dyn Backward>, Rc<dyn NoGrad>)>>,
+) {
+    // The buffers hold copies of the operations, which must be dropped for the ownership checks.
+    history.buffer_mut().clear();
+    if let Some(backward) = backward.as_deref() {
+        backward.buffer_mut().clear();
+    }
+
+    let tape = history.to_vec();
+    let chains = chains(&tape, data, &history.released_mut());
+
+    for positions in chains {
+        let ops: Vec<Rc<dyn Forward>> = positions
+            .iter()
+            .map(|position| tape[*position].0.clone())
+            .collect();
+
+        let mut inputs = Vec::new();
+        let stages = ops
+            .iter()
+            .enumerate()
+            .map(|(index, op)| {
+                let previous = index
+                    .checked_sub(1)
+                    .map(|index| ops[index].data().address());
+                let args = op
+                    .operands()
+                    .iter()
+                    .enumerate()
+                    .map(|(operand_index, operand)| {
+                        if Some(operand.address()) == previous {
+                            return Arg::Previous;
+                        }
+
+                        inputs.push((index, operand_index));
+                        Arg::Input(inputs.len() - 1)
+                    })
+                    .collect();
+
+                Stage {
+                    kernel: op.kernel().unwrap(),
+                    args,
+                }
+            })
+            .collect();
+
+        let chain = Rc::new(Chain::new(ops, stages, inputs));
+        chain.release(&mut history.released_mut());
+
+        let removed: HashSet<usize> = positions
+            .iter()
+            .map(|position| Rc::as_ptr(&tape[*position].0) as *const () as usize)
+            .collect();
+        let (_, order) = history.remove(&removed);
+        let op: Rc<dyn Forward> = Rc::new(Fused::new(chain.clone()));
+        history.insert_at(
+            Rc::as_ptr(&op) as *const () as usize,
+            order,
+            (op, Cell::default()),
+        );
+
+        let backward = match backward.as_deref_mut() {
+            Some(backward) => backward,
+            None => continue,
+        };
+
+        let gradient = match backward.gradient(chain.data().address()) {
+            Some((gradient, _)) => gradient,
+            None => continue,
+        };
+
+        let input_gradients = chain
+            .inputs()
+            .iter()
+            .map(|input| {
+                backward
+                    .gradient(input.address())
+                    .map(|(gradient, _)| gradient)
+            })
+            .collect();
+
+        let removed: HashSet<usize> = chain
+            .results()
+            .filter_map(|result| backward.gradient(result.address()))
+            .filter_map(|(_, ptr)| ptr)
+            .collect();
+        let (tape, order) = backward.remove(&removed);
+        let op = Rc::new(FusedBackward::new(chain, gradient, input_gradients, tape));
+        backward.insert_at(
+            Rc::as_ptr(&op) as *const () as usize,
+            order,
+            (op.clone(), op),
+        );
+    }
+}
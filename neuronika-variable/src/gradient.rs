@@ -11,6 +11,16 @@ pub(crate) trait NoGrad {
     fn with_grad(&self);
 }
 
+/// Element-wise access to a gradient, regardless of its dimensionality.
+pub(crate) trait FlatGradient {
+    /// Returns the elements of the gradient in logical order, if they are contiguous in memory.
+    fn flat(&self) -> Option<Ref<'_, [f32]>>;
+
+    /// Returns the elements of the gradient in logical order for writing, if they are contiguous
+    /// in memory.
+    fn flat_mut(&self) -> Option<RefMut<'_, [f32]>>;
+}
+
 pub(crate) struct Gradient<T, D>
 where
     D: Dimension,
@@ -61,6 +71,19 @@ where
     }
 }
 
+impl<D> FlatGradient for Gradient<Array<f32, D>, D>
+where
+    D: Dimension,
+{
+    fn flat(&self) -> Option<Ref<'_, [f32]>> {
+        Ref::filter_map(self.borrow(), |array| array.as_slice()).ok()
+    }
+
+    fn flat_mut(&self) -> Option<RefMut<'_, [f32]>> {
+        RefMut::filter_map(self.borrow_mut(), |array| array.as_slice_mut()).ok()
+    }
+}
+
 impl<D> NoGrad for Gradient<Array<f32, D>, D>
 where
     D: Dimension,
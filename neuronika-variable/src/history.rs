@@ -1,8 +1,11 @@
 use std::{
-    cell::{Ref, RefCell, RefMut},
-    collections::BTreeMap,
+    cell::{Cell, Ref, RefCell, RefMut},
+    collections::{BTreeMap, HashMap, HashSet},
+    rc::Rc,
 };
 
+use crate::gradient::FlatGradient;
+
 /// Id of an operation in the tape. The first component is the address of the struct and the second
 /// is the size of the history at insertion. The former is unique, the latter enforces order.
 #[derive(Copy, Clone, Eq)]
@@ -57,6 +60,10 @@ where
 {
     path: BTreeMap<HistoryId, T>,
     buffer: RefCell<Vec<T>>,
+    planned: Cell<bool>,
+    released: RefCell<HashMap<usize, Vec<usize>>>,
+    versions: HashMap<usize, (Rc<Cell<usize>>, Cell<usize>)>,
+    gradients: HashMap<usize, (Rc<dyn FlatGradient>, Option<usize>)>,
 }
 
 impl<T> History<T>
@@ -70,6 +77,10 @@ where
     /// `other` - other history.
     pub(crate) fn merge(&mut self, mut other: Self) {
         self.path.append(&mut other.path);
+        self.planned.set(self.planned.get() || other.planned.get());
+        self.track_all(&other);
+        self.released.get_mut().extend(other.released.into_inner());
+        self.gradients.extend(other.gradients);
     }
 
     /// Appends a new computation to the history.
@@ -80,12 +91,56 @@ where
     ///
     /// * `op` - computation to append.
     pub(crate) fn insert(&mut self, ptr: usize, op: T) {
-        let id = HistoryId::new(ptr, self.path.len());
+        // The history may be shorter than its last position if some computations were removed.
+        let order = self
+            .path
+            .keys()
+            .next_back()
+            .map_or(0, |HistoryId((_, order))| order + 1)
+            .max(self.path.len());
+        let id = HistoryId::new(ptr, order);
 
         self.path.insert(id, op);
         self.buffer.borrow_mut().truncate(0);
     }
 
+    /// Inserts a computation in the history at the given position.
+    ///
+    /// # Arguments
+    ///
+    /// * `ptr` - address of the new node.
+    ///
+    /// * `order` - position of the new node.
+    ///
+    /// * `op` - computation to insert.
+    pub(crate) fn insert_at(&mut self, ptr: usize, order: usize, op: T) {
+        self.path.insert(HistoryId::new(ptr, order), op);
+        self.buffer.borrow_mut().truncate(0);
+    }
+
+    /// Removes from the history the computations of the nodes at the given addresses. Returns the
+    /// removed computations in order, together with the position of the last of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `ptrs` - addresses of the nodes to remove.
+    pub(crate) fn remove(&mut self, ptrs: &HashSet<usize>) -> (Vec<T>, usize) {
+        let mut removed = Vec::new();
+        let mut last = 0;
+        self.path.retain(|HistoryId((ptr, order)), op| {
+            if !ptrs.contains(ptr) {
+                return true;
+            }
+
+            removed.push(op.clone());
+            last = last.max(*order);
+            false
+        });
+        self.buffer.borrow_mut().truncate(0);
+
+        (removed, last)
+    }
+
     /// Returns the length of the history.
     pub(crate) fn len(&self) -> usize {
         self.path.len()
@@ -110,6 +165,97 @@ where
     pub(crate) fn buffer_mut(&self) -> RefMut<Vec<T>> {
         self.buffer.borrow_mut()
     }
+
+    /// Enables the memory planner for the history.
+    pub(crate) fn plan(&self) {
+        self.planned.set(true)
+    }
+
+    /// Returns `true` if the memory planner is enabled for the history.
+    pub(crate) fn is_planned(&self) -> bool {
+        self.planned.get()
+    }
+
+    /// Returns a mutable reference to the shapes of the buffers released by the memory planner,
+    /// indexed by address.
+    pub(crate) fn released_mut(&self) -> RefMut<HashMap<usize, Vec<usize>>> {
+        self.released.borrow_mut()
+    }
+
+    /// Starts tracking the version of a buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - address of the buffer.
+    pub(crate) fn track(&mut self, address: usize) {
+        self.versions
+            .entry(address)
+            .or_insert_with(|| (Rc::new(Cell::new(0)), Cell::new(0)));
+    }
+
+    /// Starts tracking the versions of all the buffers tracked by another history.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - other history.
+    pub(crate) fn track_all(&mut self, other: &Self) {
+        other
+            .versions
+            .iter()
+            .for_each(|(address, (version, seen))| {
+                self.versions
+                    .entry(*address)
+                    .or_insert_with(|| (version.clone(), seen.clone()));
+            });
+    }
+
+    /// Increments the version of a tracked buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - address of the buffer.
+    pub(crate) fn bump(&self, address: usize) {
+        if let Some((version, _)) = self.versions.get(&address) {
+            version.set(version.get() + 1);
+        }
+    }
+
+    /// Associates a gradient to the buffer of the corresponding data.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - address of the data buffer.
+    ///
+    /// * `gradient` - gradient of the data.
+    ///
+    /// * `ptr` - address of the node computing the gradient, if any.
+    pub(crate) fn register(
+        &mut self,
+        address: usize,
+        gradient: Rc<dyn FlatGradient>,
+        ptr: Option<usize>,
+    ) {
+        self.gradients.insert(address, (gradient, ptr));
+    }
+
+    /// Returns the gradient associated to the buffer at `address`, together with the address of
+    /// the node computing it, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - address of the data buffer.
+    pub(crate) fn gradient(&self, address: usize) -> Option<(Rc<dyn FlatGradient>, Option<usize>)> {
+        self.gradients.get(&address).cloned()
+    }
+
+    /// Returns the addresses of the tracked buffers that have been modified since the last call.
+    pub(crate) fn modified(&self) -> HashSet<usize> {
+        self.versions
+            .iter()
+            .filter(|(_, (version, seen))| version.get() != seen.replace(version.get()))
+            .map(|(address, _)| *address)
+            .collect()
+    }
 }
 
 impl<T> Default for History<T>
@@ -119,7 +265,18 @@ where
     fn default() -> Self {
         let path = BTreeMap::new();
         let buffer = RefCell::new(Vec::new());
+        let planned = Cell::new(false);
+        let released = RefCell::new(HashMap::new());
+        let versions = HashMap::new();
+        let gradients = HashMap::new();
 
-        Self { path, buffer }
+        Self {
+            path,
+            buffer,
+            planned,
+            released,
+            versions,
+            gradients,
+        }
     }
 }
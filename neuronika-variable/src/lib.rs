@@ -1,7 +1,11 @@
 mod autograd;
+mod fusion;
 mod gradient;
 mod history;
 mod node;
+mod onnx;
+mod planner;
+mod state;
 mod utils;
 mod var;
 mod vardiff;
@@ -9,6 +13,8 @@ mod vardiff;
 #[cfg(feature = "serialize")]
 mod serde;
 
+use std::rc::Rc;
+
 use ndarray::{Array, Array2, Dimension, Ix1, Ix2, ShapeBuilder};
 
 use ndarray_rand::{rand_distr::Uniform, RandomExt};
@@ -16,11 +22,18 @@ use ndarray_rand::{rand_distr::Uniform, RandomExt};
 use neuronika_core::*;
 
 pub use crate::{
-    node::{Constant, PaddingMode, Reflective, Replicative, Zero},
+    node::{Circular, Constant, Interpolation, PaddingMode, Reflective, Replicative, Zero},
+    onnx::{OnnxError, OnnxExporter, OnnxImporter, OnnxModel, OnnxValue, ONNX_OPSET},
+    state::{
+        load_safetensors, read_safetensors, save_safetensors, write_safetensors, CheckpointError,
+        Dtype, LoadReport, ModelCheckpoint, ParameterDict, StateDict, CHECKPOINT_VERSION,
+    },
     var::Var,
     vardiff::VarDiff,
 };
 
+use crate::node::{Checkpoint, CheckpointBackward, Segment};
+
 #[cfg(feature = "cuda")]
 pub mod cuda;
 
@@ -285,6 +298,157 @@ where
     Stack::stack(lhs, rhs, axis)
 }
 
+/// Picks the elements of `lhs` where `condition` is strictly positive and those of `rhs`
+/// elsewhere.
+///
+/// `lhs` and `rhs` are broadcast together and `condition` must be broadcastable to the resulting
+/// shape. The condition is not differentiable; the incoming gradient is routed to the operand
+/// that was selected.
+///
+/// # Arguments
+///
+/// * `condition` - selection mask.
+///
+/// * `lhs` - variable picked where the condition holds.
+///
+/// * `rhs` - variable picked elsewhere.
+///
+/// # Panics
+///
+/// If `lhs` and `rhs` cannot be broadcast together or if `condition` cannot be broadcast to their
+/// shape.
+///
+/// # Examples
+///
+/// ```
+/// # use neuronika_variable as neuronika;
+/// use ndarray::arr1;
+///
+/// let condition = neuronika::from_ndarray(arr1(&[1., 0., -1.]));
+/// let lhs = neuronika::ones(3);
+/// let rhs = neuronika::zeros(3);
+///
+/// let selected = neuronika::select(condition, lhs, rhs);
+/// selected.forward();
+/// assert!(*selected.data() == arr1(&[1., 0., 0.]))
+/// ```
+pub fn select<Lhs, Rhs, D>(
+    condition: Var<D>,
+    lhs: Lhs,
+    rhs: Rhs,
+) -> <Lhs as Select<Rhs, Var<D>>>::Output
+where
+    D: Dimension,
+    Lhs: Select<Rhs, Var<D>>,
+{
+    Select::select(lhs, rhs, condition)
+}
+
+/// Inputs of a [checkpointed](checkpoint()) computation.
+///
+/// It is implemented for [`VarDiff`] and for tuples of up to three checkpointable inputs.
+pub trait Checkpointable {
+    /// Returns a copy of `self` that shares its data and gradient, but is detached from its
+    /// computational graph.
+    fn detach(&self) -> Self;
+
+    /// Merges the computational graph of `self` into the one of `node`.
+    #[doc(hidden)]
+    fn merge_into<D>(self, node: &mut VarDiff<D>)
+    where
+        D: Dimension;
+}
+
+impl<A, B> Checkpointable for (A, B)
+where
+    A: Checkpointable,
+    B: Checkpointable,
+{
+    fn detach(&self) -> Self {
+        (self.0.detach(), self.1.detach())
+    }
+
+    fn merge_into<D>(self, node: &mut VarDiff<D>)
+    where
+        D: Dimension,
+    {
+        self.0.merge_into(node);
+        self.1.merge_into(node);
+    }
+}
+
+impl<A, B, C> Checkpointable for (A, B, C)
+where
+    A: Checkpointable,
+    B: Checkpointable,
+    C: Checkpointable,
+{
+    fn detach(&self) -> Self {
+        (self.0.detach(), self.1.detach(), self.2.detach())
+    }
+
+    fn merge_into<D>(self, node: &mut VarDiff<D>)
+    where
+        D: Dimension,
+    {
+        self.0.merge_into(node);
+        self.1.merge_into(node);
+        self.2.merge_into(node);
+    }
+}
+
+/// Applies `f` to `inputs` without keeping the intermediate results.
+///
+/// The data of the variables computed inside `f` is discarded at the end of each forward pass and
+/// is recomputed by the backward pass, using the same operations, right before it is needed. The
+/// memory used by the activations thus scales with the number of checkpoints rather than with the
+/// depth of the model, at the cost of an additional evaluation of every checkpointed segment.
+///
+/// Every variable computed outside of `f` that `f` depends on must be passed in `inputs`, while
+/// parameters can be simply captured. Operations that draw random numbers, such as dropout, are
+/// sampled again when recomputed.
+///
+/// # Arguments
+///
+/// * `f` - computation to checkpoint.
+///
+/// * `inputs` - inputs of the computation.
+///
+/// # Examples
+///
+/// ```
+/// # use neuronika_variable as neuronika;
+/// let w = neuronika::ones((3, 3)).requires_grad();
+/// let x = neuronika::ones((2, 3)).requires_grad();
+///
+/// let block = |x: neuronika::VarDiff<_>| x.mm(w.clone()).tanh().mm(w.clone()).tanh();
+/// let y = neuronika::checkpoint(block, x.clone()).sum();
+///
+/// y.forward();
+/// y.backward(1.);
+/// ```
+pub fn checkpoint<I, F, D>(f: F, inputs: I) -> VarDiff<D>
+where
+    I: Checkpointable,
+    F: FnOnce(I) -> VarDiff<D>,
+    D: 'static + Dimension,
+{
+    let output = f(inputs.detach());
+    let segment = Rc::new(Segment::new(output.var.history.to_vec(), &output.var.data));
+    let tape = output.history.to_vec();
+
+    let mut node = output.detach();
+    node.var.history.track_all(&output.var.history);
+    inputs.merge_into(&mut node);
+    drop(output);
+
+    let op = Checkpoint::new(segment.clone(), node.var.data.clone());
+    let var = Var::node(node.var.data, Rc::new(op), node.var.history);
+    let op = Rc::new(CheckpointBackward::new(segment, tape));
+
+    VarDiff::node(var, node.grad, (op.clone(), op), node.history)
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
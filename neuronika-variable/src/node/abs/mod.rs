@@ -0,0 +1,99 @@
+use std::rc::Rc;
+
+use ndarray::{Array, Dimension, Zip};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    gradient::Gradient,
+    onnx::Operator,
+    utils::Shared,
+};
+
+pub(crate) struct Abs<D>
+where
+    D: Dimension,
+{
+    operand_data: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D>>,
+}
+
+impl<D> Abs<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(operand_data: Shared<Array<f32, D>>, data: Shared<Array<f32, D>>) -> Self {
+        Self { operand_data, data }
+    }
+}
+
+impl<D> Forward for Abs<D>
+where
+    D: Dimension,
+{
+    fn forward(&self) {
+        Zip::from(&mut *self.data.borrow_mut())
+            .and(&*self.operand_data.borrow())
+            .for_each(|v, &o| *v = o.abs());
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("Abs"))
+    }
+}
+
+pub(crate) struct AbsBackward<D>
+where
+    D: Dimension,
+{
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    operand_data: Shared<Array<f32, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+}
+
+impl<D> AbsBackward<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        operand_data: Shared<Array<f32, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+    ) -> Self {
+        Self {
+            operand_gradient,
+            operand_data,
+            gradient,
+        }
+    }
+}
+
+impl<D> Backward for AbsBackward<D>
+where
+    D: Dimension,
+{
+    fn backward(&self) {
+        Zip::from(&mut *self.operand_gradient.borrow_mut())
+            .and(&*self.gradient.borrow())
+            .and(&*self.operand_data.borrow())
+            .for_each(|op_grad_el, &grad_el, &data_el| {
+                *op_grad_el += if data_el > 0. {
+                    grad_el
+                } else if data_el < 0. {
+                    -grad_el
+                } else {
+                    0.
+                }
+            });
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,75 @@
+use std::error::Error;
+
+use ndarray::Array;
+
+use crate::utils::{are_similar, new_shared};
+
+#[cfg(test)]
+mod forward {
+    use super::super::{Abs, Forward};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_data = Array::zeros((3, 3));
+        let data = Array::ones((3, 3));
+        let op = Abs::new(new_shared(operand_data.clone()), new_shared(data.clone()));
+
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.data.borrow(), &data)
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let op = Abs::new(
+            new_shared(Array::linspace(-4., 4., 9).into_shape((3, 3))?),
+            new_shared(Array::zeros((3, 3))),
+        );
+
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::from_shape_vec((3, 3), vec![4., 3., 2., 1., 0., 1., 2., 3., 4.])?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod backward {
+    use std::rc::Rc;
+
+    use super::super::{AbsBackward, Backward, Gradient};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_gradient = Array::zeros((3, 3));
+        let operand_data = Array::ones((3, 3));
+        let gradient = Array::from_elem((3, 3), 2.);
+        let op = AbsBackward::new(
+            Rc::new(Gradient::from_ndarray(operand_gradient.clone())),
+            new_shared(operand_data.clone()),
+            Rc::new(Gradient::from_ndarray(gradient.clone())),
+        );
+
+        are_similar(op.operand_gradient.borrow(), &operand_gradient)?;
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.gradient.borrow(), &gradient)
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let op = AbsBackward::new(
+            Rc::new(Gradient::ndarray_zeros((3, 3))),
+            new_shared(Array::linspace(-4., 4., 9).into_shape((3, 3))?),
+            Rc::new(Gradient::from_ndarray(Array::ones((3, 3)))),
+        );
+        let expected = Array::from_shape_vec((3, 3), vec![-1., -1., -1., -1., 0., 1., 1., 1., 1.])?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &expected)?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &(expected * 2.))
+    }
+}
@@ -3,7 +3,7 @@ use std::rc::Rc;
 use ndarray::{arr0, Array, Dimension, Ix0, Zip};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
     utils::Shared,
     Reduction,
@@ -55,6 +55,14 @@ where
             }
         };
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.input_data, &self.target_data]
+    }
 }
 
 pub struct AbsoluteErrorBackward<D>
@@ -3,8 +3,10 @@ use std::rc::Rc;
 use ndarray::{Array, DimMax, Dimension, Zip};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
+    fusion::Kernel,
     gradient::Gradient,
+    onnx::Operator,
     utils::{accumulate, Broadcast, Shared},
 };
 
@@ -47,6 +49,22 @@ where
             .and_broadcast(&*self.right_data.borrow())
             .for_each(|v, &l, &r| *v = l + r);
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.left_data, &self.right_data]
+    }
+
+    fn kernel(&self) -> Option<Kernel> {
+        Some(Kernel::Binary(|l, r| l + r, |_, _, _| (1., 1.)))
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("Add"))
+    }
 }
 pub(crate) struct AdditionBackwardLeft<D, E>
 where
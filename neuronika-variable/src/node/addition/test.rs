@@ -68,7 +68,7 @@ mod forward {
 }
 
 mod backward {
-    use ndarray::{Ix1, Ix2};
+    use ndarray::{Ix1, Ix2, Ix3};
 
     use super::super::{
         AdditionBackward, AdditionBackwardLeft, AdditionBackwardRight, Backward, Gradient,
@@ -171,6 +171,47 @@ mod backward {
         are_similar(op.gradient.borrow(), &grad)
     }
 
+    #[test]
+    fn right_stretched_reduction() -> Result<(), Box<dyn Error>> {
+        let grad = Array::linspace(1., 9., 9).into_shape((3, 3))?;
+        let op = AdditionBackwardRight::<Ix2, Ix2>::new(
+            Rc::new(Gradient::ndarray_zeros((1, 3))),
+            Rc::new(Gradient::from_ndarray(grad.clone())),
+        );
+
+        op.backward();
+        are_similar(
+            op.operand_gradient.borrow(),
+            &Array::from_shape_vec((1, 3), vec![12., 15., 18.])?,
+        )?;
+
+        let op = AdditionBackwardRight::<Ix2, Ix2>::new(
+            Rc::new(Gradient::ndarray_zeros((3, 1))),
+            Rc::new(Gradient::from_ndarray(grad)),
+        );
+
+        op.backward();
+        are_similar(
+            op.operand_gradient.borrow(),
+            &Array::from_shape_vec((3, 1), vec![6., 15., 24.])?,
+        )
+    }
+
+    #[test]
+    fn right_leading_reduction() -> Result<(), Box<dyn Error>> {
+        let grad = Array::linspace(1., 12., 12).into_shape((2, 2, 3))?;
+        let op = AdditionBackwardRight::<Ix3, Ix1>::new(
+            Rc::new(Gradient::ndarray_zeros(3)),
+            Rc::new(Gradient::from_ndarray(grad)),
+        );
+
+        op.backward();
+        are_similar(
+            op.operand_gradient.borrow(),
+            &Array::from(vec![22., 26., 30.]),
+        )
+    }
+
     #[test]
     fn backward() -> Result<(), Box<dyn Error>> {
         let left = Array::zeros((3, 3));
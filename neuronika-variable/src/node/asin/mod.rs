@@ -0,0 +1,88 @@
+use std::rc::Rc;
+
+use ndarray::{Array, Dimension, Zip};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    gradient::Gradient,
+    utils::Shared,
+};
+
+pub(crate) struct Asin<D>
+where
+    D: Dimension,
+{
+    operand_data: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D>>,
+}
+
+impl<D> Asin<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(operand_data: Shared<Array<f32, D>>, data: Shared<Array<f32, D>>) -> Self {
+        Self { operand_data, data }
+    }
+}
+
+impl<D> Forward for Asin<D>
+where
+    D: Dimension,
+{
+    fn forward(&self) {
+        Zip::from(&mut *self.data.borrow_mut())
+            .and(&*self.operand_data.borrow())
+            .for_each(|v, &o| *v = o.asin());
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+}
+
+pub(crate) struct AsinBackward<D>
+where
+    D: Dimension,
+{
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    operand_data: Shared<Array<f32, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+}
+
+impl<D> AsinBackward<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        operand_data: Shared<Array<f32, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+    ) -> Self {
+        Self {
+            operand_gradient,
+            operand_data,
+            gradient,
+        }
+    }
+}
+
+impl<D> Backward for AsinBackward<D>
+where
+    D: Dimension,
+{
+    fn backward(&self) {
+        Zip::from(&mut *self.operand_gradient.borrow_mut())
+            .and(&*self.gradient.borrow())
+            .and(&*self.operand_data.borrow())
+            .for_each(|op_grad_el, &grad_el, &op_data_el| {
+                *op_grad_el += grad_el / (1. - op_data_el * op_data_el).sqrt()
+            });
+    }
+}
+
+#[cfg(test)]
+mod test;
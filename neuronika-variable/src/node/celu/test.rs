@@ -0,0 +1,98 @@
+use std::error::Error;
+
+use ndarray::Array;
+
+use crate::utils::{are_similar, new_shared};
+
+#[cfg(test)]
+mod forward {
+    use super::super::{Forward, CELU};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_data = Array::zeros((3, 3));
+        let data = Array::ones((3, 3));
+        let op = CELU::new(
+            new_shared(operand_data.clone()),
+            new_shared(data.clone()),
+            1.5,
+        );
+
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.data.borrow(), &data)?;
+        assert_eq!(op.alpha, 1.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let op = CELU::new(
+            new_shared(Array::linspace(-3.5, 4.5, 9).into_shape((3, 3))?),
+            new_shared(Array::zeros((3, 3))),
+            1.5,
+        );
+
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::from_shape_vec(
+                (3, 3),
+                vec![
+                    -1.3545, -1.2167, -0.9482, -0.4252, 0.5000, 1.5000, 2.5000, 3.5000, 4.5000,
+                ],
+            )?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod backward {
+    use std::rc::Rc;
+
+    use super::super::{Backward, CELUBackward, Gradient};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_gradient = Array::zeros((3, 3));
+        let operand_data = Array::ones((3, 3));
+        let gradient = Array::from_elem((3, 3), 2.);
+        let op = CELUBackward::new(
+            Rc::new(Gradient::from_ndarray(operand_gradient.clone())),
+            new_shared(operand_data.clone()),
+            Rc::new(Gradient::from_ndarray(gradient.clone())),
+            1.5,
+        );
+
+        are_similar(op.operand_gradient.borrow(), &operand_gradient)?;
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.gradient.borrow(), &gradient)?;
+        assert_eq!(op.alpha, 1.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let op = CELUBackward::new(
+            Rc::new(Gradient::ndarray_zeros((3, 3))),
+            new_shared(Array::linspace(-3.5, 4.5, 9).into_shape((3, 3))?),
+            Rc::new(Gradient::from_ndarray(Array::ones((3, 3)))),
+            1.5,
+        );
+        let expected = Array::from_shape_vec(
+            (3, 3),
+            vec![
+                0.0970, 0.1889, 0.3679, 0.7165, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000,
+            ],
+        )?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &expected)?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &(expected * 2.))
+    }
+}
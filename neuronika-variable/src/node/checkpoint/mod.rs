@@ -0,0 +1,183 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use ndarray::{Array, Dimension};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    gradient::NoGrad,
+    planner::Pool,
+    utils::Shared,
+};
+
+/// A checkpointed sub-graph. The results of its operations are discarded after the evaluation and
+/// recomputed when they are needed by the backward pass.
+pub(crate) struct Segment {
+    tape: Vec<Rc<dyn Forward>>,
+    data: usize,
+    owners: HashMap<usize, usize>,
+    released: RefCell<HashMap<usize, Vec<usize>>>,
+}
+
+impl Segment {
+    /// Creates a new segment.
+    ///
+    /// # Arguments
+    ///
+    /// * `tape` - operations of the sub-graph, in topological order.
+    ///
+    /// * `data` - buffer holding the result of the sub-graph, which is never discarded.
+    pub(crate) fn new(tape: Vec<(Rc<dyn Forward>, Cell<bool>)>, data: &dyn Buffer) -> Self {
+        let owners = tape
+            .iter()
+            .map(|(op, _)| (op.data().address(), op.data().owners()))
+            .collect();
+
+        Self {
+            tape: tape.into_iter().map(|(op, _)| op).collect(),
+            data: data.address(),
+            owners,
+            released: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Evaluates all the operations of the segment and then discards the intermediate results.
+    fn forward(&self) {
+        let mut pool = Pool::default();
+        let mut released = self.released.borrow_mut();
+        self.tape.iter().for_each(|op| {
+            if let Some(shape) = released.remove(&op.data().address()) {
+                op.data().reserve(&shape, &mut pool);
+            }
+            op.forward();
+        });
+        drop(released);
+
+        self.release();
+    }
+
+    /// Evaluates again the operations whose results have been discarded.
+    fn recompute(&self) {
+        let mut pool = Pool::default();
+        let mut released = self.released.borrow_mut();
+        self.tape.iter().for_each(|op| {
+            if let Some(shape) = released.remove(&op.data().address()) {
+                op.data().reserve(&shape, &mut pool);
+                op.forward();
+            }
+        });
+    }
+
+    /// Discards the intermediate results of the segment. The results of operations that also
+    /// belong to other graphs are kept, and so are the ones that gained an owner since the
+    /// creation of the segment, such as a variable or an operation built on them afterwards.
+    fn release(&self) {
+        let mut pool = Pool::default();
+        let mut released = self.released.borrow_mut();
+        self.tape
+            .iter()
+            .filter(|op| Rc::strong_count(op) == 1)
+            .map(|op| op.data())
+            .for_each(|data| {
+                let address = data.address();
+                if address == self.data
+                    || released.contains_key(&address)
+                    || data.owners() > self.owners[&address]
+                {
+                    return;
+                }
+
+                if let Some(shape) = data.release(&mut pool) {
+                    released.insert(address, shape);
+                }
+            });
+    }
+
+    /// Returns the buffers read by the segment that are not computed by it.
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        let results: HashSet<usize> = self.tape.iter().map(|op| op.data().address()).collect();
+        let mut seen = HashSet::new();
+
+        self.tape
+            .iter()
+            .flat_map(|op| op.operands())
+            .filter(|operand| {
+                let address = operand.address();
+                !results.contains(&address) && seen.insert(address)
+            })
+            .collect()
+    }
+}
+
+pub(crate) struct Checkpoint<D>
+where
+    D: Dimension,
+{
+    segment: Rc<Segment>,
+    data: Shared<Array<f32, D>>,
+}
+
+impl<D> Checkpoint<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(segment: Rc<Segment>, data: Shared<Array<f32, D>>) -> Self {
+        Self { segment, data }
+    }
+}
+
+impl<D> Forward for Checkpoint<D>
+where
+    D: Dimension,
+{
+    fn forward(&self) {
+        self.segment.forward();
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        self.segment.operands()
+    }
+
+    fn is_volatile(&self) -> bool {
+        self.segment.tape.iter().any(|op| op.is_volatile())
+    }
+}
+
+pub(crate) struct CheckpointBackward {
+    segment: Rc<Segment>,
+    tape: Vec<(Rc<dyn Backward>, Rc<dyn NoGrad>)>,
+}
+
+impl CheckpointBackward {
+    pub(crate) fn new(segment: Rc<Segment>, tape: Vec<(Rc<dyn Backward>, Rc<dyn NoGrad>)>) -> Self {
+        Self { segment, tape }
+    }
+}
+
+impl Backward for CheckpointBackward {
+    fn backward(&self) {
+        self.segment.recompute();
+        self.tape.iter().rev().for_each(|(op, _)| op.backward());
+        self.segment.release();
+    }
+}
+
+impl NoGrad for CheckpointBackward {
+    fn no_grad(&self) {
+        self.tape.iter().for_each(|(_, grad)| grad.no_grad());
+    }
+
+    fn with_grad(&self) {
+        self.tape.iter().for_each(|(_, grad)| grad.with_grad());
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,148 @@
+use std::{cell::Cell, error::Error, rc::Rc};
+
+use ndarray::{Array, Ix2};
+
+use crate::{
+    autograd::Forward,
+    node::{Exp, Negation},
+    utils::{are_similar, new_shared, Shared},
+};
+
+use super::Segment;
+
+/// Returns the shared buffers of *x*, *exp(x)* and *-exp(x)*.
+#[allow(clippy::type_complexity)]
+fn buffers() -> Result<
+    (
+        Shared<Array<f32, Ix2>>,
+        Shared<Array<f32, Ix2>>,
+        Shared<Array<f32, Ix2>>,
+    ),
+    Box<dyn Error>,
+> {
+    let input = new_shared(Array::linspace(-4., 4., 9).into_shape((3, 3))?);
+    let exp = new_shared(Array::zeros((3, 3)));
+    let data = new_shared(Array::zeros((3, 3)));
+
+    Ok((input, exp, data))
+}
+
+/// Returns a segment computing *-exp(x)* from the buffers returned by [`buffers()`].
+fn segment(
+    input: &Shared<Array<f32, Ix2>>,
+    exp: &Shared<Array<f32, Ix2>>,
+    data: &Shared<Array<f32, Ix2>>,
+) -> Rc<Segment> {
+    let tape: Vec<(Rc<dyn Forward>, Cell<bool>)> = vec![
+        (
+            Rc::new(Exp::new(input.clone(), exp.clone())),
+            Cell::default(),
+        ),
+        (
+            Rc::new(Negation::new(exp.clone(), data.clone())),
+            Cell::default(),
+        ),
+    ];
+
+    Rc::new(Segment::new(tape, data))
+}
+
+#[cfg(test)]
+mod forward {
+    use super::super::{Buffer, Checkpoint, Forward};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let (input, exp, data) = buffers()?;
+        let segment = segment(&input, &exp, &data);
+        let op = Checkpoint::new(segment, data.clone());
+
+        are_similar(op.data.borrow(), &*data.borrow())?;
+
+        let operands = op.operands();
+        assert_eq!(operands.len(), 1);
+        assert_eq!(operands[0].address(), input.address());
+
+        Ok(())
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let (input, exp, data) = buffers()?;
+        let segment = segment(&input, &exp, &data);
+        let op = Checkpoint::new(segment, data.clone());
+
+        op.forward();
+        are_similar(data.borrow(), &-input.borrow().mapv(f32::exp))?;
+        assert_eq!(exp.borrow().len(), 0);
+
+        op.forward();
+        are_similar(data.borrow(), &-input.borrow().mapv(f32::exp))?;
+        assert_eq!(exp.borrow().len(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn shared_result() -> Result<(), Box<dyn Error>> {
+        let (input, exp, data) = buffers()?;
+        let segment = segment(&input, &exp, &data);
+        let op = Checkpoint::new(segment, data.clone());
+
+        // An owner gained after the creation of the segment keeps the result alive.
+        let owner = exp.clone();
+        op.forward();
+        are_similar(owner.borrow(), &input.borrow().mapv(f32::exp))?;
+
+        drop(owner);
+        op.forward();
+        assert_eq!(exp.borrow().len(), 0);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod backward {
+    use super::super::{Backward, Checkpoint, CheckpointBackward, Forward, NoGrad};
+    use super::*;
+    use crate::{
+        gradient::Gradient,
+        node::{ExpBackward, NegationBackward},
+    };
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let (input, exp, data) = buffers()?;
+        let input_gradient = Rc::new(Gradient::ndarray_zeros((3, 3)));
+        let exp_gradient = Rc::new(Gradient::ndarray_zeros((3, 3)));
+        let gradient = Rc::new(Gradient::from_ndarray(Array::ones((3, 3))));
+        let tape: Vec<(Rc<dyn Backward>, Rc<dyn NoGrad>)> = vec![
+            (
+                Rc::new(ExpBackward::new(
+                    input_gradient.clone(),
+                    exp.clone(),
+                    exp_gradient.clone(),
+                )),
+                exp_gradient.clone(),
+            ),
+            (
+                Rc::new(NegationBackward::new(exp_gradient, gradient.clone())),
+                gradient,
+            ),
+        ];
+        // As in a graph, the segment is created after the backward operations.
+        let segment = segment(&input, &exp, &data);
+        let forward_op = Checkpoint::new(segment.clone(), data);
+        let op = CheckpointBackward::new(segment, tape);
+        let expected = -input.borrow().mapv(f32::exp);
+
+        forward_op.forward();
+        assert_eq!(exp.borrow().len(), 0);
+
+        op.backward();
+        assert_eq!(exp.borrow().len(), 0);
+        are_similar(input_gradient.borrow(), &expected)
+    }
+}
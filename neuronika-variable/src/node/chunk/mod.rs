@@ -3,7 +3,7 @@ use std::rc::Rc;
 use ndarray::{Array, Dimension};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
     utils::Shared,
 };
@@ -61,6 +61,14 @@ where
 
         self.data.borrow_mut().assign(&operand_data_chunk);
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
 }
 
 pub(crate) struct ChunkBackward<D>
@@ -0,0 +1,123 @@
+use std::rc::Rc;
+
+use ndarray::{Array, Dimension, Zip};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    gradient::Gradient,
+    onnx::{Initializer, Operator},
+    utils::Shared,
+};
+
+pub(crate) struct Clamp<D>
+where
+    D: Dimension,
+{
+    operand_data: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D>>,
+    min: f32,
+    max: f32,
+}
+
+impl<D> Clamp<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(
+        operand_data: Shared<Array<f32, D>>,
+        data: Shared<Array<f32, D>>,
+        min: f32,
+        max: f32,
+    ) -> Self {
+        debug_assert!(min <= max);
+
+        Self {
+            operand_data,
+            data,
+            min,
+            max,
+        }
+    }
+}
+
+impl<D> Forward for Clamp<D>
+where
+    D: Dimension,
+{
+    fn forward(&self) {
+        let (min, max) = (self.min, self.max);
+        Zip::from(&mut *self.data.borrow_mut())
+            .and(&*self.operand_data.borrow())
+            .for_each(|v, &o| *v = o.clamp(min, max));
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(
+            Operator::new("Clip")
+                .initializer(Initializer::Float(self.min))
+                .initializer(Initializer::Float(self.max)),
+        )
+    }
+}
+
+pub(crate) struct ClampBackward<D>
+where
+    D: Dimension,
+{
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    operand_data: Shared<Array<f32, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+    min: f32,
+    max: f32,
+}
+
+impl<D> ClampBackward<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        operand_data: Shared<Array<f32, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+        min: f32,
+        max: f32,
+    ) -> Self {
+        debug_assert!(min <= max);
+
+        Self {
+            operand_gradient,
+            operand_data,
+            gradient,
+            min,
+            max,
+        }
+    }
+}
+
+impl<D> Backward for ClampBackward<D>
+where
+    D: Dimension,
+{
+    fn backward(&self) {
+        let (min, max) = (self.min, self.max);
+        Zip::from(&mut *self.operand_gradient.borrow_mut())
+            .and(&*self.gradient.borrow())
+            .and(&*self.operand_data.borrow())
+            .for_each(|op_grad_el, &grad_el, &data_el| {
+                if data_el >= min && data_el <= max {
+                    *op_grad_el += grad_el
+                }
+            });
+    }
+}
+
+#[cfg(test)]
+mod test;
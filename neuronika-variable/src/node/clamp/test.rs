@@ -0,0 +1,90 @@
+use std::error::Error;
+
+use ndarray::Array;
+
+use crate::utils::{are_similar, new_shared};
+
+#[cfg(test)]
+mod forward {
+    use super::super::{Clamp, Forward};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_data = Array::zeros((3, 3));
+        let data = Array::ones((3, 3));
+        let op = Clamp::new(
+            new_shared(operand_data.clone()),
+            new_shared(data.clone()),
+            -1.,
+            1.,
+        );
+
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.data.borrow(), &data)?;
+        assert_eq!(op.min, -1.);
+        assert_eq!(op.max, 1.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let op = Clamp::new(
+            new_shared(Array::linspace(-4., 4., 9).into_shape((3, 3))?),
+            new_shared(Array::zeros((3, 3))),
+            -2.,
+            3.,
+        );
+
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::from_shape_vec((3, 3), vec![-2., -2., -2., -1., 0., 1., 2., 3., 3.])?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod backward {
+    use std::rc::Rc;
+
+    use super::super::{Backward, ClampBackward, Gradient};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_gradient = Array::zeros((3, 3));
+        let operand_data = Array::ones((3, 3));
+        let gradient = Array::from_elem((3, 3), 2.);
+        let op = ClampBackward::new(
+            Rc::new(Gradient::from_ndarray(operand_gradient.clone())),
+            new_shared(operand_data.clone()),
+            Rc::new(Gradient::from_ndarray(gradient.clone())),
+            -1.,
+            1.,
+        );
+
+        are_similar(op.operand_gradient.borrow(), &operand_gradient)?;
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.gradient.borrow(), &gradient)
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let op = ClampBackward::new(
+            Rc::new(Gradient::ndarray_zeros((3, 3))),
+            new_shared(Array::linspace(-4., 4., 9).into_shape((3, 3))?),
+            Rc::new(Gradient::from_ndarray(Array::ones((3, 3)))),
+            -2.,
+            3.,
+        );
+        let expected = Array::from_shape_vec((3, 3), vec![0., 0., 1., 1., 1., 1., 1., 1., 0.])?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &expected)?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &(expected * 2.))
+    }
+}
@@ -3,8 +3,9 @@ use std::rc::Rc;
 use ndarray::{Array, Axis, Dimension, RemoveAxis, Zip};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
+    onnx::{Attribute, Operator},
     utils::Shared,
 };
 
@@ -56,6 +57,18 @@ where
             .and(&*self.right.borrow())
             .for_each(|fused_el, &single_el| *fused_el = single_el);
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.left, &self.right]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("Concat").attribute("axis", Attribute::Int(self.axis.index() as i64)))
+    }
 }
 
 pub(crate) struct ConcatenateBackwardLeft<D>
@@ -0,0 +1,110 @@
+use std::{cell::RefCell, collections::HashMap, time::Instant};
+
+use ndarray::{ArrayBase, Data, DataMut, Dimension, RemoveAxis};
+
+use super::{convolution, fft, winograd};
+
+/// Algorithms computing the forward pass of a convolution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Algorithm {
+    /// Lowering of the input to columns, followed by a matrix product.
+    Gemm,
+    /// Winograd minimal filtering, for kernels of size 3.
+    Winograd,
+    /// Products in the frequency domain, for large kernels.
+    Fft,
+}
+
+/// Shapes of the input and of the kernel, stride, dilation and groups of a convolution.
+type Key = (Vec<usize>, Vec<usize>, Vec<usize>, Vec<usize>, usize);
+
+thread_local! {
+    /// Fastest algorithm measured for each of the convolutions computed so far.
+    static SELECTED: RefCell<HashMap<Key, Algorithm>> = RefCell::new(HashMap::new());
+}
+
+impl Algorithm {
+    /// Returns the algorithms able to compute a convolution with the given parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel_shape` - shape of the kernel.
+    ///
+    /// * `stride` - stride.
+    ///
+    /// * `dilation` - dilation.
+    pub(super) fn candidates(
+        kernel_shape: &[usize],
+        stride: &[usize],
+        dilation: &[usize],
+    ) -> Vec<Self> {
+        let mut candidates = vec![Self::Gemm];
+        if winograd::is_supported(kernel_shape, stride, dilation) {
+            candidates.push(Self::Winograd);
+        }
+        if fft::is_supported(kernel_shape, stride, dilation) {
+            candidates.push(Self::Fft);
+        }
+
+        candidates
+    }
+
+    /// Computes a convolution with this algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - input map.
+    ///
+    /// * `kernel` - kernel.
+    ///
+    /// * `output` - output map.
+    ///
+    /// * `stride` - stride.
+    ///
+    /// * `dilation` - dilation.
+    pub(super) fn convolution<
+        D: Dimension + RemoveAxis,
+        S: Data<Elem = f32>,
+        U: Data<Elem = f32>,
+        T: DataMut<Elem = f32>,
+    >(
+        self,
+        input: &ArrayBase<S, D>,
+        kernel: &ArrayBase<U, D>,
+        output: &mut ArrayBase<T, D>,
+        stride: &[usize],
+        dilation: &[usize],
+    ) {
+        match self {
+            Self::Gemm => convolution(input, kernel, output, stride, dilation),
+            Self::Winograd => winograd::convolution(input, kernel, output),
+            Self::Fft => fft::convolution(input, kernel, output, dilation),
+        }
+    }
+}
+
+/// Returns the fastest algorithm for a convolution, if it has already been measured.
+pub(super) fn selected(key: &Key) -> Option<Algorithm> {
+    SELECTED.with(|selected| selected.borrow().get(key).copied())
+}
+
+/// Computes a convolution with each of `candidates`, remembering the fastest one for `key`.
+///
+/// As each candidate computes the whole convolution, `compute` must overwrite its output.
+pub(super) fn benchmark<F>(key: Key, candidates: &[Algorithm], mut compute: F)
+where
+    F: FnMut(Algorithm),
+{
+    let fastest = candidates
+        .iter()
+        .map(|algorithm| {
+            let start = Instant::now();
+            compute(*algorithm);
+            (start.elapsed(), *algorithm)
+        })
+        .min_by_key(|(elapsed, _)| *elapsed)
+        .map(|(_, algorithm)| algorithm)
+        .unwrap_or(Algorithm::Gemm);
+
+    SELECTED.with(|selected| selected.borrow_mut().insert(key, fastest));
+}
@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use ndarray::{ArrayBase, ArrayViewMutD, Axis, Data, DataMut, Dimension, RemoveAxis, Zip};
+
+use rustfft::{num_complex::Complex, Fft, FftPlanner};
+
+use super::unravel;
+
+/// Smallest extent, along every spatial dimension, of a dilated kernel for which the FFT algorithm
+/// is considered. Smaller kernels are always faster with the other algorithms.
+const MIN_KERNEL_EXTENT: usize = 5;
+
+/// Computes in place the `n`-dimensional discrete Fourier transform of `buffer`, applying the
+/// corresponding one-dimensional transform of `ffts` along each axis.
+///
+/// # Arguments
+///
+/// * `buffer` - array of shape `shape`, in row-major order.
+///
+/// * `shape` - shape of the array.
+///
+/// * `ffts` - transforms, one for each axis.
+fn fft_nd(buffer: &mut [Complex<f32>], shape: &[usize], ffts: &[Arc<dyn Fft<f32>>]) {
+    let mut lane = Vec::new();
+    for (axis, fft) in ffts.iter().enumerate() {
+        let len = shape[axis];
+        let inner: usize = shape[axis + 1..].iter().product();
+        if inner == 1 {
+            fft.process(buffer);
+            continue;
+        }
+
+        lane.resize(len, Complex::default());
+        let outer = buffer.len() / (len * inner);
+        for o in 0..outer {
+            for i in 0..inner {
+                let start = o * len * inner + i;
+                lane.iter_mut()
+                    .enumerate()
+                    .for_each(|(k, lane_el)| *lane_el = buffer[start + k * inner]);
+                fft.process(&mut lane);
+                lane.iter()
+                    .enumerate()
+                    .for_each(|(k, lane_el)| buffer[start + k * inner] = *lane_el);
+            }
+        }
+    }
+}
+
+/// Returns the position, in the row-major order of an array of shape `shape`, of the element at
+/// `multi_index`.
+fn ravel(multi_index: &[usize], shape: &[usize]) -> usize {
+    multi_index
+        .iter()
+        .zip(shape)
+        .fold(0, |offset, (position, len)| offset * len + position)
+}
+
+/// Returns `true` if the FFT algorithm is worth trying for the convolution. The stride must be
+/// unitary and the dilated kernel must be large.
+pub(super) fn is_supported(kernel_shape: &[usize], stride: &[usize], dilation: &[usize]) -> bool {
+    stride.iter().all(|&len| len == 1)
+        && kernel_shape
+            .iter()
+            .skip(2)
+            .zip(dilation)
+            .all(|(len, dilation)| dilation * (len - 1) + 1 >= MIN_KERNEL_EXTENT)
+}
+
+/// Computes a convolution *(cross correlation)* in the frequency domain.
+///
+/// Each input channel and each kernel are transformed once, the products of their transforms are
+/// accumulated over the input channels and transformed back. As the transforms are as large as the
+/// input, the cost doesn't depend on the size of the kernel. See [`is_supported`] for the
+/// parameters allowed.
+pub(super) fn convolution<
+    D: Dimension + RemoveAxis,
+    S: Data<Elem = f32>,
+    U: Data<Elem = f32>,
+    T: DataMut<Elem = f32>,
+>(
+    input: &ArrayBase<S, D>,
+    kernel: &ArrayBase<U, D>,
+    output: &mut ArrayBase<T, D>,
+    dilation: &[usize],
+) {
+    let n = input.ndim() - 2;
+    let (out_channels, in_channels) = (kernel.len_of(Axis(0)), kernel.len_of(Axis(1)));
+    let input = input.as_standard_layout();
+    let spatial_shape = input.shape()[2..].to_vec();
+    let out_shape = output.shape()[2..].to_vec();
+    let len: usize = spatial_shape.iter().product();
+
+    let mut planner = FftPlanner::new();
+    let forward: Vec<Arc<dyn Fft<f32>>> = spatial_shape
+        .iter()
+        .map(|len| planner.plan_fft_forward(*len))
+        .collect();
+    let inverse: Vec<Arc<dyn Fft<f32>>> = spatial_shape
+        .iter()
+        .map(|len| planner.plan_fft_inverse(*len))
+        .collect();
+
+    // Conjugated transforms of the dilated kernels, laid out as (out channel, in channel, element).
+    let mut kernel_transformed = vec![Complex::default(); out_channels * in_channels * len];
+    let mut multi_index = vec![0; n];
+    kernel
+        .view()
+        .into_dyn()
+        .indexed_iter()
+        .for_each(|(index, value)| {
+            let index = index.slice();
+            index[2..]
+                .iter()
+                .zip(dilation)
+                .zip(&mut multi_index)
+                .for_each(|((position, dilation), dilated)| *dilated = position * dilation);
+            let filter = index[0] * in_channels + index[1];
+            kernel_transformed[filter * len + ravel(&multi_index, &spatial_shape)] =
+                Complex::new(*value, 0.);
+        });
+    kernel_transformed.chunks_mut(len).for_each(|transformed| {
+        fft_nd(transformed, &spatial_shape, &forward);
+        transformed.iter_mut().for_each(|el| *el = el.conj());
+    });
+
+    Zip::from(input.axis_iter(Axis(0)))
+        .and(output.axis_iter_mut(Axis(0)))
+        .par_for_each(|input_sample, output_sample| {
+            let mut input_transformed: Vec<Complex<f32>> = input_sample
+                .iter()
+                .map(|value| Complex::new(*value, 0.))
+                .collect();
+            input_transformed
+                .chunks_mut(len)
+                .for_each(|channel| fft_nd(channel, &spatial_shape, &forward));
+
+            let mut output_sample: ArrayViewMutD<f32> = output_sample.into_dyn();
+            let mut accumulator = vec![Complex::default(); len];
+            let mut multi_index = vec![0; n];
+            let mut position = vec![0; n + 1];
+            let out_len: usize = out_shape.iter().product();
+            for out_channel in 0..out_channels {
+                accumulator
+                    .iter_mut()
+                    .for_each(|el| *el = Complex::default());
+                input_transformed
+                    .chunks(len)
+                    .zip(kernel_transformed[out_channel * in_channels * len..].chunks(len))
+                    .for_each(|(input, kernel)| {
+                        accumulator
+                            .iter_mut()
+                            .zip(input.iter().zip(kernel))
+                            .for_each(|(acc_el, (input_el, kernel_el))| {
+                                *acc_el += input_el * kernel_el
+                            })
+                    });
+                fft_nd(&mut accumulator, &spatial_shape, &inverse);
+
+                // Only the elements whose windows don't wrap around are kept.
+                position[0] = out_channel;
+                for index in 0..out_len {
+                    unravel(index, &out_shape, &mut multi_index);
+                    position[1..].copy_from_slice(&multi_index);
+                    output_sample[&position[..]] =
+                        accumulator[ravel(&multi_index, &spatial_shape)].re / len as f32;
+                }
+            }
+        });
+}
@@ -9,11 +9,18 @@ use ndarray::{
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
+    onnx::{ints, Attribute, Operator},
     utils::{as_windows, as_windows_mut, columns_shape, Shared},
 };
 
+use self::algorithm::Algorithm;
+
+mod algorithm;
+mod fft;
+mod winograd;
+
 /// Iterators needed for the **backward pass** of a grouped convolution.
 type GroupedBackwardArgs<'a, D> = (
     AxisChunksIterMut<'a, f32, D>,
@@ -57,6 +64,14 @@ where
     flat_shape
 }
 
+/// Converts the flat index `index` into the multi-index of an array of shape `shape`.
+fn unravel(mut index: usize, shape: &[usize], multi_index: &mut [usize]) {
+    for (position, len) in multi_index.iter_mut().zip(shape).rev() {
+        *position = index % len;
+        index /= len;
+    }
+}
+
 /// Assigns to the **n**-dimensional feature map's gradient `dest` the **2**-dimensional
 /// array `columns`. This method encapsulates the functionalities of **col2sig**, **col2im** and
 /// **col2vol**.
@@ -122,7 +137,10 @@ fn convolution<
         });
 }
 
+/// Computes a convolution with the given algorithm, splitting it in `groups` independent
+/// convolutions if needed.
 fn grouped_convolution<D>(
+    algorithm: Algorithm,
     input: &Array<f32, D>,
     kernel: &Array<f32, D>,
     output: &mut Array<f32, D>,
@@ -132,6 +150,11 @@ fn grouped_convolution<D>(
 ) where
     D: Dimension + RemoveAxis,
 {
+    if groups < 2 {
+        algorithm.convolution(input, kernel, output, stride, dilation);
+        return;
+    }
+
     let (input_groups, kernel_groups, output_buffer_groups) =
         group_inputs(input, kernel, output, groups);
     kernel_groups
@@ -139,11 +162,49 @@ fn grouped_convolution<D>(
         .zip(input_groups.into_iter())
         .zip(output_buffer_groups.into_iter())
         .for_each(|((kernel, input), mut output)| {
-            convolution(&input, &kernel, &mut output, stride, dilation);
+            algorithm.convolution(&input, &kernel, &mut output, stride, dilation);
         });
 }
 
-fn convolution_backward_input<
+/// Computes a convolution with the fastest algorithm for its parameters.
+///
+/// The first time a convolution with given shapes and parameters is computed, all the algorithms
+/// able to compute it are measured and the fastest one is kept for the following times. The
+/// lowering to columns is the only one available with strides greater than one, Winograd's is
+/// considered for kernels of size 3 and the FFT based one for large kernels.
+pub(super) fn fastest_convolution<D>(
+    input: &Array<f32, D>,
+    kernel: &Array<f32, D>,
+    output: &mut Array<f32, D>,
+    stride: &[usize],
+    dilation: &[usize],
+    groups: usize,
+) where
+    D: Dimension + RemoveAxis,
+{
+    let candidates = Algorithm::candidates(kernel.shape(), stride, dilation);
+    if let [algorithm] = candidates[..] {
+        return grouped_convolution(algorithm, input, kernel, output, stride, dilation, groups);
+    }
+
+    let key = (
+        input.shape().to_vec(),
+        kernel.shape().to_vec(),
+        stride.to_vec(),
+        dilation.to_vec(),
+        groups,
+    );
+    match algorithm::selected(&key) {
+        Some(algorithm) => {
+            grouped_convolution(algorithm, input, kernel, output, stride, dilation, groups)
+        }
+        None => algorithm::benchmark(key, &candidates, |algorithm| {
+            grouped_convolution(algorithm, input, kernel, output, stride, dilation, groups)
+        }),
+    }
+}
+
+pub(super) fn convolution_backward_input<
     D: Dimension + RemoveAxis,
     S: DataMut<Elem = f32>,
     T: Data<Elem = f32>,
@@ -163,10 +224,11 @@ fn convolution_backward_input<
         grad.shape(),
     );
 
+    // The columns are laid out as the windows of the input, one row per output position.
     let mut buffer_shape = Ix3::zeros(3);
     buffer_shape[0] = grad_shape[0];
-    buffer_shape[1] = flattened_kernel.shape()[1];
-    buffer_shape[2] = grad_shape.iter().skip(2).product();
+    buffer_shape[1] = grad_shape.iter().skip(2).product();
+    buffer_shape[2] = flattened_kernel.shape()[1];
     let mut buffer = Array::<f32, Ix3>::zeros(buffer_shape);
 
     Zip::from(grad.axis_iter(Axis(0)))
@@ -178,8 +240,8 @@ fn convolution_backward_input<
                 .unwrap();
             general_mat_mul(
                 1.,
-                &flattened_kernel.t(),
-                &flattened_sample_in,
+                &flattened_sample_in.t(),
+                &flattened_kernel,
                 0.,
                 &mut buffer_sample,
             );
@@ -188,7 +250,7 @@ fn convolution_backward_input<
     assign_from_cols(input_grad, buffer, kernel_shape, stride, dilation);
 }
 
-fn convolution_backward_kernel<
+pub(super) fn convolution_backward_kernel<
     D: Dimension + RemoveAxis,
     S: DataMut<Elem = f32>,
     T: Data<Elem = f32>,
@@ -273,7 +335,7 @@ pub(super) fn grouped_convolution_backward_input<D: Dimension + RemoveAxis>(
         });
 }
 
-fn grouped_convolution_backward_kernel<D: Dimension + RemoveAxis>(
+pub(super) fn grouped_convolution_backward_kernel<D: Dimension + RemoveAxis>(
     kernel_grad: &mut Array<f32, D>,
     grad: &Array<f32, D>,
     input: &Array<f32, D>,
@@ -333,24 +395,31 @@ where
     D: Dimension + RemoveAxis,
 {
     fn forward(&self) {
-        if self.groups < 2 {
-            convolution(
-                &*self.input_data.borrow(),
-                &*self.kernel_data.borrow(),
-                &mut *self.data.borrow_mut(),
-                self.stride.slice(),
-                self.dilation.slice(),
-            );
-        } else {
-            grouped_convolution(
-                &*self.input_data.borrow(),
-                &*self.kernel_data.borrow(),
-                &mut *self.data.borrow_mut(),
-                self.stride.slice(),
-                self.dilation.slice(),
-                self.groups,
-            )
-        }
+        fastest_convolution(
+            &*self.input_data.borrow(),
+            &*self.kernel_data.borrow(),
+            &mut *self.data.borrow_mut(),
+            self.stride.slice(),
+            self.dilation.slice(),
+            self.groups,
+        );
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.input_data, &self.kernel_data]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(
+            Operator::new("Conv")
+                .attribute("strides", Attribute::Ints(ints(self.stride.slice())))
+                .attribute("dilations", Attribute::Ints(ints(self.dilation.slice())))
+                .attribute("group", Attribute::Int(self.groups as i64)),
+        )
     }
 }
 
@@ -1,10 +1,11 @@
-use ndarray::{array, stack, Array, Axis, Ix3, Ix4, Ix5};
+use ndarray::{array, stack, Array, Axis, Dimension, Ix3, Ix4, Ix5, RemoveAxis};
 
 use crate::utils::{check_conv_args, check_groups_args, conv_out_shape};
 
 use super::{
+    algorithm::{self, Algorithm},
     as_windows, columns_shape, convolution, convolution_backward_input,
-    convolution_backward_kernel, flat_shape, grouped_convolution,
+    convolution_backward_kernel, fastest_convolution, flat_shape, grouped_convolution,
     grouped_convolution_backward_input, grouped_convolution_backward_kernel,
 };
 
@@ -694,6 +695,18 @@ fn conv3d_strided() {
     );
 }
 
+#[test]
+fn conv1d_strided_backward_input() {
+    // The gradient is not uniform, so that the windows it is scattered to are distinguishable.
+    let kernel = Array::<f32, _>::ones((1, 1, 3));
+    let d_out = array![[[1., 2.]]];
+    let mut input_grad = Array::<f32, _>::zeros((1, 1, 5));
+
+    convolution_backward_input(&mut input_grad, &d_out, &kernel, &[2], &[1]);
+
+    assert_eq!(input_grad, array![[[1., 1., 3., 2., 2.]]]);
+}
+
 #[test]
 fn conv1d_dilated() {
     let input_elems = (0..150).map(|el| el as f32).collect::<Array<f32, _>>();
@@ -954,7 +967,15 @@ fn grouped_conv1d() {
     // Convolution result
     let mut conv_out = Array::<f32, _>::zeros(conv_out_shape);
 
-    grouped_convolution(&input, &kernel, &mut conv_out, stride, dilation, groups);
+    grouped_convolution(
+        Algorithm::Gemm,
+        &input,
+        &kernel,
+        &mut conv_out,
+        stride,
+        dilation,
+        groups,
+    );
 
     assert_eq!(
         conv_out,
@@ -1016,7 +1037,15 @@ fn grouped_conv2d() {
     // Convolution result
     let mut conv_out = Array::<f32, _>::zeros(conv_out_shape);
 
-    grouped_convolution(&input, &kernel, &mut conv_out, stride, dilation, groups);
+    grouped_convolution(
+        Algorithm::Gemm,
+        &input,
+        &kernel,
+        &mut conv_out,
+        stride,
+        dilation,
+        groups,
+    );
 
     let true_output_elems = vec![
         648., 664., 680., 696., 728., 744., 760., 776., 808., 824., 840., 856., 888., 904., 920.,
@@ -1157,7 +1186,15 @@ fn grouped_conv3d() {
     // Convolution result
     let mut conv_out = Array::<f32, _>::zeros(conv_out_shape);
 
-    grouped_convolution(&input, &kernel, &mut conv_out, stride, dilation, groups);
+    grouped_convolution(
+        Algorithm::Gemm,
+        &input,
+        &kernel,
+        &mut conv_out,
+        stride,
+        dilation,
+        groups,
+    );
 
     let true_output_elems = vec![
         1296., 1456., 1696., 1856., 2096., 2256., 2496., 2656., 1296., 1456., 1696., 1856., 2096.,
@@ -1326,3 +1363,194 @@ fn grouped_conv3d() {
         Array::from_shape_vec(kernel_grad.raw_dim(), true_kernel_grad_elems).unwrap(),
     );
 }
+
+/// Computes a convolution of deterministic operands of the given shapes both with `algorithm` and
+/// by lowering to columns, returning the two results.
+fn against_gemm<D>(
+    algorithm: Algorithm,
+    input_shape: D,
+    kernel_shape: D,
+    stride: &[usize],
+    dilation: &[usize],
+    groups: usize,
+) -> (Array<f32, D>, Array<f32, D>)
+where
+    D: Dimension + RemoveAxis,
+{
+    let input = (0..input_shape.size())
+        .map(|i| ((i * 7) % 13) as f32 / 13. - 0.5)
+        .collect::<Array<f32, _>>()
+        .into_shape(input_shape.clone())
+        .unwrap();
+    let kernel = (0..kernel_shape.size())
+        .map(|i| ((i * 5) % 11) as f32 / 11. - 0.5)
+        .collect::<Array<f32, _>>()
+        .into_shape(kernel_shape.clone())
+        .unwrap();
+    let shape = conv_out_shape::<D>(input.shape(), kernel.shape(), stride, dilation);
+
+    let mut result = Array::zeros(shape.clone());
+    grouped_convolution(
+        algorithm,
+        &input,
+        &kernel,
+        &mut result,
+        stride,
+        dilation,
+        groups,
+    );
+    let mut expected = Array::zeros(shape);
+    grouped_convolution(
+        Algorithm::Gemm,
+        &input,
+        &kernel,
+        &mut expected,
+        stride,
+        dilation,
+        groups,
+    );
+
+    (result, expected)
+}
+
+#[test]
+fn algorithm_candidates() {
+    assert_eq!(
+        Algorithm::candidates(&[4, 2, 3, 3], &[1, 1], &[1, 1]),
+        vec![Algorithm::Gemm, Algorithm::Winograd]
+    );
+    assert_eq!(
+        Algorithm::candidates(&[4, 2, 3, 3], &[2, 2], &[1, 1]),
+        vec![Algorithm::Gemm]
+    );
+    assert_eq!(
+        Algorithm::candidates(&[4, 2, 3, 3], &[1, 1], &[2, 2]),
+        vec![Algorithm::Gemm, Algorithm::Fft]
+    );
+    assert_eq!(
+        Algorithm::candidates(&[4, 2, 7, 7], &[1, 1], &[1, 1]),
+        vec![Algorithm::Gemm, Algorithm::Fft]
+    );
+}
+
+#[test]
+fn winograd_conv1d() {
+    let (result, expected) = against_gemm(
+        Algorithm::Winograd,
+        Ix3(2, 3, 10),
+        Ix3(4, 3, 3),
+        &[1],
+        &[1],
+        1,
+    );
+    assert!(result.abs_diff_eq(&expected, 1e-4));
+}
+
+#[test]
+fn winograd_conv2d() {
+    let (result, expected) = against_gemm(
+        Algorithm::Winograd,
+        Ix4(2, 4, 7, 8),
+        Ix4(6, 2, 3, 3),
+        &[1, 1],
+        &[1, 1],
+        2,
+    );
+    assert!(result.abs_diff_eq(&expected, 1e-4));
+}
+
+#[test]
+fn winograd_conv3d() {
+    let (result, expected) = against_gemm(
+        Algorithm::Winograd,
+        Ix5(2, 2, 5, 6, 7),
+        Ix5(3, 2, 3, 3, 3),
+        &[1, 1, 1],
+        &[1, 1, 1],
+        1,
+    );
+    assert!(result.abs_diff_eq(&expected, 1e-4));
+}
+
+#[test]
+fn fft_conv1d() {
+    let (result, expected) =
+        against_gemm(Algorithm::Fft, Ix3(2, 3, 20), Ix3(4, 3, 9), &[1], &[1], 1);
+    assert!(result.abs_diff_eq(&expected, 1e-4));
+}
+
+#[test]
+fn fft_conv2d() {
+    let (result, expected) = against_gemm(
+        Algorithm::Fft,
+        Ix4(2, 4, 12, 11),
+        Ix4(6, 2, 3, 5),
+        &[1, 1],
+        &[3, 2],
+        2,
+    );
+    assert!(result.abs_diff_eq(&expected, 1e-4));
+}
+
+#[test]
+fn fft_conv3d() {
+    let (result, expected) = against_gemm(
+        Algorithm::Fft,
+        Ix5(1, 2, 8, 9, 10),
+        Ix5(3, 2, 5, 5, 6),
+        &[1, 1, 1],
+        &[1, 1, 1],
+        1,
+    );
+    assert!(result.abs_diff_eq(&expected, 1e-4));
+}
+
+#[test]
+fn fastest_convolution_selects() {
+    let input = Array::linspace(-1., 1., 2 * 3 * 6 * 6)
+        .into_shape((2, 3, 6, 6))
+        .unwrap();
+    let kernel = Array::linspace(-1., 1., 4 * 3 * 3 * 3)
+        .into_shape((4, 3, 3, 3))
+        .unwrap();
+    let key = (
+        input.shape().to_vec(),
+        kernel.shape().to_vec(),
+        vec![1, 1],
+        vec![1, 1],
+        1,
+    );
+    assert!(algorithm::selected(&key).is_none());
+
+    let mut expected = Array::zeros((2, 4, 4, 4));
+    convolution(&input, &kernel, &mut expected, &[1, 1], &[1, 1]);
+
+    // The first call measures the candidates, the second one uses the fastest.
+    for _ in 0..2 {
+        let mut result = Array::zeros((2, 4, 4, 4));
+        fastest_convolution(&input, &kernel, &mut result, &[1, 1], &[1, 1], 1);
+        assert!(result.abs_diff_eq(&expected, 1e-4));
+    }
+    assert!(algorithm::selected(&key).is_some());
+}
+
+#[test]
+fn convolution_tracks_input_history() {
+    use neuronika_core::Convolution;
+
+    // The input is the result of an operation, which must be computed before the convolution.
+    let input = crate::ones((1, 1, 3)) * 2.;
+    let output = crate::ones((1, 1, 2)).convolution(input.clone(), 1, 1, 1);
+    assert_eq!(output.history.len(), 2);
+
+    output.forward();
+    assert_eq!(*output.data(), array![[[4., 4.]]]);
+
+    let kernel = crate::ones((1, 1, 2)).requires_grad();
+    let output = kernel.clone().convolution(input, 1, 1, 1);
+    output.forward();
+    assert_eq!(*output.data(), array![[[4., 4.]]]);
+
+    output.backward(1.);
+    assert_eq!(*kernel.grad(), array![[[4., 4.]]]);
+}
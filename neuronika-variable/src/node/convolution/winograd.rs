@@ -0,0 +1,222 @@
+use ndarray::{
+    linalg::general_mat_mul, s, Array, Array3, ArrayBase, Axis, Data, DataMut, Dimension,
+    RemoveAxis, Zip,
+};
+
+use super::unravel;
+
+/// Input transform of *F(2, 3)*.
+const B_T: [[f32; 4]; 4] = [
+    [1., 0., -1., 0.],
+    [0., 1., 1., 0.],
+    [0., -1., 1., 0.],
+    [0., 1., 0., -1.],
+];
+
+/// Kernel transform of *F(2, 3)*.
+const G: [[f32; 3]; 4] = [
+    [1., 0., 0.],
+    [0.5, 0.5, 0.5],
+    [0.5, -0.5, 0.5],
+    [0., 0., 1.],
+];
+
+/// Output transform of *F(2, 3)*.
+const A_T: [[f32; 4]; 2] = [[1., 1., 1., 0.], [0., 1., -1., -1.]];
+
+/// Applies in place `matrix` along every axis of the `n`-dimensional cube `cube`, whose side must
+/// be equal to the number of columns of `matrix`. The side of the resulting cube is equal to the
+/// number of rows of `matrix`.
+///
+/// `scratch` is used as temporary storage, so that no allocation is needed once it is large
+/// enough.
+fn transform<const R: usize, const C: usize>(
+    cube: &mut Vec<f32>,
+    scratch: &mut Vec<f32>,
+    matrix: &[[f32; C]; R],
+    n: usize,
+) {
+    for axis in 0..n {
+        let outer = R.pow(axis as u32);
+        let inner = C.pow((n - axis - 1) as u32);
+        scratch.clear();
+        scratch.resize(outer * R * inner, 0.);
+        for o in 0..outer {
+            for (r, row) in matrix.iter().enumerate() {
+                let dest = &mut scratch[(o * R + r) * inner..(o * R + r + 1) * inner];
+                for (c, coefficient) in row.iter().enumerate() {
+                    if *coefficient == 0. {
+                        continue;
+                    }
+
+                    let src = &cube[(o * C + c) * inner..(o * C + c + 1) * inner];
+                    dest.iter_mut()
+                        .zip(src)
+                        .for_each(|(dest_el, src_el)| *dest_el += coefficient * src_el);
+                }
+            }
+        }
+        std::mem::swap(cube, scratch);
+    }
+}
+
+/// Returns the multi-index of each element of an `n`-dimensional cube of side `side`, together
+/// with its position in the row-major order of an array of shape `shape`.
+fn cube_elements(side: usize, n: usize, shape: &[usize]) -> Vec<(Vec<usize>, usize)> {
+    let cube_shape = vec![side; n];
+    (0..side.pow(n as u32))
+        .map(|element| {
+            let mut multi_index = vec![0; n];
+            unravel(element, &cube_shape, &mut multi_index);
+            let offset = multi_index
+                .iter()
+                .zip(shape)
+                .fold(0, |offset, (position, len)| offset * len + position);
+
+            (multi_index, offset)
+        })
+        .collect()
+}
+
+/// Returns `true` if the element at `multi_index` of the tile at `tile_index` lies within `shape`.
+fn is_inside(multi_index: &[usize], tile_index: &[usize], shape: &[usize]) -> bool {
+    multi_index
+        .iter()
+        .zip(tile_index)
+        .zip(shape)
+        .all(|((position, tile_position), len)| tile_position * 2 + position < *len)
+}
+
+/// Returns `true` if the Winograd algorithm can compute the convolution. The kernel must be of
+/// size 3 along all of its spatial dimensions, with unit stride and dilation.
+pub(super) fn is_supported(kernel_shape: &[usize], stride: &[usize], dilation: &[usize]) -> bool {
+    kernel_shape.iter().skip(2).all(|&len| len == 3)
+        && stride.iter().all(|&len| len == 1)
+        && dilation.iter().all(|&len| len == 1)
+}
+
+/// Computes a convolution *(cross correlation)* with the Winograd minimal filtering algorithm
+/// *F(2, 3)*, nested along each of the spatial dimensions.
+///
+/// The output is split in tiles of side 2, each of them computed from a tile of side 4 of the input
+/// with a single element-wise product in the transformed domain. See [`is_supported`] for the
+/// parameters allowed.
+pub(super) fn convolution<
+    D: Dimension + RemoveAxis,
+    S: Data<Elem = f32>,
+    U: Data<Elem = f32>,
+    T: DataMut<Elem = f32>,
+>(
+    input: &ArrayBase<S, D>,
+    kernel: &ArrayBase<U, D>,
+    output: &mut ArrayBase<T, D>,
+) {
+    let n = input.ndim() - 2;
+    let (out_channels, in_channels) = (kernel.len_of(Axis(0)), kernel.len_of(Axis(1)));
+    let tile_len = 4_usize.pow(n as u32);
+    let mut cube = Vec::new();
+    let mut scratch = Vec::new();
+
+    // Transformed kernel, laid out as (tile element, out channel, in channel).
+    let mut kernel_transformed = Array3::<f32>::zeros((tile_len, out_channels, in_channels));
+    let kernel = kernel.as_standard_layout();
+    kernel
+        .as_slice()
+        .unwrap()
+        .chunks(3_usize.pow(n as u32))
+        .enumerate()
+        .for_each(|(filter, values)| {
+            let (out_channel, in_channel) = (filter / in_channels, filter % in_channels);
+            cube.clear();
+            cube.extend_from_slice(values);
+            transform(&mut cube, &mut scratch, &G, n);
+            cube.iter().enumerate().for_each(|(element, value)| {
+                kernel_transformed[[element, out_channel, in_channel]] = *value
+            });
+        });
+
+    let input = input.as_standard_layout();
+    let spatial_shape = &input.shape()[2..];
+    let out_shape = output.shape()[2..].to_vec();
+    let tiles_shape: Vec<usize> = out_shape.iter().map(|len| len.div_ceil(2)).collect();
+    let tiles: usize = tiles_shape.iter().product();
+    let channel_len: usize = spatial_shape.iter().product();
+    let out_channel_len: usize = out_shape.iter().product();
+    let input_elements = cube_elements(4, n, spatial_shape);
+    let output_elements = cube_elements(2, n, &out_shape);
+
+    Zip::from(input.axis_iter(Axis(0)))
+        .and(output.axis_iter_mut(Axis(0)))
+        .par_for_each(|input_sample, mut output_sample| {
+            let input_sample = input_sample.as_slice().unwrap();
+            let mut tile_index = vec![0; n];
+            let mut cube = Vec::with_capacity(tile_len);
+            let mut scratch = Vec::with_capacity(tile_len);
+
+            // Transformed input tiles, laid out as (tile element, in channel, tile).
+            let mut input_transformed = Array3::<f32>::zeros((tile_len, in_channels, tiles));
+            for tile in 0..tiles {
+                unravel(tile, &tiles_shape, &mut tile_index);
+                let origin = tile_index
+                    .iter()
+                    .zip(spatial_shape)
+                    .fold(0, |offset, (position, len)| offset * len + position * 2);
+                let is_inner = tile_index
+                    .iter()
+                    .zip(spatial_shape)
+                    .all(|(position, len)| position * 2 + 4 <= *len);
+
+                for in_channel in 0..in_channels {
+                    let channel =
+                        &input_sample[in_channel * channel_len..(in_channel + 1) * channel_len];
+                    cube.clear();
+                    // The tiles on the border are padded with zeros.
+                    cube.extend(input_elements.iter().map(|(multi_index, offset)| {
+                        if is_inner || is_inside(multi_index, &tile_index, spatial_shape) {
+                            channel[origin + offset]
+                        } else {
+                            0.
+                        }
+                    }));
+                    transform(&mut cube, &mut scratch, &B_T, n);
+                    cube.iter().enumerate().for_each(|(element, value)| {
+                        input_transformed[[element, in_channel, tile]] = *value
+                    });
+                }
+            }
+
+            // A batch of independent matrix products, one for each element of the tiles.
+            let mut product = Array3::<f32>::zeros((tile_len, out_channels, tiles));
+            Zip::from(product.outer_iter_mut())
+                .and(kernel_transformed.outer_iter())
+                .and(input_transformed.outer_iter())
+                .for_each(|mut product, kernel, input| {
+                    general_mat_mul(1., &kernel, &input, 0., &mut product)
+                });
+
+            let mut result = Array::<f32, _>::zeros(out_channels * out_channel_len);
+            for tile in 0..tiles {
+                unravel(tile, &tiles_shape, &mut tile_index);
+                let origin = tile_index
+                    .iter()
+                    .zip(&out_shape)
+                    .fold(0, |offset, (position, len)| offset * len + position * 2);
+
+                for out_channel in 0..out_channels {
+                    cube.clear();
+                    cube.extend(product.slice(s![.., out_channel, tile]).iter());
+                    transform(&mut cube, &mut scratch, &A_T, n);
+
+                    let start = out_channel * out_channel_len + origin;
+                    cube.iter()
+                        .zip(&output_elements)
+                        .filter(|(_, (multi_index, _))| {
+                            is_inside(multi_index, &tile_index, &out_shape)
+                        })
+                        .for_each(|(value, (_, offset))| result[start + offset] = *value);
+                }
+            }
+
+            output_sample.assign(&result.into_shape(output_sample.raw_dim()).unwrap());
+        });
+}
@@ -0,0 +1,275 @@
+use std::rc::Rc;
+
+use ndarray::{Array, Dimension, RemoveAxis};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    gradient::Gradient,
+    onnx::{ints, Attribute, Operator},
+    utils::Shared,
+};
+
+use super::convolution::{
+    convolution_backward_input, convolution_backward_kernel, fastest_convolution,
+    grouped_convolution_backward_input, grouped_convolution_backward_kernel,
+};
+
+/// Computes a transposed convolution, that is the gradient of a convolution with respect to its
+/// input. The kernel is laid out as *(Cin, Cout / groups, ...)*.
+fn convolution_transpose<D>(
+    output: &mut Array<f32, D>,
+    input: &Array<f32, D>,
+    kernel: &Array<f32, D>,
+    stride: &[usize],
+    dilation: &[usize],
+    groups: usize,
+) where
+    D: Dimension + RemoveAxis,
+{
+    output.fill(0.);
+    if groups < 2 {
+        convolution_backward_input(output, input, kernel, stride, dilation);
+    } else {
+        grouped_convolution_backward_input(output, input, kernel, stride, dilation, groups);
+    }
+}
+
+pub(crate) struct ConvolutionTranspose<D>
+where
+    D: Dimension + RemoveAxis,
+{
+    input_data: Shared<Array<f32, D>>,
+    kernel_data: Shared<Array<f32, D>>,
+    stride: <D::Smaller as Dimension>::Smaller,
+    dilation: <D::Smaller as Dimension>::Smaller,
+    groups: usize,
+    data: Shared<Array<f32, D>>,
+}
+
+impl<D> ConvolutionTranspose<D>
+where
+    D: Dimension + RemoveAxis,
+{
+    pub(crate) fn new(
+        input_data: Shared<Array<f32, D>>,
+        kernel_data: Shared<Array<f32, D>>,
+        stride: <D::Smaller as Dimension>::Smaller,
+        dilation: <D::Smaller as Dimension>::Smaller,
+        groups: usize,
+        data: Shared<Array<f32, D>>,
+    ) -> Self {
+        Self {
+            input_data,
+            kernel_data,
+            stride,
+            dilation,
+            groups,
+            data,
+        }
+    }
+}
+
+impl<D> Forward for ConvolutionTranspose<D>
+where
+    D: Dimension + RemoveAxis,
+{
+    fn forward(&self) {
+        convolution_transpose(
+            &mut *self.data.borrow_mut(),
+            &*self.input_data.borrow(),
+            &*self.kernel_data.borrow(),
+            self.stride.slice(),
+            self.dilation.slice(),
+            self.groups,
+        );
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.input_data, &self.kernel_data]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        let (input_shape, kernel_shape, shape) = (
+            self.input_data.shape(),
+            self.kernel_data.shape(),
+            self.data.shape(),
+        );
+        // The output padding isn't stored, it's what's left of the output after the convolution.
+        let output_padding: Vec<i64> = itertools::izip!(
+            &shape[2..],
+            &input_shape[2..],
+            &kernel_shape[2..],
+            self.stride.slice(),
+            self.dilation.slice()
+        )
+        .map(|(len, input_len, kernel_len, stride, dilation)| {
+            (len - (input_len - 1) * stride - dilation * (kernel_len - 1) - 1) as i64
+        })
+        .collect();
+
+        Some(
+            Operator::new("ConvTranspose")
+                .attribute("strides", Attribute::Ints(ints(self.stride.slice())))
+                .attribute("dilations", Attribute::Ints(ints(self.dilation.slice())))
+                .attribute("group", Attribute::Int(self.groups as i64))
+                .attribute("output_padding", Attribute::Ints(output_padding)),
+        )
+    }
+}
+
+pub(crate) struct ConvolutionTransposeBackward<D>
+where
+    D: Dimension + RemoveAxis,
+{
+    backward_input: ConvolutionTransposeBackwardInput<D>,
+    backward_kernel: ConvolutionTransposeBackwardKernel<D>,
+}
+
+impl<D> ConvolutionTransposeBackward<D>
+where
+    D: Dimension + RemoveAxis,
+{
+    pub(crate) fn new(
+        backward_input: ConvolutionTransposeBackwardInput<D>,
+        backward_kernel: ConvolutionTransposeBackwardKernel<D>,
+    ) -> Self {
+        Self {
+            backward_input,
+            backward_kernel,
+        }
+    }
+}
+
+impl<D> Backward for ConvolutionTransposeBackward<D>
+where
+    D: Dimension + RemoveAxis,
+{
+    fn backward(&self) {
+        self.backward_input.backward();
+        self.backward_kernel.backward();
+    }
+}
+
+pub(crate) struct ConvolutionTransposeBackwardInput<D>
+where
+    D: Dimension + RemoveAxis,
+{
+    kernel_data: Shared<Array<f32, D>>,
+    input_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+    stride: <D::Smaller as Dimension>::Smaller,
+    dilation: <D::Smaller as Dimension>::Smaller,
+    groups: usize,
+}
+
+impl<D> ConvolutionTransposeBackwardInput<D>
+where
+    D: Dimension + RemoveAxis,
+{
+    pub(crate) fn new(
+        kernel_data: Shared<Array<f32, D>>,
+        input_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+        stride: <D::Smaller as Dimension>::Smaller,
+        dilation: <D::Smaller as Dimension>::Smaller,
+        groups: usize,
+    ) -> Self {
+        Self {
+            kernel_data,
+            input_gradient,
+            gradient,
+            stride,
+            dilation,
+            groups,
+        }
+    }
+}
+
+impl<D> Backward for ConvolutionTransposeBackwardInput<D>
+where
+    D: Dimension + RemoveAxis,
+{
+    fn backward(&self) {
+        // The gradient of the input is the convolution of the incoming gradient with the kernel.
+        let mut buffer = Array::zeros(self.input_gradient.shape());
+        fastest_convolution(
+            &*self.gradient.borrow(),
+            &*self.kernel_data.borrow(),
+            &mut buffer,
+            self.stride.slice(),
+            self.dilation.slice(),
+            self.groups,
+        );
+
+        *self.input_gradient.borrow_mut() += &buffer;
+    }
+}
+
+pub(crate) struct ConvolutionTransposeBackwardKernel<D>
+where
+    D: Dimension + RemoveAxis,
+{
+    input_data: Shared<Array<f32, D>>,
+    kernel_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+    stride: <D::Smaller as Dimension>::Smaller,
+    dilation: <D::Smaller as Dimension>::Smaller,
+    groups: usize,
+}
+
+impl<D> ConvolutionTransposeBackwardKernel<D>
+where
+    D: Dimension + RemoveAxis,
+{
+    pub(crate) fn new(
+        input_data: Shared<Array<f32, D>>,
+        kernel_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+        stride: <D::Smaller as Dimension>::Smaller,
+        dilation: <D::Smaller as Dimension>::Smaller,
+        groups: usize,
+    ) -> Self {
+        Self {
+            input_data,
+            kernel_gradient,
+            gradient,
+            stride,
+            dilation,
+            groups,
+        }
+    }
+}
+
+impl<D> Backward for ConvolutionTransposeBackwardKernel<D>
+where
+    D: Dimension + RemoveAxis,
+{
+    fn backward(&self) {
+        // The roles of input and output of the corresponding convolution are swapped.
+        if self.groups < 2 {
+            convolution_backward_kernel(
+                &mut *self.kernel_gradient.borrow_mut(),
+                &*self.input_data.borrow(),
+                &*self.gradient.borrow(),
+                self.stride.slice(),
+                self.dilation.slice(),
+            );
+        } else {
+            grouped_convolution_backward_kernel(
+                &mut *self.kernel_gradient.borrow_mut(),
+                &*self.input_data.borrow(),
+                &*self.gradient.borrow(),
+                self.stride.slice(),
+                self.dilation.slice(),
+                self.groups,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,173 @@
+use std::{error::Error, rc::Rc};
+
+use ndarray::{array, Array, Ix1, Ix3};
+
+use crate::utils::{are_similar, conv_transpose_out_shape, new_shared};
+
+#[cfg(test)]
+mod forward {
+    use super::super::{ConvolutionTranspose, Forward};
+    use super::*;
+
+    #[test]
+    fn creation() {
+        let input = new_shared(array![[[1., 2.]]]);
+        let kernel = new_shared(array![[[1., 1., 1.]]]);
+        let data = new_shared(Array::<f32, Ix3>::zeros((1, 1, 5)));
+        let op = ConvolutionTranspose::new(input, kernel, Ix1(2), Ix1(1), 1, data.clone());
+
+        assert_eq!(*op.data.borrow(), *data.borrow());
+        assert_eq!(op.operands().len(), 2);
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let shape: Ix3 = conv_transpose_out_shape(&[1, 1, 2], &[1, 1, 3], &[2], &[1], &[0], 1);
+        assert_eq!(shape, Ix3(1, 1, 5));
+
+        let input = new_shared(array![[[1., 2.]]]);
+        let kernel = new_shared(array![[[1., 1., 1.]]]);
+        let data = new_shared(Array::zeros(shape));
+        let op = ConvolutionTranspose::new(input, kernel, Ix1(2), Ix1(1), 1, data.clone());
+
+        op.forward();
+        are_similar(data.borrow(), &array![[[1., 1., 3., 2., 2.]]])?;
+
+        op.forward();
+        are_similar(data.borrow(), &array![[[1., 1., 3., 2., 2.]]])
+    }
+
+    #[test]
+    fn output_padding() -> Result<(), Box<dyn Error>> {
+        let shape: Ix3 = conv_transpose_out_shape(&[1, 1, 2], &[1, 1, 3], &[2], &[1], &[1], 1);
+        assert_eq!(shape, Ix3(1, 1, 6));
+
+        let input = new_shared(array![[[1., 2.]]]);
+        let kernel = new_shared(array![[[1., 1., 1.]]]);
+        let data = new_shared(Array::zeros(shape));
+        let op = ConvolutionTranspose::new(input, kernel, Ix1(2), Ix1(1), 1, data.clone());
+
+        op.forward();
+        are_similar(data.borrow(), &array![[[1., 1., 3., 2., 2., 0.]]])
+    }
+
+    #[test]
+    fn grouped() -> Result<(), Box<dyn Error>> {
+        let shape: Ix3 = conv_transpose_out_shape(&[1, 2, 2], &[2, 1, 3], &[1], &[1], &[0], 2);
+        assert_eq!(shape, Ix3(1, 2, 4));
+
+        let input = new_shared(array![[[1., 2.], [3., 4.]]]);
+        let kernel = new_shared(array![[[1., 1., 1.]], [[1., 0., -1.]]]);
+        let data = new_shared(Array::zeros(shape));
+        let op = ConvolutionTranspose::new(input, kernel, Ix1(1), Ix1(1), 2, data.clone());
+
+        op.forward();
+        are_similar(
+            data.borrow(),
+            &array![[[1., 3., 3., 2.], [3., 4., -3., -4.]]],
+        )
+    }
+}
+
+#[cfg(test)]
+mod backward {
+    use super::super::{
+        Backward, ConvolutionTransposeBackward, ConvolutionTransposeBackwardInput,
+        ConvolutionTransposeBackwardKernel,
+    };
+    use super::*;
+    use crate::gradient::Gradient;
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let input = new_shared(array![[[1., 2.]]]);
+        let kernel = new_shared(array![[[1., 1., 1.]]]);
+        let input_gradient = Rc::new(Gradient::ndarray_zeros((1, 1, 2)));
+        let kernel_gradient = Rc::new(Gradient::ndarray_zeros((1, 1, 3)));
+        let gradient = Rc::new(Gradient::from_ndarray(Array::ones((1, 1, 5))));
+        let op = ConvolutionTransposeBackward::new(
+            ConvolutionTransposeBackwardInput::new(
+                kernel,
+                input_gradient.clone(),
+                gradient.clone(),
+                Ix1(2),
+                Ix1(1),
+                1,
+            ),
+            ConvolutionTransposeBackwardKernel::new(
+                input,
+                kernel_gradient.clone(),
+                gradient,
+                Ix1(2),
+                Ix1(1),
+                1,
+            ),
+        );
+
+        op.backward();
+        are_similar(input_gradient.borrow(), &array![[[3., 3.]]])?;
+        are_similar(kernel_gradient.borrow(), &array![[[3., 3., 3.]]])?;
+
+        op.backward();
+        are_similar(input_gradient.borrow(), &array![[[6., 6.]]])?;
+        are_similar(kernel_gradient.borrow(), &array![[[6., 6., 6.]]])
+    }
+
+    #[test]
+    fn grouped() -> Result<(), Box<dyn Error>> {
+        let input = new_shared(array![[[1., 2.], [3., 4.]]]);
+        let kernel = new_shared(array![[[1., 1., 1.]], [[1., 0., -1.]]]);
+        let input_gradient = Rc::new(Gradient::ndarray_zeros((1, 2, 2)));
+        let kernel_gradient = Rc::new(Gradient::ndarray_zeros((2, 1, 3)));
+        let gradient = Rc::new(Gradient::from_ndarray(array![[
+            [1., 2., 3., 4.],
+            [1., 1., 1., 1.]
+        ]]));
+        let op = ConvolutionTransposeBackward::new(
+            ConvolutionTransposeBackwardInput::new(
+                kernel,
+                input_gradient.clone(),
+                gradient.clone(),
+                Ix1(1),
+                Ix1(1),
+                2,
+            ),
+            ConvolutionTransposeBackwardKernel::new(
+                input,
+                kernel_gradient.clone(),
+                gradient,
+                Ix1(1),
+                Ix1(1),
+                2,
+            ),
+        );
+
+        op.backward();
+        are_similar(input_gradient.borrow(), &array![[[6., 9.], [0., 0.]]])?;
+        are_similar(
+            kernel_gradient.borrow(),
+            &array![[[5., 8., 11.]], [[7., 7., 7.]]],
+        )
+    }
+}
+
+#[test]
+fn convolution_transpose_tracks_input_history() {
+    use neuronika_core::ConvolutionTranspose;
+
+    // The input is the result of an operation, which must be computed before the convolution.
+    let input = crate::ones((1, 1, 2)) * 2.;
+    let output = crate::ones((1, 1, 2)).convolution_transpose(input.clone(), 1, 1, 0, 1);
+    assert_eq!(output.history.len(), 2);
+
+    output.forward();
+    assert_eq!(*output.data(), array![[[2., 4., 2.]]]);
+
+    let kernel = crate::ones((1, 1, 2)).requires_grad();
+    let output = kernel.clone().convolution_transpose(input, 1, 1, 0, 1);
+    output.forward();
+    assert_eq!(*output.data(), array![[[2., 4., 2.]]]);
+
+    output.backward(1.);
+    assert_eq!(*kernel.grad(), array![[[4., 4.]]]);
+}
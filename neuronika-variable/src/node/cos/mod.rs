@@ -0,0 +1,98 @@
+use std::rc::Rc;
+
+use ndarray::{Array, Dimension, Zip};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    fusion::Kernel,
+    gradient::Gradient,
+    onnx::Operator,
+    utils::Shared,
+};
+
+pub(crate) struct Cos<D>
+where
+    D: Dimension,
+{
+    operand_data: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D>>,
+}
+
+impl<D> Cos<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(operand_data: Shared<Array<f32, D>>, data: Shared<Array<f32, D>>) -> Self {
+        Self { operand_data, data }
+    }
+}
+
+impl<D> Forward for Cos<D>
+where
+    D: Dimension,
+{
+    fn forward(&self) {
+        Zip::from(&mut *self.data.borrow_mut())
+            .and(&*self.operand_data.borrow())
+            .for_each(|v, &o| *v = o.cos());
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn kernel(&self) -> Option<Kernel> {
+        Some(Kernel::Unary(f32::cos, |x, _| -x.sin()))
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("Cos"))
+    }
+}
+
+pub(crate) struct CosBackward<D>
+where
+    D: Dimension,
+{
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    operand_data: Shared<Array<f32, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+}
+
+impl<D> CosBackward<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        operand_data: Shared<Array<f32, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+    ) -> Self {
+        Self {
+            operand_gradient,
+            operand_data,
+            gradient,
+        }
+    }
+}
+
+impl<D> Backward for CosBackward<D>
+where
+    D: Dimension,
+{
+    fn backward(&self) {
+        Zip::from(&mut *self.operand_gradient.borrow_mut())
+            .and(&*self.gradient.borrow())
+            .and(&*self.operand_data.borrow())
+            .for_each(|op_grad_el, &grad_el, &op_data_el| {
+                *op_grad_el += -grad_el * op_data_el.sin()
+            });
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,84 @@
+use std::error::Error;
+
+use ndarray::Array;
+
+use crate::utils::{are_similar, new_shared};
+
+#[cfg(test)]
+mod forward {
+    use super::super::{Digamma, Forward};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_data = Array::zeros((3, 3));
+        let data = Array::ones((3, 3));
+        let op = Digamma::new(new_shared(operand_data.clone()), new_shared(data.clone()));
+
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.data.borrow(), &data)
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let input = Array::linspace(0.5, 4.5, 9).into_shape((3, 3))?;
+        let op = Digamma::new(new_shared(input.clone()), new_shared(Array::zeros((3, 3))));
+
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::from_shape_vec(
+                (3, 3),
+                vec![
+                    -1.9635, -0.5772, 0.0365, 0.4228, 0.7032, 0.9228, 1.1032, 1.2561, 1.3889,
+                ],
+            )?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod backward {
+    use std::rc::Rc;
+
+    use super::super::{Backward, DigammaBackward, Gradient};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_gradient = Array::zeros((3, 3));
+        let operand_data = Array::ones((3, 3));
+        let gradient = Array::from_elem((3, 3), 2.);
+        let op = DigammaBackward::new(
+            Rc::new(Gradient::from_ndarray(operand_gradient.clone())),
+            new_shared(operand_data.clone()),
+            Rc::new(Gradient::from_ndarray(gradient.clone())),
+        );
+
+        are_similar(op.operand_gradient.borrow(), &operand_gradient)?;
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.gradient.borrow(), &gradient)
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let input = Array::linspace(0.5, 4.5, 9).into_shape((3, 3))?;
+        let op = DigammaBackward::new(
+            Rc::new(Gradient::ndarray_zeros((3, 3))),
+            new_shared(input.clone()),
+            Rc::new(Gradient::from_ndarray(Array::ones((3, 3)))),
+        );
+        let expected = Array::from_shape_vec(
+            (3, 3),
+            vec![
+                4.9348, 1.6449, 0.9348, 0.6449, 0.4904, 0.3949, 0.3304, 0.2838, 0.2487,
+            ],
+        )?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &expected)?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &(expected * 2.))
+    }
+}
@@ -3,8 +3,10 @@ use std::rc::Rc;
 use ndarray::{Array, DimMax, Dimension, Zip};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
+    fusion::Kernel,
     gradient::{BufferedGradient, Gradient},
+    onnx::Operator,
     utils::{accumulate, Broadcast, Shared},
 };
 
@@ -47,6 +49,25 @@ where
             .and_broadcast(&*self.right_data.borrow())
             .for_each(|v, &l, &r| *v = l / r);
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.left_data, &self.right_data]
+    }
+
+    fn kernel(&self) -> Option<Kernel> {
+        Some(Kernel::Binary(
+            |l, r| l / r,
+            |l, r, _| (1. / r, -l / r.powi(2)),
+        ))
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("Div"))
+    }
 }
 
 pub(crate) struct DivisionBackwardLeft<D, E>
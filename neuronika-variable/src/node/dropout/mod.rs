@@ -7,8 +7,9 @@ use rand::thread_rng;
 use rand_distr::{Bernoulli, Distribution};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
+    onnx::Operator,
     utils::Shared,
 };
 
@@ -76,6 +77,24 @@ where
                 *data_el = (operand_data_el * noise_el) / (1. - self.p as f32)
             });
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn is_volatile(&self) -> bool {
+        // The noise is sampled anew at each evaluation and the status may have been switched.
+        true
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        // Only inference graphs are exported.
+        Some(Operator::new("Identity"))
+    }
 }
 
 pub(crate) struct DropoutBackward<D>
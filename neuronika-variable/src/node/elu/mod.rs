@@ -0,0 +1,112 @@
+use std::rc::Rc;
+
+use ndarray::{Array, Dimension, Zip};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    gradient::Gradient,
+    onnx::{Attribute, Operator},
+    utils::Shared,
+};
+
+#[allow(clippy::upper_case_acronyms)]
+pub(crate) struct ELU<D>
+where
+    D: Dimension,
+{
+    operand_data: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D>>,
+    alpha: f32,
+}
+
+impl<D> ELU<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(
+        operand_data: Shared<Array<f32, D>>,
+        data: Shared<Array<f32, D>>,
+        alpha: f32,
+    ) -> Self {
+        Self {
+            operand_data,
+            data,
+            alpha,
+        }
+    }
+}
+
+impl<D> Forward for ELU<D>
+where
+    D: Dimension,
+{
+    fn forward(&self) {
+        Zip::from(&mut *self.data.borrow_mut())
+            .and(&*self.operand_data.borrow())
+            .for_each(|v, &o| *v = if o > 0. { o } else { self.alpha * o.exp_m1() });
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("Elu").attribute("alpha", Attribute::Float(self.alpha)))
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+pub(crate) struct ELUBackward<D>
+where
+    D: Dimension,
+{
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    operand_data: Shared<Array<f32, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+    alpha: f32,
+}
+
+impl<D> ELUBackward<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        operand_data: Shared<Array<f32, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+        alpha: f32,
+    ) -> Self {
+        Self {
+            operand_gradient,
+            operand_data,
+            gradient,
+            alpha,
+        }
+    }
+}
+
+impl<D> Backward for ELUBackward<D>
+where
+    D: Dimension,
+{
+    fn backward(&self) {
+        Zip::from(&mut *self.operand_gradient.borrow_mut())
+            .and(&*self.gradient.borrow())
+            .and(&*self.operand_data.borrow())
+            .for_each(|op_grad_el, &grad_el, &op_data_el| {
+                *op_grad_el += grad_el
+                    * if op_data_el > 0. {
+                        1.
+                    } else {
+                        self.alpha * op_data_el.exp()
+                    }
+            });
+    }
+}
+
+#[cfg(test)]
+mod test;
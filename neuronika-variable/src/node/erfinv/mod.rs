@@ -0,0 +1,131 @@
+use std::{f32::consts::FRAC_2_SQRT_PI, rc::Rc};
+
+use ndarray::{Array, Dimension, Zip};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    gradient::Gradient,
+    utils::Shared,
+};
+
+pub(crate) struct Erfinv<D>
+where
+    D: Dimension,
+{
+    operand_data: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D>>,
+}
+
+impl<D> Erfinv<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(operand_data: Shared<Array<f32, D>>, data: Shared<Array<f32, D>>) -> Self {
+        Self { operand_data, data }
+    }
+}
+
+impl<D> Forward for Erfinv<D>
+where
+    D: Dimension,
+{
+    fn forward(&self) {
+        Zip::from(&mut *self.data.borrow_mut())
+            .and(&*self.operand_data.borrow())
+            .for_each(|v, &o| *v = erfinv(o));
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+}
+
+pub(crate) struct ErfinvBackward<D>
+where
+    D: Dimension,
+{
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    data: Shared<Array<f32, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+}
+
+impl<D> ErfinvBackward<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        data: Shared<Array<f32, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+    ) -> Self {
+        Self {
+            operand_gradient,
+            data,
+            gradient,
+        }
+    }
+}
+
+impl<D> Backward for ErfinvBackward<D>
+where
+    D: Dimension,
+{
+    fn backward(&self) {
+        Zip::from(&mut *self.operand_gradient.borrow_mut())
+            .and(&*self.gradient.borrow())
+            .and(&*self.data.borrow())
+            .for_each(|op_grad_el, &grad_el, &data_el| {
+                *op_grad_el += grad_el * (data_el * data_el).exp() / FRAC_2_SQRT_PI
+            });
+    }
+}
+
+/// Single precision approximation of the inverse error function by M. Giles.
+fn erfinv(x: f32) -> f32 {
+    const CENTRAL: [f32; 9] = [
+        2.810_226_4e-8,
+        3.432_739_4e-7,
+        -3.523_387_7e-6,
+        -4.391_506_5e-6,
+        2.185_808_7e-4,
+        -1.253_725e-3,
+        -4.177_681_6e-3,
+        2.466_407_3e-1,
+        1.501_409_4,
+    ];
+    const TAIL: [f32; 9] = [
+        -2.002_142_6e-4,
+        1.009_505_6e-4,
+        1.349_343_2e-3,
+        -3.673_428_4e-3,
+        5.739_507_7e-3,
+        -7.622_461_3e-3,
+        9.438_870_5e-3,
+        1.001_674,
+        2.832_976_8,
+    ];
+
+    if x.abs() >= 1. {
+        return if x.abs() == 1. {
+            x * f32::INFINITY
+        } else {
+            f32::NAN
+        };
+    }
+
+    let w = -((1. - x) * (1. + x)).ln();
+    let (coefficients, w) = if w < 5. {
+        (&CENTRAL, w - 2.5)
+    } else {
+        (&TAIL, w.sqrt() - 3.)
+    };
+
+    coefficients.iter().fold(0., |p, &c| c + p * w) * x
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,89 @@
+use std::error::Error;
+
+use ndarray::Array;
+
+use crate::utils::{are_similar, new_shared};
+
+#[cfg(test)]
+mod forward {
+    use super::super::{Erfinv, Forward};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_data = Array::zeros((3, 3));
+        let data = Array::ones((3, 3));
+        let op = Erfinv::new(new_shared(operand_data.clone()), new_shared(data.clone()));
+
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.data.borrow(), &data)
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let input = Array::linspace(-0.8, 0.8, 9).into_shape((3, 3))?;
+        let op = Erfinv::new(new_shared(input.clone()), new_shared(Array::zeros((3, 3))));
+
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::from_shape_vec(
+                (3, 3),
+                vec![
+                    -0.9062, -0.5951, -0.3708, -0.1791, 0.0000, 0.1791, 0.3708, 0.5951, 0.9062,
+                ],
+            )?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod backward {
+    use std::rc::Rc;
+
+    use super::super::{Backward, ErfinvBackward, Gradient};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_gradient = Array::zeros((3, 3));
+        let data = Array::ones((3, 3));
+        let gradient = Array::from_elem((3, 3), 2.);
+        let op = ErfinvBackward::new(
+            Rc::new(Gradient::from_ndarray(operand_gradient.clone())),
+            new_shared(data.clone()),
+            Rc::new(Gradient::from_ndarray(gradient.clone())),
+        );
+
+        are_similar(op.operand_gradient.borrow(), &operand_gradient)?;
+        are_similar(op.data.borrow(), &data)?;
+        are_similar(op.gradient.borrow(), &gradient)
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let data = Array::from_shape_vec(
+            (3, 3),
+            vec![
+                -0.9062, -0.5951, -0.3708, -0.1791, 0.0000, 0.1791, 0.3708, 0.5951, 0.9062,
+            ],
+        )?;
+        let op = ErfinvBackward::new(
+            Rc::new(Gradient::ndarray_zeros((3, 3))),
+            new_shared(data.clone()),
+            Rc::new(Gradient::from_ndarray(Array::ones((3, 3)))),
+        );
+        let expected = Array::from_shape_vec(
+            (3, 3),
+            vec![
+                2.0146, 1.2629, 1.0169, 0.9151, 0.8862, 0.9151, 1.0169, 1.2629, 2.0146,
+            ],
+        )?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &expected)?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &(expected * 2.))
+    }
+}
@@ -0,0 +1,277 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use ndarray::{Array, DimMax, Dimension, Zip};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    gradient::{BufferedGradient, Gradient},
+    utils::{accumulate, Broadcast, Shared},
+};
+
+/// Comparison choosing between two elements, it parameterizes the element-wise extremum.
+pub(crate) trait Comparison {
+    /// Returns the element chosen between `left` and `right`.
+    fn select(left: f32, right: f32) -> f32;
+
+    /// Returns `true` if `this` is strictly preferred to `other`.
+    fn prefers(this: f32, other: f32) -> bool;
+}
+
+/// Chooses the greater element.
+pub(crate) struct Greater;
+
+impl Comparison for Greater {
+    fn select(left: f32, right: f32) -> f32 {
+        left.max(right)
+    }
+
+    fn prefers(this: f32, other: f32) -> bool {
+        this > other
+    }
+}
+
+/// Chooses the lesser element.
+pub(crate) struct Less;
+
+impl Comparison for Less {
+    fn select(left: f32, right: f32) -> f32 {
+        left.min(right)
+    }
+
+    fn prefers(this: f32, other: f32) -> bool {
+        this < other
+    }
+}
+
+pub(crate) type Maximum<D, E> = Extremum<Greater, D, E>;
+pub(crate) type MaximumBackwardLeft<D, E> = ExtremumBackwardLeft<Greater, D, E>;
+pub(crate) type MaximumBackwardRight<D, E> = ExtremumBackwardRight<Greater, D, E>;
+pub(crate) type MaximumBackward<D, E> = ExtremumBackward<Greater, D, E>;
+
+pub(crate) type Minimum<D, E> = Extremum<Less, D, E>;
+pub(crate) type MinimumBackwardLeft<D, E> = ExtremumBackwardLeft<Less, D, E>;
+pub(crate) type MinimumBackwardRight<D, E> = ExtremumBackwardRight<Less, D, E>;
+pub(crate) type MinimumBackward<D, E> = ExtremumBackward<Less, D, E>;
+
+pub(crate) struct Extremum<C, D, E>
+where
+    C: Comparison,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    left_data: Shared<Array<f32, D>>,
+    right_data: Shared<Array<f32, E>>,
+    data: Shared<Array<f32, Broadcast<D, E>>>,
+    comparison: PhantomData<C>,
+}
+
+impl<C, D, E> Extremum<C, D, E>
+where
+    C: Comparison,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    pub(crate) fn new(
+        left_data: Shared<Array<f32, D>>,
+        right_data: Shared<Array<f32, E>>,
+        data: Shared<Array<f32, Broadcast<D, E>>>,
+    ) -> Self {
+        Self {
+            left_data,
+            right_data,
+            data,
+            comparison: PhantomData,
+        }
+    }
+}
+
+impl<C, D, E> Forward for Extremum<C, D, E>
+where
+    C: Comparison,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    fn forward(&self) {
+        Zip::from(&mut *self.data.borrow_mut())
+            .and_broadcast(&*self.left_data.borrow())
+            .and_broadcast(&*self.right_data.borrow())
+            .for_each(|v, &l, &r| *v = C::select(l, r));
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.left_data, &self.right_data]
+    }
+}
+
+/// Portion of the incoming gradient routed to the first argument. Ties split it evenly.
+fn select_gradient<C>(grad: f32, this: f32, other: f32) -> f32
+where
+    C: Comparison,
+{
+    if C::prefers(this, other) {
+        grad
+    } else if C::prefers(other, this) {
+        0.
+    } else {
+        grad * 0.5
+    }
+}
+
+pub(crate) struct ExtremumBackwardLeft<C, D, E>
+where
+    C: Comparison,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    left_data: Shared<Array<f32, D>>,
+    right_data: Shared<Array<f32, E>>,
+    left_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    gradient: Rc<BufferedGradient<Array<f32, Broadcast<D, E>>, Broadcast<D, E>>>,
+    comparison: PhantomData<C>,
+}
+
+impl<C, D, E> ExtremumBackwardLeft<C, D, E>
+where
+    C: Comparison,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    pub(crate) fn new(
+        left_data: Shared<Array<f32, D>>,
+        right_data: Shared<Array<f32, E>>,
+        left_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        gradient: Rc<BufferedGradient<Array<f32, Broadcast<D, E>>, Broadcast<D, E>>>,
+    ) -> Self {
+        debug_assert!(left_gradient
+            .borrow()
+            .broadcast(gradient.shape().slice())
+            .is_some());
+
+        Self {
+            left_data,
+            right_data,
+            left_gradient,
+            gradient,
+            comparison: PhantomData,
+        }
+    }
+}
+
+impl<C, D, E> Backward for ExtremumBackwardLeft<C, D, E>
+where
+    C: Comparison,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    fn backward(&self) {
+        let mut buffer = self.gradient.buffer_mut();
+        Zip::from(&mut *buffer)
+            .and(&*self.gradient.borrow())
+            .and_broadcast(&*self.left_data.borrow())
+            .and_broadcast(&*self.right_data.borrow())
+            .for_each(|d, &g, &l, &r| *d = select_gradient::<C>(g, l, r));
+
+        accumulate(&mut self.left_gradient.borrow_mut(), &buffer);
+    }
+}
+
+pub(crate) struct ExtremumBackwardRight<C, D, E>
+where
+    C: Comparison,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    left_data: Shared<Array<f32, D>>,
+    right_data: Shared<Array<f32, E>>,
+    right_gradient: Rc<Gradient<Array<f32, E>, E>>,
+    gradient: Rc<BufferedGradient<Array<f32, Broadcast<D, E>>, Broadcast<D, E>>>,
+    comparison: PhantomData<C>,
+}
+
+impl<C, D, E> ExtremumBackwardRight<C, D, E>
+where
+    C: Comparison,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    pub(crate) fn new(
+        left_data: Shared<Array<f32, D>>,
+        right_data: Shared<Array<f32, E>>,
+        right_gradient: Rc<Gradient<Array<f32, E>, E>>,
+        gradient: Rc<BufferedGradient<Array<f32, Broadcast<D, E>>, Broadcast<D, E>>>,
+    ) -> Self {
+        debug_assert!(right_gradient
+            .borrow()
+            .broadcast(gradient.shape().slice())
+            .is_some());
+
+        Self {
+            left_data,
+            right_data,
+            right_gradient,
+            gradient,
+            comparison: PhantomData,
+        }
+    }
+}
+
+impl<C, D, E> Backward for ExtremumBackwardRight<C, D, E>
+where
+    C: Comparison,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    fn backward(&self) {
+        let mut buffer = self.gradient.buffer_mut();
+        Zip::from(&mut *buffer)
+            .and(&*self.gradient.borrow())
+            .and_broadcast(&*self.left_data.borrow())
+            .and_broadcast(&*self.right_data.borrow())
+            .for_each(|d, &g, &l, &r| *d = select_gradient::<C>(g, r, l));
+
+        accumulate(&mut self.right_gradient.borrow_mut(), &buffer);
+    }
+}
+
+pub(crate) struct ExtremumBackward<C, D, E>
+where
+    C: Comparison,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    left: ExtremumBackwardLeft<C, D, E>,
+    right: ExtremumBackwardRight<C, D, E>,
+}
+
+impl<C, D, E> ExtremumBackward<C, D, E>
+where
+    C: Comparison,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    pub(crate) fn new(
+        left: ExtremumBackwardLeft<C, D, E>,
+        right: ExtremumBackwardRight<C, D, E>,
+    ) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<C, D, E> Backward for ExtremumBackward<C, D, E>
+where
+    C: Comparison,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    fn backward(&self) {
+        self.left.backward();
+        self.right.backward();
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,236 @@
+use std::{error::Error, rc::Rc};
+
+use ndarray::Array;
+
+use crate::utils::{are_similar, new_shared};
+
+mod forward {
+    use super::super::{Forward, Maximum, Minimum};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let left = Array::linspace(1., 9., 9).into_shape((3, 3))?;
+        let right = Array::from_elem((3, 3), 5.);
+        let data = Array::zeros((3, 3));
+        let op = Maximum::new(
+            new_shared(left.clone()),
+            new_shared(right.clone()),
+            new_shared(data.clone()),
+        );
+
+        are_similar(op.left_data.borrow(), &left)?;
+        are_similar(op.right_data.borrow(), &right)?;
+        are_similar(op.data.borrow(), &data)
+    }
+
+    #[test]
+    fn maximum_base_case() -> Result<(), Box<dyn Error>> {
+        let op = Maximum::new(
+            new_shared(Array::linspace(1., 9., 9).into_shape((3, 3))?),
+            new_shared(Array::from_elem((3, 3), 5.)),
+            new_shared(Array::zeros((3, 3))),
+        );
+
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::from_shape_vec((3, 3), vec![5., 5., 5., 5., 5., 6., 7., 8., 9.])?,
+        )
+    }
+
+    #[test]
+    fn maximum_right_broadcast() -> Result<(), Box<dyn Error>> {
+        let op = Maximum::new(
+            new_shared(Array::linspace(1., 9., 9).into_shape((3, 3))?),
+            new_shared(Array::from_shape_vec((1, 3), vec![2., 5., 8.])?),
+            new_shared(Array::zeros((3, 3))),
+        );
+
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::from_shape_vec((3, 3), vec![2., 5., 8., 4., 5., 8., 7., 8., 9.])?,
+        )
+    }
+
+    #[test]
+    fn minimum_base_case() -> Result<(), Box<dyn Error>> {
+        let op = Minimum::new(
+            new_shared(Array::linspace(1., 9., 9).into_shape((3, 3))?),
+            new_shared(Array::from_elem((3, 3), 5.)),
+            new_shared(Array::zeros((3, 3))),
+        );
+
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::from_shape_vec((3, 3), vec![1., 2., 3., 4., 5., 5., 5., 5., 5.])?,
+        )
+    }
+
+    #[test]
+    fn minimum_left_broadcast() -> Result<(), Box<dyn Error>> {
+        let op = Minimum::new(
+            new_shared(Array::from_shape_vec((1, 3), vec![2., 5., 8.])?),
+            new_shared(Array::linspace(1., 9., 9).into_shape((3, 3))?),
+            new_shared(Array::zeros((3, 3))),
+        );
+
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::from_shape_vec((3, 3), vec![1., 2., 3., 2., 5., 6., 2., 5., 8.])?,
+        )
+    }
+}
+
+mod backward {
+    use super::super::{
+        Backward, BufferedGradient, Gradient, MaximumBackward, MaximumBackwardLeft,
+        MaximumBackwardRight, MinimumBackward, MinimumBackwardLeft, MinimumBackwardRight,
+    };
+    use super::*;
+
+    #[test]
+    fn maximum_left_base_case() -> Result<(), Box<dyn Error>> {
+        let op = MaximumBackwardLeft::new(
+            new_shared(Array::linspace(1., 9., 9).into_shape((3, 3))?),
+            new_shared(Array::from_elem((3, 3), 5.)),
+            Rc::new(Gradient::ndarray_zeros((3, 3))),
+            Rc::new(BufferedGradient::from_ndarray(Rc::new(
+                Gradient::from_ndarray(Array::ones((3, 3))),
+            ))),
+        );
+        let expected = Array::from_shape_vec((3, 3), vec![0., 0., 0., 0., 0.5, 1., 1., 1., 1.])?;
+
+        op.backward();
+        are_similar(op.left_gradient.borrow(), &expected)?;
+
+        op.backward();
+        are_similar(op.left_gradient.borrow(), &(expected * 2.))
+    }
+
+    #[test]
+    fn maximum_right_reduction() -> Result<(), Box<dyn Error>> {
+        let op = MaximumBackwardRight::new(
+            new_shared(Array::linspace(1., 9., 9).into_shape((3, 3))?),
+            new_shared(Array::from_elem((1, 3), 5.)),
+            Rc::new(Gradient::ndarray_zeros((1, 3))),
+            Rc::new(BufferedGradient::from_ndarray(Rc::new(
+                Gradient::from_ndarray(Array::ones((3, 3))),
+            ))),
+        );
+        let expected = Array::from_shape_vec((1, 3), vec![2., 1.5, 1.])?;
+
+        op.backward();
+        are_similar(op.right_gradient.borrow(), &expected)?;
+
+        op.backward();
+        are_similar(op.right_gradient.borrow(), &(expected * 2.))
+    }
+
+    #[test]
+    fn maximum_backward() -> Result<(), Box<dyn Error>> {
+        let left_data = new_shared(Array::linspace(1., 9., 9).into_shape((3, 3))?);
+        let right_data = new_shared(Array::from_elem((3, 3), 5.));
+        let gradient = Rc::new(BufferedGradient::from_ndarray(Rc::new(
+            Gradient::from_ndarray(Array::ones((3, 3))),
+        )));
+        let op = MaximumBackward::new(
+            MaximumBackwardLeft::new(
+                left_data.clone(),
+                right_data.clone(),
+                Rc::new(Gradient::ndarray_zeros((3, 3))),
+                gradient.clone(),
+            ),
+            MaximumBackwardRight::new(
+                left_data,
+                right_data,
+                Rc::new(Gradient::ndarray_zeros((3, 3))),
+                gradient,
+            ),
+        );
+
+        op.backward();
+        are_similar(
+            op.left.left_gradient.borrow(),
+            &Array::from_shape_vec((3, 3), vec![0., 0., 0., 0., 0.5, 1., 1., 1., 1.])?,
+        )?;
+        are_similar(
+            op.right.right_gradient.borrow(),
+            &Array::from_shape_vec((3, 3), vec![1., 1., 1., 1., 0.5, 0., 0., 0., 0.])?,
+        )
+    }
+
+    #[test]
+    fn minimum_left_base_case() -> Result<(), Box<dyn Error>> {
+        let op = MinimumBackwardLeft::new(
+            new_shared(Array::linspace(1., 9., 9).into_shape((3, 3))?),
+            new_shared(Array::from_elem((3, 3), 5.)),
+            Rc::new(Gradient::ndarray_zeros((3, 3))),
+            Rc::new(BufferedGradient::from_ndarray(Rc::new(
+                Gradient::from_ndarray(Array::ones((3, 3))),
+            ))),
+        );
+        let expected = Array::from_shape_vec((3, 3), vec![1., 1., 1., 1., 0.5, 0., 0., 0., 0.])?;
+
+        op.backward();
+        are_similar(op.left_gradient.borrow(), &expected)?;
+
+        op.backward();
+        are_similar(op.left_gradient.borrow(), &(expected * 2.))
+    }
+
+    #[test]
+    fn minimum_right_reduction() -> Result<(), Box<dyn Error>> {
+        let op = MinimumBackwardRight::new(
+            new_shared(Array::linspace(1., 9., 9).into_shape((3, 3))?),
+            new_shared(Array::from_elem((1, 3), 5.)),
+            Rc::new(Gradient::ndarray_zeros((1, 3))),
+            Rc::new(BufferedGradient::from_ndarray(Rc::new(
+                Gradient::from_ndarray(Array::ones((3, 3))),
+            ))),
+        );
+        let expected = Array::from_shape_vec((1, 3), vec![1., 1.5, 2.])?;
+
+        op.backward();
+        are_similar(op.right_gradient.borrow(), &expected)?;
+
+        op.backward();
+        are_similar(op.right_gradient.borrow(), &(expected * 2.))
+    }
+
+    #[test]
+    fn minimum_backward() -> Result<(), Box<dyn Error>> {
+        let left_data = new_shared(Array::linspace(1., 9., 9).into_shape((3, 3))?);
+        let right_data = new_shared(Array::from_elem((3, 3), 5.));
+        let gradient = Rc::new(BufferedGradient::from_ndarray(Rc::new(
+            Gradient::from_ndarray(Array::ones((3, 3))),
+        )));
+        let op = MinimumBackward::new(
+            MinimumBackwardLeft::new(
+                left_data.clone(),
+                right_data.clone(),
+                Rc::new(Gradient::ndarray_zeros((3, 3))),
+                gradient.clone(),
+            ),
+            MinimumBackwardRight::new(
+                left_data,
+                right_data,
+                Rc::new(Gradient::ndarray_zeros((3, 3))),
+                gradient,
+            ),
+        );
+
+        op.backward();
+        are_similar(
+            op.left.left_gradient.borrow(),
+            &Array::from_shape_vec((3, 3), vec![1., 1., 1., 1., 0.5, 0., 0., 0., 0.])?,
+        )?;
+        are_similar(
+            op.right.right_gradient.borrow(),
+            &Array::from_shape_vec((3, 3), vec![0., 0., 0., 0., 0.5, 1., 1., 1., 1.])?,
+        )
+    }
+}
@@ -0,0 +1,309 @@
+use std::{
+    cell::{Ref, RefCell, RefMut},
+    collections::HashMap,
+    rc::Rc,
+};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    fusion::{Arg, Stage},
+    gradient::{FlatGradient, NoGrad},
+    planner::Pool,
+};
+
+/// A chain of element-wise operations evaluated in a single loop. The results of all the operations
+/// but the last one are never stored.
+pub(crate) struct Chain {
+    ops: Vec<Rc<dyn Forward>>,
+    stages: Vec<Stage>,
+    inputs: Vec<(usize, usize)>,
+    released: RefCell<HashMap<usize, Vec<usize>>>,
+}
+
+impl Chain {
+    /// Creates a new chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `ops` - operations of the chain, in topological order.
+    ///
+    /// * `stages` - functions computed by the operations.
+    ///
+    /// * `inputs` - position of the operation and of the operand of each input of the chain.
+    pub(crate) fn new(
+        ops: Vec<Rc<dyn Forward>>,
+        stages: Vec<Stage>,
+        inputs: Vec<(usize, usize)>,
+    ) -> Self {
+        Self {
+            ops,
+            stages,
+            inputs,
+            released: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the buffer holding the result of the chain.
+    pub(crate) fn data(&self) -> &dyn Buffer {
+        self.ops.last().unwrap().data()
+    }
+
+    /// Returns the buffers read by the chain.
+    pub(crate) fn inputs(&self) -> Vec<&dyn Buffer> {
+        self.inputs
+            .iter()
+            .map(|(op, operand)| self.ops[*op].operands()[*operand])
+            .collect()
+    }
+
+    /// Returns the buffers holding the results of the operations of the chain.
+    pub(crate) fn results(&self) -> impl Iterator<Item = &dyn Buffer> {
+        self.ops.iter().map(|op| op.data())
+    }
+
+    /// Discards the intermediate results of the chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `released` - shapes of the buffers already released by the memory planner, indexed by
+    ///   address. The entries concerning the chain are moved into it.
+    pub(crate) fn release(&self, released: &mut HashMap<usize, Vec<usize>>) {
+        let mut pool = Pool::default();
+        let mut own = self.released.borrow_mut();
+        self.ops[..self.ops.len() - 1]
+            .iter()
+            .map(|op| op.data())
+            .for_each(|data| {
+                let address = data.address();
+                if own.contains_key(&address) {
+                    return;
+                }
+
+                if let Some(shape) = released
+                    .remove(&address)
+                    .or_else(|| data.release(&mut pool))
+                {
+                    own.insert(address, shape);
+                }
+            });
+    }
+
+    /// Evaluates the operations of the chain one by one, storing all their results.
+    fn materialize(&self) {
+        let mut pool = Pool::default();
+        let mut released = self.released.borrow_mut();
+        self.ops.iter().for_each(|op| {
+            if let Some(shape) = released.remove(&op.data().address()) {
+                op.data().reserve(&shape, &mut pool);
+            }
+            op.forward();
+        });
+    }
+
+    /// Fills `args` with the arguments of the function of a stage.
+    ///
+    /// # Arguments
+    ///
+    /// * `stage` - stage of the chain.
+    ///
+    /// * `previous` - result of the previous stage.
+    ///
+    /// * `inputs` - inputs of the chain.
+    ///
+    /// * `index` - index of the element.
+    fn args(stage: &Stage, previous: f32, inputs: &[Ref<[f32]>], index: usize) -> [f32; 2] {
+        let mut args = [0.; 2];
+        stage.args.iter().zip(&mut args).for_each(|(arg, slot)| {
+            *slot = match arg {
+                Arg::Previous => previous,
+                Arg::Input(input) => inputs[*input][index],
+            }
+        });
+
+        args
+    }
+
+    /// Evaluates the chain.
+    fn forward(&self) {
+        let buffers = self.inputs();
+        let inputs: Option<Vec<Ref<[f32]>>> = buffers.iter().map(|input| input.flat()).collect();
+
+        if let (Some(inputs), Some(mut data)) = (inputs, self.data().flat_mut()) {
+            data.iter_mut().enumerate().for_each(|(index, data_el)| {
+                *data_el = self.stages.iter().fold(0., |previous, stage| {
+                    stage
+                        .kernel
+                        .eval(Self::args(stage, previous, &inputs, index))
+                });
+            });
+            return;
+        }
+
+        // Non contiguous buffers are handled by the operations themselves.
+        self.materialize();
+        self.release(&mut HashMap::new());
+    }
+
+    /// Back-propagates `gradient` through the chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `gradient` - gradient of the result of the chain.
+    ///
+    /// * `gradients` - gradients to accumulate into.
+    ///
+    /// * `slots` - position in `gradients` of the gradient of each input, if it has one.
+    fn backward(&self, gradient: &[f32], gradients: &mut [RefMut<[f32]>], slots: &[Option<usize>]) {
+        let buffers = self.inputs();
+        let inputs: Vec<Ref<[f32]>> = buffers.iter().map(|input| input.flat().unwrap()).collect();
+
+        let mut args = vec![[0.; 2]; self.stages.len()];
+        let mut values = vec![0.; self.stages.len()];
+        gradient.iter().enumerate().for_each(|(index, &grad_el)| {
+            // The intermediate results are recomputed element by element.
+            let mut previous = 0.;
+            for (position, stage) in self.stages.iter().enumerate() {
+                args[position] = Self::args(stage, previous, &inputs, index);
+                previous = stage.kernel.eval(args[position]);
+                values[position] = previous;
+            }
+
+            let mut grad_el = grad_el;
+            for (position, stage) in self.stages.iter().enumerate().rev() {
+                let partials = stage.kernel.partials(args[position], values[position]);
+                let mut previous_grad_el = 0.;
+                for (arg, partial) in stage.args.iter().zip(partials) {
+                    match arg {
+                        Arg::Previous => previous_grad_el = grad_el * partial,
+                        Arg::Input(input) => {
+                            if let Some(slot) = slots[*input] {
+                                gradients[slot][index] += grad_el * partial;
+                            }
+                        }
+                    }
+                }
+                grad_el = previous_grad_el;
+            }
+        });
+    }
+
+    /// Returns `true` if the inputs of the chain can be read element-wise.
+    fn is_contiguous(&self) -> bool {
+        self.inputs().iter().all(|input| input.flat().is_some())
+    }
+}
+
+pub(crate) struct Fused {
+    chain: Rc<Chain>,
+}
+
+impl Fused {
+    pub(crate) fn new(chain: Rc<Chain>) -> Self {
+        Self { chain }
+    }
+}
+
+impl Forward for Fused {
+    fn forward(&self) {
+        self.chain.forward();
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        self.chain.data()
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        self.chain.inputs()
+    }
+}
+
+pub(crate) struct FusedBackward {
+    chain: Rc<Chain>,
+    gradient: Rc<dyn FlatGradient>,
+    gradients: Vec<Rc<dyn FlatGradient>>,
+    slots: Vec<Option<usize>>,
+    tape: Vec<(Rc<dyn Backward>, Rc<dyn NoGrad>)>,
+}
+
+impl FusedBackward {
+    /// Creates the backward component of a chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain` - fused chain.
+    ///
+    /// * `gradient` - gradient of the result of the chain.
+    ///
+    /// * `input_gradients` - gradients of the inputs of the chain, if any.
+    ///
+    /// * `tape` - backward components of the operations of the chain, in topological order.
+    pub(crate) fn new(
+        chain: Rc<Chain>,
+        gradient: Rc<dyn FlatGradient>,
+        input_gradients: Vec<Option<Rc<dyn FlatGradient>>>,
+        tape: Vec<(Rc<dyn Backward>, Rc<dyn NoGrad>)>,
+    ) -> Self {
+        // An input read more than once is back-propagated into just once.
+        let mut gradients: Vec<Rc<dyn FlatGradient>> = Vec::new();
+        let slots = input_gradients
+            .into_iter()
+            .map(|input_gradient| {
+                let input_gradient = input_gradient?;
+                let slot = gradients
+                    .iter()
+                    .position(|other| Rc::ptr_eq(other, &input_gradient))
+                    .unwrap_or_else(|| {
+                        gradients.push(input_gradient);
+                        gradients.len() - 1
+                    });
+
+                Some(slot)
+            })
+            .collect();
+
+        Self {
+            chain,
+            gradient,
+            gradients,
+            slots,
+            tape,
+        }
+    }
+}
+
+impl Backward for FusedBackward {
+    fn backward(&self) {
+        let gradient = self.gradient.flat();
+        let gradients: Option<Vec<RefMut<[f32]>>> = self
+            .gradients
+            .iter()
+            .map(|other| other.flat_mut())
+            .collect();
+
+        if let (true, Some(gradient), Some(mut gradients)) =
+            (self.chain.is_contiguous(), gradient, gradients)
+        {
+            self.chain.backward(&gradient, &mut gradients, &self.slots);
+            return;
+        }
+
+        // Non contiguous buffers are handled by the operations themselves, which need the
+        // intermediate results.
+        self.chain.materialize();
+        self.tape.iter().rev().for_each(|(op, _)| op.backward());
+        self.chain.release(&mut HashMap::new());
+    }
+}
+
+impl NoGrad for FusedBackward {
+    fn no_grad(&self) {
+        self.tape.iter().for_each(|(_, grad)| grad.no_grad());
+    }
+
+    fn with_grad(&self) {
+        self.tape.iter().for_each(|(_, grad)| grad.with_grad());
+    }
+}
+
+#[cfg(test)]
+mod test;
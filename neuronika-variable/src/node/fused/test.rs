@@ -0,0 +1,167 @@
+use std::{collections::HashMap, error::Error, rc::Rc};
+
+use ndarray::{Array, Ix2};
+
+use crate::{
+    autograd::Forward,
+    fusion::{Arg, Stage},
+    node::{Multiplication, Sigmoid},
+    utils::{are_similar, new_shared, Shared},
+};
+
+use super::Chain;
+
+/// Returns a chain computing *sigmoid(l * r)* together with the shared buffers of *l*, *r*,
+/// *l * r* and *sigmoid(l * r)*.
+#[allow(clippy::type_complexity)]
+fn chain(
+    left: Array<f32, Ix2>,
+    right: Array<f32, Ix2>,
+) -> (
+    Rc<Chain>,
+    Shared<Array<f32, Ix2>>,
+    Shared<Array<f32, Ix2>>,
+    Shared<Array<f32, Ix2>>,
+    Shared<Array<f32, Ix2>>,
+) {
+    let left = new_shared(left);
+    let right = new_shared(right);
+    let product = new_shared(Array::zeros((3, 3)));
+    let data = new_shared(Array::zeros((3, 3)));
+    let ops: Vec<Rc<dyn Forward>> = vec![
+        Rc::new(Multiplication::new(
+            left.clone(),
+            right.clone(),
+            product.clone(),
+        )),
+        Rc::new(Sigmoid::new(product.clone(), data.clone())),
+    ];
+    let stages = ops
+        .iter()
+        .zip([vec![Arg::Input(0), Arg::Input(1)], vec![Arg::Previous]])
+        .map(|(op, args)| Stage {
+            kernel: op.kernel().unwrap(),
+            args,
+        })
+        .collect();
+    let chain = Rc::new(Chain::new(ops, stages, vec![(0, 0), (0, 1)]));
+    chain.release(&mut HashMap::new());
+
+    (chain, left, right, product, data)
+}
+
+#[allow(clippy::type_complexity)]
+fn operands() -> Result<(Array<f32, Ix2>, Array<f32, Ix2>), Box<dyn Error>> {
+    Ok((
+        Array::linspace(-4., 4., 9).into_shape((3, 3))?,
+        Array::linspace(-1., 1., 9).into_shape((3, 3))?,
+    ))
+}
+
+#[cfg(test)]
+mod forward {
+    use super::super::{Buffer, Forward, Fused};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let (left, right) = operands()?;
+        let (chain, left, right, product, data) = chain(left, right);
+        let op = Fused::new(chain);
+
+        assert_eq!(op.data().address(), data.address());
+        assert_eq!(product.borrow().len(), 0);
+
+        let operands = op.operands();
+        assert_eq!(operands.len(), 2);
+        assert_eq!(operands[0].address(), left.address());
+        assert_eq!(operands[1].address(), right.address());
+
+        Ok(())
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let (left, right) = operands()?;
+        let expected = (&left * &right).mapv(|el| 1. / (1. + (-el).exp()));
+        let (chain, _, _, product, data) = chain(left, right);
+        let op = Fused::new(chain);
+
+        op.forward();
+        are_similar(data.borrow(), &expected)?;
+        assert_eq!(product.borrow().len(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn non_contiguous() -> Result<(), Box<dyn Error>> {
+        let (left, right) = operands()?;
+        let left = left.reversed_axes();
+        let expected = (&left * &right).mapv(|el| 1. / (1. + (-el).exp()));
+        let (chain, _, _, product, data) = chain(left, right);
+        let op = Fused::new(chain);
+
+        op.forward();
+        are_similar(data.borrow(), &expected)?;
+        assert_eq!(product.borrow().len(), 0);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod backward {
+    use super::super::{Backward, Forward, Fused, FusedBackward};
+    use super::*;
+    use crate::gradient::{FlatGradient, Gradient};
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let (left, right) = operands()?;
+        let sigmoid = (&left * &right).mapv(|el| 1. / (1. + (-el).exp()));
+        let derivative = &sigmoid * &(1. - &sigmoid);
+        let (expected_left, expected_right) = (&derivative * &right, &derivative * &left);
+
+        let (chain, _, _, _, _) = chain(left, right);
+        let left_gradient = Rc::new(Gradient::ndarray_zeros((3, 3)));
+        let right_gradient = Rc::new(Gradient::ndarray_zeros((3, 3)));
+        let gradient: Rc<dyn FlatGradient> = Rc::new(Gradient::from_ndarray(Array::ones((3, 3))));
+        let forward_op = Fused::new(chain.clone());
+        let op = FusedBackward::new(
+            chain,
+            gradient,
+            vec![Some(left_gradient.clone()), Some(right_gradient.clone())],
+            Vec::new(),
+        );
+
+        forward_op.forward();
+        op.backward();
+        are_similar(left_gradient.borrow(), &expected_left)?;
+        are_similar(right_gradient.borrow(), &expected_right)?;
+
+        op.backward();
+        are_similar(left_gradient.borrow(), &(&expected_left * 2.))?;
+        are_similar(right_gradient.borrow(), &(&expected_right * 2.))
+    }
+
+    #[test]
+    fn shared_input() -> Result<(), Box<dyn Error>> {
+        let (left, _) = operands()?;
+        let sigmoid = (&left * &left).mapv(|el| 1. / (1. + (-el).exp()));
+        let expected = &sigmoid * &(1. - &sigmoid) * &left * 2.;
+
+        let (chain, _, _, _, _) = chain(left.clone(), left);
+        let input_gradient = Rc::new(Gradient::ndarray_zeros((3, 3)));
+        let gradient: Rc<dyn FlatGradient> = Rc::new(Gradient::from_ndarray(Array::ones((3, 3))));
+        let op = FusedBackward::new(
+            chain,
+            gradient,
+            vec![Some(input_gradient.clone()), Some(input_gradient.clone())],
+            Vec::new(),
+        );
+
+        op.backward();
+        are_similar(input_gradient.borrow(), &expected)
+    }
+}
@@ -0,0 +1,128 @@
+use std::{
+    f32::consts::{FRAC_1_SQRT_2, FRAC_2_SQRT_PI},
+    rc::Rc,
+};
+
+use ndarray::{Array, Dimension, Zip};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    gradient::Gradient,
+    utils::Shared,
+};
+
+/// *sqrt(2 / π)*, used by the tanh approximation.
+const SQRT_2_OVER_PI: f32 = FRAC_2_SQRT_PI * FRAC_1_SQRT_2;
+/// Cubic coefficient of the tanh approximation.
+const COEFFICIENT: f32 = 0.044_715;
+
+fn gelu(x: f32, approximate: bool) -> f32 {
+    if approximate {
+        0.5 * x * (1. + (SQRT_2_OVER_PI * (x + COEFFICIENT * x.powi(3))).tanh())
+    } else {
+        0.5 * x * (1. + libm::erff(x * FRAC_1_SQRT_2))
+    }
+}
+
+fn gelu_derivative(x: f32, approximate: bool) -> f32 {
+    if approximate {
+        let tanh = (SQRT_2_OVER_PI * (x + COEFFICIENT * x.powi(3))).tanh();
+        0.5 * (1. + tanh)
+            + 0.5 * x * (1. - tanh * tanh) * SQRT_2_OVER_PI * (1. + 3. * COEFFICIENT * x * x)
+    } else {
+        0.5 * (1. + libm::erff(x * FRAC_1_SQRT_2)) + 0.5 * x * SQRT_2_OVER_PI * (-0.5 * x * x).exp()
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+pub(crate) struct GELU<D>
+where
+    D: Dimension,
+{
+    operand_data: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D>>,
+    approximate: bool,
+}
+
+impl<D> GELU<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(
+        operand_data: Shared<Array<f32, D>>,
+        data: Shared<Array<f32, D>>,
+        approximate: bool,
+    ) -> Self {
+        Self {
+            operand_data,
+            data,
+            approximate,
+        }
+    }
+}
+
+impl<D> Forward for GELU<D>
+where
+    D: Dimension,
+{
+    fn forward(&self) {
+        Zip::from(&mut *self.data.borrow_mut())
+            .and(&*self.operand_data.borrow())
+            .for_each(|v, &o| *v = gelu(o, self.approximate));
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+pub(crate) struct GELUBackward<D>
+where
+    D: Dimension,
+{
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    operand_data: Shared<Array<f32, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+    approximate: bool,
+}
+
+impl<D> GELUBackward<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        operand_data: Shared<Array<f32, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+        approximate: bool,
+    ) -> Self {
+        Self {
+            operand_gradient,
+            operand_data,
+            gradient,
+            approximate,
+        }
+    }
+}
+
+impl<D> Backward for GELUBackward<D>
+where
+    D: Dimension,
+{
+    fn backward(&self) {
+        Zip::from(&mut *self.operand_gradient.borrow_mut())
+            .and(&*self.gradient.borrow())
+            .and(&*self.operand_data.borrow())
+            .for_each(|op_grad_el, &grad_el, &op_data_el| {
+                *op_grad_el += grad_el * gelu_derivative(op_data_el, self.approximate)
+            });
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,140 @@
+use std::error::Error;
+
+use ndarray::Array;
+
+use crate::utils::{are_similar, new_shared};
+
+#[cfg(test)]
+mod forward {
+    use super::super::{Forward, GELU};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_data = Array::zeros((3, 3));
+        let data = Array::ones((3, 3));
+        let op = GELU::new(
+            new_shared(operand_data.clone()),
+            new_shared(data.clone()),
+            false,
+        );
+
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.data.borrow(), &data)?;
+        assert!(!op.approximate);
+
+        Ok(())
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let op = GELU::new(
+            new_shared(Array::linspace(-3.5, 4.5, 9).into_shape((3, 3))?),
+            new_shared(Array::zeros((3, 3))),
+            false,
+        );
+
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::from_shape_vec(
+                (3, 3),
+                vec![
+                    -0.0008, -0.0155, -0.1002, -0.1543, 0.3457, 1.3998, 2.4845, 3.4992, 4.5000,
+                ],
+            )?,
+        )
+    }
+
+    #[test]
+    fn base_case_tanh() -> Result<(), Box<dyn Error>> {
+        let op = GELU::new(
+            new_shared(Array::linspace(-3.5, 4.5, 9).into_shape((3, 3))?),
+            new_shared(Array::zeros((3, 3))),
+            true,
+        );
+
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::from_shape_vec(
+                (3, 3),
+                vec![
+                    -0.0006, -0.0151, -0.1004, -0.1543, 0.3457, 1.3996, 2.4849, 3.4994, 4.5000,
+                ],
+            )?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod backward {
+    use std::rc::Rc;
+
+    use super::super::{Backward, GELUBackward, Gradient};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_gradient = Array::zeros((3, 3));
+        let operand_data = Array::ones((3, 3));
+        let gradient = Array::from_elem((3, 3), 2.);
+        let op = GELUBackward::new(
+            Rc::new(Gradient::from_ndarray(operand_gradient.clone())),
+            new_shared(operand_data.clone()),
+            Rc::new(Gradient::from_ndarray(gradient.clone())),
+            false,
+        );
+
+        are_similar(op.operand_gradient.borrow(), &operand_gradient)?;
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.gradient.borrow(), &gradient)?;
+        assert!(!op.approximate);
+
+        Ok(())
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let op = GELUBackward::new(
+            Rc::new(Gradient::ndarray_zeros((3, 3))),
+            new_shared(Array::linspace(-3.5, 4.5, 9).into_shape((3, 3))?),
+            Rc::new(Gradient::from_ndarray(Array::ones((3, 3)))),
+            false,
+        );
+        let expected = Array::from_shape_vec(
+            (3, 3),
+            vec![
+                -0.0028, -0.0376, -0.1275, 0.1325, 0.8675, 1.1275, 1.0376, 1.0028, 1.0001,
+            ],
+        )?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &expected)?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &(expected * 2.))
+    }
+
+    #[test]
+    fn base_case_tanh() -> Result<(), Box<dyn Error>> {
+        let op = GELUBackward::new(
+            Rc::new(Gradient::ndarray_zeros((3, 3))),
+            new_shared(Array::linspace(-3.5, 4.5, 9).into_shape((3, 3))?),
+            Rc::new(Gradient::from_ndarray(Array::ones((3, 3)))),
+            true,
+        );
+        let expected = Array::from_shape_vec(
+            (3, 3),
+            vec![
+                -0.0024, -0.0380, -0.1277, 0.1326, 0.8674, 1.1277, 1.0380, 1.0024, 1.0000,
+            ],
+        )?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &expected)?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &(expected * 2.))
+    }
+}
@@ -1,210 +1,98 @@
-use super::{
-    assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Cache, Data,
-    Forward, Gradient, LeakyReLU, LeakyReLUBackward, Overwrite, Tensor,
-};
+use std::error::Error;
 
-mod forward {
-    use super::{
-        assert_almost_equals, new_input, new_tensor, Cache, Data, Forward, LeakyReLU, Tensor,
-    };
-
-    #[test]
-    fn creation() {
-        let input = new_input((3, 3), vec![-4., -3., -2., -1., 0., 1., 2., 3., 4.]);
-        let node = LeakyReLU::new(input);
-
-        assert_eq!(*node.data(), Tensor::from_elem((3, 3), 0.));
-        assert_eq!(*node.data_mut(), Tensor::from_elem((3, 3), 0.));
-        assert!(!node.was_computed());
-    }
-
-    #[test]
-    fn computation_was_computed_transition() {
-        let input = new_input((3, 3), vec![-4., -3., -2., -1., 0., 1., 2., 3., 4.]);
-        let node = LeakyReLU::new(input);
-
-        node.forward();
-        assert!(node.was_computed());
-
-        node.forward();
-        assert!(node.was_computed());
+use ndarray::Array;
 
-        node.reset_computation();
-        assert!(!node.was_computed());
+use crate::utils::{are_similar, new_shared};
 
-        node.reset_computation();
-        assert!(!node.was_computed());
-    }
+#[cfg(test)]
+mod forward {
+    use super::super::{Forward, LeakyReLU};
+    use super::*;
 
     #[test]
-    fn forward() {
-        let input = new_input((3, 3), vec![-4., -3., -2., -1., 0., 1., 2., 3., 4.]);
-        let node = LeakyReLU::new(input.clone());
-
-        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-        node.forward();
-        assert_almost_equals(
-            &*node.data(),
-            &new_tensor((3, 3), vec![-0.04, -0.03, -0.02, -0.01, 0., 1., 2., 3., 4.]),
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_data = Array::zeros((3, 3));
+        let data = Array::ones((3, 3));
+        let op = LeakyReLU::new(
+            new_shared(operand_data.clone()),
+            new_shared(data.clone()),
+            0.2,
         );
 
-        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ No Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-        {
-            let mut data = input.data_mut();
-            *data = &*data + &Tensor::from_elem(1, 1.);
-        }
-        assert_almost_equals(
-            &*input.data(),
-            &new_tensor((3, 3), vec![-3., -2., -1., 0., 1., 2., 3., 4., 5.]),
-        );
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.data.borrow(), &data)?;
+        assert_eq!(op.slope, 0.2);
 
-        node.forward();
-        assert_almost_equals(
-            &*node.data(),
-            &new_tensor((3, 3), vec![-0.04, -0.03, -0.02, -0.01, 0., 1., 2., 3., 4.]),
-        );
-
-        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-        node.reset_computation();
-        node.forward();
-        assert_almost_equals(
-            &*node.data(),
-            &new_tensor((3, 3), vec![-0.03, -0.02, -0.01, 0., 1., 2., 3., 4., 5.]),
-        );
+        Ok(())
     }
 
     #[test]
-    fn debug() {
-        let input = new_input((3, 3), vec![-4., -3., -2., -1., 0., 1., 2., 3., 4.]);
-        let node = LeakyReLU::new(input);
-
-        let output = "LeakyReLU { data: [[0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0]], shape=[3, 3], strides=[3, 1], layout=Cc (0x5), const ndim=2, computed: false }";
-
-        assert_eq!(output, format!("{:?}", node));
-    }
-
-    #[test]
-    fn display() {
-        let input = new_input((3, 3), vec![-4., -3., -2., -1., 0., 1., 2., 3., 4.]);
-        let node = LeakyReLU::new(input);
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let op = LeakyReLU::new(
+            new_shared(Array::linspace(-3.5, 4.5, 9).into_shape((3, 3))?),
+            new_shared(Array::zeros((3, 3))),
+            0.2,
+        );
 
-        assert_eq!(format!("{}", node.data()), format!("{}", node));
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::from_shape_vec(
+                (3, 3),
+                vec![
+                    -0.7000, -0.5000, -0.3000, -0.1000, 0.5000, 1.5000, 2.5000, 3.5000, 4.5000,
+                ],
+            )?,
+        )
     }
 }
 
+#[cfg(test)]
 mod backward {
-    use super::{
-        assert_almost_equals, new_backward_input, new_input, new_tensor, Backward, Gradient,
-        LeakyReLUBackward, Overwrite, Tensor,
-    };
-
-    #[test]
-    fn creation() {
-        let node = LeakyReLUBackward::new(
-            new_backward_input(3, vec![0.; 3]),
-            new_input(3, vec![-1., 2., -3.]),
-        );
+    use std::rc::Rc;
 
-        assert_eq!(*node.gradient(), Tensor::from_elem(3, 0.));
-        assert_eq!(*node.gradient_mut(), Tensor::from_elem(3, 0.));
-        assert!(node.can_overwrite());
-    }
+    use super::super::{Backward, Gradient, LeakyReLUBackward};
+    use super::*;
 
     #[test]
-    fn computation_state_transition() {
-        let diff = new_backward_input(3, vec![0.; 3]);
-        let node = LeakyReLUBackward::new(diff.clone(), new_input(3, vec![-1., 2., -3.]));
-
-        node.backward();
-        assert!(node.can_overwrite());
-        assert!(!diff.can_overwrite());
-
-        node.backward();
-        assert!(node.can_overwrite());
-        assert!(!diff.can_overwrite());
-
-        diff.set_overwrite(true);
-        assert!(node.can_overwrite());
-        assert!(diff.can_overwrite());
-
-        diff.set_overwrite(true);
-        assert!(node.can_overwrite());
-        assert!(diff.can_overwrite());
-
-        node.set_overwrite(false);
-        assert!(!node.can_overwrite());
-        assert!(diff.can_overwrite());
-
-        node.set_overwrite(false);
-        assert!(!node.can_overwrite());
-        assert!(diff.can_overwrite());
-
-        node.backward();
-        assert!(!node.can_overwrite());
-        assert!(!diff.can_overwrite());
-
-        node.backward();
-        assert!(!node.can_overwrite());
-        assert!(!diff.can_overwrite());
-    }
-
-    #[test]
-    fn backward() {
-        let diff = new_backward_input(3, vec![0.; 3]);
-        let node = LeakyReLUBackward::new(diff.clone(), new_input(3, vec![-1., 2., -3.]));
-
-        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Seed Gradient ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-        *node.gradient_mut() = new_tensor(3, vec![1.; 3]);
-        assert_almost_equals(&*node.gradient(), &new_tensor(3, vec![1.; 3]));
-
-        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ First Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-        node.backward();
-        assert_almost_equals(&*diff.gradient(), &new_tensor(3, vec![0.01, 1., 0.01]));
-
-        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Second Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-        node.backward();
-        assert_almost_equals(&*diff.gradient(), &new_tensor(3, vec![0.02, 2., 0.02]));
-
-        // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Third Evaluation ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-        diff.set_overwrite(true);
-        node.backward();
-        assert_almost_equals(&*diff.gradient(), &new_tensor(3, vec![0.01, 1., 0.01]));
-    }
-
-    #[test]
-    fn no_grad() {
-        // LeakyReLUBackward
-        let node = LeakyReLUBackward::new(
-            new_backward_input((3, 3), vec![0.; 9]),
-            new_input((3, 3), vec![0.; 9]),
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_gradient = Array::zeros((3, 3));
+        let operand_data = Array::ones((3, 3));
+        let gradient = Array::from_elem((3, 3), 2.);
+        let op = LeakyReLUBackward::new(
+            Rc::new(Gradient::from_ndarray(operand_gradient.clone())),
+            new_shared(operand_data.clone()),
+            Rc::new(Gradient::from_ndarray(gradient.clone())),
+            0.2,
         );
 
-        node.no_grad();
-        assert!(node.gradient.borrow().is_none());
+        are_similar(op.operand_gradient.borrow(), &operand_gradient)?;
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.gradient.borrow(), &gradient)?;
+        assert_eq!(op.slope, 0.2);
 
-        node.with_grad();
-        assert_eq!(&*node.gradient(), Tensor::zeros(node.shape));
+        Ok(())
     }
 
     #[test]
-    fn debug() {
-        let node = LeakyReLUBackward::new(
-            new_backward_input((3, 3), vec![0.; 9]),
-            new_input((3, 3), vec![0.; 9]),
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let op = LeakyReLUBackward::new(
+            Rc::new(Gradient::ndarray_zeros((3, 3))),
+            new_shared(Array::linspace(-3.5, 4.5, 9).into_shape((3, 3))?),
+            Rc::new(Gradient::from_ndarray(Array::ones((3, 3)))),
+            0.2,
         );
-
-        let output = "LeakyReLUBackward { gradient: Some([[0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0],\n [0.0, 0.0, 0.0]], shape=[3, 3], strides=[3, 1], layout=Cc (0x5), const ndim=2), overwrite: true }";
-
-        assert_eq!(output, format!("{:?}", node));
-    }
-
-    #[test]
-    fn display() {
-        let node = LeakyReLUBackward::new(
-            new_backward_input((3, 3), vec![0.; 9]),
-            new_input((3, 3), vec![0.; 9]),
-        );
-
-        assert_eq!(format!("{}", node.gradient()), format!("{}", node));
+        let expected = Array::from_shape_vec(
+            (3, 3),
+            vec![
+                0.2000, 0.2000, 0.2000, 0.2000, 1.0000, 1.0000, 1.0000, 1.0000, 1.0000,
+            ],
+        )?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &expected)?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &(expected * 2.))
     }
 }
@@ -3,8 +3,9 @@ use std::rc::Rc;
 use ndarray::{Array, Axis, Dimension, Zip};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
+    onnx::{Attribute, Operator},
     utils::Shared,
 };
 
@@ -50,6 +51,20 @@ where
                     .for_each(|lane_v_el, &lane_o_el| *lane_v_el = lane_o_el - log_sum_exp - max);
             });
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(
+            Operator::new("LogSoftmax").attribute("axis", Attribute::Int(self.axis.index() as i64)),
+        )
+    }
 }
 
 pub(crate) struct LogSoftmaxBackward<D>
@@ -3,8 +3,9 @@ use std::rc::Rc;
 use ndarray::{linalg::general_mat_mul, Array2, Ix2};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
+    onnx::Operator,
     utils::Shared,
 };
 
@@ -38,6 +39,18 @@ impl Forward for MatrixMatrixMul {
             &mut *self.data.borrow_mut(),
         );
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.left_data, &self.right_data]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("MatMul"))
+    }
 }
 
 pub(crate) struct MatrixMatrixMulBackwardLeft {
@@ -3,8 +3,9 @@ use std::rc::Rc;
 use ndarray::{arr0, Array, Dimension, Ix0, Zip};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
+    onnx::{Attribute, Operator},
     utils::Shared,
 };
 
@@ -32,6 +33,18 @@ where
     fn forward(&self) {
         *self.data.borrow_mut() = arr0(self.operand_data.borrow().mean().unwrap());
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("ReduceMean").attribute("keepdims", Attribute::Int(0)))
+    }
 }
 
 pub(crate) struct MeanBackward<D>
@@ -1,21 +1,44 @@
+mod abs;
 mod absolute_error;
 mod addition;
+mod asin;
+mod atan;
 mod bce;
 mod bce_with_logits;
+mod celu;
+mod checkpoint;
 mod chunk;
+mod clamp;
 mod concatenate;
 mod convolution;
+mod convolution_transpose;
+mod cos;
+mod cosh;
+mod digamma;
 mod division;
 mod dropout;
+mod elu;
+mod erf;
+mod erfinv;
 mod exp;
+mod expm1;
+mod extremum;
+mod fused;
+mod gelu;
+mod hardsigmoid;
+mod hardswish;
 mod kldiv;
 mod leaky_relu;
+mod lgamma;
+mod log1p;
+mod log2;
 mod logn;
 mod logsoftmax;
 mod matrix_matrix_mul;
 mod matrix_matrix_mul_t;
 mod matrix_vector_mul;
 mod mean;
+mod mish;
 mod multi_concatenate;
 mod multi_stack;
 mod multiplication;
@@ -23,39 +46,73 @@ mod negation;
 mod nll;
 mod pad;
 mod power;
+mod powf;
 mod relu;
+mod reshape;
+mod rsqrt;
+mod select;
+mod selu;
 mod sigmoid;
+mod sign;
+mod silu;
+mod sin;
+mod sinh;
 mod softmax;
 mod softplus;
+mod softsign;
 mod sqrt;
 mod squared_error;
 mod stack;
 mod subtraction;
 mod sum;
+mod tan;
 mod tanh;
 mod transpose;
 mod unsqueeze;
 mod vector_matrix_mul;
 mod vector_vector_mul;
 
+pub(crate) use abs::*;
 pub(crate) use absolute_error::*;
 pub(crate) use addition::*;
+pub(crate) use asin::*;
+pub(crate) use atan::*;
 pub(crate) use bce::*;
 pub(crate) use bce_with_logits::*;
+pub(crate) use celu::*;
+pub(crate) use checkpoint::*;
 pub(crate) use chunk::*;
+pub(crate) use clamp::*;
 pub(crate) use concatenate::*;
 pub(crate) use convolution::*;
+pub(crate) use convolution_transpose::*;
+pub(crate) use cos::*;
+pub(crate) use cosh::*;
+pub(crate) use digamma::*;
 pub(crate) use division::*;
 pub(crate) use dropout::*;
+pub(crate) use elu::*;
+pub(crate) use erf::*;
+pub(crate) use erfinv::*;
 pub(crate) use exp::*;
+pub(crate) use expm1::*;
+pub(crate) use extremum::*;
+pub(crate) use fused::*;
+pub(crate) use gelu::*;
+pub(crate) use hardsigmoid::*;
+pub(crate) use hardswish::*;
 pub(crate) use kldiv::*;
 pub(crate) use leaky_relu::*;
+pub(crate) use lgamma::*;
+pub(crate) use log1p::*;
+pub(crate) use log2::*;
 pub(crate) use logn::*;
 pub(crate) use logsoftmax::*;
 pub(crate) use matrix_matrix_mul::*;
 pub(crate) use matrix_matrix_mul_t::*;
 pub(crate) use matrix_vector_mul::*;
 pub(crate) use mean::*;
+pub(crate) use mish::*;
 pub(crate) use multi_concatenate::*;
 pub(crate) use multi_stack::*;
 pub(crate) use multiplication::*;
@@ -63,19 +120,30 @@ pub(crate) use negation::*;
 pub(crate) use nll::*;
 pub(crate) use pad::*;
 pub(crate) use power::*;
+pub(crate) use powf::*;
 pub(crate) use relu::*;
+pub(crate) use reshape::*;
+pub(crate) use rsqrt::*;
+pub(crate) use select::*;
+pub(crate) use selu::*;
 pub(crate) use sigmoid::*;
+pub(crate) use sign::*;
+pub(crate) use silu::*;
+pub(crate) use sin::*;
+pub(crate) use sinh::*;
 pub(crate) use softmax::*;
 pub(crate) use softplus::*;
+pub(crate) use softsign::*;
 pub(crate) use sqrt::*;
 pub(crate) use squared_error::*;
 pub(crate) use stack::*;
 pub(crate) use subtraction::*;
 pub(crate) use sum::*;
+pub(crate) use tan::*;
 pub(crate) use tanh::*;
 pub(crate) use transpose::*;
 pub(crate) use unsqueeze::*;
 pub(crate) use vector_matrix_mul::*;
 pub(crate) use vector_vector_mul::*;
 
-pub use pad::{Constant, PaddingMode, Reflective, Replicative, Zero};
+pub use pad::{Circular, Constant, Interpolation, PaddingMode, Reflective, Replicative, Zero};
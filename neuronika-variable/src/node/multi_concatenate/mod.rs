@@ -3,8 +3,9 @@ use std::rc::Rc;
 use ndarray::{Array, Axis, Dimension, Slice};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
+    onnx::{Attribute, Operator},
     utils::Shared,
 };
 
@@ -50,6 +51,21 @@ where
             offset += axis_len;
         });
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        self.operands_data
+            .iter()
+            .map(|operand| operand as &dyn Buffer)
+            .collect()
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("Concat").attribute("axis", Attribute::Int(self.axis.index() as i64)))
+    }
 }
 
 pub(crate) struct MultiConcatenateBackward<D>
@@ -3,7 +3,7 @@ use std::rc::Rc;
 use ndarray::{Array, Axis, Dimension, RemoveAxis};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
     utils::Shared,
 };
@@ -48,6 +48,17 @@ where
                 axis_data.assign(&operand_data)
             });
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        self.operands_data
+            .iter()
+            .map(|operand| operand as &dyn Buffer)
+            .collect()
+    }
 }
 
 pub(crate) struct MultiStackBackward<D>
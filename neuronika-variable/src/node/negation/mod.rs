@@ -3,8 +3,10 @@ use std::rc::Rc;
 use ndarray::{Array, Dimension, Zip};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
+    fusion::Kernel,
     gradient::Gradient,
+    onnx::Operator,
     utils::Shared,
 };
 
@@ -34,6 +36,22 @@ where
             .and(&*self.operand_data.borrow())
             .for_each(|v, &o| *v = -o);
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn kernel(&self) -> Option<Kernel> {
+        Some(Kernel::Unary(|x| -x, |_, _| -1.))
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("Neg"))
+    }
 }
 
 pub(crate) struct NegationBackward<D>
@@ -3,7 +3,7 @@ use std::rc::Rc;
 use ndarray::{arr0, Array, Axis, Dimension, Ix0, RemoveAxis, Zip};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
     utils::Shared,
     Reduction,
@@ -65,6 +65,14 @@ where
             }
         };
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.input_data, &self.target_data]
+    }
 }
 
 #[allow(clippy::upper_case_acronyms)]
@@ -0,0 +1,46 @@
+use ndarray::{ArrayView, ArrayViewMut, Dimension, RemoveAxis};
+
+use super::{
+    padding_mode::{gather, scatter},
+    PaddingMode, SampleDim,
+};
+
+/// Maps a position of the padded array to the one of the original array, wrapping it around the
+/// axis.
+fn source(position: usize, padding: usize, len: usize) -> usize {
+    (position as isize - padding as isize).rem_euclid(len as isize) as usize
+}
+
+/// Circular padding.
+///
+/// The padding before each axis is filled with the elements at its end and vice versa, as if the
+/// variable were periodic.
+#[derive(Copy, Clone, Debug)]
+pub struct Circular;
+
+impl<D> PaddingMode<D> for Circular
+where
+    D: Dimension,
+    D::Smaller: RemoveAxis,
+{
+    fn pad(
+        &self,
+        padded: &mut ArrayViewMut<f32, SampleDim<D>>,
+        base: &ArrayView<f32, SampleDim<D>>,
+        padding: SampleDim<D>,
+    ) {
+        gather(padded, base, padding, source);
+    }
+
+    fn unpad(
+        &self,
+        gradient: &mut ArrayViewMut<f32, SampleDim<D>>,
+        padded_gradient: &ArrayView<f32, SampleDim<D>>,
+        padding: SampleDim<D>,
+    ) {
+        scatter(gradient, padded_gradient, padding, source);
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,63 @@
+use super::{Circular, PaddingMode};
+
+use ndarray::{self, Array, IntoDimension, Ix3, Ix4};
+
+#[test]
+fn test_1d() {
+    let padding = Circular;
+
+    let base = Array::range(0., 5., 1.);
+    let mut padded = Array::<f32, _>::zeros(9);
+
+    PaddingMode::<Ix3>::pad(
+        &padding,
+        &mut padded.view_mut(),
+        &base.view(),
+        [2].into_dimension(),
+    );
+
+    assert_eq!(padded, ndarray::array![3., 4., 0., 1., 2., 3., 4., 0., 1.]);
+}
+
+#[test]
+fn test_2d_asymmetric() {
+    let padding = Circular;
+
+    let base = Array::range(0., 9., 1.).into_shape((3, 3)).unwrap();
+    let mut padded = Array::<f32, _>::zeros((4, 6));
+
+    PaddingMode::<Ix4>::pad(
+        &padding,
+        &mut padded.view_mut(),
+        &base.view(),
+        [1, 2].into_dimension(),
+    );
+
+    assert_eq!(
+        padded,
+        ndarray::array![
+            [7., 8., 6., 7., 8., 6.],
+            [1., 2., 0., 1., 2., 0.],
+            [4., 5., 3., 4., 5., 3.],
+            [7., 8., 6., 7., 8., 6.],
+        ]
+    );
+}
+
+#[test]
+fn test_unpad() {
+    let padding = Circular;
+
+    let padded_gradient = Array::range(1., 8., 1.);
+    let mut gradient = Array::<f32, _>::zeros(3);
+
+    PaddingMode::<Ix3>::unpad(
+        &padding,
+        &mut gradient.view_mut(),
+        &padded_gradient.view(),
+        [2].into_dimension(),
+    );
+
+    // The padded positions map to [1, 2, 0, 1, 2, 0, 1].
+    assert_eq!(gradient, ndarray::array![3. + 6., 1. + 4. + 7., 2. + 5.]);
+}
@@ -24,19 +24,16 @@ where
         let mut base_slice = padded.view_mut();
 
         base_slice.slice_each_axis_inplace(|ax| {
-            let (ax_index, ax_len) = (ax.axis.index(), base.len_of(ax.axis));
-            let range = {
-                if padding_slice[ax_index] != 0 {
-                    padding_slice[ax_index] as isize..-(padding_slice[ax_index] as isize)
-                } else {
-                    0..ax_len as isize
-                }
-            };
-            Slice::from(range)
+            let (start, len) = (padding_slice[ax.axis.index()], base.len_of(ax.axis));
+            Slice::from(start..start + len)
         });
 
         base_slice.assign(base);
     }
+
+    fn onnx_mode(&self) -> Option<(&'static str, f32)> {
+        Some(("constant", self.0))
+    }
 }
 
 #[cfg(test)]
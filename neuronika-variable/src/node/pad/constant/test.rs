@@ -29,3 +29,20 @@ fn test() {
         ]
     );
 }
+
+#[test]
+fn test_unpad() {
+    let padding = Constant(8.);
+
+    let padded_gradient = Array::range(0., 12., 1.).into_shape((3, 4)).unwrap();
+    let mut gradient = Array::<f32, _>::ones((2, 2));
+
+    PaddingMode::<Ix4>::unpad(
+        &padding,
+        &mut gradient.view_mut(),
+        &padded_gradient.view(),
+        [1, 1].into_dimension(),
+    );
+
+    assert_eq!(gradient, ndarray::array![[6., 7.], [10., 11.]]);
+}
@@ -0,0 +1,255 @@
+use std::rc::Rc;
+
+use ndarray::{Array, ArrayBase, ArrayD, ArrayViewMut1, Axis, Data, Dimension, Zip};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    gradient::Gradient,
+    utils::Shared,
+};
+
+/// Coefficient of the cubic convolution kernel, the same used by *PyTorch* and *OpenCV*.
+const CUBIC_COEFFICIENT: f32 = -0.75;
+
+/// Interpolation algorithm used to resize the spatial dimensions of a variable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interpolation {
+    /// Each element takes the value of the nearest one of the source.
+    Nearest,
+    /// Linear interpolation along each spatial dimension. This is *bilinear* interpolation for
+    /// variables of dimensionality 4 and *trilinear* interpolation for those of dimensionality 5.
+    Linear,
+    /// Cubic convolution along each spatial dimension. This is *bicubic* interpolation for
+    /// variables of dimensionality 4.
+    Cubic,
+}
+
+/// Source elements, together with their weights, of each element of the output along an axis.
+type Taps = Vec<Vec<(usize, f32)>>;
+
+/// Maps the element at `index` of an axis of length `out_len` to a, possibly fractional, position
+/// on an axis of length `in_len`.
+///
+/// When `align_corners` is `true` the centers of the corner elements of the two axes are aligned,
+/// otherwise their outer edges are.
+fn source_position(index: usize, in_len: usize, out_len: usize, align_corners: bool) -> f32 {
+    if align_corners {
+        if out_len > 1 {
+            index as f32 * (in_len - 1) as f32 / (out_len - 1) as f32
+        } else {
+            0.
+        }
+    } else {
+        (index as f32 + 0.5) * in_len as f32 / out_len as f32 - 0.5
+    }
+}
+
+/// Computes the taps needed to resize an axis of length `in_len` to `out_len`.
+fn taps(in_len: usize, out_len: usize, mode: Interpolation, align_corners: bool) -> Taps {
+    let last = in_len as isize - 1;
+    let clamp = |index: isize| index.clamp(0, last) as usize;
+
+    (0..out_len)
+        .map(|index| match mode {
+            Interpolation::Nearest => {
+                let position = (index * in_len) as f32 / out_len as f32;
+                vec![(clamp(position.floor() as isize), 1.)]
+            }
+            Interpolation::Linear => {
+                let position = source_position(index, in_len, out_len, align_corners).max(0.);
+                let (left, lambda) = (position.floor(), position - position.floor());
+                let left = left as isize;
+                vec![(clamp(left), 1. - lambda), (clamp(left + 1), lambda)]
+            }
+            Interpolation::Cubic => {
+                let position = source_position(index, in_len, out_len, align_corners);
+                let (left, t) = (position.floor(), position - position.floor());
+                let left = left as isize;
+                let a = CUBIC_COEFFICIENT;
+                let near = |x: f32| ((a + 2.) * x - (a + 3.)) * x * x + 1.;
+                let far = |x: f32| ((a * x - 5. * a) * x + 8. * a) * x - 4. * a;
+                vec![
+                    (clamp(left - 1), far(t + 1.)),
+                    (clamp(left), near(t)),
+                    (clamp(left + 1), near(1. - t)),
+                    (clamp(left + 2), far(2. - t)),
+                ]
+            }
+        })
+        .collect()
+}
+
+/// Computes the taps of each spatial dimension needed to resize `in_shape` to `out_shape`.
+fn spatial_taps(
+    in_shape: &[usize],
+    out_shape: &[usize],
+    mode: Interpolation,
+    align_corners: bool,
+) -> Vec<Taps> {
+    in_shape
+        .iter()
+        .zip(out_shape)
+        .skip(2)
+        .map(|(in_len, out_len)| taps(*in_len, *out_len, mode, align_corners))
+        .collect()
+}
+
+/// Applies `taps` along each spatial dimension of `array`, one dimension at a time.
+///
+/// When `scatter` is `false` every element of the result is the weighted sum of its source
+/// elements in `array`. When it's `true` the transposed operation is computed instead, and every
+/// element of `array` is distributed to its sources with the corresponding weights, so that the
+/// result has the shape of the source.
+fn resample<S, D>(
+    array: &ArrayBase<S, D>,
+    taps: &[Taps],
+    out_shape: &[usize],
+    scatter: bool,
+) -> ArrayD<f32>
+where
+    S: Data<Elem = f32>,
+    D: Dimension,
+{
+    let mut current = array.view().into_dyn().to_owned();
+    for (axis, taps) in taps.iter().enumerate() {
+        let axis = Axis(axis + 2);
+        let mut shape = current.shape().to_vec();
+        shape[axis.index()] = out_shape[axis.index()];
+
+        let mut next = ArrayD::zeros(shape);
+        Zip::from(next.lanes_mut(axis))
+            .and(current.lanes(axis))
+            .par_for_each(|mut out_lane: ArrayViewMut1<f32>, in_lane| {
+                if scatter {
+                    taps.iter().zip(in_lane).for_each(|(taps, value)| {
+                        taps.iter()
+                            .for_each(|(index, weight)| out_lane[*index] += weight * value)
+                    });
+                } else {
+                    out_lane.iter_mut().zip(taps).for_each(|(out_el, taps)| {
+                        *out_el = taps
+                            .iter()
+                            .map(|(index, weight)| weight * in_lane[*index])
+                            .sum()
+                    });
+                }
+            });
+        current = next;
+    }
+
+    current
+}
+
+pub(crate) struct Interpolate<D>
+where
+    D: Dimension,
+{
+    operand_data: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D>>,
+    taps: Vec<Taps>,
+}
+
+impl<D> Interpolate<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(
+        operand_data: Shared<Array<f32, D>>,
+        data: Shared<Array<f32, D>>,
+        mode: Interpolation,
+        align_corners: bool,
+    ) -> Self {
+        let taps = spatial_taps(
+            operand_data.borrow().shape(),
+            data.borrow().shape(),
+            mode,
+            align_corners,
+        );
+
+        Self {
+            operand_data,
+            data,
+            taps,
+        }
+    }
+}
+
+impl<D> Forward for Interpolate<D>
+where
+    D: Dimension,
+{
+    fn forward(&self) {
+        let mut data = self.data.borrow_mut();
+        let result = resample(
+            &*self.operand_data.borrow(),
+            &self.taps,
+            data.shape(),
+            false,
+        );
+
+        data.assign(&result.into_dimensionality::<D>().unwrap());
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+}
+
+pub(crate) struct InterpolateBackward<D>
+where
+    D: Dimension,
+{
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+    taps: Vec<Taps>,
+}
+
+impl<D> InterpolateBackward<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+        mode: Interpolation,
+        align_corners: bool,
+    ) -> Self {
+        let taps = spatial_taps(
+            operand_gradient.shape().slice(),
+            gradient.shape().slice(),
+            mode,
+            align_corners,
+        );
+
+        Self {
+            operand_gradient,
+            gradient,
+            taps,
+        }
+    }
+}
+
+impl<D> Backward for InterpolateBackward<D>
+where
+    D: Dimension,
+{
+    fn backward(&self) {
+        let mut operand_gradient = self.operand_gradient.borrow_mut();
+        let result = resample(
+            &*self.gradient.borrow(),
+            &self.taps,
+            operand_gradient.shape(),
+            true,
+        );
+
+        *operand_gradient += &result.into_dimensionality::<D>().unwrap();
+    }
+}
+
+#[cfg(test)]
+mod test;
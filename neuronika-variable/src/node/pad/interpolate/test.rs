@@ -0,0 +1,152 @@
+use std::{error::Error, rc::Rc};
+
+use ndarray::{array, Array};
+
+use super::Interpolation;
+use crate::utils::{are_similar, new_shared};
+
+#[cfg(test)]
+mod forward {
+    use super::super::{Forward, Interpolate};
+    use super::*;
+
+    #[test]
+    fn creation() {
+        let input = new_shared(array![[[1., 2.]]]);
+        let data = new_shared(Array::zeros((1, 1, 4)));
+        let op = Interpolate::new(input, data.clone(), Interpolation::Nearest, false);
+
+        assert_eq!(*op.data.borrow(), *data.borrow());
+        assert_eq!(op.operands().len(), 1);
+    }
+
+    #[test]
+    fn nearest() -> Result<(), Box<dyn Error>> {
+        let input = new_shared(array![[[1., 2., 3.]]]);
+        let data = new_shared(Array::zeros((1, 1, 6)));
+        let op = Interpolate::new(input, data.clone(), Interpolation::Nearest, false);
+
+        op.forward();
+        are_similar(data.borrow(), &array![[[1., 1., 2., 2., 3., 3.]]])?;
+
+        op.forward();
+        are_similar(data.borrow(), &array![[[1., 1., 2., 2., 3., 3.]]])
+    }
+
+    #[test]
+    fn linear() -> Result<(), Box<dyn Error>> {
+        let input = new_shared(array![[[1., 2.]]]);
+        let data = new_shared(Array::zeros((1, 1, 4)));
+        let op = Interpolate::new(input, data.clone(), Interpolation::Linear, false);
+
+        op.forward();
+        are_similar(data.borrow(), &array![[[1., 1.25, 1.75, 2.]]])
+    }
+
+    #[test]
+    fn bilinear_align_corners() -> Result<(), Box<dyn Error>> {
+        let input = new_shared(array![[1., 2.], [3., 4.]].into_shape((1, 1, 2, 2))?);
+        let data = new_shared(Array::zeros((1, 1, 3, 3)));
+        let op = Interpolate::new(input, data.clone(), Interpolation::Linear, true);
+
+        op.forward();
+        are_similar(
+            data.borrow(),
+            &array![[1., 1.5, 2.], [2., 2.5, 3.], [3., 3.5, 4.]].into_shape((1, 1, 3, 3))?,
+        )
+    }
+
+    #[test]
+    fn trilinear_downsample() -> Result<(), Box<dyn Error>> {
+        let input = new_shared(Array::range(0., 8., 1.).into_shape((1, 1, 2, 2, 2))?);
+        let data = new_shared(Array::zeros((1, 1, 1, 1, 1)));
+        let op = Interpolate::new(input, data.clone(), Interpolation::Linear, false);
+
+        op.forward();
+        are_similar(data.borrow(), &Array::from_elem((1, 1, 1, 1, 1), 3.5))
+    }
+
+    #[test]
+    fn cubic() -> Result<(), Box<dyn Error>> {
+        let input = new_shared(array![[[0., 1., 2., 3.]]]);
+        let data = new_shared(Array::zeros((1, 1, 7)));
+        let op = Interpolate::new(input, data.clone(), Interpolation::Cubic, true);
+
+        op.forward();
+        are_similar(
+            data.borrow(),
+            &array![[[0., 0.40625, 1., 1.5, 2., 2.59375, 3.]]],
+        )
+    }
+
+    #[test]
+    fn bicubic_same_size() -> Result<(), Box<dyn Error>> {
+        let input = new_shared(array![[1., 2., 3.], [4., 5., 6.]].into_shape((1, 1, 2, 3))?);
+        let data = new_shared(Array::zeros((1, 1, 2, 3)));
+        let op = Interpolate::new(input, data.clone(), Interpolation::Cubic, false);
+
+        op.forward();
+        are_similar(
+            data.borrow(),
+            &array![[1., 2., 3.], [4., 5., 6.]].into_shape((1, 1, 2, 3))?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod backward {
+    use super::super::{Backward, InterpolateBackward};
+    use super::*;
+    use crate::gradient::Gradient;
+
+    #[test]
+    fn nearest() -> Result<(), Box<dyn Error>> {
+        let operand_gradient = Rc::new(Gradient::ndarray_zeros((1, 1, 2)));
+        let gradient = Rc::new(Gradient::from_ndarray(array![[[1., 2., 3., 4.]]]));
+        let op = InterpolateBackward::new(
+            operand_gradient.clone(),
+            gradient,
+            Interpolation::Nearest,
+            false,
+        );
+
+        op.backward();
+        are_similar(operand_gradient.borrow(), &array![[[3., 7.]]])?;
+
+        op.backward();
+        are_similar(operand_gradient.borrow(), &array![[[6., 14.]]])
+    }
+
+    #[test]
+    fn linear() -> Result<(), Box<dyn Error>> {
+        let operand_gradient = Rc::new(Gradient::ndarray_zeros((1, 1, 2)));
+        let gradient = Rc::new(Gradient::from_ndarray(array![[[1., 2., 3., 4.]]]));
+        let op = InterpolateBackward::new(
+            operand_gradient.clone(),
+            gradient,
+            Interpolation::Linear,
+            false,
+        );
+
+        op.backward();
+        are_similar(operand_gradient.borrow(), &array![[[3.25, 6.75]]])
+    }
+
+    #[test]
+    fn bilinear_align_corners() -> Result<(), Box<dyn Error>> {
+        let operand_gradient = Rc::new(Gradient::ndarray_zeros((1, 1, 2, 2)));
+        let gradient = Rc::new(Gradient::from_ndarray(Array::ones((1, 1, 3, 3))));
+        let op = InterpolateBackward::new(
+            operand_gradient.clone(),
+            gradient,
+            Interpolation::Linear,
+            true,
+        );
+
+        op.backward();
+        are_similar(
+            operand_gradient.borrow(),
+            &Array::from_elem((1, 1, 2, 2), 2.25),
+        )
+    }
+}
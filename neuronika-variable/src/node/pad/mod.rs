@@ -1,4 +1,6 @@
+mod circular;
 mod constant;
+mod interpolate;
 mod padding_mode;
 mod reflective;
 mod replicative;
@@ -6,24 +8,41 @@ mod zero;
 
 use std::rc::Rc;
 
-use ndarray::{Array, Dimension, RemoveAxis, Slice};
+use ndarray::{Array, Dimension, RemoveAxis};
 
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
+    onnx::{Attribute, Initializer, Operator},
     utils::Shared,
 };
 
+pub use circular::Circular;
 pub use constant::Constant;
+pub use interpolate::Interpolation;
 pub use padding_mode::PaddingMode;
 pub use reflective::Reflective;
 pub use replicative::Replicative;
 pub use zero::Zero;
 
+pub(crate) use interpolate::{Interpolate, InterpolateBackward};
+
 type SampleDim<D> = <<D as Dimension>::Smaller as Dimension>::Smaller;
 
+/// Collapses the batch and the channel dimensions of `dim` into a single one.
+fn batch_collapsed<D>(dim: D) -> D::Smaller
+where
+    D: Dimension,
+{
+    let mut batch_collapsed_dim = D::Smaller::zeros(dim.ndim() - 1);
+    batch_collapsed_dim[0] = dim[0] * dim[1];
+    batch_collapsed_dim.slice_mut()[1..].copy_from_slice(&dim.slice()[2..]);
+
+    batch_collapsed_dim
+}
+
 pub(crate) struct Pad<D, T>
 where
     D: Dimension,
@@ -52,36 +71,8 @@ where
         mode: T,
         padding: SampleDim<D>,
     ) -> Self {
-        let operand_data_dim = operand_data.borrow().raw_dim();
-        let data_dim = data.borrow().raw_dim();
-
-        let (mut batch_collapsed_dim, mut batch_collapsed_padded_dim) = (
-            <D as Dimension>::Smaller::zeros(operand_data_dim.ndim() - 1),
-            <D as Dimension>::Smaller::zeros(data_dim.ndim() - 1),
-        );
-
-        let outer_dimension: usize = operand_data_dim.slice().iter().take(2).product();
-
-        batch_collapsed_dim[0] = outer_dimension;
-        batch_collapsed_padded_dim[0] = outer_dimension;
-
-        let (sample_dim, padded_sample_dim) = (
-            operand_data_dim.slice().iter().skip(2),
-            data_dim.slice().iter().skip(2),
-        );
-
-        batch_collapsed_dim
-            .slice_mut()
-            .iter_mut()
-            .skip(1)
-            .zip(sample_dim)
-            .for_each(|(view_dim, inner_dim)| *view_dim = *inner_dim);
-        batch_collapsed_padded_dim
-            .slice_mut()
-            .iter_mut()
-            .skip(1)
-            .zip(padded_sample_dim)
-            .for_each(|(view_dim, inner_dim)| *view_dim = *inner_dim);
+        let batch_collapsed_dim = batch_collapsed(operand_data.borrow().raw_dim());
+        let batch_collapsed_padded_dim = batch_collapsed(data.borrow().raw_dim());
 
         Self {
             operand_data,
@@ -126,57 +117,104 @@ where
                 mode.pad(&mut padded_sample, &base_sample, padding)
             });
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        let (mode, value) = self.mode.onnx_mode()?;
+        let (shape, padded_shape) = (self.operand_data.shape(), self.data.shape());
+
+        // The batch and the channel axes aren't padded.
+        let ndim = shape.len();
+        let mut pads = vec![0; 2 * ndim];
+        for (axis, before) in self.padding.slice().iter().enumerate() {
+            let axis = axis + 2;
+            pads[axis] = *before as i64;
+            pads[ndim + axis] = (padded_shape[axis] - shape[axis] - before) as i64;
+        }
+
+        Some(
+            Operator::new("Pad")
+                .attribute("mode", Attribute::String(mode))
+                .initializer(Initializer::Ints(pads))
+                .initializer(Initializer::Float(value)),
+        )
+    }
 }
 
-pub(crate) struct PadBackward<D>
+pub(crate) struct PadBackward<D, T>
 where
     D: Dimension,
+    D::Smaller: RemoveAxis,
+    SampleDim<D>: Copy,
+    T: PaddingMode<D>,
 {
     operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
     gradient: Rc<Gradient<Array<f32, D>, D>>,
+    mode: T,
     padding: SampleDim<D>,
 }
 
-impl<D> PadBackward<D>
+impl<D, T> PadBackward<D, T>
 where
     D: Dimension,
+    D::Smaller: RemoveAxis,
+    SampleDim<D>: Copy,
+    T: PaddingMode<D>,
 {
     pub(crate) fn new(
         operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
         gradient: Rc<Gradient<Array<f32, D>, D>>,
+        mode: T,
         padding: SampleDim<D>,
     ) -> Self {
         Self {
             operand_gradient,
             gradient,
+            mode,
             padding,
         }
     }
 }
 
-impl<D> Backward for PadBackward<D>
+impl<D, T> Backward for PadBackward<D, T>
 where
     D: Dimension,
+    D::Smaller: RemoveAxis,
+    SampleDim<D>: Copy,
+    T: PaddingMode<D>,
 {
     fn backward(&self) {
         let mut operand_gradient = self.operand_gradient.borrow_mut();
         let gradient = self.gradient.borrow();
 
-        let padding = self.padding.slice();
-
-        let gradient_slice = gradient.slice_each_axis(|ax| {
-            let (index, len) = (ax.axis.index(), gradient.len_of(ax.axis));
-            let range = {
-                if index > 1 && padding[index - 2] != 0 {
-                    padding[index - 2] as isize..-(padding[index - 2] as isize)
-                } else {
-                    0..len as isize
-                }
-            };
+        let batch_collapsed_dim = batch_collapsed(operand_gradient.raw_dim());
+        let (mut operand_gradient_view_mut, gradient_view) = (
+            operand_gradient
+                .view_mut()
+                .into_shape(batch_collapsed_dim)
+                .unwrap(),
+            gradient
+                .view()
+                .into_shape(batch_collapsed(gradient.raw_dim()))
+                .unwrap(),
+        );
 
-            Slice::from(range)
-        });
+        let mode = self.mode;
+        let padding = self.padding;
 
-        *operand_gradient += &gradient_slice;
+        operand_gradient_view_mut
+            .outer_iter_mut()
+            .into_par_iter()
+            .zip(gradient_view.outer_iter())
+            .for_each(|(mut sample_gradient, padded_sample_gradient)| {
+                mode.unpad(&mut sample_gradient, &padded_sample_gradient, padding)
+            });
     }
 }
@@ -1,4 +1,4 @@
-use ndarray::{ArrayView, ArrayViewMut, Dimension, RemoveAxis};
+use ndarray::{ArrayView, ArrayViewMut, Dimension, RemoveAxis, Slice};
 
 use super::SampleDim;
 
@@ -8,10 +8,117 @@ where
     D: Dimension,
     D::Smaller: RemoveAxis,
 {
+    /// Pads `base` into `padded`. `padding` is the amount of padding before each axis, the amount
+    /// after it is given by the shape of `padded`.
     fn pad(
         &self,
         padded: &mut ArrayViewMut<f32, SampleDim<D>>,
         base: &ArrayView<f32, SampleDim<D>>,
         padding: SampleDim<D>,
     );
+
+    /// Accumulates into `gradient` the gradient of the padded array, `padded_gradient`.
+    /// `padding` has the same meaning as in [`.pad()`](PaddingMode::pad()).
+    ///
+    /// By default only the elements that aren't padding contribute to the gradient, which is
+    /// correct for the modes filling the padding with constant values.
+    fn unpad(
+        &self,
+        gradient: &mut ArrayViewMut<f32, SampleDim<D>>,
+        padded_gradient: &ArrayView<f32, SampleDim<D>>,
+        padding: SampleDim<D>,
+    ) {
+        let padding = padding.slice();
+        let inner = padded_gradient.slice_each_axis(|ax| {
+            let (start, len) = (padding[ax.axis.index()], gradient.len_of(ax.axis));
+            Slice::from(start..start + len)
+        });
+
+        *gradient += &inner;
+    }
+
+    /// Returns the name of the equivalent ONNX padding mode together with the padding value, if
+    /// there's such a mode.
+    fn onnx_mode(&self) -> Option<(&'static str, f32)> {
+        None
+    }
+}
+
+/// Maps a position along an axis of a padded array to the position, along the same axis of the
+/// original array, of the element it's copied from. The other arguments are the padding before the
+/// axis and the original length of the axis.
+pub(super) type Source = fn(usize, usize, usize) -> usize;
+
+/// Computes in `source_index` the multi-index of the element of the original array of shape
+/// `shape` from which the element at `index` of the padded array is copied.
+fn source_index(
+    index: &[usize],
+    padding: &[usize],
+    shape: &[usize],
+    source: Source,
+    source_index: &mut [usize],
+) {
+    source_index
+        .iter_mut()
+        .zip(index)
+        .zip(padding.iter().zip(shape))
+        .for_each(|((source_el, position), (padding, len))| {
+            *source_el = source(*position, *padding, *len)
+        });
+}
+
+/// Fills `padded` by copying each of its elements from `base`, as specified by `source`.
+pub(super) fn gather<D>(
+    padded: &mut ArrayViewMut<f32, D>,
+    base: &ArrayView<f32, D>,
+    padding: D,
+    source: Source,
+) where
+    D: Dimension,
+{
+    let base = base.view().into_dyn();
+    let mut index = vec![0; base.ndim()];
+    padded
+        .view_mut()
+        .into_dyn()
+        .indexed_iter_mut()
+        .for_each(|(padded_index, padded_el)| {
+            source_index(
+                padded_index.slice(),
+                padding.slice(),
+                base.shape(),
+                source,
+                &mut index,
+            );
+            *padded_el = base[index.as_slice()];
+        });
+}
+
+/// Accumulates each element of `padded_gradient` into the element of `gradient` it was copied
+/// from, as specified by `source`. This is the backward counterpart of [`gather`].
+pub(super) fn scatter<D>(
+    gradient: &mut ArrayViewMut<f32, D>,
+    padded_gradient: &ArrayView<f32, D>,
+    padding: D,
+    source: Source,
+) where
+    D: Dimension,
+{
+    let mut gradient = gradient.view_mut().into_dyn();
+    let shape = gradient.shape().to_vec();
+    let mut index = vec![0; gradient.ndim()];
+    padded_gradient
+        .view()
+        .into_dyn()
+        .indexed_iter()
+        .for_each(|(padded_index, padded_el)| {
+            source_index(
+                padded_index.slice(),
+                padding.slice(),
+                &shape,
+                source,
+                &mut index,
+            );
+            gradient[index.as_slice()] += padded_el;
+        });
 }
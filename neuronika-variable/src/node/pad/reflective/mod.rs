@@ -1,6 +1,18 @@
 use ndarray::{ArrayView, ArrayViewMut, Dimension, Ix3, Ix4, Ix5};
 
-use super::{PaddingMode, SampleDim};
+use super::{padding_mode::scatter, PaddingMode, SampleDim};
+
+/// Maps a position of the padded array to the one of the original array, mirroring it about the
+/// first and last elements of the axis.
+fn source(position: usize, padding: usize, len: usize) -> usize {
+    if position < padding {
+        padding - position
+    } else if position < len + padding {
+        position - padding
+    } else {
+        (len - 1) * 2 - (position - padding)
+    }
+}
 
 /// Reflective padding.
 #[derive(Copy, Clone, Debug)]
@@ -31,6 +43,19 @@ impl PaddingMode<Ix3> for Reflective {
             *padded_slice_el = base_slice[pos];
         }
     }
+
+    fn unpad(
+        &self,
+        gradient: &mut ArrayViewMut<f32, SampleDim<Ix3>>,
+        padded_gradient: &ArrayView<f32, SampleDim<Ix3>>,
+        padding: SampleDim<Ix3>,
+    ) {
+        scatter(gradient, padded_gradient, padding, source);
+    }
+
+    fn onnx_mode(&self) -> Option<(&'static str, f32)> {
+        Some(("reflect", 0.))
+    }
 }
 
 impl PaddingMode<Ix4> for Reflective {
@@ -48,7 +73,7 @@ impl PaddingMode<Ix4> for Reflective {
         };
 
         let (pad_x, pad_y) = padding.into_pattern();
-        let (out_len_x, out_len_y) = (len_x + pad_x * 2, len_y + pad_y * 2);
+        let (out_len_x, out_len_y) = padded.dim();
         let (slice_in, slice_out) = (base.as_slice().unwrap(), padded.as_slice_mut().unwrap());
 
         for i in 0..out_len_x {
@@ -75,6 +100,19 @@ impl PaddingMode<Ix4> for Reflective {
             }
         }
     }
+
+    fn unpad(
+        &self,
+        gradient: &mut ArrayViewMut<f32, SampleDim<Ix4>>,
+        padded_gradient: &ArrayView<f32, SampleDim<Ix4>>,
+        padding: SampleDim<Ix4>,
+    ) {
+        scatter(gradient, padded_gradient, padding, source);
+    }
+
+    fn onnx_mode(&self) -> Option<(&'static str, f32)> {
+        Some(("reflect", 0.))
+    }
 }
 
 impl PaddingMode<Ix5> for Reflective {
@@ -92,8 +130,7 @@ impl PaddingMode<Ix5> for Reflective {
         };
 
         let (pad_z, pad_x, pad_y) = padding.into_pattern();
-        let (out_len_x, out_len_y, out_len_z) =
-            (len_x + pad_x * 2, len_y + pad_y * 2, len_z + pad_z * 2);
+        let (out_len_z, out_len_x, out_len_y) = padded.dim();
         let (slice_in, slice_out) = { (base.as_slice().unwrap(), padded.as_slice_mut().unwrap()) };
 
         for z in 0..out_len_z {
@@ -132,6 +169,19 @@ impl PaddingMode<Ix5> for Reflective {
             }
         }
     }
+
+    fn unpad(
+        &self,
+        gradient: &mut ArrayViewMut<f32, SampleDim<Ix5>>,
+        padded_gradient: &ArrayView<f32, SampleDim<Ix5>>,
+        padding: SampleDim<Ix5>,
+    ) {
+        scatter(gradient, padded_gradient, padding, source);
+    }
+
+    fn onnx_mode(&self) -> Option<(&'static str, f32)> {
+        Some(("reflect", 0.))
+    }
 }
 
 #[cfg(test)]
@@ -146,3 +146,38 @@ fn test_3d() {
         ]
     )
 }
+
+#[test]
+fn test_1d_asymmetric() {
+    let padding = Reflective;
+
+    let base = Array::range(0., 5., 1.);
+    let mut padded = Array::<f32, _>::zeros(8);
+
+    PaddingMode::<Ix3>::pad(
+        &padding,
+        &mut padded.view_mut(),
+        &base.view(),
+        [1].into_dimension(),
+    );
+
+    assert_eq!(padded, ndarray::array![1., 0., 1., 2., 3., 4., 3., 2.]);
+}
+
+#[test]
+fn test_unpad() {
+    let padding = Reflective;
+
+    let padded_gradient = Array::range(1., 8., 1.);
+    let mut gradient = Array::<f32, _>::zeros(3);
+
+    PaddingMode::<Ix3>::unpad(
+        &padding,
+        &mut gradient.view_mut(),
+        &padded_gradient.view(),
+        [2].into_dimension(),
+    );
+
+    // The padded positions map to [2, 1, 0, 1, 2, 1, 0].
+    assert_eq!(gradient, ndarray::array![3. + 7., 2. + 4. + 6., 1. + 5.]);
+}
@@ -19,6 +19,10 @@ where
     ) {
         PaddingMode::<D>::pad(&Constant(0.0), padded, base, padding);
     }
+
+    fn onnx_mode(&self) -> Option<(&'static str, f32)> {
+        Some(("constant", 0.))
+    }
 }
 
 #[cfg(test)]
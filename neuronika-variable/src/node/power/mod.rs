@@ -3,8 +3,9 @@ use std::rc::Rc;
 use ndarray::{Array, Dimension, Zip};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
+    onnx::{Initializer, Operator},
     utils::Shared,
 };
 
@@ -43,6 +44,18 @@ where
             .and(&*self.operand_data.borrow())
             .for_each(|v, &o| *v = o.powi(self.exp));
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("Pow").initializer(Initializer::Float(self.exp as f32)))
+    }
 }
 
 pub(crate) struct PowerBackward<D>
@@ -0,0 +1,110 @@
+use std::rc::Rc;
+
+use ndarray::{Array, Dimension, Zip};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    gradient::Gradient,
+    onnx::{Initializer, Operator},
+    utils::Shared,
+};
+
+pub(crate) struct PowF<D>
+where
+    D: Dimension,
+{
+    operand_data: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D>>,
+    exp: f32,
+}
+
+impl<D> PowF<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(
+        operand_data: Shared<Array<f32, D>>,
+        data: Shared<Array<f32, D>>,
+        exp: f32,
+    ) -> Self {
+        Self {
+            operand_data,
+            data,
+            exp,
+        }
+    }
+}
+
+impl<D> Forward for PowF<D>
+where
+    D: Dimension,
+{
+    fn forward(&self) {
+        Zip::from(&mut *self.data.borrow_mut())
+            .and(&*self.operand_data.borrow())
+            .for_each(|v, &o| *v = o.powf(self.exp));
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("Pow").initializer(Initializer::Float(self.exp)))
+    }
+}
+
+pub(crate) struct PowFBackward<D>
+where
+    D: Dimension,
+{
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    operand_data: Shared<Array<f32, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+    exp: f32,
+}
+
+impl<D> PowFBackward<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        operand_data: Shared<Array<f32, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+        exp: f32,
+    ) -> Self {
+        Self {
+            operand_gradient,
+            operand_data,
+            gradient,
+            exp,
+        }
+    }
+}
+
+impl<D> Backward for PowFBackward<D>
+where
+    D: Dimension,
+{
+    fn backward(&self) {
+        // The result is constant, while 0^-1 would turn the gradient into NaN at zero.
+        if self.exp == 0. {
+            return;
+        }
+
+        Zip::from(&mut *self.operand_gradient.borrow_mut())
+            .and(&*self.gradient.borrow())
+            .and(&*self.operand_data.borrow())
+            .for_each(|op_grad_el, &grad_el, &op_data_el| {
+                *op_grad_el += grad_el * op_data_el.powf(self.exp - 1.) * self.exp;
+            });
+    }
+}
+
+#[cfg(test)]
+mod test;
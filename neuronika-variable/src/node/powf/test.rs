@@ -0,0 +1,101 @@
+use std::error::Error;
+
+use ndarray::Array;
+
+use crate::utils::{are_similar, new_shared};
+
+#[cfg(test)]
+mod forward {
+    use super::super::{Forward, PowF};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_data = Array::zeros((3, 3));
+        let data = Array::ones((3, 3));
+        let op = PowF::new(
+            new_shared(operand_data.clone()),
+            new_shared(data.clone()),
+            1.5,
+        );
+
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.data.borrow(), &data)?;
+        assert_eq!(op.exp, 1.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let input = Array::linspace(1., 9., 9).into_shape((3, 3))?;
+        let op = PowF::new(
+            new_shared(input.clone()),
+            new_shared(Array::zeros((3, 3))),
+            0.5,
+        );
+
+        op.forward();
+        are_similar(op.data.borrow(), &input.mapv(f32::sqrt))
+    }
+}
+
+#[cfg(test)]
+mod backward {
+    use std::rc::Rc;
+
+    use super::super::{Backward, Gradient, PowFBackward};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_gradient = Array::zeros((3, 3));
+        let operand_data = Array::ones((3, 3));
+        let gradient = Array::from_elem((3, 3), 2.);
+        let op = PowFBackward::new(
+            Rc::new(Gradient::from_ndarray(operand_gradient.clone())),
+            new_shared(operand_data.clone()),
+            Rc::new(Gradient::from_ndarray(gradient.clone())),
+            1.5,
+        );
+
+        are_similar(op.operand_gradient.borrow(), &operand_gradient)?;
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.gradient.borrow(), &gradient)?;
+        assert_eq!(op.exp, 1.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let input = Array::linspace(1., 9., 9).into_shape((3, 3))?;
+        let op = PowFBackward::new(
+            Rc::new(Gradient::ndarray_zeros((3, 3))),
+            new_shared(input.clone()),
+            Rc::new(Gradient::from_ndarray(Array::ones((3, 3)))),
+            0.5,
+        );
+        let expected = input.mapv(|x| 0.5 / x.sqrt());
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &expected)?;
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &(expected * 2.))
+    }
+
+    #[test]
+    fn zero_exponent() -> Result<(), Box<dyn Error>> {
+        let input = Array::linspace(0., 8., 9).into_shape((3, 3))?;
+        let op = PowFBackward::new(
+            Rc::new(Gradient::ndarray_zeros((3, 3))),
+            new_shared(input),
+            Rc::new(Gradient::from_ndarray(Array::ones((3, 3)))),
+            0.,
+        );
+
+        op.backward();
+        are_similar(op.operand_gradient.borrow(), &Array::zeros((3, 3)))
+    }
+}
@@ -3,8 +3,10 @@ use std::rc::Rc;
 use ndarray::{Array, Dimension, Zip};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
+    fusion::Kernel,
     gradient::Gradient,
+    onnx::Operator,
     utils::Shared,
 };
 
@@ -31,10 +33,35 @@ where
     D: Dimension,
 {
     fn forward(&self) {
+        // In-place computation.
+        if Rc::ptr_eq(&self.operand_data, &self.data) {
+            self.data.borrow_mut().mapv_inplace(|el| el.max(0.));
+            return;
+        }
+
         Zip::from(&mut *self.data.borrow_mut())
             .and(&*self.operand_data.borrow())
             .for_each(|v, &o| *v = o.max(0.));
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn kernel(&self) -> Option<Kernel> {
+        Some(Kernel::Unary(
+            |x| x.max(0.),
+            |x, _| (x > 0.) as usize as f32,
+        ))
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("Relu"))
+    }
 }
 
 #[allow(clippy::upper_case_acronyms)]
@@ -0,0 +1,97 @@
+use std::rc::Rc;
+
+use ndarray::{Array, Dimension};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    gradient::Gradient,
+    onnx::{ints, Initializer, Operator},
+    utils::Shared,
+};
+
+pub(crate) struct Reshape<D, E>
+where
+    D: Dimension,
+    E: Dimension,
+{
+    operand_data: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, E>>,
+}
+
+impl<D, E> Reshape<D, E>
+where
+    D: Dimension,
+    E: Dimension,
+{
+    pub(crate) fn new(operand_data: Shared<Array<f32, D>>, data: Shared<Array<f32, E>>) -> Self {
+        Self { operand_data, data }
+    }
+}
+
+impl<D, E> Forward for Reshape<D, E>
+where
+    D: Dimension,
+    E: Dimension,
+{
+    fn forward(&self) {
+        self.data
+            .borrow_mut()
+            .iter_mut()
+            .zip(self.operand_data.borrow().iter())
+            .for_each(|(v, o)| *v = *o);
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("Reshape").initializer(Initializer::Ints(ints(&self.data.shape()))))
+    }
+}
+
+pub(crate) struct ReshapeBackward<D, E>
+where
+    D: Dimension,
+    E: Dimension,
+{
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    gradient: Rc<Gradient<Array<f32, E>, E>>,
+}
+
+impl<D, E> ReshapeBackward<D, E>
+where
+    D: Dimension,
+    E: Dimension,
+{
+    pub(crate) fn new(
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        gradient: Rc<Gradient<Array<f32, E>, E>>,
+    ) -> Self {
+        Self {
+            operand_gradient,
+            gradient,
+        }
+    }
+}
+
+impl<D, E> Backward for ReshapeBackward<D, E>
+where
+    D: Dimension,
+    E: Dimension,
+{
+    fn backward(&self) {
+        self.operand_gradient
+            .borrow_mut()
+            .iter_mut()
+            .zip(self.gradient.borrow().iter())
+            .for_each(|(o, g)| *o += *g);
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,94 @@
+use std::error::Error;
+
+use ndarray::Array;
+
+use crate::utils::{are_similar, new_shared};
+
+mod forward {
+    use super::super::{Forward, Reshape};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_data = Array::linspace(-4., 4., 9).into_shape((3, 3))?;
+        let data = Array::zeros(9);
+        let op = Reshape::new(new_shared(operand_data.clone()), new_shared(data.clone()));
+
+        are_similar(op.operand_data.borrow(), &operand_data)?;
+        are_similar(op.data.borrow(), &data)
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let op = Reshape::new(
+            new_shared(Array::linspace(-5., 6., 12).into_shape((2, 3, 2))?),
+            new_shared(Array::zeros((4, 3))),
+        );
+
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::linspace(-5., 6., 12).into_shape((4, 3))?,
+        )
+    }
+
+    #[test]
+    fn transposed_operand() -> Result<(), Box<dyn Error>> {
+        let op = Reshape::new(
+            new_shared(
+                Array::linspace(0., 5., 6)
+                    .into_shape((2, 3))?
+                    .reversed_axes(),
+            ),
+            new_shared(Array::zeros(6).into_dyn()),
+        );
+
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::from_vec(vec![0., 3., 1., 4., 2., 5.]).into_dyn(),
+        )
+    }
+}
+
+mod backward {
+    use std::rc::Rc;
+
+    use super::super::{Backward, Gradient, ReshapeBackward};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let operand_gradient = Array::zeros((3, 3));
+        let gradient = Array::ones(9);
+        let op = ReshapeBackward::new(
+            Rc::new(Gradient::from_ndarray(operand_gradient.clone())),
+            Rc::new(Gradient::from_ndarray(gradient.clone())),
+        );
+
+        are_similar(op.operand_gradient.borrow(), &operand_gradient)?;
+        are_similar(op.gradient.borrow(), &gradient)
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let op = ReshapeBackward::new(
+            Rc::new(Gradient::ndarray_zeros((2, 3))),
+            Rc::new(Gradient::from_ndarray(
+                Array::linspace(1., 6., 6).into_shape((3, 2))?,
+            )),
+        );
+
+        op.backward();
+        are_similar(
+            op.operand_gradient.borrow(),
+            &Array::linspace(1., 6., 6).into_shape((2, 3))?,
+        )?;
+
+        op.backward();
+        are_similar(
+            op.operand_gradient.borrow(),
+            &(Array::linspace(1., 6., 6).into_shape((2, 3))? * 2.),
+        )
+    }
+}
@@ -0,0 +1,223 @@
+use std::rc::Rc;
+
+use ndarray::{Array, DimMax, Dimension, Zip};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    gradient::{BufferedGradient, Gradient},
+    utils::{accumulate, Broadcast, Shared},
+};
+
+/// Elements of the condition strictly greater than zero pick the left operand.
+fn is_selected(condition: f32) -> bool {
+    condition > 0.
+}
+
+pub(crate) struct Select<D, E, M>
+where
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+    M: Dimension,
+{
+    left_data: Shared<Array<f32, D>>,
+    right_data: Shared<Array<f32, E>>,
+    condition: Shared<Array<f32, M>>,
+    data: Shared<Array<f32, Broadcast<D, E>>>,
+}
+
+impl<D, E, M> Select<D, E, M>
+where
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+    M: Dimension,
+{
+    pub(crate) fn new(
+        left_data: Shared<Array<f32, D>>,
+        right_data: Shared<Array<f32, E>>,
+        condition: Shared<Array<f32, M>>,
+        data: Shared<Array<f32, Broadcast<D, E>>>,
+    ) -> Self {
+        assert!(
+            condition
+                .borrow()
+                .broadcast(data.borrow().raw_dim())
+                .is_some(),
+            "error: cannot broadcast condition of shape {:?} to {:?}.",
+            condition.borrow().shape(),
+            data.borrow().shape()
+        );
+
+        Self {
+            left_data,
+            right_data,
+            condition,
+            data,
+        }
+    }
+}
+
+impl<D, E, M> Forward for Select<D, E, M>
+where
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+    M: Dimension,
+{
+    fn forward(&self) {
+        Zip::from(&mut *self.data.borrow_mut())
+            .and_broadcast(&*self.left_data.borrow())
+            .and_broadcast(&*self.right_data.borrow())
+            .and_broadcast(&*self.condition.borrow())
+            .for_each(|v, &l, &r, &c| *v = if is_selected(c) { l } else { r });
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.left_data, &self.right_data, &self.condition]
+    }
+}
+
+pub(crate) struct SelectBackwardLeft<D, E, M>
+where
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+    M: Dimension,
+{
+    condition: Shared<Array<f32, M>>,
+    left_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    gradient: Rc<BufferedGradient<Array<f32, Broadcast<D, E>>, Broadcast<D, E>>>,
+}
+
+impl<D, E, M> SelectBackwardLeft<D, E, M>
+where
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+    M: Dimension,
+{
+    pub(crate) fn new(
+        condition: Shared<Array<f32, M>>,
+        left_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        gradient: Rc<BufferedGradient<Array<f32, Broadcast<D, E>>, Broadcast<D, E>>>,
+    ) -> Self {
+        debug_assert!(left_gradient
+            .borrow()
+            .broadcast(gradient.shape().slice())
+            .is_some());
+
+        Self {
+            condition,
+            left_gradient,
+            gradient,
+        }
+    }
+}
+
+impl<D, E, M> Backward for SelectBackwardLeft<D, E, M>
+where
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+    M: Dimension,
+{
+    fn backward(&self) {
+        let mut buffer = self.gradient.buffer_mut();
+        Zip::from(&mut *buffer)
+            .and(&*self.gradient.borrow())
+            .and_broadcast(&*self.condition.borrow())
+            .for_each(|d, &g, &c| *d = if is_selected(c) { g } else { 0. });
+
+        accumulate(&mut self.left_gradient.borrow_mut(), &buffer);
+    }
+}
+
+pub(crate) struct SelectBackwardRight<D, E, M>
+where
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+    M: Dimension,
+{
+    condition: Shared<Array<f32, M>>,
+    right_gradient: Rc<Gradient<Array<f32, E>, E>>,
+    gradient: Rc<BufferedGradient<Array<f32, Broadcast<D, E>>, Broadcast<D, E>>>,
+}
+
+impl<D, E, M> SelectBackwardRight<D, E, M>
+where
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+    M: Dimension,
+{
+    pub(crate) fn new(
+        condition: Shared<Array<f32, M>>,
+        right_gradient: Rc<Gradient<Array<f32, E>, E>>,
+        gradient: Rc<BufferedGradient<Array<f32, Broadcast<D, E>>, Broadcast<D, E>>>,
+    ) -> Self {
+        debug_assert!(right_gradient
+            .borrow()
+            .broadcast(gradient.shape().slice())
+            .is_some());
+
+        Self {
+            condition,
+            right_gradient,
+            gradient,
+        }
+    }
+}
+
+impl<D, E, M> Backward for SelectBackwardRight<D, E, M>
+where
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+    M: Dimension,
+{
+    fn backward(&self) {
+        let mut buffer = self.gradient.buffer_mut();
+        Zip::from(&mut *buffer)
+            .and(&*self.gradient.borrow())
+            .and_broadcast(&*self.condition.borrow())
+            .for_each(|d, &g, &c| *d = if is_selected(c) { 0. } else { g });
+
+        accumulate(&mut self.right_gradient.borrow_mut(), &buffer);
+    }
+}
+
+pub(crate) struct SelectBackward<D, E, M>
+where
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+    M: Dimension,
+{
+    left: SelectBackwardLeft<D, E, M>,
+    right: SelectBackwardRight<D, E, M>,
+}
+
+impl<D, E, M> SelectBackward<D, E, M>
+where
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+    M: Dimension,
+{
+    pub(crate) fn new(
+        left: SelectBackwardLeft<D, E, M>,
+        right: SelectBackwardRight<D, E, M>,
+    ) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<D, E, M> Backward for SelectBackward<D, E, M>
+where
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+    M: Dimension,
+{
+    fn backward(&self) {
+        self.left.backward();
+        self.right.backward();
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,110 @@
+use std::{error::Error, rc::Rc};
+
+use ndarray::Array;
+
+use crate::utils::{are_similar, new_shared};
+
+mod forward {
+    use super::super::{Forward, Select};
+    use super::*;
+
+    #[test]
+    fn creation() -> Result<(), Box<dyn Error>> {
+        let left = Array::ones((3, 3));
+        let right = Array::zeros((3, 3));
+        let condition = Array::from_elem((3, 3), 1.);
+        let data = Array::zeros((3, 3));
+        let op = Select::new(
+            new_shared(left.clone()),
+            new_shared(right.clone()),
+            new_shared(condition.clone()),
+            new_shared(data.clone()),
+        );
+
+        are_similar(op.left_data.borrow(), &left)?;
+        are_similar(op.right_data.borrow(), &right)?;
+        are_similar(op.condition.borrow(), &condition)?;
+        are_similar(op.data.borrow(), &data)
+    }
+
+    #[test]
+    #[should_panic(expected = "error: cannot broadcast condition of shape [2] to [3, 3].")]
+    fn fail_condition() {
+        Select::new(
+            new_shared(Array::<f32, _>::ones((3, 3))),
+            new_shared(Array::<f32, _>::zeros((3, 3))),
+            new_shared(Array::<f32, _>::zeros(2)),
+            new_shared(Array::<f32, _>::zeros((3, 3))),
+        );
+    }
+
+    #[test]
+    fn base_case() -> Result<(), Box<dyn Error>> {
+        let op = Select::new(
+            new_shared(Array::linspace(1., 9., 9).into_shape((3, 3))?),
+            new_shared(Array::from_elem((3, 3), -1.)),
+            new_shared(Array::from_shape_vec(
+                (3, 3),
+                vec![1., 0., -1., 2., 0., 0., 0., 0., 3.],
+            )?),
+            new_shared(Array::zeros((3, 3))),
+        );
+
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::from_shape_vec((3, 3), vec![1., -1., -1., 4., -1., -1., -1., -1., 9.])?,
+        )
+    }
+
+    #[test]
+    fn condition_broadcast() -> Result<(), Box<dyn Error>> {
+        let op = Select::new(
+            new_shared(Array::linspace(1., 9., 9).into_shape((3, 3))?),
+            new_shared(Array::from_elem(3, -1.)),
+            new_shared(Array::from_shape_vec((3, 1), vec![1., 0., 1.])?),
+            new_shared(Array::zeros((3, 3))),
+        );
+
+        op.forward();
+        are_similar(
+            op.data.borrow(),
+            &Array::from_shape_vec((3, 3), vec![1., 2., 3., -1., -1., -1., 7., 8., 9.])?,
+        )
+    }
+}
+
+mod backward {
+    use super::super::{
+        Backward, BufferedGradient, Gradient, SelectBackward, SelectBackwardLeft,
+        SelectBackwardRight,
+    };
+    use super::*;
+
+    #[test]
+    fn backward() -> Result<(), Box<dyn Error>> {
+        let condition = new_shared(Array::from_shape_vec((3, 1), vec![1., 0., 1.])?);
+        let gradient = Rc::new(BufferedGradient::from_ndarray(Rc::new(
+            Gradient::from_ndarray(Array::ones((3, 3))),
+        )));
+        let op = SelectBackward::new(
+            SelectBackwardLeft::new(
+                condition.clone(),
+                Rc::new(Gradient::ndarray_zeros((3, 3))),
+                gradient.clone(),
+            ),
+            SelectBackwardRight::new(condition, Rc::new(Gradient::ndarray_zeros(3)), gradient),
+        );
+        let left_expected =
+            Array::from_shape_vec((3, 3), vec![1., 1., 1., 0., 0., 0., 1., 1., 1.])?;
+        let right_expected = Array::ones(3);
+
+        op.backward();
+        are_similar(op.left.left_gradient.borrow(), &left_expected)?;
+        are_similar(op.right.right_gradient.borrow(), &right_expected)?;
+
+        op.backward();
+        are_similar(op.left.left_gradient.borrow(), &(left_expected * 2.))?;
+        are_similar(op.right.right_gradient.borrow(), &(right_expected * 2.))
+    }
+}
@@ -0,0 +1,110 @@
+use std::rc::Rc;
+
+use ndarray::{Array, Dimension, Zip};
+
+use crate::{
+    autograd::{Backward, Buffer, Forward},
+    gradient::Gradient,
+    onnx::{Attribute, Operator},
+    utils::Shared,
+};
+
+/// Scale of the SELU, as derived in *Self-Normalizing Neural Networks*.
+const SCALE: f32 = 1.050_701;
+/// Negative saturation value of the SELU, as derived in *Self-Normalizing Neural Networks*.
+const ALPHA: f32 = 1.673_263_2;
+
+#[allow(clippy::upper_case_acronyms)]
+pub(crate) struct SELU<D>
+where
+    D: Dimension,
+{
+    operand_data: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D>>,
+}
+
+impl<D> SELU<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(operand_data: Shared<Array<f32, D>>, data: Shared<Array<f32, D>>) -> Self {
+        Self { operand_data, data }
+    }
+}
+
+impl<D> Forward for SELU<D>
+where
+    D: Dimension,
+{
+    fn forward(&self) {
+        Zip::from(&mut *self.data.borrow_mut())
+            .and(&*self.operand_data.borrow())
+            .for_each(|v, &o| *v = SCALE * if o > 0. { o } else { ALPHA * o.exp_m1() });
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(
+            Operator::new("Selu")
+                .attribute("alpha", Attribute::Float(ALPHA))
+                .attribute("gamma", Attribute::Float(SCALE)),
+        )
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+pub(crate) struct SELUBackward<D>
+where
+    D: Dimension,
+{
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    operand_data: Shared<Array<f32, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+}
+
+impl<D> SELUBackward<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        operand_data: Shared<Array<f32, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+    ) -> Self {
+        Self {
+            operand_gradient,
+            operand_data,
+            gradient,
+        }
+    }
+}
+
+impl<D> Backward for SELUBackward<D>
+where
+    D: Dimension,
+{
+    fn backward(&self) {
+        Zip::from(&mut *self.operand_gradient.borrow_mut())
+            .and(&*self.gradient.borrow())
+            .and(&*self.operand_data.borrow())
+            .for_each(|op_grad_el, &grad_el, &op_data_el| {
+                *op_grad_el += grad_el
+                    * SCALE
+                    * if op_data_el > 0. {
+                        1.
+                    } else {
+                        ALPHA * op_data_el.exp()
+                    }
+            });
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -3,8 +3,10 @@ use std::rc::Rc;
 use ndarray::{Array, Dimension, Zip};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
+    fusion::Kernel,
     gradient::Gradient,
+    onnx::Operator,
     utils::Shared,
 };
 
@@ -30,10 +32,37 @@ where
     D: Dimension,
 {
     fn forward(&self) {
+        // In-place computation.
+        if Rc::ptr_eq(&self.operand_data, &self.data) {
+            self.data
+                .borrow_mut()
+                .mapv_inplace(|el| 1. / (1. + (-el).exp()));
+            return;
+        }
+
         Zip::from(&mut *self.data.borrow_mut())
             .and(&*self.operand_data.borrow())
             .for_each(|v, &o| *v = 1. / (1. + (-o).exp()));
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn kernel(&self) -> Option<Kernel> {
+        Some(Kernel::Unary(
+            |x| 1. / (1. + (-x).exp()),
+            |_, y| y * (1. - y),
+        ))
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("Sigmoid"))
+    }
 }
 
 pub(crate) struct SigmoidBackward<D>
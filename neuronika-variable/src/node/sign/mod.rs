@@ -0,0 +1,53 @@
+use ndarray::{Array, Dimension, Zip};
+
+use crate::{
+    autograd::{Buffer, Forward},
+    utils::Shared,
+};
+
+pub(crate) struct Sign<D>
+where
+    D: Dimension,
+{
+    operand_data: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D>>,
+}
+
+impl<D> Sign<D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(operand_data: Shared<Array<f32, D>>, data: Shared<Array<f32, D>>) -> Self {
+        Self { operand_data, data }
+    }
+}
+
+impl<D> Forward for Sign<D>
+where
+    D: Dimension,
+{
+    fn forward(&self) {
+        Zip::from(&mut *self.data.borrow_mut())
+            .and(&*self.operand_data.borrow())
+            .for_each(|v, &o| {
+                *v = if o > 0. {
+                    1.
+                } else if o < 0. {
+                    -1.
+                } else {
+                    0.
+                }
+            });
+    }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+}
+
+#[cfg(test)]
+mod test;
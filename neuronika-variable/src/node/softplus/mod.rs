@@ -3,8 +3,10 @@ use std::rc::Rc;
 use ndarray::{Array, Dimension, Zip};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
+    fusion::Kernel,
     gradient::Gradient,
+    onnx::Operator,
     utils::Shared,
 };
 
@@ -34,6 +36,25 @@ where
             .and(&*self.operand_data.borrow())
             .for_each(|v, &o| *v = (1. + o.exp()).ln());
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn kernel(&self) -> Option<Kernel> {
+        Some(Kernel::Unary(
+            |x| (1. + x.exp()).ln(),
+            |x, _| 1. / (1. + (-x).exp()),
+        ))
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("Softplus"))
+    }
 }
 
 pub(crate) struct SoftPlusBackward<D>
@@ -3,7 +3,7 @@ use std::rc::Rc;
 use ndarray::{Array, Axis, Dimension, RemoveAxis, Zip};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
     utils::Shared,
 };
@@ -68,6 +68,14 @@ where
             .and(&*rhs_data)
             .for_each(|fused_el, &single_el| *fused_el = single_el);
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.left, &self.right]
+    }
 }
 
 pub(crate) struct StackBackwardLeft<D>
@@ -3,8 +3,9 @@ use std::rc::Rc;
 use ndarray::{Array, Dimension};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
+    onnx::{ints, Initializer, Operator},
     utils::Shared,
 };
 
@@ -38,6 +39,18 @@ where
         let mut unsqueezed = data.view_mut().into_shape(operand_data.raw_dim()).unwrap();
         unsqueezed.assign(&operand_data);
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.operand_data]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("Reshape").initializer(Initializer::Ints(ints(&self.data.shape()))))
+    }
 }
 
 pub(crate) struct UnsqueezeBackward<D>
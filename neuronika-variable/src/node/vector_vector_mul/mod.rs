@@ -3,8 +3,9 @@ use std::rc::Rc;
 use ndarray::{arr0, Array, Ix0, Ix1, Zip};
 
 use crate::{
-    autograd::{Backward, Forward},
+    autograd::{Backward, Buffer, Forward},
     gradient::Gradient,
+    onnx::Operator,
     utils::Shared,
 };
 
@@ -32,6 +33,18 @@ impl Forward for VectorVectorMul {
     fn forward(&self) {
         *self.data.borrow_mut() = arr0(self.left_data.borrow().dot(&*self.right_data.borrow()));
     }
+
+    fn data(&self) -> &dyn Buffer {
+        &self.data
+    }
+
+    fn operands(&self) -> Vec<&dyn Buffer> {
+        vec![&self.left_data, &self.right_data]
+    }
+
+    fn onnx(&self) -> Option<Operator> {
+        Some(Operator::new("MatMul"))
+    }
 }
 
 pub(crate) struct VectorVectorMulBackwardUnary {
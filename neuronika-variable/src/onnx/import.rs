@@ -0,0 +1,1358 @@
+use std::{
+    cell::Ref,
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt::Display,
+    fs,
+    path::Path,
+};
+
+use half::{bf16, f16};
+use ndarray::{Array, ArrayD, Dimension, Ix1, Ix2, Ix3, Ix4, Ix5, IxDyn, RemoveAxis};
+
+use crate::{
+    Cat, Constant, Convolution, ConvolutionTranspose, PaddingMode, ParameterDict, Reflective,
+    Replicative, Var, VarDiff, Zero,
+};
+
+use super::{
+    proto::Fields, OnnxError, BFLOAT16, DOUBLE, FLOAT, FLOAT16, INT32, INT64, INT8, ONNX_OPSET,
+    UINT8,
+};
+
+/// Operators of the default domain that can be imported.
+const SUPPORTED_OPERATORS: &[&str] = &[
+    "Abs",
+    "Add",
+    "Clip",
+    "Concat",
+    "Constant",
+    "Conv",
+    "ConvTranspose",
+    "Cos",
+    "Div",
+    "Dropout",
+    "Elu",
+    "Erf",
+    "Exp",
+    "Flatten",
+    "Gemm",
+    "HardSigmoid",
+    "Identity",
+    "LeakyRelu",
+    "Log",
+    "LogSoftmax",
+    "MatMul",
+    "Mul",
+    "Neg",
+    "Pad",
+    "Pow",
+    "RNN",
+    "ReduceMean",
+    "ReduceSum",
+    "Relu",
+    "Reshape",
+    "Selu",
+    "Sigmoid",
+    "Sin",
+    "Softmax",
+    "Softplus",
+    "Softsign",
+    "Sqrt",
+    "Squeeze",
+    "Sub",
+    "Tanh",
+    "Transpose",
+    "Unsqueeze",
+];
+
+/// Applies the same computation to both kinds of variable.
+macro_rules! unary {
+    ($value:expr, |$x:ident| $body:expr) => {
+        match $value {
+            OnnxValue::Var($x) => OnnxValue::Var($body),
+            OnnxValue::VarDiff($x) => OnnxValue::VarDiff($body),
+        }
+    };
+}
+
+/// Applies the same computation to all the combinations of kinds of the operands. The result is
+/// differentiable if any of the operands is.
+macro_rules! binary {
+    ($lhs:expr, $rhs:expr, |$l:ident, $r:ident| $body:expr) => {
+        match ($lhs, $rhs) {
+            (OnnxValue::Var($l), OnnxValue::Var($r)) => OnnxValue::Var($body),
+            (OnnxValue::Var($l), OnnxValue::VarDiff($r)) => OnnxValue::VarDiff($body),
+            (OnnxValue::VarDiff($l), OnnxValue::Var($r)) => OnnxValue::VarDiff($body),
+            (OnnxValue::VarDiff($l), OnnxValue::VarDiff($r)) => OnnxValue::VarDiff($body),
+        }
+    };
+}
+
+/// A variable of an imported graph, differentiable if any parameter contributes to it.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone)]
+pub enum OnnxValue<D = IxDyn>
+where
+    D: Dimension,
+{
+    Var(Var<D>),
+    VarDiff(VarDiff<D>),
+}
+
+impl<D> OnnxValue<D>
+where
+    D: 'static + Dimension,
+{
+    /// Propagates the computations forwards and populates the data of the variable and of all
+    /// its ancestors.
+    pub fn forward(&self) {
+        match self {
+            Self::Var(var) => var.forward(),
+            Self::VarDiff(var) => var.forward(),
+        }
+    }
+
+    /// Returns an immutable reference to the data of the variable.
+    pub fn data(&self) -> Ref<'_, Array<f32, D>> {
+        match self {
+            Self::Var(var) => var.data(),
+            Self::VarDiff(var) => var.data(),
+        }
+    }
+
+    fn shape(&self) -> Vec<usize> {
+        self.data().shape().to_vec()
+    }
+
+    /// Reshapes the variable to the given shape, which has the same number of elements.
+    fn reshape(self, shape: &[usize]) -> OnnxValue {
+        unary!(self, |x| x.reshape(IxDyn(shape)))
+    }
+
+    /// Converts the variable to a fixed dimensionality matching its rank.
+    fn fixed<E>(self) -> OnnxValue<E>
+    where
+        E: 'static + Dimension,
+    {
+        let shape = E::from_dimension(&IxDyn(&self.shape())).unwrap();
+        unary!(self, |x| x.reshape(shape.clone()))
+    }
+
+    fn into_dyn(self) -> OnnxValue {
+        let shape = self.shape();
+        self.reshape(&shape)
+    }
+}
+
+/// A constant tensor of the model, either an initializer or the result of a `Constant`.
+struct Tensor {
+    data: ArrayD<f32>,
+    /// The exact values of integer tensors.
+    ints: Option<Vec<i64>>,
+}
+
+impl Tensor {
+    fn ints(&self) -> Vec<i64> {
+        self.ints
+            .clone()
+            .unwrap_or_else(|| self.data.iter().map(|el| *el as i64).collect())
+    }
+
+    fn scalar(&self) -> Option<f32> {
+        (self.data.len() == 1).then(|| self.data.iter().next().copied().unwrap())
+    }
+}
+
+/// Decodes the little-endian elements of `raw`, each `N` bytes long.
+fn from_raw<const N: usize, T>(raw: &[u8], decode: impl Fn([u8; N]) -> T) -> Vec<T> {
+    raw.chunks_exact(N)
+        .map(|chunk| decode(chunk.try_into().unwrap()))
+        .collect()
+}
+
+/// Decodes a `TensorProto`, returning its name and its content.
+fn decode_tensor(tensor: &Fields) -> Result<(String, Tensor), OnnxError> {
+    let name = tensor.string(8)?;
+    if tensor.int(14) == Some(1) {
+        return Err(OnnxError::InvalidModel(format!(
+            "the data of {} is stored externally",
+            name
+        )));
+    }
+    let shape = tensor
+        .ints(1)?
+        .into_iter()
+        .map(usize::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| OnnxError::InvalidModel(format!("{} has a negative dimension", name)))?;
+
+    let raw = tensor.bytes(9)?.pop();
+    let data_type = tensor.int(2).unwrap_or_default();
+    let (data, ints) = match (data_type, raw) {
+        (FLOAT, Some(raw)) => (from_raw(raw, f32::from_le_bytes), None),
+        (FLOAT, None) => (tensor.floats(4)?, None),
+        (DOUBLE, Some(raw)) => (from_raw(raw, |el| f64::from_le_bytes(el) as f32), None),
+        (DOUBLE, None) => (
+            tensor
+                .doubles(10)?
+                .into_iter()
+                .map(|el| el as f32)
+                .collect(),
+            None,
+        ),
+        (FLOAT16, Some(raw)) => (from_raw(raw, |el| f16::from_le_bytes(el).to_f32()), None),
+        (BFLOAT16, Some(raw)) => (from_raw(raw, |el| bf16::from_le_bytes(el).to_f32()), None),
+        (FLOAT16, None) => (
+            tensor
+                .ints(5)?
+                .into_iter()
+                .map(|bits| f16::from_bits(bits as u16).to_f32())
+                .collect(),
+            None,
+        ),
+        (BFLOAT16, None) => (
+            tensor
+                .ints(5)?
+                .into_iter()
+                .map(|bits| bf16::from_bits(bits as u16).to_f32())
+                .collect(),
+            None,
+        ),
+        (INT64, raw) => {
+            let ints = match raw {
+                Some(raw) => from_raw(raw, i64::from_le_bytes),
+                None => tensor.ints(7)?,
+            };
+            (ints.iter().map(|el| *el as f32).collect(), Some(ints))
+        }
+        (INT32 | INT8 | UINT8, raw) => {
+            let ints = match (data_type, raw) {
+                (INT32, Some(raw)) => from_raw(raw, |el| i32::from_le_bytes(el) as i64),
+                (INT8, Some(raw)) => raw.iter().map(|el| *el as i8 as i64).collect(),
+                (_, Some(raw)) => raw.iter().map(|el| *el as i64).collect(),
+                (_, None) => tensor.ints(5)?,
+            };
+            (ints.iter().map(|el| *el as f32).collect(), Some(ints))
+        }
+        (data_type, _) => {
+            return Err(OnnxError::InvalidModel(format!(
+                "{} has the unsupported data type {}",
+                name, data_type
+            )))
+        }
+    };
+
+    let data = ArrayD::from_shape_vec(IxDyn(&shape), data).map_err(|_| {
+        OnnxError::InvalidModel(format!("the data of {} doesn't match its shape", name))
+    })?;
+
+    Ok((name, Tensor { data, ints }))
+}
+
+/// A decoded `NodeProto`.
+struct Node<'a> {
+    op_type: String,
+    domain: String,
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+    attributes: HashMap<String, Fields<'a>>,
+}
+
+impl<'a> Node<'a> {
+    fn decode(node: &Fields<'a>) -> Result<Self, OnnxError> {
+        let attributes = node
+            .messages(5)?
+            .into_iter()
+            .map(|attribute| Ok((attribute.string(1)?, attribute)))
+            .collect::<Result<_, OnnxError>>()?;
+
+        Ok(Self {
+            op_type: node.string(4)?,
+            domain: node.string(7)?,
+            inputs: node.strings(1)?,
+            outputs: node.strings(2)?,
+            attributes,
+        })
+    }
+
+    /// Returns the name of the operator, qualified by its domain if it isn't the default one.
+    fn qualified_name(&self) -> String {
+        match self.domain.as_str() {
+            "" | "ai.onnx" => self.op_type.clone(),
+            domain => format!("{}::{}", domain, self.op_type),
+        }
+    }
+
+    /// Returns the name of the optional input at `index`, if present.
+    fn input(&self, index: usize) -> Option<&str> {
+        self.inputs
+            .get(index)
+            .map(String::as_str)
+            .filter(|name| !name.is_empty())
+    }
+
+    fn invalid(&self, reason: impl Display) -> OnnxError {
+        OnnxError::InvalidModel(format!("{}: {}", self.op_type, reason))
+    }
+
+    fn unsupported(&self, reason: impl Into<String>) -> OnnxError {
+        OnnxError::UnsupportedConfiguration {
+            op_type: self.op_type.clone(),
+            reason: reason.into(),
+        }
+    }
+
+    fn int(&self, name: &str, default: i64) -> i64 {
+        self.attributes
+            .get(name)
+            .and_then(|attribute| attribute.int(3))
+            .unwrap_or(default)
+    }
+
+    fn float(&self, name: &str, default: f32) -> f32 {
+        self.attributes
+            .get(name)
+            .and_then(|attribute| attribute.float(2))
+            .unwrap_or(default)
+    }
+
+    fn ints(&self, name: &str) -> Result<Option<Vec<i64>>, OnnxError> {
+        self.attributes
+            .get(name)
+            .map(|attribute| attribute.ints(8))
+            .transpose()
+    }
+
+    fn string(&self, name: &str) -> Result<Option<String>, OnnxError> {
+        Ok(self
+            .attributes
+            .get(name)
+            .map(|attribute| attribute.strings(4))
+            .transpose()?
+            .and_then(|mut strings| strings.pop()))
+    }
+
+    fn strings(&self, name: &str) -> Result<Option<Vec<String>>, OnnxError> {
+        self.attributes
+            .get(name)
+            .map(|attribute| attribute.strings(9))
+            .transpose()
+    }
+
+    /// Returns the non-negative integers of the attribute `name`, `len` zeros if it's missing.
+    fn sizes(&self, name: &str, len: usize) -> Result<Vec<usize>, OnnxError> {
+        match self.ints(name)? {
+            Some(values) => sizes(self, &values, len),
+            None => Ok(vec![0; len]),
+        }
+    }
+}
+
+/// Converts `values` to sizes, checking that there are `len` of them.
+fn sizes(node: &Node, values: &[i64], len: usize) -> Result<Vec<usize>, OnnxError> {
+    if values.len() != len {
+        return Err(OnnxError::InvalidModel(format!(
+            "{} expected {} values, found {}",
+            node.op_type,
+            len,
+            values.len()
+        )));
+    }
+    values
+        .iter()
+        .map(|value| usize::try_from(*value))
+        .collect::<Result<_, _>>()
+        .map_err(|_| node.unsupported("negative values aren't supported"))
+}
+
+/// Normalizes a possibly negative axis of a tensor with `rank` dimensions.
+fn axis(node: &Node, axis: i64, rank: usize) -> Result<usize, OnnxError> {
+    let normalized = if axis < 0 { axis + rank as i64 } else { axis };
+    if (0..rank as i64).contains(&normalized) {
+        Ok(normalized as usize)
+    } else {
+        Err(OnnxError::InvalidModel(format!(
+            "axis {} of {} is out of bounds for rank {}",
+            axis, node.op_type, rank
+        )))
+    }
+}
+
+/// Builds a dimension from its components.
+fn dim<E>(values: &[usize]) -> E
+where
+    E: Dimension,
+{
+    let mut dim = E::zeros(values.len());
+    dim.slice_mut().copy_from_slice(values);
+    dim
+}
+
+/// Computes the padding before and after the spatial dimensions for the `auto_pad` mode of a
+/// convolution.
+fn conv_pads(
+    node: &Node,
+    input: &[usize],
+    kernel: &[usize],
+    strides: &[usize],
+    dilations: &[usize],
+) -> Result<(Vec<usize>, Vec<usize>), OnnxError> {
+    let spatial = input.len();
+    let mode = node.string("auto_pad")?.unwrap_or_default();
+    match mode.as_str() {
+        "" | "NOTSET" => {
+            let pads = node.sizes("pads", 2 * spatial)?;
+            Ok((pads[..spatial].to_vec(), pads[spatial..].to_vec()))
+        }
+        "VALID" => Ok((vec![0; spatial], vec![0; spatial])),
+        "SAME_UPPER" | "SAME_LOWER" => {
+            let (mut before, mut after) = (Vec::new(), Vec::new());
+            for i in 0..spatial {
+                let output = input[i].div_ceil(strides[i]);
+                let total = ((output - 1) * strides[i] + (kernel[i] - 1) * dilations[i] + 1)
+                    .saturating_sub(input[i]);
+                let (small, large) = (total / 2, total - total / 2);
+                if mode == "SAME_UPPER" {
+                    before.push(small);
+                    after.push(large);
+                } else {
+                    before.push(large);
+                    after.push(small);
+                }
+            }
+            Ok((before, after))
+        }
+        mode => Err(OnnxError::InvalidModel(format!(
+            "unknown auto_pad mode {}",
+            mode
+        ))),
+    }
+}
+
+/// Builds the variables of an ONNX graph.
+struct Builder {
+    opset: i64,
+    values: HashMap<String, OnnxValue>,
+    constants: HashMap<String, Tensor>,
+    initializers: HashSet<String>,
+    parameters: Vec<(String, VarDiff<IxDyn>)>,
+}
+
+impl Builder {
+    /// Returns the variable named `name`. Constants are turned into leaves the first time they
+    /// are used, floating point initializers become differentiable.
+    fn value(&mut self, name: &str) -> Result<OnnxValue, OnnxError> {
+        if let Some(value) = self.values.get(name) {
+            return Ok(value.clone());
+        }
+
+        let tensor = self.constants.get(name).ok_or_else(|| {
+            OnnxError::InvalidModel(format!("{} is used before being defined", name))
+        })?;
+        let var = crate::from_ndarray(tensor.data.clone());
+        let value = if self.initializers.contains(name) && tensor.ints.is_none() {
+            let parameter = var.requires_grad();
+            self.parameters.push((name.to_string(), parameter.clone()));
+            OnnxValue::VarDiff(parameter)
+        } else {
+            OnnxValue::Var(var)
+        };
+        self.values.insert(name.to_string(), value.clone());
+
+        Ok(value)
+    }
+
+    /// Returns the variable of the input at `index` of `node`.
+    fn input(&mut self, node: &Node, index: usize) -> Result<OnnxValue, OnnxError> {
+        let name = node.input(index).ok_or_else(|| {
+            OnnxError::InvalidModel(format!("{} misses its input {}", node.op_type, index))
+        })?;
+        self.value(name)
+    }
+
+    /// Returns the constant input at `index` of `node`, if present.
+    fn constant(&self, node: &Node, index: usize) -> Result<Option<&Tensor>, OnnxError> {
+        node.input(index)
+            .map(|name| {
+                self.constants
+                    .get(name)
+                    .ok_or_else(|| node.unsupported(format!("{} must be a constant", name)))
+            })
+            .transpose()
+    }
+
+    /// Returns the axes of `node`, given as an attribute up to opset 12 and as an input later.
+    fn axes(&self, node: &Node, index: usize) -> Result<Option<Vec<i64>>, OnnxError> {
+        match node.ints("axes")? {
+            Some(axes) => Ok(Some(axes)),
+            None => Ok(self.constant(node, index)?.map(Tensor::ints)),
+        }
+    }
+
+    /// Builds the variables computed by `node`.
+    fn node(&mut self, node: &Node) -> Result<(), OnnxError> {
+        if node.op_type == "Constant" {
+            let tensor = self.constant_node(node)?;
+            self.constants.insert(node.outputs[0].clone(), tensor);
+            return Ok(());
+        }
+
+        let outputs = match node.op_type.as_str() {
+            "RNN" => self.rnn(node)?,
+            _ => vec![self.operation(node)?],
+        };
+        for (name, value) in node.outputs.iter().zip(outputs) {
+            if !name.is_empty() {
+                self.values.insert(name.clone(), value);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn constant_node(&self, node: &Node) -> Result<Tensor, OnnxError> {
+        if let Some(tensor) = node.attributes.get("value") {
+            let tensor = tensor.messages(5)?.pop().ok_or_else(|| {
+                OnnxError::InvalidModel("the value of Constant isn't a tensor".to_string())
+            })?;
+            return Ok(decode_tensor(&tensor)?.1);
+        }
+
+        let (data, ints) = if let Some(ints) = node.ints("value_ints")? {
+            (Array::from_vec(ints.clone()).into_dyn(), Some(ints))
+        } else if node.attributes.contains_key("value_int") {
+            let int = node.int("value_int", 0);
+            (ArrayD::from_elem(IxDyn(&[]), int), Some(vec![int]))
+        } else if let Some(attribute) = node.attributes.get("value_floats") {
+            let floats = Array::from_vec(attribute.floats(7)?).into_dyn();
+            return Ok(Tensor {
+                data: floats,
+                ints: None,
+            });
+        } else if node.attributes.contains_key("value_float") {
+            let float = node.float("value_float", 0.);
+            return Ok(Tensor {
+                data: ArrayD::from_elem(IxDyn(&[]), float),
+                ints: None,
+            });
+        } else {
+            return Err(node.unsupported("only tensors and numbers are supported as values"));
+        };
+
+        Ok(Tensor {
+            data: data.mapv(|el| el as f32),
+            ints,
+        })
+    }
+
+    /// Builds the variable computed by a single-output node.
+    fn operation(&mut self, node: &Node) -> Result<OnnxValue, OnnxError> {
+        let x = self.input(node, 0)?;
+        let output = match node.op_type.as_str() {
+            "Identity" | "Dropout" => x,
+            "Add" => binary!(x, self.input(node, 1)?, |l, r| l + r),
+            "Sub" => binary!(x, self.input(node, 1)?, |l, r| l - r),
+            "Mul" => binary!(x, self.input(node, 1)?, |l, r| l * r),
+            "Div" => binary!(x, self.input(node, 1)?, |l, r| l / r),
+            "Neg" => unary!(x, |x| -x),
+            "Abs" => unary!(x, |x| x.abs()),
+            "Cos" => unary!(x, |x| x.cos()),
+            "Erf" => unary!(x, |x| x.erf()),
+            "Exp" => unary!(x, |x| x.exp()),
+            "Log" => unary!(x, |x| x.ln()),
+            "Relu" => unary!(x, |x| x.relu()),
+            "Sigmoid" => unary!(x, |x| x.sigmoid()),
+            "Sin" => unary!(x, |x| x.sin()),
+            "Softplus" => unary!(x, |x| x.softplus()),
+            "Softsign" => unary!(x, |x| x.softsign()),
+            "Sqrt" => unary!(x, |x| x.sqrt()),
+            "Tanh" => unary!(x, |x| x.tanh()),
+            "Elu" => {
+                let alpha = node.float("alpha", 1.);
+                unary!(x, |x| x.elu(alpha))
+            }
+            "LeakyRelu" => {
+                let alpha = node.float("alpha", 0.01);
+                unary!(x, |x| x.leaky_relu_with_slope(alpha))
+            }
+            "Selu" => {
+                let alpha = node.float("alpha", 1.673_263_2);
+                let gamma = node.float("gamma", 1.050_701);
+                if (alpha - 1.673_263_2).abs() > 1e-6 || (gamma - 1.050_701).abs() > 1e-6 {
+                    return Err(node.unsupported("only the default alpha and gamma are supported"));
+                }
+                unary!(x, |x| x.selu())
+            }
+            "HardSigmoid" => {
+                let alpha = node.float("alpha", 0.2);
+                let beta = node.float("beta", 0.5);
+                unary!(x, |x| (x * alpha + beta).clamp(0., 1.))
+            }
+            "Clip" => {
+                let (min, max) = if self.opset < 11 {
+                    (
+                        node.float("min", f32::NEG_INFINITY),
+                        node.float("max", f32::INFINITY),
+                    )
+                } else {
+                    let bound = |index, default| -> Result<f32, OnnxError> {
+                        match self.constant(node, index)? {
+                            Some(tensor) => tensor
+                                .scalar()
+                                .ok_or_else(|| node.unsupported("the bounds must be scalars")),
+                            None => Ok(default),
+                        }
+                    };
+                    (bound(1, f32::NEG_INFINITY)?, bound(2, f32::INFINITY)?)
+                };
+                unary!(x, |x| x.clamp(min, max))
+            }
+            "Pow" => {
+                let exp = self
+                    .constant(node, 1)?
+                    .and_then(Tensor::scalar)
+                    .ok_or_else(|| node.unsupported("the exponent must be a constant scalar"))?;
+                unary!(x, |x| x.powf(exp))
+            }
+            "Softmax" | "LogSoftmax" => self.softmax(node, x)?,
+            "MatMul" => self.matmul(node, x)?,
+            "Gemm" => self.gemm(node, x)?,
+            "Conv" | "ConvTranspose" | "Pad" => {
+                let rank = x.shape().len();
+                match rank {
+                    3 => self.spatial::<Ix3>(node, x)?,
+                    4 => self.spatial::<Ix4>(node, x)?,
+                    5 => self.spatial::<Ix5>(node, x)?,
+                    _ => {
+                        return Err(node
+                            .unsupported(format!("{}-dimensional inputs aren't supported", rank)))
+                    }
+                }
+            }
+            "Reshape" | "Flatten" | "Squeeze" | "Unsqueeze" => {
+                let shape = self.reshaped(node, &x.shape())?;
+                x.reshape(&shape)
+            }
+            "Transpose" => {
+                let rank = x.shape().len();
+                let reversed: Vec<i64> = (0..rank as i64).rev().collect();
+                match node.ints("perm")? {
+                    None => unary!(x, |x| x.t()),
+                    Some(perm) if perm == reversed => unary!(x, |x| x.t()),
+                    Some(perm) if perm.iter().copied().eq(0..rank as i64) => x,
+                    Some(_) => return Err(node.unsupported("only reversing the axes is supported")),
+                }
+            }
+            "Concat" => {
+                let axis = axis(node, node.int("axis", 0), x.shape().len())?;
+                let mut output = x;
+                for index in 1..node.inputs.len() {
+                    let rhs = self.input(node, index)?;
+                    output = binary!(output, rhs, |l, r| Cat::cat(l, r, axis));
+                }
+                output
+            }
+            "ReduceSum" | "ReduceMean" => {
+                let shape = x.shape();
+                let axes = self.axes(node, 1)?.unwrap_or_default();
+                if axes.is_empty() && node.int("noop_with_empty_axes", 0) == 1 {
+                    return Ok(x);
+                }
+                let axes = axes
+                    .iter()
+                    .map(|value| axis(node, *value, shape.len()))
+                    .collect::<Result<BTreeSet<_>, _>>()?;
+                if !axes.is_empty() && axes.len() != shape.len() {
+                    return Err(node.unsupported("only the reduction of all axes is supported"));
+                }
+                let reduced = if node.op_type == "ReduceSum" {
+                    unary!(x, |x| x.sum())
+                } else {
+                    unary!(x, |x| x.mean())
+                };
+                match node.int("keepdims", 1) {
+                    0 => reduced.reshape(&[]),
+                    _ => reduced.reshape(&vec![1; shape.len()]),
+                }
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(output)
+    }
+
+    fn softmax(&self, node: &Node, x: OnnxValue) -> Result<OnnxValue, OnnxError> {
+        let shape = x.shape();
+        let default = if self.opset < 13 { 1 } else { -1 };
+        let axis = axis(node, node.int("axis", default), shape.len())?;
+        let softmax = |x: OnnxValue, axis| {
+            if node.op_type == "Softmax" {
+                unary!(x, |x| x.softmax(axis))
+            } else {
+                unary!(x, |x| x.log_softmax(axis))
+            }
+        };
+
+        // Up to opset 12 the input is coerced into a matrix, flattening the axes starting at
+        // `axis`.
+        if self.opset >= 13 || axis + 1 == shape.len() {
+            Ok(softmax(x, axis))
+        } else {
+            let rows = shape[..axis].iter().product();
+            let matrix = x.reshape(&[rows, shape[axis..].iter().product()]);
+            Ok(softmax(matrix, 1).reshape(&shape))
+        }
+    }
+
+    fn matmul(&mut self, node: &Node, x: OnnxValue) -> Result<OnnxValue, OnnxError> {
+        let y = self.input(node, 1)?;
+        let (x_shape, y_shape) = (x.shape(), y.shape());
+        if let (Some(x_len), Some(y_len)) = (x_shape.last(), y_shape.first()) {
+            if x_len != y_len {
+                return Err(node.invalid(format!(
+                    "can't multiply operands of shapes {:?} and {:?}",
+                    x_shape, y_shape
+                )));
+            }
+        }
+        let output = match (x.shape().len(), y.shape().len()) {
+            (2, 2) => binary!(x.fixed::<Ix2>(), y.fixed::<Ix2>(), |l, r| l.mm(r)).into_dyn(),
+            (2, 1) => binary!(x.fixed::<Ix2>(), y.fixed::<Ix1>(), |l, r| l.mv(r)).into_dyn(),
+            (1, 2) => binary!(x.fixed::<Ix1>(), y.fixed::<Ix2>(), |l, r| l.vm(r)).into_dyn(),
+            (1, 1) => binary!(x.fixed::<Ix1>(), y.fixed::<Ix1>(), |l, r| l.vv(r)).into_dyn(),
+            _ => return Err(node.unsupported("only 1 and 2-dimensional operands are supported")),
+        };
+
+        Ok(output)
+    }
+
+    fn gemm(&mut self, node: &Node, a: OnnxValue) -> Result<OnnxValue, OnnxError> {
+        let b = self.input(node, 1)?;
+        if a.shape().len() != 2 || b.shape().len() != 2 {
+            return Err(OnnxError::InvalidModel(
+                "the operands of Gemm must be matrices".to_string(),
+            ));
+        }
+
+        let (trans_a, trans_b) = (node.int("transA", 0) != 0, node.int("transB", 0) != 0);
+        let (a_shape, b_shape) = (a.shape(), b.shape());
+        let (rows, inner) = if trans_a {
+            (a_shape[1], a_shape[0])
+        } else {
+            (a_shape[0], a_shape[1])
+        };
+        let (b_inner, columns) = if trans_b {
+            (b_shape[1], b_shape[0])
+        } else {
+            (b_shape[0], b_shape[1])
+        };
+        if inner != b_inner {
+            return Err(node.invalid(format!(
+                "can't multiply operands of shapes {:?} and {:?}",
+                a_shape, b_shape
+            )));
+        }
+
+        let mut a = a.fixed::<Ix2>();
+        if trans_a {
+            a = unary!(a, |x| x.t());
+        }
+        let b = b.fixed::<Ix2>();
+        let mut output = if trans_b {
+            binary!(a, b, |l, r| l.mm_t(r))
+        } else {
+            binary!(a, b, |l, r| l.mm(r))
+        };
+        let alpha = node.float("alpha", 1.);
+        if alpha != 1. {
+            output = unary!(output, |x| x * alpha);
+        }
+
+        let mut output = output.into_dyn();
+        if node.input(2).is_some() {
+            let mut c = self.input(node, 2)?;
+            // C must be unidirectionally broadcastable to the shape of the product.
+            let c_shape = c.shape();
+            if c_shape.len() > 2
+                || c_shape
+                    .iter()
+                    .rev()
+                    .zip([columns, rows])
+                    .any(|(len, expected)| *len != 1 && *len != expected)
+            {
+                return Err(node.invalid(format!(
+                    "C of shape {:?} can't be broadcast to {:?}",
+                    c_shape,
+                    [rows, columns]
+                )));
+            }
+            let beta = node.float("beta", 1.);
+            if beta != 1. {
+                c = unary!(c, |x| x * beta);
+            }
+            output = binary!(output, c, |l, r| l + r);
+        }
+
+        Ok(output)
+    }
+
+    /// Builds the operators acting on the spatial dimensions of their input.
+    fn spatial<D>(&mut self, node: &Node, x: OnnxValue) -> Result<OnnxValue, OnnxError>
+    where
+        D: 'static + Dimension + RemoveAxis,
+        D::Smaller: RemoveAxis,
+        <D::Smaller as Dimension>::Smaller: Copy,
+        Reflective: PaddingMode<D>,
+        Replicative: PaddingMode<D>,
+    {
+        let shape = x.shape();
+        let spatial = shape.len() - 2;
+        if node.op_type == "Pad" {
+            let mode = node
+                .string("mode")?
+                .unwrap_or_else(|| "constant".to_string());
+            let (pads, value) = if self.opset < 11 {
+                let pads = node.ints("pads")?.unwrap_or_default();
+                (pads, node.float("value", 0.))
+            } else {
+                let pads = self
+                    .constant(node, 1)?
+                    .map(Tensor::ints)
+                    .unwrap_or_default();
+                let value = self.constant(node, 2)?.and_then(Tensor::scalar);
+                (pads, value.unwrap_or(0.))
+            };
+            if self.opset >= 18 && node.input(3).is_some() {
+                return Err(node.unsupported("the axes input isn't supported"));
+            }
+
+            let pads = sizes(node, &pads, 2 * shape.len())?;
+            let (before, after) = pads.split_at(shape.len());
+            if before[..2].iter().chain(&after[..2]).any(|pad| *pad != 0) {
+                return Err(node.unsupported("only the spatial dimensions can be padded"));
+            }
+            let before: <D::Smaller as Dimension>::Smaller = dim(&before[2..]);
+            let after: <D::Smaller as Dimension>::Smaller = dim(&after[2..]);
+
+            let x = x.fixed::<D>();
+            let output = match mode.as_str() {
+                "constant" => unary!(x, |x| x.pad_asymmetric(before, after, Constant(value))),
+                "reflect" => unary!(x, |x| x.pad_asymmetric(before, after, Reflective)),
+                "edge" => unary!(x, |x| x.pad_asymmetric(before, after, Replicative)),
+                mode => return Err(node.unsupported(format!("the {} mode isn't supported", mode))),
+            };
+            return Ok(output.into_dyn());
+        }
+
+        let kernel = self.input(node, 1)?;
+        let kernel_shape = kernel.shape();
+        if kernel_shape.len() != shape.len() {
+            return Err(OnnxError::InvalidModel(format!(
+                "the kernel of {} doesn't match the rank of its input",
+                node.op_type
+            )));
+        }
+        let strides = match node.ints("strides")? {
+            Some(strides) => sizes(node, &strides, spatial)?,
+            None => vec![1; spatial],
+        };
+        let dilations = match node.ints("dilations")? {
+            Some(dilations) => sizes(node, &dilations, spatial)?,
+            None => vec![1; spatial],
+        };
+        let groups = usize::try_from(node.int("group", 1))
+            .ok()
+            .filter(|groups| *groups > 0)
+            .ok_or_else(|| node.invalid("the group must be positive"))?;
+        if strides.iter().chain(&dilations).any(|len| *len == 0) {
+            return Err(node.invalid("the strides and the dilations must be positive"));
+        }
+        if kernel_shape[2..].contains(&0) {
+            return Err(node.invalid(format!("the kernel has an empty shape {:?}", kernel_shape)));
+        }
+        // The kernel of Conv has one filter per output channel, that of ConvTranspose one per input
+        // channel.
+        let (filters, in_channels) = if node.op_type == "Conv" {
+            (kernel_shape[0], kernel_shape[1] * groups)
+        } else {
+            (kernel_shape[0], kernel_shape[0])
+        };
+        if filters % groups != 0 {
+            return Err(node.invalid(format!(
+                "the {} filters of the kernel aren't divisible by the group {}",
+                filters, groups
+            )));
+        }
+        if shape[1] != in_channels {
+            return Err(node.invalid(format!(
+                "the input has {} channels, the kernel of shape {:?} expects {}",
+                shape[1], kernel_shape, in_channels
+            )));
+        }
+        let stride: <D::Smaller as Dimension>::Smaller = dim(&strides);
+        let dilation: <D::Smaller as Dimension>::Smaller = dim(&dilations);
+
+        let output = if node.op_type == "Conv" {
+            let (before, after) =
+                conv_pads(node, &shape[2..], &kernel_shape[2..], &strides, &dilations)?;
+            let too_small = (0..spatial).any(|i| {
+                shape[2 + i] + before[i] + after[i] < (kernel_shape[2 + i] - 1) * dilations[i] + 1
+            });
+            if too_small {
+                return Err(node.invalid(format!(
+                    "the kernel of shape {:?} is larger than the padded input of shape {:?}",
+                    kernel_shape, shape
+                )));
+            }
+            let mut x = x.fixed::<D>();
+            if before.iter().chain(&after).any(|pad| *pad != 0) {
+                let (before, after): (<D::Smaller as Dimension>::Smaller, _) =
+                    (dim(&before), dim(&after));
+                x = unary!(x, |x| x.pad_asymmetric(before, after, Zero));
+            }
+            binary!(kernel.fixed::<D>(), x, |w, x| w
+                .convolution(x, stride, dilation, groups))
+        } else {
+            let padded = conv_pads(node, &shape[2..], &kernel_shape[2..], &strides, &dilations)
+                .map(|(before, after)| before.iter().chain(&after).any(|pad| *pad != 0))?;
+            if padded || node.attributes.contains_key("output_shape") {
+                return Err(node.unsupported("padding and output shapes aren't supported"));
+            }
+            let output_padding = node.sizes("output_padding", spatial)?;
+            if output_padding
+                .iter()
+                .zip(&strides)
+                .any(|(pad, stride)| pad >= stride)
+            {
+                return Err(node.invalid("the output padding must be smaller than the stride"));
+            }
+            let output_padding: <D::Smaller as Dimension>::Smaller = dim(&output_padding);
+            binary!(kernel.fixed::<D>(), x.fixed::<D>(), |w, x| w
+                .convolution_transpose(
+                    x,
+                    stride,
+                    dilation,
+                    output_padding,
+                    groups
+                ))
+        };
+
+        let mut output = output.into_dyn();
+        if node.input(2).is_some() {
+            let bias = self.input(node, 2)?;
+            let mut shape = vec![1; shape.len()];
+            shape[1] = bias.data().len();
+            output = binary!(output, bias.reshape(&shape), |l, r| l + r);
+        }
+
+        Ok(output)
+    }
+
+    /// Computes the shape of the result of the reshaping operators.
+    fn reshaped(&self, node: &Node, shape: &[usize]) -> Result<Vec<usize>, OnnxError> {
+        let len: usize = shape.iter().product();
+        let reshaped = match node.op_type.as_str() {
+            "Flatten" => {
+                let axis = match node.int("axis", 1) {
+                    axis if axis == shape.len() as i64 => shape.len(),
+                    value => axis(node, value, shape.len())?,
+                };
+                let rows: usize = shape[..axis].iter().product();
+                vec![rows, len / rows.max(1)]
+            }
+            "Squeeze" => match self.axes(node, 1)? {
+                Some(axes) => {
+                    let axes = axes
+                        .iter()
+                        .map(|value| axis(node, *value, shape.len()))
+                        .collect::<Result<HashSet<_>, _>>()?;
+                    shape
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| !axes.contains(i))
+                        .map(|(_, len)| *len)
+                        .collect()
+                }
+                None => shape.iter().copied().filter(|len| *len != 1).collect(),
+            },
+            "Unsqueeze" => {
+                let axes = self.axes(node, 1)?.ok_or_else(|| {
+                    OnnxError::InvalidModel("Unsqueeze misses its axes".to_string())
+                })?;
+                let rank = shape.len() + axes.len();
+                let axes = axes
+                    .iter()
+                    .map(|value| axis(node, *value, rank))
+                    .collect::<Result<HashSet<_>, _>>()?;
+                let mut lens = shape.iter();
+                (0..rank)
+                    .map(|i| match axes.contains(&i) {
+                        true => 1,
+                        false => *lens.next().unwrap(),
+                    })
+                    .collect()
+            }
+            _ => {
+                let target = match self.opset {
+                    opset if opset < 5 => node.ints("shape")?.unwrap_or_default(),
+                    _ => self
+                        .constant(node, 1)?
+                        .map(Tensor::ints)
+                        .ok_or_else(|| node.unsupported("the shape must be a constant"))?,
+                };
+                let allow_zero = node.int("allowzero", 0) != 0;
+                let mut reshaped = target
+                    .iter()
+                    .enumerate()
+                    .map(|(i, len)| match *len {
+                        0 if !allow_zero => Ok(shape.get(i).copied().unwrap_or(0)),
+                        -1 => Ok(1),
+                        len => usize::try_from(len).map_err(|_| {
+                            OnnxError::InvalidModel(format!("invalid shape {:?}", target))
+                        }),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                if let Some(inferred) = target.iter().position(|len| *len == -1) {
+                    reshaped[inferred] = len / reshaped.iter().product::<usize>().max(1);
+                }
+                reshaped
+            }
+        };
+
+        if reshaped.iter().product::<usize>() != len {
+            return Err(OnnxError::InvalidModel(format!(
+                "{} can't reshape {:?} into {:?}",
+                node.op_type, shape, reshaped
+            )));
+        }
+
+        Ok(reshaped)
+    }
+
+    /// Builds a single layer, forward recurrent network with the hyperbolic tangent activation.
+    fn rnn(&mut self, node: &Node) -> Result<Vec<OnnxValue>, OnnxError> {
+        let direction = node.string("direction")?.unwrap_or_default();
+        let activations = node.strings("activations")?.unwrap_or_default();
+        if !matches!(direction.as_str(), "" | "forward")
+            || !activations.iter().all(|activation| activation == "Tanh")
+            || node.attributes.contains_key("clip")
+            || node.int("layout", 0) != 0
+            || node.input(4).is_some()
+        {
+            return Err(node.unsupported(
+                "only forward networks with the default activation, layout and no sequence \
+                lengths are supported",
+            ));
+        }
+
+        let x = self.input(node, 0)?;
+        let (w, r) = (self.input(node, 1)?, self.input(node, 2)?);
+        let shape = x.shape();
+        let hidden = w.shape().get(1).copied().unwrap_or(0);
+        if shape.len() != 3
+            || w.shape() != [1, hidden, shape[2]]
+            || r.shape() != [1, hidden, hidden]
+        {
+            return Err(OnnxError::InvalidModel(
+                "the inputs of RNN have inconsistent shapes".to_string(),
+            ));
+        }
+        let (steps, batch) = (shape[0], shape[1]);
+        let w = w.reshape(&[hidden, shape[2]]).fixed::<Ix2>();
+        let r = r.reshape(&[hidden, hidden]).fixed::<Ix2>();
+
+        let bias = match node.input(3) {
+            Some(_) => {
+                let mut halves = match self.input(node, 3)?.reshape(&[2 * hidden]).fixed::<Ix1>() {
+                    OnnxValue::Var(b) => b.chunks(hidden).into_iter().map(OnnxValue::Var).collect(),
+                    OnnxValue::VarDiff(b) => b
+                        .chunks(hidden)
+                        .into_iter()
+                        .map(OnnxValue::VarDiff)
+                        .collect::<Vec<_>>(),
+                };
+                let (wb, rb) = (halves.remove(0), halves.remove(0));
+                Some(binary!(wb, rb, |l, r| l + r))
+            }
+            None => None,
+        };
+        let mut h = match node.input(5) {
+            Some(_) => Some(
+                self.input(node, 5)?
+                    .reshape(&[batch, hidden])
+                    .fixed::<Ix2>(),
+            ),
+            None => None,
+        };
+
+        let inputs = match x.fixed::<Ix3>() {
+            OnnxValue::Var(x) => x
+                .chunks((1, batch, shape[2]))
+                .into_iter()
+                .map(OnnxValue::Var)
+                .collect(),
+            OnnxValue::VarDiff(x) => x
+                .chunks((1, batch, shape[2]))
+                .into_iter()
+                .map(OnnxValue::VarDiff)
+                .collect::<Vec<_>>(),
+        };
+        let mut y: Option<OnnxValue<Ix3>> = None;
+        for x in inputs {
+            let x = x.reshape(&[batch, shape[2]]).fixed::<Ix2>();
+            let mut a = binary!(x, w.clone(), |l, r| l.mm_t(r));
+            if let Some(h) = h {
+                a = binary!(a, binary!(h, r.clone(), |l, r| l.mm_t(r)), |l, r| l + r);
+            }
+            if let Some(bias) = &bias {
+                a = binary!(a, bias.clone(), |l, r| l + r);
+            }
+            let step = unary!(a, |x| x.tanh());
+
+            let unsqueezed = unary!(step.clone(), |x| x.unsqueeze(0));
+            y = Some(match y {
+                Some(y) => binary!(y, unsqueezed, |l, r| Cat::cat(l, r, 0)),
+                None => unsqueezed,
+            });
+            h = Some(step);
+        }
+
+        match (y, h) {
+            (Some(y), Some(h)) => Ok(vec![
+                y.reshape(&[steps, 1, batch, hidden]),
+                h.reshape(&[1, batch, hidden]),
+            ]),
+            _ => Err(OnnxError::InvalidModel(
+                "the input sequence of RNN is empty".to_string(),
+            )),
+        }
+    }
+}
+
+/// Imports ONNX models as computational graphs.
+///
+/// The initializers of the model become differentiable leaves, so that imported models can be
+/// fine-tuned. The graph inputs are non-differentiable leaves filled with zeros: their data should
+/// be assigned before each call to `.forward()`.
+///
+/// The following operators of the default domain can be imported: `Abs`, `Add`,
+/// `Clip`, `Concat`, `Constant`, `Conv`, `ConvTranspose`, `Cos`, `Div`, `Dropout`, `Elu`, `Erf`,
+/// `Exp`, `Flatten`, `Gemm`, `HardSigmoid`, `Identity`, `LeakyRelu`, `Log`, `LogSoftmax`,
+/// `MatMul`, `Mul`, `Neg`, `Pad`, `Pow`, `RNN`, `ReduceMean`, `ReduceSum`, `Relu`, `Reshape`,
+/// `Selu`, `Sigmoid`, `Sin`, `Softmax`, `Softplus`, `Softsign`, `Sqrt`, `Squeeze`, `Sub`, `Tanh`,
+/// `Transpose` and `Unsqueeze`. Some of them are only supported in their most common
+/// configurations.
+///
+/// ```
+/// # use neuronika_variable as neuronika;
+/// use neuronika::{OnnxExporter, OnnxImporter};
+///
+/// let x = neuronika::zeros((4, 3));
+/// let weight = neuronika::rand((2, 3)).requires_grad();
+/// let bytes = x
+///     .clone()
+///     .mm_t(weight)
+///     .to_onnx(&OnnxExporter::new("linear").input("input", &x))
+///     .unwrap();
+///
+/// let model = OnnxImporter::new().import(&bytes).unwrap();
+/// model.input("input").unwrap().data_mut().fill(1.);
+///
+/// let output = model.output("output").unwrap();
+/// output.forward();
+/// assert_eq!(output.data().shape(), &[4, 2]);
+/// assert_eq!(model.parameters().len(), 1);
+/// ```
+#[derive(Default)]
+pub struct OnnxImporter {
+    shapes: HashMap<String, Vec<usize>>,
+}
+
+impl OnnxImporter {
+    /// Creates a new importer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the shape of the input named `name`, overriding the one declared by the model. This
+    /// is needed for inputs having symbolic dimensions, such as the batch size.
+    pub fn input_shape(mut self, name: impl Into<String>, shape: &[usize]) -> Self {
+        self.shapes.insert(name.into(), shape.to_vec());
+        self
+    }
+
+    /// Imports the model stored at `path`.
+    pub fn load<P>(&self, path: P) -> Result<OnnxModel, OnnxError>
+    where
+        P: AsRef<Path>,
+    {
+        self.import(&fs::read(path)?)
+    }
+
+    /// Imports the model encoded in `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// [`OnnxError::UnsupportedOperators`] lists all the operators of the model that have no
+    /// counterpart, while [`OnnxError::UnsupportedConfiguration`] is returned for the supported
+    /// ones used in a way that can't be mapped.
+    pub fn import(&self, bytes: &[u8]) -> Result<OnnxModel, OnnxError> {
+        let model = Fields::decode(bytes)?;
+        let mut opset = ONNX_OPSET;
+        for opset_id in model.messages(8)? {
+            if matches!(opset_id.string(1)?.as_str(), "" | "ai.onnx") {
+                opset = opset_id.int(2).unwrap_or(opset);
+            }
+        }
+        let graph = model
+            .messages(7)?
+            .pop()
+            .ok_or_else(|| OnnxError::InvalidModel("the model has no graph".to_string()))?;
+
+        let nodes = graph
+            .messages(1)?
+            .iter()
+            .map(Node::decode)
+            .collect::<Result<Vec<_>, _>>()?;
+        let unsupported: BTreeSet<String> = nodes
+            .iter()
+            .map(Node::qualified_name)
+            .filter(|name| !SUPPORTED_OPERATORS.contains(&name.as_str()))
+            .collect();
+        if !unsupported.is_empty() {
+            return Err(OnnxError::UnsupportedOperators(
+                unsupported.into_iter().collect(),
+            ));
+        }
+
+        let mut builder = Builder {
+            opset,
+            values: HashMap::new(),
+            constants: HashMap::new(),
+            initializers: HashSet::new(),
+            parameters: Vec::new(),
+        };
+        for tensor in graph.messages(5)? {
+            let (name, tensor) = decode_tensor(&tensor)?;
+            builder.initializers.insert(name.clone());
+            builder.constants.insert(name, tensor);
+        }
+
+        let mut inputs = Vec::new();
+        for value_info in graph.messages(11)? {
+            let name = value_info.string(1)?;
+            if builder.constants.contains_key(&name) {
+                continue;
+            }
+            let shape = match self.shapes.get(&name) {
+                Some(shape) => shape.clone(),
+                None => input_shape(&name, &value_info)?,
+            };
+            let input = crate::zeros(IxDyn(&shape));
+            builder
+                .values
+                .insert(name.clone(), OnnxValue::Var(input.clone()));
+            inputs.push((name, input));
+        }
+
+        for node in &nodes {
+            builder.node(node)?;
+        }
+
+        let outputs = graph
+            .messages(12)?
+            .iter()
+            .map(|value_info| {
+                let name = value_info.string(1)?;
+                let value = builder.value(&name)?;
+                Ok((name, value))
+            })
+            .collect::<Result<_, OnnxError>>()?;
+
+        Ok(OnnxModel {
+            inputs,
+            outputs,
+            parameters: builder.parameters,
+        })
+    }
+}
+
+/// Reads the shape of a graph input from its `ValueInfoProto`.
+fn input_shape(name: &str, value_info: &Fields) -> Result<Vec<usize>, OnnxError> {
+    let tensor_type = value_info
+        .messages(2)?
+        .pop()
+        .and_then(|type_proto| type_proto.messages(1).ok()?.pop())
+        .ok_or_else(|| OnnxError::InvalidModel(format!("the input {} isn't a tensor", name)))?;
+    if tensor_type.int(1) != Some(FLOAT) {
+        return Err(OnnxError::InvalidModel(format!(
+            "the input {} isn't a single-precision tensor",
+            name
+        )));
+    }
+
+    let unknown = || {
+        OnnxError::InvalidModel(format!(
+            "the shape of the input {} isn't fully known, it must be set on the importer",
+            name
+        ))
+    };
+    let shape = tensor_type.messages(2)?.pop().ok_or_else(unknown)?;
+    shape
+        .messages(1)?
+        .iter()
+        .map(|dimension| {
+            dimension
+                .int(1)
+                .and_then(|len| usize::try_from(len).ok())
+                .ok_or_else(unknown)
+        })
+        .collect()
+}
+
+/// A computational graph imported from an ONNX model.
+pub struct OnnxModel {
+    inputs: Vec<(String, Var<IxDyn>)>,
+    outputs: Vec<(String, OnnxValue)>,
+    parameters: Vec<(String, VarDiff<IxDyn>)>,
+}
+
+impl OnnxModel {
+    /// Returns the input named `name`, if any.
+    pub fn input(&self, name: &str) -> Option<&Var<IxDyn>> {
+        self.inputs
+            .iter()
+            .find(|(input, _)| input == name)
+            .map(|(_, var)| var)
+    }
+
+    /// Returns the inputs of the model with their names, in order of declaration.
+    pub fn inputs(&self) -> &[(String, Var<IxDyn>)] {
+        &self.inputs
+    }
+
+    /// Returns the output named `name`, if any.
+    pub fn output(&self, name: &str) -> Option<&OnnxValue> {
+        self.outputs
+            .iter()
+            .find(|(output, _)| output == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the outputs of the model with their names, in order of declaration.
+    pub fn outputs(&self) -> &[(String, OnnxValue)] {
+        &self.outputs
+    }
+
+    /// Returns the parameters of the model, named after their initializers.
+    pub fn parameters(&self) -> &[(String, VarDiff<IxDyn>)] {
+        &self.parameters
+    }
+
+    /// Registers the parameters of the model into `parameters`, named after their initializers.
+    pub fn register_parameters(&self, parameters: &mut ParameterDict) {
+        for (name, parameter) in &self.parameters {
+            parameters.insert(name.clone(), parameter.clone());
+        }
+    }
+}
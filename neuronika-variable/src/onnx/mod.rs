@@ -0,0 +1,387 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::{self, Display},
+    io,
+    rc::Rc,
+};
+
+use ndarray::Dimension;
+
+use crate::{
+    autograd::{Buffer, Forward},
+    Var,
+};
+
+use self::proto::Message;
+
+pub use self::import::{OnnxImporter, OnnxModel, OnnxValue};
+
+mod import;
+mod proto;
+
+/// Version of the default ONNX operator set targeted by the exporter.
+pub const ONNX_OPSET: i64 = 13;
+
+/// Version of the ONNX intermediate representation matching [`ONNX_OPSET`].
+const IR_VERSION: i64 = 7;
+
+/// ONNX data type of single-precision floats.
+const FLOAT: i64 = 1;
+
+/// ONNX data type of 8-bit unsigned integers.
+const UINT8: i64 = 2;
+
+/// ONNX data type of 8-bit signed integers.
+const INT8: i64 = 3;
+
+/// ONNX data type of 32-bit signed integers.
+const INT32: i64 = 6;
+
+/// ONNX data type of 64-bit signed integers.
+const INT64: i64 = 7;
+
+/// ONNX data type of half-precision floats.
+const FLOAT16: i64 = 10;
+
+/// ONNX data type of double-precision floats.
+const DOUBLE: i64 = 11;
+
+/// ONNX data type of brain floating point numbers.
+const BFLOAT16: i64 = 16;
+
+/// Errors raised while exporting a computational graph to ONNX or importing one from it.
+#[derive(Debug)]
+pub enum OnnxError {
+    /// The underlying reader or writer failed.
+    Io(io::Error),
+    /// The operation at the given position of the computational graph has no ONNX counterpart.
+    UnsupportedOperation { position: usize },
+    /// The data of a leaf isn't laid out contiguously in memory.
+    NonContiguousData { name: String },
+    /// The model isn't a well-formed ONNX model.
+    InvalidModel(String),
+    /// The operators of the model, in the `domain::op_type` form for non-default domains, that
+    /// have no neuronika counterpart.
+    UnsupportedOperators(Vec<String>),
+    /// The operator is supported, but not with the configuration used by the model.
+    UnsupportedConfiguration { op_type: String, reason: String },
+}
+
+impl Display for OnnxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "I/O error: {}", error),
+            Self::UnsupportedOperation { position } => write!(
+                f,
+                "the operation at position {} of the graph has no ONNX counterpart",
+                position
+            ),
+            Self::NonContiguousData { name } => {
+                write!(f, "the data of {} isn't contiguous in memory", name)
+            }
+            Self::InvalidModel(reason) => write!(f, "invalid ONNX model: {}", reason),
+            Self::UnsupportedOperators(operators) => write!(
+                f,
+                "the model uses unsupported operators: {}",
+                operators.join(", ")
+            ),
+            Self::UnsupportedConfiguration { op_type, reason } => {
+                write!(f, "unsupported {} operator: {}", op_type, reason)
+            }
+        }
+    }
+}
+
+impl Error for OnnxError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for OnnxError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Value of an attribute of an ONNX operator.
+pub(crate) enum Attribute {
+    Float(f32),
+    Int(i64),
+    Ints(Vec<i64>),
+    String(&'static str),
+}
+
+/// Constant input of an ONNX operator, stored as an initializer and appended after the inputs
+/// corresponding to the operands of the computation.
+pub(crate) enum Initializer {
+    /// A 1-dimensional tensor of 64-bit integers.
+    Ints(Vec<i64>),
+    /// A scalar single-precision float.
+    Float(f32),
+}
+
+/// The ONNX operator equivalent to a computation.
+pub(crate) struct Operator {
+    op_type: &'static str,
+    attributes: Vec<(&'static str, Attribute)>,
+    initializers: Vec<Initializer>,
+}
+
+impl Operator {
+    /// Creates an operator of the given type, in the default domain.
+    pub(crate) fn new(op_type: &'static str) -> Self {
+        Self {
+            op_type,
+            attributes: Vec::new(),
+            initializers: Vec::new(),
+        }
+    }
+
+    /// Adds an attribute to the operator.
+    pub(crate) fn attribute(mut self, name: &'static str, value: Attribute) -> Self {
+        self.attributes.push((name, value));
+        self
+    }
+
+    /// Adds a constant input to the operator.
+    pub(crate) fn initializer(mut self, value: Initializer) -> Self {
+        self.initializers.push(value);
+        self
+    }
+}
+
+/// Converts `values` to the signed integers used by ONNX.
+pub(crate) fn ints(values: &[usize]) -> Vec<i64> {
+    values.iter().map(|value| *value as i64).collect()
+}
+
+/// Exports computational graphs to ONNX models.
+///
+/// Each leaf of the graph registered with [`.input()`](OnnxExporter::input()) becomes an input of
+/// the model, all the other leaves, such as the parameters, become initializers holding their
+/// current data. The result of the graph is the only output of the model, named `output`.
+///
+/// Only inference graphs are meaningful, dropout is exported as an identity.
+///
+/// ```
+/// # use neuronika_variable as neuronika;
+/// let x = neuronika::zeros((4, 3));
+/// let weight = neuronika::rand((2, 3)).requires_grad();
+/// let y = x.clone().mm_t(weight).relu();
+///
+/// let model = y
+///     .to_onnx(&neuronika::OnnxExporter::new("linear").input("input", &x))
+///     .unwrap();
+/// // std::fs::write("linear.onnx", model).unwrap();
+/// ```
+pub struct OnnxExporter {
+    name: String,
+    inputs: Vec<(String, usize, Vec<usize>)>,
+}
+
+impl OnnxExporter {
+    /// Creates an exporter producing graphs with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            inputs: Vec::new(),
+        }
+    }
+
+    /// Registers `input` as an input of the model, named `name`. Its current shape becomes the
+    /// shape of the input.
+    ///
+    /// `input` should be a leaf, the operations computing it aren't exported.
+    pub fn input<D>(mut self, name: impl Into<String>, input: &Var<D>) -> Self
+    where
+        D: Dimension,
+    {
+        self.inputs.push((
+            name.into(),
+            input.data.address(),
+            input.data.borrow().shape().to_vec(),
+        ));
+        self
+    }
+
+    /// Encodes the computations of `operations`, which produce `root`, as an ONNX model.
+    pub(crate) fn export<'a>(
+        &self,
+        root: &dyn Buffer,
+        operations: impl IntoIterator<Item = &'a Rc<dyn Forward>>,
+    ) -> Result<Vec<u8>, OnnxError> {
+        let operations: Vec<&Rc<dyn Forward>> = operations.into_iter().collect();
+        let inputs: HashSet<usize> = self.inputs.iter().map(|(_, address, _)| *address).collect();
+        let mut names: HashMap<usize, String> = self
+            .inputs
+            .iter()
+            .map(|(name, address, _)| (*address, name.clone()))
+            .collect();
+
+        let mut initializers = 0;
+        let mut graph = Message::new();
+        graph.string(2, &self.name);
+        for (name, _, shape) in &self.inputs {
+            graph.message(11, &value_info(name, shape));
+        }
+
+        // The last operation writing the result names it, earlier ones may compute it in-place.
+        let last = operations
+            .iter()
+            .rposition(|op| op.data().address() == root.address());
+        for (position, op) in operations.iter().enumerate() {
+            let address = op.data().address();
+            if inputs.contains(&address) {
+                continue;
+            }
+
+            let operator = op
+                .onnx()
+                .ok_or(OnnxError::UnsupportedOperation { position })?;
+            let node_name = format!("{}_{}", operator.op_type, position);
+
+            let mut node = Message::new();
+            for operand in op.operands() {
+                let name = match names.get(&operand.address()) {
+                    Some(name) => name.clone(),
+                    None => {
+                        let name = format!("initializer_{}", initializers);
+                        graph.message(5, &leaf_tensor(&name, operand)?);
+                        initializers += 1;
+                        names.insert(operand.address(), name.clone());
+                        name
+                    }
+                };
+                node.string(1, &name);
+            }
+            for (index, initializer) in operator.initializers.iter().enumerate() {
+                let name = format!("{}_constant_{}", node_name, index);
+                graph.message(5, &constant_tensor(&name, initializer));
+                node.string(1, &name);
+            }
+
+            let output = if Some(position) == last {
+                "output".to_string()
+            } else {
+                format!("{}_output", node_name)
+            };
+            node.string(2, &output)
+                .string(3, &node_name)
+                .string(4, operator.op_type);
+            for (name, value) in &operator.attributes {
+                node.message(5, &attribute(name, value));
+            }
+
+            graph.message(1, &node);
+            names.insert(address, output);
+        }
+
+        // The result is a leaf or an input.
+        if last.is_none() {
+            let input = match names.get(&root.address()) {
+                Some(name) => name.clone(),
+                None => {
+                    let name = format!("initializer_{}", initializers);
+                    graph.message(5, &leaf_tensor(&name, root)?);
+                    name
+                }
+            };
+            let mut node = Message::new();
+            node.string(1, &input)
+                .string(2, "output")
+                .string(3, "Identity_0")
+                .string(4, "Identity");
+            graph.message(1, &node);
+        }
+        graph.message(12, &value_info("output", &root.shape()));
+
+        let mut opset = Message::new();
+        opset.string(1, "").int(2, ONNX_OPSET);
+
+        let mut model = Message::new();
+        model
+            .int(1, IR_VERSION)
+            .string(2, "neuronika")
+            .string(3, env!("CARGO_PKG_VERSION"))
+            .message(7, &graph)
+            .message(8, &opset);
+
+        Ok(model.into_bytes())
+    }
+}
+
+/// Encodes a `ValueInfoProto` describing a single-precision tensor of the given shape.
+fn value_info(name: &str, shape: &[usize]) -> Message {
+    let mut tensor_shape = Message::new();
+    for len in shape {
+        let mut dimension = Message::new();
+        dimension.int(1, *len as i64);
+        tensor_shape.message(1, &dimension);
+    }
+
+    let mut tensor_type = Message::new();
+    tensor_type.int(1, FLOAT).message(2, &tensor_shape);
+
+    let mut type_proto = Message::new();
+    type_proto.message(1, &tensor_type);
+
+    let mut value_info = Message::new();
+    value_info.string(1, name).message(2, &type_proto);
+    value_info
+}
+
+/// Encodes a `TensorProto` holding the data of a leaf.
+fn leaf_tensor(name: &str, buffer: &dyn Buffer) -> Result<Message, OnnxError> {
+    let data = buffer.flat().ok_or_else(|| OnnxError::NonContiguousData {
+        name: name.to_string(),
+    })?;
+    let raw_data: Vec<u8> = data.iter().flat_map(|el| el.to_le_bytes()).collect();
+
+    let mut tensor = Message::new();
+    tensor
+        .ints(1, &ints(&buffer.shape()))
+        .int(2, FLOAT)
+        .string(8, name)
+        .bytes(9, &raw_data);
+    Ok(tensor)
+}
+
+/// Encodes a `TensorProto` holding a constant input.
+fn constant_tensor(name: &str, initializer: &Initializer) -> Message {
+    let mut tensor = Message::new();
+    match initializer {
+        Initializer::Ints(values) => {
+            let raw_data: Vec<u8> = values.iter().flat_map(|el| el.to_le_bytes()).collect();
+            tensor
+                .int(1, values.len() as i64)
+                .int(2, INT64)
+                .bytes(9, &raw_data);
+        }
+        Initializer::Float(value) => {
+            tensor.int(2, FLOAT).bytes(9, &value.to_le_bytes());
+        }
+    }
+    tensor.string(8, name);
+    tensor
+}
+
+/// Encodes an `AttributeProto`.
+fn attribute(name: &str, value: &Attribute) -> Message {
+    let mut attribute = Message::new();
+    attribute.string(1, name);
+    match value {
+        Attribute::Float(value) => attribute.int(20, 1).float(2, *value),
+        Attribute::Int(value) => attribute.int(20, 2).int(3, *value),
+        Attribute::String(value) => attribute.int(20, 3).string(4, value),
+        Attribute::Ints(values) => attribute.int(20, 7).ints(8, values),
+    };
+    attribute
+}
+
+#[cfg(test)]
+mod test;
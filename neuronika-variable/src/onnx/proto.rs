@@ -0,0 +1,274 @@
+use super::OnnxError;
+
+/// Wire type of the varint encoded fields.
+const VARINT: u8 = 0;
+
+/// Wire type of the length delimited fields.
+const LEN: u8 = 2;
+
+/// Wire type of the 64-bit fields.
+const I64: u8 = 1;
+
+/// Wire type of the 32-bit fields.
+const I32: u8 = 5;
+
+/// A protocol buffer message under construction.
+///
+/// Only the wire format matters, the meaning of the fields is given by the caller. Repeated
+/// scalars are written unpacked, as required by the proto2 messages of ONNX.
+#[derive(Default)]
+pub(crate) struct Message(Vec<u8>);
+
+impl Message {
+    /// Creates an empty message.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.0.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.0.push(value as u8);
+    }
+
+    fn key(&mut self, field: u32, wire_type: u8) {
+        self.varint((field as u64) << 3 | wire_type as u64);
+    }
+
+    /// Appends an integer field.
+    pub(crate) fn int(&mut self, field: u32, value: i64) -> &mut Self {
+        self.key(field, VARINT);
+        self.varint(value as u64);
+        self
+    }
+
+    /// Appends a repeated integer field.
+    pub(crate) fn ints(&mut self, field: u32, values: &[i64]) -> &mut Self {
+        values.iter().for_each(|value| {
+            self.int(field, *value);
+        });
+        self
+    }
+
+    /// Appends a single-precision float field.
+    pub(crate) fn float(&mut self, field: u32, value: f32) -> &mut Self {
+        self.key(field, I32);
+        self.0.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    /// Appends a bytes field.
+    pub(crate) fn bytes(&mut self, field: u32, bytes: &[u8]) -> &mut Self {
+        self.key(field, LEN);
+        self.varint(bytes.len() as u64);
+        self.0.extend_from_slice(bytes);
+        self
+    }
+
+    /// Appends a string field.
+    pub(crate) fn string(&mut self, field: u32, string: &str) -> &mut Self {
+        self.bytes(field, string.as_bytes())
+    }
+
+    /// Appends an embedded message field.
+    pub(crate) fn message(&mut self, field: u32, message: &Message) -> &mut Self {
+        self.bytes(field, &message.0)
+    }
+
+    /// Returns the encoded message.
+    pub(crate) fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+/// Value of a decoded field.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Value<'a> {
+    Varint(u64),
+    I64(u64),
+    Len(&'a [u8]),
+    I32(u32),
+}
+
+/// The fields of a decoded protocol buffer message, in order of appearance.
+pub(crate) struct Fields<'a>(Vec<(u32, Value<'a>)>);
+
+/// Reads a varint starting at `position`, advancing it.
+fn read_varint(bytes: &[u8], position: &mut usize) -> Result<u64, OnnxError> {
+    let mut value = 0;
+    for shift in (0..64).step_by(7) {
+        let byte = *bytes.get(*position).ok_or_else(truncated)?;
+        *position += 1;
+        value |= ((byte & 0x7F) as u64) << shift;
+        if byte < 0x80 {
+            return Ok(value);
+        }
+    }
+
+    Err(OnnxError::InvalidModel("malformed varint".to_string()))
+}
+
+fn truncated() -> OnnxError {
+    OnnxError::InvalidModel("truncated message".to_string())
+}
+
+/// Reads `len` bytes starting at `position`, advancing it.
+fn read_bytes<'a>(
+    bytes: &'a [u8],
+    position: &mut usize,
+    len: usize,
+) -> Result<&'a [u8], OnnxError> {
+    let end = position.checked_add(len).ok_or_else(truncated)?;
+    let slice = bytes.get(*position..end).ok_or_else(truncated)?;
+    *position = end;
+    Ok(slice)
+}
+
+impl<'a> Fields<'a> {
+    /// Decodes the top-level fields of `bytes`.
+    pub(crate) fn decode(bytes: &'a [u8]) -> Result<Self, OnnxError> {
+        let mut fields = Vec::new();
+        let mut position = 0;
+        while position < bytes.len() {
+            let key = read_varint(bytes, &mut position)?;
+            let value = match (key & 7) as u8 {
+                VARINT => Value::Varint(read_varint(bytes, &mut position)?),
+                I64 => Value::I64(u64::from_le_bytes(
+                    read_bytes(bytes, &mut position, 8)?.try_into().unwrap(),
+                )),
+                LEN => {
+                    let len = read_varint(bytes, &mut position)? as usize;
+                    Value::Len(read_bytes(bytes, &mut position, len)?)
+                }
+                I32 => Value::I32(u32::from_le_bytes(
+                    read_bytes(bytes, &mut position, 4)?.try_into().unwrap(),
+                )),
+                wire_type => {
+                    return Err(OnnxError::InvalidModel(format!(
+                        "unsupported wire type {}",
+                        wire_type
+                    )))
+                }
+            };
+            fields.push(((key >> 3) as u32, value));
+        }
+
+        Ok(Self(fields))
+    }
+
+    /// Returns the values of `field`.
+    pub(crate) fn get(&self, field: u32) -> impl Iterator<Item = Value<'a>> + '_ {
+        self.0
+            .iter()
+            .filter(move |(number, _)| *number == field)
+            .map(|(_, value)| *value)
+    }
+
+    /// Returns the last value of the integer `field`.
+    pub(crate) fn int(&self, field: u32) -> Option<i64> {
+        self.get(field).fold(None, |last, value| match value {
+            Value::Varint(value) => Some(value as i64),
+            _ => last,
+        })
+    }
+
+    /// Returns the values of the repeated integer `field`, either packed or not.
+    pub(crate) fn ints(&self, field: u32) -> Result<Vec<i64>, OnnxError> {
+        let mut ints = Vec::new();
+        for value in self.get(field) {
+            match value {
+                Value::Varint(value) => ints.push(value as i64),
+                Value::Len(bytes) => {
+                    let mut position = 0;
+                    while position < bytes.len() {
+                        ints.push(read_varint(bytes, &mut position)? as i64);
+                    }
+                }
+                _ => return Err(mismatch(field)),
+            }
+        }
+
+        Ok(ints)
+    }
+
+    /// Returns the last value of the single-precision float `field`.
+    pub(crate) fn float(&self, field: u32) -> Option<f32> {
+        self.get(field).fold(None, |last, value| match value {
+            Value::I32(bits) => Some(f32::from_bits(bits)),
+            _ => last,
+        })
+    }
+
+    /// Returns the values of the repeated single-precision float `field`, either packed or not.
+    pub(crate) fn floats(&self, field: u32) -> Result<Vec<f32>, OnnxError> {
+        let mut floats = Vec::new();
+        for value in self.get(field) {
+            match value {
+                Value::I32(bits) => floats.push(f32::from_bits(bits)),
+                Value::Len(bytes) if bytes.len() % 4 == 0 => floats.extend(
+                    bytes
+                        .chunks_exact(4)
+                        .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap())),
+                ),
+                _ => return Err(mismatch(field)),
+            }
+        }
+
+        Ok(floats)
+    }
+
+    /// Returns the values of the repeated double-precision float `field`, either packed or not.
+    pub(crate) fn doubles(&self, field: u32) -> Result<Vec<f64>, OnnxError> {
+        let mut doubles = Vec::new();
+        for value in self.get(field) {
+            match value {
+                Value::I64(bits) => doubles.push(f64::from_bits(bits)),
+                Value::Len(bytes) if bytes.len() % 8 == 0 => doubles.extend(
+                    bytes
+                        .chunks_exact(8)
+                        .map(|chunk| f64::from_le_bytes(chunk.try_into().unwrap())),
+                ),
+                _ => return Err(mismatch(field)),
+            }
+        }
+
+        Ok(doubles)
+    }
+
+    /// Returns the values of the repeated bytes `field`.
+    pub(crate) fn bytes(&self, field: u32) -> Result<Vec<&'a [u8]>, OnnxError> {
+        self.get(field)
+            .map(|value| match value {
+                Value::Len(bytes) => Ok(bytes),
+                _ => Err(mismatch(field)),
+            })
+            .collect()
+    }
+
+    /// Returns the values of the repeated string `field`.
+    pub(crate) fn strings(&self, field: u32) -> Result<Vec<String>, OnnxError> {
+        self.bytes(field)?
+            .into_iter()
+            .map(|bytes| {
+                String::from_utf8(bytes.to_vec())
+                    .map_err(|_| OnnxError::InvalidModel(format!("field {} isn't UTF-8", field)))
+            })
+            .collect()
+    }
+
+    /// Returns the last value of the string `field`, or an empty string if it's missing.
+    pub(crate) fn string(&self, field: u32) -> Result<String, OnnxError> {
+        Ok(self.strings(field)?.pop().unwrap_or_default())
+    }
+
+    /// Returns the decoded values of the repeated embedded message `field`.
+    pub(crate) fn messages(&self, field: u32) -> Result<Vec<Fields<'a>>, OnnxError> {
+        self.bytes(field)?.into_iter().map(Fields::decode).collect()
+    }
+}
+
+fn mismatch(field: u32) -> OnnxError {
+    OnnxError::InvalidModel(format!("unexpected wire type for field {}", field))
+}
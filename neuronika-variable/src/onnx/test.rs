@@ -0,0 +1,550 @@
+use ndarray::{array, Array, IxDyn};
+
+use crate::Convolution;
+
+use super::{
+    attribute, proto::Fields, proto::Message, value_info, Attribute, OnnxError, OnnxExporter,
+    OnnxImporter, OnnxValue, FLOAT, IR_VERSION, ONNX_OPSET,
+};
+
+fn messages(bytes: &[u8], number: u32) -> Vec<Vec<u8>> {
+    Fields::decode(bytes)
+        .unwrap()
+        .bytes(number)
+        .unwrap()
+        .into_iter()
+        .map(<[u8]>::to_vec)
+        .collect()
+}
+
+fn strings(bytes: &[u8], number: u32) -> Vec<String> {
+    Fields::decode(bytes).unwrap().strings(number).unwrap()
+}
+
+fn ints(bytes: &[u8], number: u32) -> Vec<i64> {
+    Fields::decode(bytes).unwrap().ints(number).unwrap()
+}
+
+fn graph(model: &[u8]) -> Vec<u8> {
+    messages(model, 7).remove(0)
+}
+
+fn op_types(graph: &[u8]) -> Vec<String> {
+    messages(graph, 1)
+        .iter()
+        .map(|node| strings(node, 4).remove(0))
+        .collect()
+}
+
+/// Returns the integer values of the attribute `name` of `node`.
+fn int_attribute(node: &[u8], name: &str) -> Vec<i64> {
+    messages(node, 5)
+        .into_iter()
+        .find(|attribute| strings(attribute, 1)[0] == name)
+        .map(|attribute| [ints(&attribute, 3), ints(&attribute, 8)].concat())
+        .unwrap()
+}
+
+/// Returns the name, the shape and the raw data of the initializers of `graph`.
+fn initializers(graph: &[u8]) -> Vec<(String, Vec<i64>, Vec<u8>)> {
+    messages(graph, 5)
+        .iter()
+        .map(|tensor| {
+            (
+                strings(tensor, 8).remove(0),
+                ints(tensor, 1),
+                messages(tensor, 9).remove(0),
+            )
+        })
+        .collect()
+}
+
+fn raw(data: &[f32]) -> Vec<u8> {
+    data.iter().flat_map(|el| el.to_le_bytes()).collect()
+}
+
+#[test]
+fn model() {
+    let input = crate::zeros((4, 3));
+    let weight = crate::ones((2, 3)).requires_grad();
+    let output = input.clone().mm_t(weight).relu();
+
+    let model = output
+        .to_onnx(&OnnxExporter::new("linear").input("input", &input))
+        .unwrap();
+    assert_eq!(ints(&model, 1), vec![IR_VERSION]);
+    assert_eq!(ints(&messages(&model, 8)[0], 2), vec![ONNX_OPSET]);
+
+    let graph = graph(&model);
+    assert_eq!(strings(&graph, 2), vec!["linear"]);
+    assert_eq!(op_types(&graph), vec!["Gemm", "Relu"]);
+    assert_eq!(strings(&messages(&graph, 11)[0], 1), vec!["input"]);
+    assert_eq!(strings(&messages(&graph, 12)[0], 1), vec!["output"]);
+
+    let nodes = messages(&graph, 1);
+    assert_eq!(strings(&nodes[0], 1), vec!["input", "initializer_0"]);
+    assert_eq!(int_attribute(&nodes[0], "transB"), vec![1]);
+    assert_eq!(strings(&nodes[1], 1), strings(&nodes[0], 2));
+    assert_eq!(strings(&nodes[1], 2), vec!["output"]);
+}
+
+#[test]
+fn linear_layer() {
+    let input = crate::zeros((4, 3));
+    let weight = crate::full((2, 3), 0.5).requires_grad();
+    let bias = crate::full(2, -1.).requires_grad();
+    let output = (input.clone().mm_t(weight) + bias).softmax(1);
+
+    let model = output
+        .to_onnx(&OnnxExporter::new("linear").input("input", &input))
+        .unwrap();
+    let graph = graph(&model);
+    assert_eq!(op_types(&graph), vec!["Gemm", "Add", "Softmax"]);
+    assert_eq!(int_attribute(&messages(&graph, 1)[2], "axis"), vec![1]);
+    assert_eq!(
+        initializers(&graph),
+        vec![
+            ("initializer_0".to_string(), vec![2, 3], raw(&[0.5; 6])),
+            ("initializer_1".to_string(), vec![2], raw(&[-1.; 2])),
+        ]
+    );
+}
+
+#[test]
+fn convolution() {
+    let input = crate::zeros((1, 2, 5));
+    let kernel = crate::ones((3, 2, 3)).requires_grad();
+    let output = kernel.convolution(
+        input.clone().pad_asymmetric(1, 2, crate::Reflective),
+        2,
+        1,
+        1,
+    );
+
+    let model = output
+        .to_onnx(&OnnxExporter::new("convolution").input("input", &input))
+        .unwrap();
+    let graph = graph(&model);
+    assert_eq!(op_types(&graph), vec!["Pad", "Conv"]);
+
+    let nodes = messages(&graph, 1);
+    let pad = messages(&nodes[0], 5)
+        .into_iter()
+        .find(|attribute| strings(attribute, 1)[0] == "mode")
+        .unwrap();
+    assert_eq!(strings(&pad, 4), vec!["reflect"]);
+    assert_eq!(strings(&nodes[0], 1)[0], "input");
+    assert_eq!(int_attribute(&nodes[1], "strides"), vec![2]);
+    assert_eq!(int_attribute(&nodes[1], "dilations"), vec![1]);
+    assert_eq!(int_attribute(&nodes[1], "group"), vec![1]);
+
+    let pads: Vec<u8> = [0_i64, 0, 1, 0, 0, 2]
+        .iter()
+        .flat_map(|el| el.to_le_bytes())
+        .collect();
+    let initializers = initializers(&graph);
+    assert_eq!(initializers[0].0, "Pad_0_constant_0");
+    assert_eq!(initializers[0].2, pads);
+    assert_eq!(initializers[2].1, vec![3, 2, 3]);
+}
+
+#[test]
+fn leaf() {
+    let input = crate::from_ndarray(array![1., 2.]);
+
+    let model = input
+        .to_onnx(&OnnxExporter::new("identity").input("input", &input))
+        .unwrap();
+    let graph = graph(&model);
+    assert_eq!(op_types(&graph), vec!["Identity"]);
+    assert_eq!(strings(&messages(&graph, 1)[0], 1), vec!["input"]);
+}
+
+#[test]
+fn unsupported() {
+    let input = crate::zeros(3);
+    let output = input.clone().exp().gelu(false);
+
+    match output.to_onnx(&OnnxExporter::new("gelu").input("input", &input)) {
+        Err(OnnxError::UnsupportedOperation { position }) => assert_eq!(position, 1),
+        _ => panic!("the export should fail"),
+    }
+}
+
+/// Encodes a single-precision `TensorProto`.
+fn tensor(name: &str, shape: &[i64], data: &[f32]) -> Message {
+    let mut tensor = Message::new();
+    tensor
+        .ints(1, shape)
+        .int(2, FLOAT)
+        .string(8, name)
+        .bytes(9, &raw(data));
+    tensor
+}
+
+/// Encodes a `NodeProto`.
+fn node(op_type: &str, inputs: &[&str], outputs: &[&str], attributes: &[Message]) -> Message {
+    let mut node = Message::new();
+    inputs.iter().for_each(|input| {
+        node.string(1, input);
+    });
+    outputs.iter().for_each(|output| {
+        node.string(2, output);
+    });
+    node.string(4, op_type);
+    attributes.iter().for_each(|attribute| {
+        node.message(5, attribute);
+    });
+    node
+}
+
+/// Encodes a `ModelProto` with the given graph components.
+fn encode(
+    nodes: &[Message],
+    initializers: &[Message],
+    inputs: &[Message],
+    outputs: &[&str],
+) -> Vec<u8> {
+    let mut graph = Message::new();
+    nodes.iter().for_each(|node| {
+        graph.message(1, node);
+    });
+    initializers.iter().for_each(|initializer| {
+        graph.message(5, initializer);
+    });
+    inputs.iter().for_each(|input| {
+        graph.message(11, input);
+    });
+    outputs.iter().for_each(|output| {
+        graph.message(12, &value_info(output, &[]));
+    });
+
+    let mut opset = Message::new();
+    opset.string(1, "").int(2, ONNX_OPSET);
+    let mut model = Message::new();
+    model
+        .int(1, IR_VERSION)
+        .message(7, &graph)
+        .message(8, &opset);
+    model.into_bytes()
+}
+
+fn output_data(value: &OnnxValue) -> ndarray::ArrayD<f32> {
+    value.forward();
+    value.data().clone()
+}
+
+#[test]
+fn import_linear_layer() {
+    let input = crate::from_ndarray(array![[1., 2., 3.], [-1., 0., 2.]]);
+    let weight = crate::from_ndarray(array![[0.5, -0.5, 1.], [1., 2., -1.]]).requires_grad();
+    let bias = crate::from_ndarray(array![0.1, -0.2]).requires_grad();
+    let output = (input.clone().mm_t(weight) + bias).relu().softmax(1);
+    let bytes = output
+        .to_onnx(&OnnxExporter::new("linear").input("input", &input))
+        .unwrap();
+    output.forward();
+
+    let model = OnnxImporter::new().import(&bytes).unwrap();
+    model
+        .input("input")
+        .unwrap()
+        .data_mut()
+        .assign(&input.data().view().into_dyn());
+    let imported = model.output("output").unwrap();
+    assert_eq!(output_data(imported), output.data().clone().into_dyn());
+
+    let names: Vec<&str> = model
+        .parameters()
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect();
+    assert_eq!(names, vec!["initializer_0", "initializer_1"]);
+
+    match imported {
+        OnnxValue::VarDiff(imported) => {
+            let loss = imported.clone().sum();
+            loss.forward();
+            loss.backward(1.);
+        }
+        OnnxValue::Var(_) => panic!("the output should be differentiable"),
+    }
+    assert!(model.parameters()[0].1.grad().iter().any(|el| *el != 0.));
+}
+
+#[test]
+fn import_convolution() {
+    let input = crate::rand((2, 2, 6, 5));
+    let kernel = crate::rand((3, 2, 3, 3)).requires_grad();
+    let output = kernel
+        .convolution(
+            input
+                .clone()
+                .pad_asymmetric((1, 0), (2, 1), crate::Replicative),
+            (2, 1),
+            (1, 2),
+            1,
+        )
+        .relu();
+    let flattened = output.clone().reshape((2, 3 * 4 * 2)).tanh();
+    let bytes = flattened
+        .to_onnx(&OnnxExporter::new("convolution").input("input", &input))
+        .unwrap();
+    flattened.forward();
+
+    let model = OnnxImporter::new().import(&bytes).unwrap();
+    model
+        .input("input")
+        .unwrap()
+        .data_mut()
+        .assign(&input.data().view().into_dyn());
+    let imported = output_data(model.output("output").unwrap());
+    assert!(imported
+        .iter()
+        .zip(flattened.data().iter())
+        .all(|(imported, expected)| (imported - expected).abs() < 1e-6));
+}
+
+#[test]
+fn import_gemm_and_reshape() {
+    let gemm = node(
+        "Gemm",
+        &["input", "weight", "bias"],
+        &["gemm"],
+        &[
+            attribute("transA", &Attribute::Int(1)),
+            attribute("alpha", &Attribute::Float(2.)),
+            attribute("beta", &Attribute::Float(0.5)),
+        ],
+    );
+    let mut shape = Message::new();
+    shape
+        .int(1, 3)
+        .int(2, super::INT64)
+        .ints(7, &[0, -1, 2])
+        .string(8, "shape");
+    let bytes = encode(
+        &[
+            gemm,
+            node("Unsqueeze", &["gemm", "axes"], &["unsqueezed"], &[]),
+            node("Flatten", &["unsqueezed"], &["flat"], &[]),
+            node("Reshape", &["flat", "shape"], &["output"], &[]),
+        ],
+        &[
+            tensor("weight", &[3, 2], &[1., 0., 0., 1., 1., 1.]),
+            tensor("bias", &[2], &[1., -1.]),
+            {
+                let mut axes = Message::new();
+                axes.int(1, 1)
+                    .int(2, super::INT64)
+                    .ints(7, &[0])
+                    .string(8, "axes");
+                axes
+            },
+            shape,
+        ],
+        &[value_info("input", &[3, 4])],
+        &["output"],
+    );
+
+    let model = OnnxImporter::new().import(&bytes).unwrap();
+    assert_eq!(model.parameters().len(), 2);
+    model
+        .input("input")
+        .unwrap()
+        .data_mut()
+        .assign(&Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f32).into_dyn());
+
+    // 2 * input^T * weight + 0.5 * bias.
+    let expected = array![[[16.5, 23.5], [20.5, 27.5], [24.5, 31.5], [28.5, 35.5]]];
+    assert_eq!(
+        output_data(model.output("output").unwrap()),
+        expected.into_dyn()
+    );
+}
+
+#[test]
+fn import_rnn() {
+    let (w, r, b) = (
+        [0.5, -0.5, 1., 0.25, -1., 0.75],
+        [0.1, 0.2, -0.3, 0., 0.5, -0.5, 0.3, -0.2, 0.1],
+        [0.1, -0.1, 0.2, 0., 0.3, -0.2],
+    );
+    let bytes = encode(
+        &[node(
+            "RNN",
+            &["input", "w", "r", "b"],
+            &["y", "y_h"],
+            &[attribute("hidden_size", &Attribute::Int(3))],
+        )],
+        &[
+            tensor("w", &[1, 3, 2], &w),
+            tensor("r", &[1, 3, 3], &r),
+            tensor("b", &[1, 6], &b),
+        ],
+        &[value_info("input", &[2, 1, 2])],
+        &["y", "y_h"],
+    );
+
+    let model = OnnxImporter::new().import(&bytes).unwrap();
+    let xs = array![[1., -1.], [0.5, 2.]];
+    model
+        .input("input")
+        .unwrap()
+        .data_mut()
+        .assign(&xs.view().into_shape((2, 1, 2)).unwrap().into_dyn());
+
+    let w = Array::from_shape_vec((3, 2), w.to_vec()).unwrap();
+    let r = Array::from_shape_vec((3, 3), r.to_vec()).unwrap();
+    let bias =
+        Array::from_shape_vec(3, b[..3].to_vec()).unwrap() + &Array::from_vec(b[3..].to_vec());
+    let mut h = Array::zeros(3);
+    let mut expected = Vec::new();
+    for x in xs.rows() {
+        h = (w.dot(&x) + r.dot(&h) + &bias).mapv(f32::tanh);
+        expected.extend(h.iter().copied());
+    }
+
+    let y = output_data(model.output("y").unwrap());
+    assert_eq!(y.shape(), &[2, 1, 1, 3]);
+    assert!(y
+        .iter()
+        .zip(&expected)
+        .all(|(y, expected)| (y - expected).abs() < 1e-6));
+    let y_h = output_data(model.output("y_h").unwrap());
+    assert_eq!(y_h.shape(), &[1, 1, 3]);
+    assert!(y_h.iter().eq(y.iter().skip(3)));
+}
+
+#[test]
+fn import_unsupported() {
+    let mut custom = node("Swish", &["input"], &["swish"], &[]);
+    custom.string(7, "com.example");
+    let bytes = encode(
+        &[
+            node("LSTM", &["input"], &["lstm"], &[]),
+            custom,
+            node("Relu", &["input"], &["output"], &[]),
+            node("LSTM", &["output"], &["again"], &[]),
+        ],
+        &[],
+        &[value_info("input", &[2, 2])],
+        &["output"],
+    );
+
+    match OnnxImporter::new().import(&bytes) {
+        Err(OnnxError::UnsupportedOperators(operators)) => {
+            assert_eq!(operators, vec!["LSTM", "com.example::Swish"])
+        }
+        _ => panic!("the import should fail"),
+    }
+
+    let bytes = encode(
+        &[node(
+            "Transpose",
+            &["input"],
+            &["output"],
+            &[attribute("perm", &Attribute::Ints(vec![1, 0, 2]))],
+        )],
+        &[],
+        &[value_info("input", &[2, 2, 2])],
+        &["output"],
+    );
+    match OnnxImporter::new().import(&bytes) {
+        Err(OnnxError::UnsupportedConfiguration { op_type, .. }) => {
+            assert_eq!(op_type, "Transpose")
+        }
+        _ => panic!("the import should fail"),
+    }
+}
+
+#[test]
+fn import_symbolic_shape() {
+    let mut dimension = Message::new();
+    dimension.string(2, "batch");
+    let mut features = Message::new();
+    features.int(1, 3);
+    let mut shape = Message::new();
+    shape.message(1, &dimension).message(1, &features);
+    let mut tensor_type = Message::new();
+    tensor_type.int(1, FLOAT).message(2, &shape);
+    let mut type_proto = Message::new();
+    type_proto.message(1, &tensor_type);
+    let mut input = Message::new();
+    input.string(1, "input").message(2, &type_proto);
+
+    let bytes = encode(
+        &[node("Sigmoid", &["input"], &["output"], &[])],
+        &[],
+        &[input],
+        &["output"],
+    );
+    assert!(matches!(
+        OnnxImporter::new().import(&bytes),
+        Err(OnnxError::InvalidModel(_))
+    ));
+
+    let model = OnnxImporter::new()
+        .input_shape("input", &[5, 3])
+        .import(&bytes)
+        .unwrap();
+    assert_eq!(model.input("input").unwrap().data().shape(), &[5, 3]);
+    assert!(model.parameters().is_empty());
+    assert_eq!(
+        output_data(model.output("output").unwrap()),
+        Array::from_elem(IxDyn(&[5, 3]), 0.5)
+    );
+}
+
+#[test]
+fn import_invalid() {
+    assert!(matches!(
+        OnnxImporter::new().import(b"\xff\xff"),
+        Err(OnnxError::InvalidModel(_))
+    ));
+}
+
+#[test]
+fn import_invalid_shapes() {
+    let import = |op_type: &str, x: &[usize], w: &[i64], attributes: &[Message]| {
+        let w_len = w.iter().product::<i64>() as usize;
+        let bytes = encode(
+            &[node(op_type, &["input", "weight"], &["output"], attributes)],
+            &[tensor("weight", w, &vec![0.; w_len])],
+            &[value_info("input", x)],
+            &["output"],
+        );
+        match OnnxImporter::new().import(&bytes) {
+            Err(OnnxError::InvalidModel(reason)) => reason,
+            Err(error) => panic!("unexpected error {}", error),
+            Ok(_) => panic!("the import should fail"),
+        }
+    };
+    let group = |value| attribute("group", &Attribute::Int(value));
+
+    assert!(import("Gemm", &[2, 3], &[4, 5], &[]).contains("shapes [2, 3] and [4, 5]"));
+    assert!(import(
+        "Gemm",
+        &[2, 3],
+        &[3, 5],
+        &[attribute("transB", &Attribute::Int(1))]
+    )
+    .contains("can't multiply"));
+    assert!(import("MatMul", &[2, 3], &[2, 3], &[]).contains("can't multiply"));
+    assert!(import("MatMul", &[3], &[4], &[]).contains("can't multiply"));
+
+    assert!(import("Conv", &[1, 4, 5], &[2, 2, 3], &[group(0)]).contains("positive"));
+    assert!(import("Conv", &[1, 4, 5], &[2, 2, 3], &[group(-2)]).contains("positive"));
+    assert!(import("Conv", &[1, 4, 5], &[3, 2, 3], &[group(2)]).contains("divisible"));
+    assert!(import("Conv", &[1, 4, 5], &[2, 4, 3], &[group(2)]).contains("channels"));
+    assert!(import("Conv", &[1, 4, 2], &[2, 4, 3], &[]).contains("larger"));
+    assert!(import("ConvTranspose", &[1, 4, 5], &[2, 2, 3], &[]).contains("channels"));
+    assert!(import("ConvTranspose", &[1, 3, 5], &[3, 2, 3], &[group(2)]).contains("divisible"));
+    assert!(import(
+        "ConvTranspose",
+        &[1, 2, 5],
+        &[2, 2, 3],
+        &[attribute("output_padding", &Attribute::Ints(vec![1]))]
+    )
+    .contains("output padding"));
+}
@@ -0,0 +1,247 @@
+use std::{
+    cell::{Cell, Ref, RefMut},
+    collections::{HashMap, HashSet},
+    iter,
+    rc::Rc,
+};
+
+use ndarray::{Array, Dimension, IxDyn};
+
+use crate::{
+    autograd::{Buffer, Forward},
+    utils::Shared,
+};
+
+/// Memory released by the planner, available to the buffers allocated afterwards.
+#[derive(Default)]
+pub(crate) struct Pool {
+    free: Vec<Vec<f32>>,
+}
+
+impl Pool {
+    /// Returns a zeroed vector of length `len`, reusing the smallest released allocation that
+    /// fits it.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - length of the vector.
+    fn take(&mut self, len: usize) -> Vec<f32> {
+        let mut memory = self
+            .free
+            .iter()
+            .enumerate()
+            .filter(|(_, memory)| memory.capacity() >= len)
+            .min_by_key(|(_, memory)| memory.capacity())
+            .map(|(position, _)| position)
+            .map(|position| self.free.swap_remove(position))
+            .unwrap_or_default();
+
+        memory.clear();
+        memory.resize(len, 0.);
+        memory
+    }
+
+    /// Gives back an allocation to the pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `memory` - allocation to recycle.
+    fn put(&mut self, memory: Vec<f32>) {
+        self.free.push(memory);
+    }
+}
+
+impl<D> Buffer for Shared<Array<f32, D>>
+where
+    D: Dimension,
+{
+    fn address(&self) -> usize {
+        Rc::as_ptr(self) as *const () as usize
+    }
+
+    fn owners(&self) -> usize {
+        Rc::strong_count(self)
+    }
+
+    fn release(&self, pool: &mut Pool) -> Option<Vec<usize>> {
+        let mut data = self.borrow_mut();
+        let shape = data.shape().to_vec();
+        let empty = Array::zeros(D::zeros(data.ndim()));
+
+        pool.put(std::mem::replace(&mut *data, empty).into_raw_vec());
+        Some(shape)
+    }
+
+    fn reserve(&self, shape: &[usize], pool: &mut Pool) {
+        let mut data = self.borrow_mut();
+        if data.shape() == shape {
+            return;
+        }
+
+        let memory = pool.take(shape.iter().product());
+        *data = Array::from_shape_vec(IxDyn(shape), memory)
+            .unwrap()
+            .into_dimensionality()
+            .unwrap();
+    }
+
+    fn shape(&self) -> Vec<usize> {
+        self.borrow().shape().to_vec()
+    }
+
+    fn flat(&self) -> Option<Ref<'_, [f32]>> {
+        Ref::filter_map(self.borrow(), |array| array.as_slice()).ok()
+    }
+
+    fn flat_mut(&self) -> Option<RefMut<'_, [f32]>> {
+        RefMut::filter_map(self.borrow_mut(), |array| array.as_slice_mut()).ok()
+    }
+}
+
+/// Returns all the buffers an operation touches, its result first.
+fn buffers(op: &dyn Forward) -> impl Iterator<Item = &dyn Buffer> {
+    iter::once(op.data()).chain(op.operands())
+}
+
+/// Marks as not computed the operations of `tape` that must be evaluated again because they read,
+/// directly or indirectly, a modified buffer, or because they are volatile. Operations whose
+/// results have been released by the memory planner are also marked if such results are needed
+/// by any other marked operation.
+///
+/// # Arguments
+///
+/// * `tape` - operations of the graph, in topological order.
+///
+/// * `modified` - addresses of the modified buffers.
+///
+/// * `released` - shapes of the released buffers, indexed by address.
+pub(crate) fn invalidate(
+    tape: &[(Rc<dyn Forward>, Cell<bool>)],
+    mut modified: HashSet<usize>,
+    released: &HashMap<usize, Vec<usize>>,
+) {
+    tape.iter().for_each(|(op, computed)| {
+        let is_stale = op.is_volatile()
+            || op
+                .operands()
+                .iter()
+                .any(|operand| modified.contains(&operand.address()));
+
+        if is_stale {
+            computed.set(false);
+            modified.insert(op.data().address());
+        }
+    });
+
+    if released.is_empty() {
+        return;
+    }
+
+    let mut producers: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (position, (op, _)) in tape.iter().enumerate() {
+        producers
+            .entry(op.data().address())
+            .or_default()
+            .push(position);
+    }
+
+    for (position, (op, computed)) in tape.iter().enumerate().rev() {
+        if computed.get() {
+            continue;
+        }
+
+        op.operands()
+            .iter()
+            .map(|operand| operand.address())
+            .filter(|address| released.contains_key(address))
+            .flat_map(|address| &producers[&address])
+            .filter(|producer| **producer < position)
+            .for_each(|producer| tape[*producer].1.set(false));
+    }
+}
+
+/// Returns `true` if the buffer is the result of an operation of `tape` and it is referenced only
+/// by such operations and by a single handle, so that it can be overwritten in place.
+///
+/// # Arguments
+///
+/// * `buffer` - buffer to check.
+///
+/// * `tape` - operations that may reference the buffer.
+pub(crate) fn is_overwritable(buffer: &dyn Buffer, tape: &[(Rc<dyn Forward>, Cell<bool>)]) -> bool {
+    let address = buffer.address();
+    let is_result = tape.iter().any(|(op, _)| op.data().address() == address);
+    let references = tape
+        .iter()
+        .flat_map(|(op, _)| buffers(op.as_ref()))
+        .filter(|other| other.address() == address)
+        .count();
+
+    is_result && buffer.owners() == references + 1
+}
+
+/// Evaluates the operations in `tape` releasing every intermediate result as soon as its last
+/// consumer has been computed. Released memory is recycled by the results computed afterwards.
+///
+/// A result is released only if it is referenced by the operations of `tape` and by nothing else,
+/// thus, the data of variables still owned by the user and the data needed by the backward pass are
+/// always kept. The shapes of the released buffers are stored in `released`, so that they can be
+/// reallocated before their producer is evaluated again.
+///
+/// # Arguments
+///
+/// * `tape` - operations to evaluate, in topological order.
+///
+/// * `released` - shapes of the released buffers, indexed by address.
+pub(crate) fn forward(
+    tape: &[(Rc<dyn Forward>, Cell<bool>)],
+    released: &mut HashMap<usize, Vec<usize>>,
+) {
+    let mut references = HashMap::new();
+    let mut last_use = HashMap::new();
+    let mut shared = HashSet::new();
+    for (position, (op, _)) in tape.iter().enumerate() {
+        // The operation is held by this tape and by its buffer only, any other owner means that
+        // it belongs to another graph as well.
+        let exclusive = Rc::strong_count(op) == 2;
+
+        for buffer in buffers(op.as_ref()) {
+            let address = buffer.address();
+
+            *references.entry(address).or_insert(0) += 1;
+            last_use.insert(address, position);
+            if !exclusive {
+                shared.insert(address);
+            }
+        }
+    }
+    let results: HashSet<usize> = tape.iter().map(|(op, _)| op.data().address()).collect();
+
+    let mut pool = Pool::default();
+    for (position, (op, computed)) in tape.iter().enumerate() {
+        if computed.get() {
+            continue;
+        }
+
+        if let Some(shape) = released.remove(&op.data().address()) {
+            op.data().reserve(&shape, &mut pool);
+        }
+        op.forward();
+        computed.set(true);
+
+        for buffer in buffers(op.as_ref()) {
+            let address = buffer.address();
+            let is_dead = last_use[&address] == position
+                && results.contains(&address)
+                && !shared.contains(&address)
+                && !released.contains_key(&address)
+                && buffer.owners() == references[&address];
+
+            if is_dead {
+                if let Some(shape) = buffer.release(&mut pool) {
+                    released.insert(address, shape);
+                }
+            }
+        }
+    }
+}
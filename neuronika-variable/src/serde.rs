@@ -27,7 +27,7 @@ where
     where
         De: Deserializer<'d>,
     {
-        let data = Array::<f32, D>::deserialize(deserializer).unwrap();
+        let data = Array::<f32, D>::deserialize(deserializer)?;
         Ok(Self::leaf(data))
     }
 }
@@ -46,13 +46,13 @@ where
 
 impl<'d, D> Deserialize<'d> for VarDiff<D>
 where
-    D: Dimension + Deserialize<'d>,
+    D: 'static + Dimension + Deserialize<'d>,
 {
     fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
     where
         De: Deserializer<'d>,
     {
-        let data = Array::<f32, D>::deserialize(deserializer).unwrap();
+        let data = Array::<f32, D>::deserialize(deserializer)?;
         Ok(Var::leaf(data).requires_grad())
     }
 }
@@ -0,0 +1,488 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{self, Display},
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use ndarray::{ArrayD, Dimension, IxDyn};
+
+use crate::VarDiff;
+
+mod safetensors;
+
+pub use self::safetensors::{
+    load_safetensors, read_safetensors, save_safetensors, write_safetensors, Dtype,
+};
+
+/// Identifies the files written by [`ModelCheckpoint::write`].
+const MAGIC: [u8; 8] = *b"NEURONKA";
+
+/// Version of the checkpoint format written by this release. Files written by later releases
+/// are rejected.
+pub const CHECKPOINT_VERSION: u32 = 1;
+
+/// Named arrays making up the state of a model or of an optimizer.
+pub type StateDict = BTreeMap<String, ArrayD<f32>>;
+
+/// Errors raised while saving or loading a checkpoint.
+#[derive(Debug)]
+pub enum CheckpointError {
+    /// The underlying reader or writer failed.
+    Io(io::Error),
+    /// The data isn't a valid checkpoint.
+    InvalidFormat(String),
+    /// The checkpoint was written with a newer, unsupported, version of the format.
+    UnsupportedVersion(u32),
+    /// The shape of a saved array doesn't match the one of the parameter with the same name.
+    ShapeMismatch {
+        name: String,
+        expected: Vec<usize>,
+        found: Vec<usize>,
+    },
+    /// A tensor has an element type that can't be converted to `f32`.
+    UnsupportedDtype { name: String, dtype: String },
+    /// Strict loading found parameters without a saved state, or saved states without a
+    /// parameter.
+    KeyMismatch {
+        missing: Vec<String>,
+        unexpected: Vec<String>,
+    },
+}
+
+impl Display for CheckpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "I/O error: {}", error),
+            Self::InvalidFormat(reason) => write!(f, "invalid checkpoint: {}", reason),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported checkpoint version {}, the latest supported is {}",
+                version, CHECKPOINT_VERSION
+            ),
+            Self::ShapeMismatch {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "shape mismatch for {}: expected {:?}, found {:?}",
+                name, expected, found
+            ),
+            Self::UnsupportedDtype { name, dtype } => {
+                write!(f, "unsupported element type {} for {}", dtype, name)
+            }
+            Self::KeyMismatch {
+                missing,
+                unexpected,
+            } => write!(
+                f,
+                "missing keys {:?}, unexpected keys {:?}",
+                missing, unexpected
+            ),
+        }
+    }
+}
+
+impl Error for CheckpointError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CheckpointError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Outcome of a successful [`ParameterDict::load_state_dict`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LoadReport {
+    /// Parameters that weren't found in the state dict and were left untouched.
+    pub missing: Vec<String>,
+    /// Entries of the state dict that don't correspond to any parameter.
+    pub unexpected: Vec<String>,
+}
+
+/// A parameter of any dimensionality.
+trait Parameter {
+    fn shape(&self) -> Vec<usize>;
+
+    fn state(&self) -> ArrayD<f32>;
+
+    fn assign(&self, state: &ArrayD<f32>);
+}
+
+impl<D> Parameter for VarDiff<D>
+where
+    D: Dimension,
+{
+    fn shape(&self) -> Vec<usize> {
+        self.data().shape().to_vec()
+    }
+
+    fn state(&self) -> ArrayD<f32> {
+        self.data().clone().into_dyn()
+    }
+
+    fn assign(&self, state: &ArrayD<f32>) {
+        let mut data = self.data_mut();
+        let dim = data.raw_dim();
+        data.assign(&state.to_shape(dim).unwrap());
+    }
+}
+
+/// Named parameters of a model, whatever their dimensionality.
+///
+/// The parameters are shared with the model, so that loading a state dict updates the model in
+/// place.
+///
+/// ```
+/// # use neuronika_variable as neuronika;
+/// let weight = neuronika::ones((3, 2)).requires_grad();
+/// let bias = neuronika::zeros(3).requires_grad();
+///
+/// let mut parameters = neuronika::ParameterDict::new();
+/// parameters.insert("weight", weight.clone());
+/// parameters.insert("bias", bias);
+///
+/// let state = parameters.state_dict();
+/// weight.data_mut().fill(0.);
+/// parameters.load_state_dict(&state, true).unwrap();
+/// assert_eq!(*weight.data(), ndarray::Array::ones((3, 2)));
+/// ```
+#[derive(Default)]
+pub struct ParameterDict {
+    parameters: BTreeMap<String, Box<dyn Parameter>>,
+}
+
+impl ParameterDict {
+    /// Creates an empty parameter dict.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `parameter` under `name`, replacing any parameter previously registered with
+    /// the same name.
+    pub fn insert<D>(&mut self, name: impl Into<String>, parameter: VarDiff<D>)
+    where
+        D: 'static + Dimension,
+    {
+        self.parameters.insert(name.into(), Box::new(parameter));
+    }
+
+    /// Returns the number of parameters.
+    pub fn len(&self) -> usize {
+        self.parameters.len()
+    }
+
+    /// Returns `true` if there are no parameters.
+    pub fn is_empty(&self) -> bool {
+        self.parameters.is_empty()
+    }
+
+    /// Returns a copy of the current values of the parameters.
+    pub fn state_dict(&self) -> StateDict {
+        self.parameters
+            .iter()
+            .map(|(name, parameter)| (name.clone(), parameter.state()))
+            .collect()
+    }
+
+    /// Copies the values in `state` into the parameters with the same names.
+    ///
+    /// When `strict` is `true` the names in `state` must match exactly those of the parameters,
+    /// otherwise the parameters without a saved value are left untouched and the values without a
+    /// parameter are ignored. Either way they are listed in the returned report.
+    ///
+    /// Nothing is modified if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// [`CheckpointError::ShapeMismatch`] if a saved value doesn't have the shape of its parameter,
+    /// up to axes of length one,
+    /// and, only for strict loading, [`CheckpointError::KeyMismatch`] if the names don't match.
+    pub fn load_state_dict(
+        &self,
+        state: &StateDict,
+        strict: bool,
+    ) -> Result<LoadReport, CheckpointError> {
+        let missing: Vec<String> = self
+            .parameters
+            .keys()
+            .filter(|name| !state.contains_key(*name))
+            .cloned()
+            .collect();
+        let unexpected: Vec<String> = state
+            .keys()
+            .filter(|name| !self.parameters.contains_key(*name))
+            .cloned()
+            .collect();
+
+        if strict && (!missing.is_empty() || !unexpected.is_empty()) {
+            return Err(CheckpointError::KeyMismatch {
+                missing,
+                unexpected,
+            });
+        }
+
+        for (name, parameter) in &self.parameters {
+            if let Some(array) = state.get(name) {
+                let expected = parameter.shape();
+                if !compatible(array.shape(), &expected) {
+                    return Err(CheckpointError::ShapeMismatch {
+                        name: name.clone(),
+                        expected,
+                        found: array.shape().to_vec(),
+                    });
+                }
+            }
+        }
+
+        for (name, parameter) in &self.parameters {
+            if let Some(array) = state.get(name) {
+                parameter.assign(array);
+            }
+        }
+
+        Ok(LoadReport {
+            missing,
+            unexpected,
+        })
+    }
+}
+
+/// Checks whether an array of shape `found` can be loaded into one of shape `expected`. The shapes
+/// may differ only by axes of length one, so that, for instance, a bias of shape `(c,)` can be loaded
+/// into a convolutional bias of shape `(c, 1, 1)`.
+fn compatible(found: &[usize], expected: &[usize]) -> bool {
+    let squeeze = |shape: &[usize]| {
+        shape
+            .iter()
+            .copied()
+            .filter(|len| *len != 1)
+            .collect::<Vec<_>>()
+    };
+
+    squeeze(found) == squeeze(expected)
+}
+
+/// A snapshot of a training run: the parameters of a model, the state of its optimizer, the epoch
+/// of its learning rate scheduler and some free-form metadata.
+///
+/// It's stored in a versioned binary format, with all the numbers in little-endian byte order.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ModelCheckpoint {
+    /// Parameters of the model, usually obtained from [`ParameterDict::state_dict`].
+    pub parameters: StateDict,
+    /// State of the optimizer.
+    pub optimizer: StateDict,
+    /// Current epoch of the learning rate scheduler, if any.
+    pub scheduler_epoch: Option<usize>,
+    /// Free-form metadata, such as the name of the model or the value of the loss.
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl ModelCheckpoint {
+    /// Creates a checkpoint holding the given parameters.
+    pub fn new(parameters: StateDict) -> Self {
+        Self {
+            parameters,
+            ..Self::default()
+        }
+    }
+
+    /// Writes the checkpoint to `writer`.
+    pub fn write<W>(&self, writer: W) -> Result<(), CheckpointError>
+    where
+        W: Write,
+    {
+        let mut writer = writer;
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&CHECKPOINT_VERSION.to_le_bytes())?;
+
+        write_len(&mut writer, self.metadata.len())?;
+        for (key, value) in &self.metadata {
+            write_string(&mut writer, key)?;
+            write_string(&mut writer, value)?;
+        }
+        write_state_dict(&mut writer, &self.parameters)?;
+        write_state_dict(&mut writer, &self.optimizer)?;
+
+        match self.scheduler_epoch {
+            Some(epoch) => {
+                writer.write_all(&[1])?;
+                write_len(&mut writer, epoch)?;
+            }
+            None => writer.write_all(&[0])?,
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Reads a checkpoint from `reader`.
+    pub fn read<R>(reader: R) -> Result<Self, CheckpointError>
+    where
+        R: Read,
+    {
+        let mut reader = reader;
+        let mut magic = [0; 8];
+        read_exact(&mut reader, &mut magic)?;
+        if magic != MAGIC {
+            return Err(CheckpointError::InvalidFormat(
+                "unrecognized file signature".to_string(),
+            ));
+        }
+
+        let mut version = [0; 4];
+        read_exact(&mut reader, &mut version)?;
+        let version = u32::from_le_bytes(version);
+        if version == 0 || version > CHECKPOINT_VERSION {
+            return Err(CheckpointError::UnsupportedVersion(version));
+        }
+
+        let mut metadata = BTreeMap::new();
+        for _ in 0..read_len(&mut reader)? {
+            let key = read_string(&mut reader)?;
+            metadata.insert(key, read_string(&mut reader)?);
+        }
+        let parameters = read_state_dict(&mut reader)?;
+        let optimizer = read_state_dict(&mut reader)?;
+
+        let mut flag = [0; 1];
+        read_exact(&mut reader, &mut flag)?;
+        let scheduler_epoch = match flag[0] {
+            0 => None,
+            1 => Some(read_len(&mut reader)?),
+            _ => {
+                return Err(CheckpointError::InvalidFormat(
+                    "corrupted scheduler epoch".to_string(),
+                ))
+            }
+        };
+
+        Ok(Self {
+            parameters,
+            optimizer,
+            scheduler_epoch,
+            metadata,
+        })
+    }
+
+    /// Saves the checkpoint to the file at `path`, replacing it if it exists.
+    pub fn save<P>(&self, path: P) -> Result<(), CheckpointError>
+    where
+        P: AsRef<Path>,
+    {
+        self.write(BufWriter::new(File::create(path)?))
+    }
+
+    /// Loads a checkpoint from the file at `path`.
+    pub fn load<P>(path: P) -> Result<Self, CheckpointError>
+    where
+        P: AsRef<Path>,
+    {
+        Self::read(BufReader::new(File::open(path)?))
+    }
+}
+
+/// Fills `buffer`, reporting a truncated input as an invalid format.
+fn read_exact<R: Read>(reader: &mut R, buffer: &mut [u8]) -> Result<(), CheckpointError> {
+    reader.read_exact(buffer).map_err(|error| {
+        if error.kind() == io::ErrorKind::UnexpectedEof {
+            CheckpointError::InvalidFormat("unexpected end of data".to_string())
+        } else {
+            CheckpointError::Io(error)
+        }
+    })
+}
+
+fn write_len<W: Write>(writer: &mut W, len: usize) -> Result<(), CheckpointError> {
+    writer.write_all(&(len as u64).to_le_bytes())?;
+    Ok(())
+}
+
+fn read_len<R: Read>(reader: &mut R) -> Result<usize, CheckpointError> {
+    let mut bytes = [0; 8];
+    read_exact(reader, &mut bytes)?;
+    usize::try_from(u64::from_le_bytes(bytes))
+        .map_err(|_| CheckpointError::InvalidFormat("length overflow".to_string()))
+}
+
+fn write_string<W: Write>(writer: &mut W, string: &str) -> Result<(), CheckpointError> {
+    write_len(writer, string.len())?;
+    writer.write_all(string.as_bytes())?;
+    Ok(())
+}
+
+fn read_string<R: Read>(reader: &mut R) -> Result<String, CheckpointError> {
+    let len = read_len(reader)?;
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != len {
+        return Err(CheckpointError::InvalidFormat(
+            "unexpected end of data".to_string(),
+        ));
+    }
+
+    String::from_utf8(bytes)
+        .map_err(|_| CheckpointError::InvalidFormat("invalid UTF-8 string".to_string()))
+}
+
+fn write_state_dict<W: Write>(writer: &mut W, state: &StateDict) -> Result<(), CheckpointError> {
+    write_len(writer, state.len())?;
+    for (name, array) in state {
+        write_string(writer, name)?;
+        write_len(writer, array.ndim())?;
+        for len in array.shape() {
+            write_len(writer, *len)?;
+        }
+        for el in array.iter() {
+            writer.write_all(&el.to_le_bytes())?;
+        }
+    }
+
+    Ok(())
+}
+
+fn read_state_dict<R: Read>(reader: &mut R) -> Result<StateDict, CheckpointError> {
+    let mut state = StateDict::new();
+    for _ in 0..read_len(reader)? {
+        let name = read_string(reader)?;
+        let shape = (0..read_len(reader)?)
+            .map(|_| read_len(reader))
+            .collect::<Result<Vec<usize>, _>>()?;
+
+        let len = shape
+            .iter()
+            .try_fold(4_usize, |len, axis_len| len.checked_mul(*axis_len))
+            .ok_or_else(|| CheckpointError::InvalidFormat("array too large".to_string()))?;
+        let mut bytes = Vec::new();
+        reader.take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != len {
+            return Err(CheckpointError::InvalidFormat(
+                "unexpected end of data".to_string(),
+            ));
+        }
+
+        let data = bytes
+            .chunks_exact(4)
+            .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect();
+        let array = ArrayD::from_shape_vec(IxDyn(&shape), data)
+            .map_err(|_| CheckpointError::InvalidFormat("array too large".to_string()))?;
+        state.insert(name, array);
+    }
+
+    Ok(state)
+}
+
+#[cfg(test)]
+mod test;
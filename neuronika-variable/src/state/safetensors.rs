@@ -0,0 +1,480 @@
+use std::{fmt::Write as _, fs, path::Path};
+
+use half::{bf16, f16};
+
+use ndarray::{ArrayD, IxDyn};
+
+use super::{CheckpointError, StateDict};
+
+/// Key of the optional free-form metadata in the header of a safetensors file.
+const METADATA_KEY: &str = "__metadata__";
+
+/// Largest header accepted, the same limit enforced by the reference implementation.
+const MAX_HEADER_LEN: usize = 100_000_000;
+
+/// Deepest nesting of arrays and objects accepted in a header, far more than its format needs.
+const MAX_DEPTH: usize = 64;
+
+/// Element type of the tensors written to a safetensors file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dtype {
+    /// Half-precision floating point.
+    F16,
+    /// Brain floating point.
+    BF16,
+    /// Single-precision floating point.
+    F32,
+    /// Double-precision floating point.
+    F64,
+}
+
+impl Dtype {
+    fn name(self) -> &'static str {
+        match self {
+            Self::F16 => "F16",
+            Self::BF16 => "BF16",
+            Self::F32 => "F32",
+            Self::F64 => "F64",
+        }
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Self::F16 | Self::BF16 => 2,
+            Self::F32 => 4,
+            Self::F64 => 8,
+        }
+    }
+
+    fn encode(self, el: f32, bytes: &mut Vec<u8>) {
+        match self {
+            Self::F16 => bytes.extend_from_slice(&f16::from_f32(el).to_le_bytes()),
+            Self::BF16 => bytes.extend_from_slice(&bf16::from_f32(el).to_le_bytes()),
+            Self::F32 => bytes.extend_from_slice(&el.to_le_bytes()),
+            Self::F64 => bytes.extend_from_slice(&(el as f64).to_le_bytes()),
+        }
+    }
+}
+
+/// Returns the size in bytes of an element of type `dtype` together with the function converting
+/// its little-endian representation to a single-precision float, or `None` if the element type
+/// isn't supported.
+#[allow(clippy::type_complexity)]
+fn decoder(dtype: &str) -> Option<(usize, fn(&[u8]) -> f32)> {
+    macro_rules! from_le_bytes {
+        ($ty:ty) => {
+            (std::mem::size_of::<$ty>(), |chunk| {
+                <$ty>::from_le_bytes(chunk.try_into().unwrap()) as f32
+            })
+        };
+    }
+
+    let decoder: (usize, fn(&[u8]) -> f32) = match dtype {
+        "BOOL" => (1, |chunk| (chunk[0] != 0) as u8 as f32),
+        "U8" => from_le_bytes!(u8),
+        "I8" => from_le_bytes!(i8),
+        "I16" => from_le_bytes!(i16),
+        "U16" => from_le_bytes!(u16),
+        "F16" => (2, |chunk| f16::from_le_bytes([chunk[0], chunk[1]]).to_f32()),
+        "BF16" => (2, |chunk| {
+            bf16::from_le_bytes([chunk[0], chunk[1]]).to_f32()
+        }),
+        "I32" => from_le_bytes!(i32),
+        "U32" => from_le_bytes!(u32),
+        "F32" => from_le_bytes!(f32),
+        "F64" => from_le_bytes!(f64),
+        "I64" => from_le_bytes!(i64),
+        "U64" => from_le_bytes!(u64),
+        _ => return None,
+    };
+
+    Some(decoder)
+}
+
+/// Reads the tensors stored in the safetensors format in `bytes`, converting them to
+/// single-precision floats. Booleans, integers and floats of any width are supported.
+///
+/// # Errors
+///
+/// [`CheckpointError::InvalidFormat`] if `bytes` isn't a valid safetensors buffer and
+/// [`CheckpointError::UnsupportedDtype`] if a tensor has an element type that can't be converted.
+pub fn read_safetensors(bytes: &[u8]) -> Result<StateDict, CheckpointError> {
+    let invalid = |reason: &str| CheckpointError::InvalidFormat(reason.to_string());
+
+    if bytes.len() < 8 {
+        return Err(invalid("unexpected end of data"));
+    }
+    let (header_len, rest) = bytes.split_at(8);
+    let header_len = u64::from_le_bytes(header_len.try_into().unwrap());
+    let header_len = usize::try_from(header_len)
+        .ok()
+        .filter(|len| *len <= rest.len() && *len <= MAX_HEADER_LEN)
+        .ok_or_else(|| invalid("header length out of bounds"))?;
+    let (header, data) = rest.split_at(header_len);
+
+    let header = std::str::from_utf8(header).map_err(|_| invalid("header isn't valid UTF-8"))?;
+    let entries = match Json::parse(header)? {
+        Json::Object(entries) => entries,
+        _ => return Err(invalid("header isn't a JSON object")),
+    };
+
+    let mut state = StateDict::new();
+    for (name, info) in entries {
+        if name == METADATA_KEY {
+            continue;
+        }
+
+        let tensor_invalid = |reason: &str| invalid(&format!("tensor {}: {}", name, reason));
+        let dtype = info
+            .get("dtype")
+            .and_then(Json::as_str)
+            .ok_or_else(|| tensor_invalid("missing dtype"))?;
+        let shape = info
+            .get("shape")
+            .and_then(Json::as_usizes)
+            .ok_or_else(|| tensor_invalid("missing shape"))?;
+        let (start, end) = match info.get("data_offsets").and_then(Json::as_usizes) {
+            Some(offsets) if offsets.len() == 2 => (offsets[0], offsets[1]),
+            _ => return Err(tensor_invalid("missing data offsets")),
+        };
+
+        let (size, decode) = decoder(dtype).ok_or_else(|| CheckpointError::UnsupportedDtype {
+            name: name.clone(),
+            dtype: dtype.to_string(),
+        })?;
+        let len = shape
+            .iter()
+            .try_fold(size, |len, axis_len| len.checked_mul(*axis_len))
+            .ok_or_else(|| tensor_invalid("tensor too large"))?;
+        if start > end || end > data.len() || end - start != len {
+            return Err(tensor_invalid("data offsets out of bounds"));
+        }
+
+        let elements = data[start..end].chunks_exact(size).map(decode).collect();
+        let array = ArrayD::from_shape_vec(IxDyn(&shape), elements)
+            .map_err(|_| tensor_invalid("tensor too large"))?;
+        state.insert(name, array);
+    }
+
+    Ok(state)
+}
+
+/// Writes `state` in the safetensors format, converting its elements to `dtype`.
+pub fn write_safetensors(state: &StateDict, dtype: Dtype) -> Vec<u8> {
+    let mut header = String::from("{");
+    let mut offset = 0;
+    for (index, (name, array)) in state.iter().enumerate() {
+        if index > 0 {
+            header.push(',');
+        }
+        let end = offset + array.len() * dtype.size();
+        write_json_string(&mut header, name);
+        write!(
+            header,
+            r#":{{"dtype":"{}","shape":{:?},"data_offsets":[{},{}]}}"#,
+            dtype.name(),
+            array.shape(),
+            offset,
+            end
+        )
+        .unwrap();
+        offset = end;
+    }
+    header.push('}');
+    // The data must start at an offset aligned to 8 bytes.
+    while header.len() % 8 != 0 {
+        header.push(' ');
+    }
+
+    let mut bytes = Vec::with_capacity(8 + header.len() + offset);
+    bytes.extend_from_slice(&(header.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    state
+        .values()
+        .flat_map(|array| array.iter())
+        .for_each(|el| dtype.encode(*el, &mut bytes));
+
+    bytes
+}
+
+/// Loads the tensors stored in the safetensors file at `path`.
+///
+/// See [`read_safetensors`] for the conversion rules and the errors.
+///
+/// ```no_run
+/// # use neuronika_variable as neuronika;
+/// let mut parameters = neuronika::ParameterDict::new();
+/// parameters.insert("fc.weight", neuronika::zeros((10, 784)).requires_grad());
+/// parameters.insert("fc.bias", neuronika::zeros(10).requires_grad());
+///
+/// let state = neuronika::load_safetensors("model.safetensors").unwrap();
+/// parameters.load_state_dict(&state, true).unwrap();
+/// ```
+pub fn load_safetensors<P>(path: P) -> Result<StateDict, CheckpointError>
+where
+    P: AsRef<Path>,
+{
+    read_safetensors(&fs::read(path)?)
+}
+
+/// Saves `state` to the safetensors file at `path`, converting its elements to `dtype`.
+pub fn save_safetensors<P>(state: &StateDict, dtype: Dtype, path: P) -> Result<(), CheckpointError>
+where
+    P: AsRef<Path>,
+{
+    fs::write(path, write_safetensors(state, dtype))?;
+    Ok(())
+}
+
+/// Appends `string` to `buffer` as a quoted and escaped JSON string.
+fn write_json_string(buffer: &mut String, string: &str) {
+    buffer.push('"');
+    for character in string.chars() {
+        match character {
+            '"' => buffer.push_str("\\\""),
+            '\\' => buffer.push_str("\\\\"),
+            character if character.is_control() => {
+                write!(buffer, "\\u{:04x}", character as u32).unwrap()
+            }
+            character => buffer.push(character),
+        }
+    }
+    buffer.push('"');
+}
+
+/// The subset of JSON needed to parse the header of a safetensors file.
+#[derive(Debug, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn parse(text: &str) -> Result<Self, CheckpointError> {
+        let mut parser = Parser {
+            text: text.as_bytes(),
+            position: 0,
+            depth: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.position != parser.text.len() {
+            return Err(parser.error());
+        }
+
+        Ok(value)
+    }
+
+    fn get(&self, key: &str) -> Option<&Self> {
+        match self {
+            Self::Object(entries) => entries
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    fn as_usizes(&self) -> Option<Vec<usize>> {
+        match self {
+            Self::Array(values) => values
+                .iter()
+                .map(|value| match value {
+                    Self::Number(number) if number.fract() == 0. && *number >= 0. => {
+                        Some(*number as usize)
+                    }
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
+/// A recursive descent JSON parser.
+struct Parser<'a> {
+    text: &'a [u8],
+    position: usize,
+    /// Number of arrays and objects enclosing the current position, bounded by [`MAX_DEPTH`] so
+    /// that a malicious header can't overflow the stack.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self) -> CheckpointError {
+        CheckpointError::InvalidFormat(format!("malformed header at byte {}", self.position))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Result<u8, CheckpointError> {
+        let byte = self.peek().ok_or_else(|| self.error())?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    fn expect(&mut self, literal: &str) -> Result<(), CheckpointError> {
+        if self.text[self.position..].starts_with(literal.as_bytes()) {
+            self.position += literal.len();
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, CheckpointError> {
+        self.skip_whitespace();
+        match self.peek().ok_or_else(|| self.error())? {
+            b'{' => self.nested(Self::object),
+            b'[' => self.nested(Self::array),
+            b'"' => self.string().map(Json::String),
+            b'n' => self.expect("null").map(|_| Json::Null),
+            b't' => self.expect("true").map(|_| Json::Bool(true)),
+            b'f' => self.expect("false").map(|_| Json::Bool(false)),
+            _ => self.number(),
+        }
+    }
+
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<Json, CheckpointError>,
+    ) -> Result<Json, CheckpointError> {
+        if self.depth == MAX_DEPTH {
+            return Err(CheckpointError::InvalidFormat(format!(
+                "header nested too deeply at byte {}",
+                self.position
+            )));
+        }
+
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn object(&mut self) -> Result<Json, CheckpointError> {
+        let mut entries = Vec::new();
+        self.expect("{")?;
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.position += 1;
+            return Ok(Json::Object(entries));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(":")?;
+            entries.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.next()? {
+                b',' => continue,
+                b'}' => return Ok(Json::Object(entries)),
+                _ => return Err(self.error()),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, CheckpointError> {
+        let mut values = Vec::new();
+        self.expect("[")?;
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(Json::Array(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            match self.next()? {
+                b',' => continue,
+                b']' => return Ok(Json::Array(values)),
+                _ => return Err(self.error()),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, CheckpointError> {
+        self.expect("\"")?;
+        let mut bytes = Vec::new();
+        loop {
+            match self.next()? {
+                b'"' => break,
+                b'\\' => match self.next()? {
+                    b'"' => bytes.push(b'"'),
+                    b'\\' => bytes.push(b'\\'),
+                    b'/' => bytes.push(b'/'),
+                    b'b' => bytes.push(b'\x08'),
+                    b'f' => bytes.push(b'\x0c'),
+                    b'n' => bytes.push(b'\n'),
+                    b'r' => bytes.push(b'\r'),
+                    b't' => bytes.push(b'\t'),
+                    b'u' => {
+                        let mut code = self.code_unit()?;
+                        if (0xD800..0xDC00).contains(&code) {
+                            self.expect("\\u")?;
+                            let low = self.code_unit()?;
+                            code = 0x10000 + ((code - 0xD800) << 10) + low.wrapping_sub(0xDC00);
+                        }
+                        let character = char::from_u32(code).ok_or_else(|| self.error())?;
+                        let mut buffer = [0; 4];
+                        bytes.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+                    }
+                    _ => return Err(self.error()),
+                },
+                byte => bytes.push(byte),
+            }
+        }
+
+        String::from_utf8(bytes).map_err(|_| self.error())
+    }
+
+    fn code_unit(&mut self) -> Result<u32, CheckpointError> {
+        let digits = self
+            .text
+            .get(self.position..self.position + 4)
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .ok_or_else(|| self.error())?;
+        self.position += 4;
+
+        Ok(digits)
+    }
+
+    fn number(&mut self) -> Result<Json, CheckpointError> {
+        let start = self.position;
+        while matches!(
+            self.peek(),
+            Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')
+        ) {
+            self.position += 1;
+        }
+
+        std::str::from_utf8(&self.text[start..self.position])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .map(Json::Number)
+            .ok_or_else(|| self.error())
+    }
+}
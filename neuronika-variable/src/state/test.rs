@@ -0,0 +1,269 @@
+use ndarray::{array, Array, ArrayD, IxDyn};
+
+use super::{
+    read_safetensors, write_safetensors, CheckpointError, Dtype, ModelCheckpoint, ParameterDict,
+    StateDict, CHECKPOINT_VERSION,
+};
+
+fn parameters() -> (ParameterDict, crate::VarDiff<ndarray::Ix2>) {
+    let weight = crate::ones((2, 3)).requires_grad();
+    let bias = crate::zeros(2).requires_grad();
+
+    let mut parameters = ParameterDict::new();
+    parameters.insert("weight", weight.clone());
+    parameters.insert("bias", bias);
+
+    (parameters, weight)
+}
+
+fn checkpoint() -> ModelCheckpoint {
+    let mut checkpoint = ModelCheckpoint::new(parameters().0.state_dict());
+    checkpoint
+        .optimizer
+        .insert("step".to_string(), ArrayD::from_elem(IxDyn(&[]), 3.));
+    checkpoint.scheduler_epoch = Some(7);
+    checkpoint
+        .metadata
+        .insert("model".to_string(), "mlp".to_string());
+
+    checkpoint
+}
+
+#[test]
+fn state_dict() {
+    let (parameters, _) = parameters();
+    let state = parameters.state_dict();
+
+    assert_eq!(parameters.len(), 2);
+    assert_eq!(state["weight"], Array::ones((2, 3)).into_dyn());
+    assert_eq!(state["bias"], Array::zeros(2).into_dyn());
+}
+
+#[test]
+fn roundtrip() {
+    let checkpoint = checkpoint();
+
+    let mut bytes = Vec::new();
+    checkpoint.write(&mut bytes).unwrap();
+    assert_eq!(&bytes[..8], b"NEURONKA");
+    assert_eq!(bytes[8..12], CHECKPOINT_VERSION.to_le_bytes());
+
+    assert_eq!(ModelCheckpoint::read(bytes.as_slice()).unwrap(), checkpoint);
+}
+
+#[test]
+fn save_and_load() {
+    let path = std::env::temp_dir().join(format!("neuronika-{}.ckpt", std::process::id()));
+    let checkpoint = checkpoint();
+
+    checkpoint.save(&path).unwrap();
+    let loaded = ModelCheckpoint::load(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.unwrap(), checkpoint);
+}
+
+#[test]
+fn invalid_format() {
+    let mut bytes = Vec::new();
+    checkpoint().write(&mut bytes).unwrap();
+
+    let error = ModelCheckpoint::read(&b"NOTACKPT"[..]).unwrap_err();
+    assert!(matches!(error, CheckpointError::InvalidFormat(_)));
+
+    let error = ModelCheckpoint::read(&bytes[..bytes.len() - 3]).unwrap_err();
+    assert!(matches!(error, CheckpointError::InvalidFormat(_)));
+
+    bytes[8..12].copy_from_slice(&(CHECKPOINT_VERSION + 1).to_le_bytes());
+    let error = ModelCheckpoint::read(bytes.as_slice()).unwrap_err();
+    assert!(matches!(error, CheckpointError::UnsupportedVersion(version) if version == 2));
+}
+
+#[test]
+fn corrupted_shape() {
+    let corrupted = |shape: &[u64]| {
+        let mut bytes = Vec::new();
+        ModelCheckpoint::default().write(&mut bytes).unwrap();
+        // Replaces the empty parameters with a single one of the given shape and no data.
+        bytes.truncate(20);
+        bytes.extend_from_slice(&1_u64.to_le_bytes());
+        bytes.extend_from_slice(&1_u64.to_le_bytes());
+        bytes.push(b'w');
+        bytes.extend_from_slice(&(shape.len() as u64).to_le_bytes());
+        shape
+            .iter()
+            .for_each(|len| bytes.extend_from_slice(&len.to_le_bytes()));
+
+        ModelCheckpoint::read(bytes.as_slice()).unwrap_err()
+    };
+
+    let error = corrupted(&[1 << 62]);
+    assert!(
+        matches!(error, CheckpointError::InvalidFormat(message) if message == "array too large")
+    );
+
+    let error = corrupted(&[0, 1 << 62, 1 << 62]);
+    assert!(
+        matches!(error, CheckpointError::InvalidFormat(message) if message == "array too large")
+    );
+
+    let error = corrupted(&[1 << 20]);
+    assert!(matches!(error, CheckpointError::InvalidFormat(_)));
+}
+
+#[test]
+fn strict_loading() {
+    let (parameters, weight) = parameters();
+    let mut state = parameters.state_dict();
+    state.insert("weight".to_string(), Array::zeros((2, 3)).into_dyn());
+
+    let report = parameters.load_state_dict(&state, true).unwrap();
+    assert!(report.missing.is_empty() && report.unexpected.is_empty());
+    assert_eq!(*weight.data(), Array::zeros((2, 3)));
+
+    state.remove("bias");
+    state.insert("scale".to_string(), Array::zeros(1).into_dyn());
+    match parameters.load_state_dict(&state, true).unwrap_err() {
+        CheckpointError::KeyMismatch {
+            missing,
+            unexpected,
+        } => {
+            assert_eq!(missing, vec!["bias".to_string()]);
+            assert_eq!(unexpected, vec!["scale".to_string()]);
+        }
+        error => panic!("unexpected error {}", error),
+    }
+}
+
+#[test]
+fn non_strict_loading() {
+    let (parameters, weight) = parameters();
+    let mut state = StateDict::new();
+    state.insert(
+        "weight".to_string(),
+        array![[1., 2., 3.], [4., 5., 6.]].into_dyn(),
+    );
+    state.insert("scale".to_string(), Array::zeros(1).into_dyn());
+
+    let report = parameters.load_state_dict(&state, false).unwrap();
+    assert_eq!(report.missing, vec!["bias".to_string()]);
+    assert_eq!(report.unexpected, vec!["scale".to_string()]);
+    assert_eq!(*weight.data(), array![[1., 2., 3.], [4., 5., 6.]]);
+}
+
+#[test]
+fn shape_mismatch() {
+    let (parameters, weight) = parameters();
+    let mut state = parameters.state_dict();
+    state.insert("bias".to_string(), Array::zeros(3).into_dyn());
+    state.insert("weight".to_string(), Array::zeros((2, 3)).into_dyn());
+
+    match parameters.load_state_dict(&state, false).unwrap_err() {
+        CheckpointError::ShapeMismatch {
+            name,
+            expected,
+            found,
+        } => {
+            assert_eq!(name, "bias");
+            assert_eq!(expected, vec![2]);
+            assert_eq!(found, vec![3]);
+        }
+        error => panic!("unexpected error {}", error),
+    }
+
+    // Nothing is loaded when an error occurs.
+    assert_eq!(*weight.data(), Array::ones((2, 3)));
+}
+
+#[test]
+fn compatible_shapes() {
+    let bias = crate::zeros((3, 1, 1)).requires_grad();
+    let mut parameters = ParameterDict::new();
+    parameters.insert("bias", bias.clone());
+
+    let mut state = StateDict::new();
+    state.insert("bias".to_string(), array![1., 2., 3.].into_dyn());
+    parameters.load_state_dict(&state, true).unwrap();
+
+    assert_eq!(
+        *bias.data(),
+        array![1., 2., 3.].into_shape((3, 1, 1)).unwrap()
+    );
+}
+
+#[test]
+fn safetensors_roundtrip() {
+    let mut state = StateDict::new();
+    state.insert(
+        "weight".to_string(),
+        array![[1., -2.5, 3.], [0.125, 5., -6.]].into_dyn(),
+    );
+    state.insert("bias".to_string(), array![0.5, -0.25].into_dyn());
+
+    for dtype in [Dtype::F16, Dtype::BF16, Dtype::F32, Dtype::F64] {
+        let bytes = write_safetensors(&state, dtype);
+        assert_eq!(read_safetensors(&bytes).unwrap(), state);
+    }
+}
+
+#[test]
+fn safetensors_dtype_conversion() {
+    let header = r#"{"__metadata__":{"format":"pt"},"steps":{"dtype":"I64","shape":[3],"data_offsets":[0,24]}}"#;
+    let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+    bytes.extend_from_slice(header.as_bytes());
+    [1_i64, -2, 3]
+        .iter()
+        .for_each(|el| bytes.extend_from_slice(&el.to_le_bytes()));
+
+    let state = read_safetensors(&bytes).unwrap();
+    assert_eq!(state.len(), 1);
+    assert_eq!(state["steps"], array![1., -2., 3.].into_dyn());
+
+    let header = r#"{"mask":{"dtype":"F8_E4M3","shape":[],"data_offsets":[0,1]}}"#;
+    let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.push(0);
+    let error = read_safetensors(&bytes).unwrap_err();
+    assert!(matches!(error, CheckpointError::UnsupportedDtype { name, .. } if name == "mask"));
+}
+
+#[test]
+fn safetensors_invalid() {
+    let error = read_safetensors(b"not a safetensors file").unwrap_err();
+    assert!(matches!(error, CheckpointError::InvalidFormat(_)));
+
+    let mut state = StateDict::new();
+    state.insert("bias".to_string(), array![0.5, -0.25].into_dyn());
+    let bytes = write_safetensors(&state, Dtype::F32);
+    let error = read_safetensors(&bytes[..bytes.len() - 1]).unwrap_err();
+    assert!(matches!(error, CheckpointError::InvalidFormat(_)));
+}
+
+#[test]
+fn safetensors_deeply_nested() {
+    let header = format!(
+        r#"{{"bias":{}{}}}"#,
+        "[".repeat(100_000),
+        "]".repeat(100_000)
+    );
+    let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+    bytes.extend_from_slice(header.as_bytes());
+    let error = read_safetensors(&bytes).unwrap_err();
+    assert!(matches!(error, CheckpointError::InvalidFormat(reason) if reason.contains("nested")));
+
+    // Nesting within the limit is still parsed.
+    let header = format!(r#"{{"__metadata__":{}{}}}"#, "[".repeat(32), "]".repeat(32));
+    let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+    bytes.extend_from_slice(header.as_bytes());
+    assert!(read_safetensors(&bytes).unwrap().is_empty());
+}
+
+#[test]
+fn safetensors_header_too_large() {
+    let mut bytes = (100_000_001_u64).to_le_bytes().to_vec();
+    bytes.resize(100_000_009, b' ');
+    let error = read_safetensors(&bytes).unwrap_err();
+    assert!(
+        matches!(error, CheckpointError::InvalidFormat(reason) if reason.contains("header length"))
+    );
+}
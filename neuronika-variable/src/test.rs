@@ -188,6 +188,22 @@ fn pow_diff() {
     assert_eq!(pow.history.len(), 1);
 }
 
+#[test]
+fn powf() {
+    let input = crate::ones((2, 2));
+    let powf = input.powf(0.5);
+
+    assert_eq!(powf.history.len(), 1);
+}
+
+#[test]
+fn powf_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let powf = input.powf(0.5);
+
+    assert_eq!(powf.history.len(), 1);
+}
+
 #[test]
 fn sqrt() {
     let input = crate::ones((2, 2));
@@ -236,6 +252,166 @@ fn leaky_relu_diff() {
     assert_eq!(leaky_relu.history.len(), 1);
 }
 
+#[test]
+fn leaky_relu_with_slope() {
+    let input = crate::ones((2, 2));
+    let leaky_relu = input.leaky_relu_with_slope(0.2);
+
+    assert_eq!(leaky_relu.history.len(), 1);
+}
+
+#[test]
+fn leaky_relu_with_slope_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let leaky_relu = input.leaky_relu_with_slope(0.2);
+
+    assert_eq!(leaky_relu.history.len(), 1);
+}
+
+#[test]
+fn gelu() {
+    let input = crate::ones((2, 2));
+    let gelu = input.gelu(false);
+
+    assert_eq!(gelu.history.len(), 1);
+}
+
+#[test]
+fn gelu_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let gelu = input.gelu(false);
+
+    assert_eq!(gelu.history.len(), 1);
+}
+
+#[test]
+fn silu() {
+    let input = crate::ones((2, 2));
+    let silu = input.silu();
+
+    assert_eq!(silu.history.len(), 1);
+}
+
+#[test]
+fn silu_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let silu = input.silu();
+
+    assert_eq!(silu.history.len(), 1);
+}
+
+#[test]
+fn elu() {
+    let input = crate::ones((2, 2));
+    let elu = input.elu(1.);
+
+    assert_eq!(elu.history.len(), 1);
+}
+
+#[test]
+fn elu_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let elu = input.elu(1.);
+
+    assert_eq!(elu.history.len(), 1);
+}
+
+#[test]
+fn celu() {
+    let input = crate::ones((2, 2));
+    let celu = input.celu(1.);
+
+    assert_eq!(celu.history.len(), 1);
+}
+
+#[test]
+fn celu_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let celu = input.celu(1.);
+
+    assert_eq!(celu.history.len(), 1);
+}
+
+#[test]
+fn selu() {
+    let input = crate::ones((2, 2));
+    let selu = input.selu();
+
+    assert_eq!(selu.history.len(), 1);
+}
+
+#[test]
+fn selu_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let selu = input.selu();
+
+    assert_eq!(selu.history.len(), 1);
+}
+
+#[test]
+fn mish() {
+    let input = crate::ones((2, 2));
+    let mish = input.mish();
+
+    assert_eq!(mish.history.len(), 1);
+}
+
+#[test]
+fn mish_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let mish = input.mish();
+
+    assert_eq!(mish.history.len(), 1);
+}
+
+#[test]
+fn hardswish() {
+    let input = crate::ones((2, 2));
+    let hardswish = input.hardswish();
+
+    assert_eq!(hardswish.history.len(), 1);
+}
+
+#[test]
+fn hardswish_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let hardswish = input.hardswish();
+
+    assert_eq!(hardswish.history.len(), 1);
+}
+
+#[test]
+fn hardsigmoid() {
+    let input = crate::ones((2, 2));
+    let hardsigmoid = input.hardsigmoid();
+
+    assert_eq!(hardsigmoid.history.len(), 1);
+}
+
+#[test]
+fn hardsigmoid_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let hardsigmoid = input.hardsigmoid();
+
+    assert_eq!(hardsigmoid.history.len(), 1);
+}
+
+#[test]
+fn softsign() {
+    let input = crate::ones((2, 2));
+    let softsign = input.softsign();
+
+    assert_eq!(softsign.history.len(), 1);
+}
+
+#[test]
+fn softsign_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let softsign = input.softsign();
+
+    assert_eq!(softsign.history.len(), 1);
+}
+
 #[test]
 fn softplus() {
     let input = crate::ones((2, 2));
@@ -316,6 +492,305 @@ fn exp_diff() {
     assert_eq!(exp.history.len(), 1);
 }
 
+#[test]
+fn sin() {
+    let input = crate::ones((2, 2));
+    let sin = input.sin();
+
+    assert_eq!(sin.history.len(), 1);
+}
+
+#[test]
+fn sin_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let sin = input.sin();
+
+    assert_eq!(sin.history.len(), 1);
+}
+
+#[test]
+fn cos() {
+    let input = crate::ones((2, 2));
+    let cos = input.cos();
+
+    assert_eq!(cos.history.len(), 1);
+}
+
+#[test]
+fn cos_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let cos = input.cos();
+
+    assert_eq!(cos.history.len(), 1);
+}
+
+#[test]
+fn tan() {
+    let input = crate::ones((2, 2));
+    let tan = input.tan();
+
+    assert_eq!(tan.history.len(), 1);
+}
+
+#[test]
+fn tan_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let tan = input.tan();
+
+    assert_eq!(tan.history.len(), 1);
+}
+
+#[test]
+fn asin() {
+    let input = crate::ones((2, 2));
+    let asin = input.asin();
+
+    assert_eq!(asin.history.len(), 1);
+}
+
+#[test]
+fn asin_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let asin = input.asin();
+
+    assert_eq!(asin.history.len(), 1);
+}
+
+#[test]
+fn atan() {
+    let input = crate::ones((2, 2));
+    let atan = input.atan();
+
+    assert_eq!(atan.history.len(), 1);
+}
+
+#[test]
+fn atan_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let atan = input.atan();
+
+    assert_eq!(atan.history.len(), 1);
+}
+
+#[test]
+fn sinh() {
+    let input = crate::ones((2, 2));
+    let sinh = input.sinh();
+
+    assert_eq!(sinh.history.len(), 1);
+}
+
+#[test]
+fn sinh_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let sinh = input.sinh();
+
+    assert_eq!(sinh.history.len(), 1);
+}
+
+#[test]
+fn cosh() {
+    let input = crate::ones((2, 2));
+    let cosh = input.cosh();
+
+    assert_eq!(cosh.history.len(), 1);
+}
+
+#[test]
+fn cosh_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let cosh = input.cosh();
+
+    assert_eq!(cosh.history.len(), 1);
+}
+
+#[test]
+fn log1p() {
+    let input = crate::ones((2, 2));
+    let log1p = input.log1p();
+
+    assert_eq!(log1p.history.len(), 1);
+}
+
+#[test]
+fn log1p_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let log1p = input.log1p();
+
+    assert_eq!(log1p.history.len(), 1);
+}
+
+#[test]
+fn expm1() {
+    let input = crate::ones((2, 2));
+    let expm1 = input.expm1();
+
+    assert_eq!(expm1.history.len(), 1);
+}
+
+#[test]
+fn expm1_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let expm1 = input.expm1();
+
+    assert_eq!(expm1.history.len(), 1);
+}
+
+#[test]
+fn log2() {
+    let input = crate::ones((2, 2));
+    let log2 = input.log2();
+
+    assert_eq!(log2.history.len(), 1);
+}
+
+#[test]
+fn log2_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let log2 = input.log2();
+
+    assert_eq!(log2.history.len(), 1);
+}
+
+#[test]
+fn rsqrt() {
+    let input = crate::ones((2, 2));
+    let rsqrt = input.rsqrt();
+
+    assert_eq!(rsqrt.history.len(), 1);
+}
+
+#[test]
+fn rsqrt_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let rsqrt = input.rsqrt();
+
+    assert_eq!(rsqrt.history.len(), 1);
+}
+
+#[test]
+fn erf() {
+    let input = crate::ones((2, 2));
+    let erf = input.erf();
+
+    assert_eq!(erf.history.len(), 1);
+}
+
+#[test]
+fn erf_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let erf = input.erf();
+
+    assert_eq!(erf.history.len(), 1);
+}
+
+#[test]
+fn erfinv() {
+    let input = crate::ones((2, 2));
+    let erfinv = input.erfinv();
+
+    assert_eq!(erfinv.history.len(), 1);
+}
+
+#[test]
+fn erfinv_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let erfinv = input.erfinv();
+
+    assert_eq!(erfinv.history.len(), 1);
+}
+
+#[test]
+fn lgamma() {
+    let input = crate::ones((2, 2));
+    let lgamma = input.lgamma();
+
+    assert_eq!(lgamma.history.len(), 1);
+}
+
+#[test]
+fn lgamma_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let lgamma = input.lgamma();
+
+    assert_eq!(lgamma.history.len(), 1);
+}
+
+#[test]
+fn digamma() {
+    let input = crate::ones((2, 2));
+    let digamma = input.digamma();
+
+    assert_eq!(digamma.history.len(), 1);
+}
+
+#[test]
+fn digamma_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let digamma = input.digamma();
+
+    assert_eq!(digamma.history.len(), 1);
+}
+
+#[test]
+fn abs() {
+    let input = crate::ones((2, 2));
+    let abs = input.abs();
+
+    assert_eq!(abs.history.len(), 1);
+}
+
+#[test]
+fn abs_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let abs = input.abs();
+
+    assert_eq!(abs.history.len(), 1);
+}
+
+#[test]
+fn sign() {
+    let input = crate::ones((2, 2));
+    let sign = input.sign();
+
+    assert_eq!(sign.history.len(), 1);
+}
+
+#[test]
+fn sign_diff() {
+    let input = crate::ones((2, 2)).requires_grad();
+    let sign = input.sign();
+
+    assert_eq!(sign.history.len(), 1);
+}
+
+#[test]
+fn clamp() {
+    let input = crate::ones((2, 2));
+    let clamp = input.clamp(0., 6.);
+
+    assert_eq!(clamp.history.len(), 1);
+}
+
+#[test]
+fn clamp_diff() {
+    let input = crate::from_ndarray(ndarray::array![[-1., 0.], [3., 7.]]).requires_grad();
+    let clamp = input.clone().clamp(0., 6.);
+
+    assert_eq!(clamp.history.len(), 1);
+
+    clamp.forward();
+    clamp.backward(1.);
+    assert_eq!(*clamp.data(), ndarray::array![[0., 0.], [3., 6.]]);
+    assert_eq!(*input.grad(), ndarray::array![[0., 1.], [1., 0.]]);
+}
+
+#[test]
+#[should_panic(expected = "error: invalid clamp range, min 1 is greater than max 0.")]
+fn clamp_fail() {
+    crate::ones((2, 2)).clamp(1., 0.);
+}
+
 #[test]
 fn softmax() {
     let input = crate::ones((2, 2));
@@ -416,6 +891,30 @@ fn unsqueeze_diff() {
     assert_eq!(unsqueeze.history.len(), 1);
 }
 
+#[test]
+fn reshape() {
+    let input = crate::ones((2, 3));
+    let reshape = input.reshape(ndarray::IxDyn(&[3, 1, 2]));
+
+    assert_eq!(reshape.history.len(), 1);
+    assert_eq!(reshape.data().shape(), &[3, 1, 2]);
+}
+
+#[test]
+fn reshape_diff() {
+    let input = crate::ones((2, 3)).requires_grad();
+    let reshape = input.reshape(6);
+
+    assert_eq!(reshape.history.len(), 1);
+    assert_eq!(reshape.grad().shape(), &[6]);
+}
+
+#[test]
+#[should_panic]
+fn reshape_fail() {
+    crate::ones((2, 3)).reshape((4, 2));
+}
+
 #[test]
 fn cat() {
     let lhs = crate::ones((2, 2));
@@ -655,6 +1154,106 @@ fn div_diff() {
     assert_eq!(div.history.len(), 1);
 }
 
+#[test]
+fn maximum() {
+    let lhs = crate::ones((2, 2));
+    let rhs = crate::zeros((2, 2));
+    let maximum = lhs.maximum(rhs);
+
+    assert_eq!(maximum.history.len(), 1);
+}
+
+#[test]
+fn maximum_diff() {
+    let lhs = crate::ones((2, 2)).requires_grad();
+    let rhs = crate::zeros((2, 2));
+    let maximum = lhs.maximum(rhs);
+
+    assert_eq!(maximum.history.len(), 1);
+
+    let lhs = crate::ones((2, 2));
+    let rhs = crate::zeros((2, 2)).requires_grad();
+    let maximum = lhs.maximum(rhs);
+
+    assert_eq!(maximum.history.len(), 1);
+
+    let lhs = crate::ones((2, 2)).requires_grad();
+    let rhs = crate::zeros((2, 2)).requires_grad();
+    let maximum = lhs.maximum(rhs);
+
+    assert_eq!(maximum.history.len(), 1);
+}
+
+#[test]
+fn minimum() {
+    let lhs = crate::ones((2, 2));
+    let rhs = crate::zeros((2, 2));
+    let minimum = lhs.minimum(rhs);
+
+    assert_eq!(minimum.history.len(), 1);
+}
+
+#[test]
+fn minimum_diff() {
+    let lhs = crate::ones((2, 2)).requires_grad();
+    let rhs = crate::zeros((2, 2));
+    let minimum = lhs.minimum(rhs);
+
+    assert_eq!(minimum.history.len(), 1);
+
+    let lhs = crate::ones((2, 2));
+    let rhs = crate::zeros((2, 2)).requires_grad();
+    let minimum = lhs.minimum(rhs);
+
+    assert_eq!(minimum.history.len(), 1);
+
+    let lhs = crate::ones((2, 2)).requires_grad();
+    let rhs = crate::zeros((2, 2)).requires_grad();
+    let minimum = lhs.minimum(rhs);
+
+    assert_eq!(minimum.history.len(), 1);
+}
+
+#[test]
+fn select() {
+    let condition = crate::ones((2, 2));
+    let lhs = crate::ones((2, 2));
+    let rhs = crate::zeros((2, 2));
+    let select = crate::select(condition, lhs, rhs);
+
+    assert_eq!(select.history.len(), 1);
+}
+
+#[test]
+fn select_diff() {
+    let condition = crate::from_ndarray(ndarray::array![[1., 0.], [0., 1.]]);
+    let lhs = crate::ones((2, 2)).requires_grad();
+    let rhs = crate::zeros((2, 2));
+    let select = crate::select(condition, lhs, rhs);
+
+    assert_eq!(select.history.len(), 1);
+
+    let condition = crate::from_ndarray(ndarray::array![[1., 0.], [0., 1.]]);
+    let lhs = crate::ones((2, 2));
+    let rhs = crate::zeros((2, 2)).requires_grad();
+    let select = crate::select(condition, lhs, rhs);
+
+    assert_eq!(select.history.len(), 1);
+
+    let condition = crate::from_ndarray(ndarray::array![[1., 0.], [0., 1.]]);
+    let lhs = crate::ones((2, 2)).requires_grad();
+    let rhs = crate::zeros((2, 2)).requires_grad();
+    let select = crate::select(condition, lhs.clone(), rhs.clone());
+
+    assert_eq!(select.history.len(), 1);
+
+    select.forward();
+    select.backward(1.);
+    assert_eq!(*select.data(), ndarray::array![[1., 0.], [0., 1.]]);
+    assert_eq!(*lhs.grad(), ndarray::array![[1., 0.], [0., 1.]]);
+    assert_eq!(*rhs.grad(), ndarray::array![[0., 1.], [1., 0.]]);
+}
+
 #[test]
 fn vv() {
     let lhs = crate::ones(2);
@@ -805,6 +1404,350 @@ fn mm_t_diff() {
     assert_eq!(mm_t.history.len(), 1);
 }
 
+#[test]
+fn mm_t_diff_non_square() {
+    let lhs = crate::ones((2, 3));
+    let rhs = crate::ones((4, 3)).requires_grad();
+    let mm_t = lhs.mm_t(rhs.clone());
+    assert_eq!(mm_t.grad().shape(), &[2, 4]);
+
+    mm_t.forward();
+    mm_t.backward(1.);
+    assert_eq!(*rhs.grad(), ndarray::Array::from_elem((4, 3), 2.));
+
+    let lhs = crate::ones((2, 3)).requires_grad();
+    let rhs = crate::ones((4, 3)).requires_grad();
+    let mm_t = lhs.clone().mm_t(rhs.clone());
+    assert_eq!(mm_t.grad().shape(), &[2, 4]);
+
+    mm_t.forward();
+    mm_t.backward(1.);
+    assert_eq!(*lhs.grad(), ndarray::Array::from_elem((2, 3), 4.));
+    assert_eq!(*rhs.grad(), ndarray::Array::from_elem((4, 3), 2.));
+}
+
+#[test]
+fn pad() {
+    let input = crate::ones((1, 1, 3));
+    let pad = input.pad(1, crate::Zero);
+
+    assert_eq!(pad.history.len(), 1);
+}
+
+#[test]
+fn pad_diff() {
+    let input = crate::ones((1, 1, 3)).requires_grad();
+    let pad = input.clone().pad(1, crate::Zero);
+    assert_eq!(pad.grad().shape(), &[1, 1, 5]);
+
+    let y = pad.sum();
+    y.forward();
+    y.backward(1.);
+    assert_eq!(*input.grad(), ndarray::Array::<f32, _>::ones((1, 1, 3)));
+}
+
+#[test]
+fn interpolate() {
+    let input = crate::ones((1, 1, 2, 2));
+    let interpolated = input.interpolate((3, 5), crate::Interpolation::Linear, false);
+    assert_eq!(interpolated.data().shape(), &[1, 1, 3, 5]);
+
+    let upsampled = crate::ones((1, 1, 2)).upsample(2., crate::Interpolation::Nearest, false);
+    upsampled.forward();
+    assert_eq!(upsampled.history.len(), 1);
+    assert_eq!(*upsampled.data(), ndarray::Array::<f32, _>::ones((1, 1, 4)));
+}
+
+#[test]
+fn interpolate_diff() {
+    let input = crate::ones((1, 1, 2, 2)).requires_grad();
+    let upsampled = input
+        .clone()
+        .upsample(2., crate::Interpolation::Nearest, false);
+    assert_eq!(upsampled.grad().shape(), &[1, 1, 4, 4]);
+
+    let y = upsampled.sum();
+    y.forward();
+    y.backward(1.);
+    assert_eq!(*input.grad(), ndarray::Array::from_elem((1, 1, 2, 2), 4.));
+}
+
+#[test]
+fn pad_asymmetric() {
+    let input = crate::ones((1, 1, 3, 3));
+    let pad = input.pad_asymmetric((0, 1), (2, 0), crate::Circular);
+
+    assert_eq!(pad.history.len(), 1);
+    assert_eq!(pad.data().shape(), &[1, 1, 5, 4]);
+}
+
+#[test]
+fn pad_asymmetric_diff() {
+    let input = crate::ones((1, 1, 3)).requires_grad();
+    let pad = input.clone().pad_asymmetric(2, 1, crate::Circular);
+    assert_eq!(pad.grad().shape(), &[1, 1, 6]);
+
+    let y = pad.sum();
+    y.forward();
+    y.backward(1.);
+    assert_eq!(*input.grad(), ndarray::array![[[2., 2., 2.]]]);
+}
+
+#[test]
+fn forward_recomputes_modified() {
+    let x = crate::ones((2, 2));
+    let z = crate::ones((2, 2));
+    let h = x.clone().exp();
+    let y = h.clone() + z.clone();
+    y.forward();
+
+    // Tampers with the result of the untouched branch, which must not be recomputed.
+    h.data.borrow_mut().fill(0.);
+    *z.data_mut() = ndarray::array![[1., 2.], [3., 4.]];
+    y.forward();
+    assert_eq!(*y.data(), ndarray::array![[1., 2.], [3., 4.]]);
+
+    *x.data_mut() = ndarray::Array::zeros((2, 2));
+    y.forward();
+    assert_eq!(*h.data(), ndarray::Array::ones((2, 2)));
+    assert_eq!(*y.data(), ndarray::array![[2., 3.], [4., 5.]]);
+}
+
+#[test]
+fn forward_recomputes_volatile() {
+    let status = std::rc::Rc::new(std::cell::Cell::new(true));
+    let x = crate::ones((2, 2));
+    let y = (x + 1.).dropout(1., status.clone());
+
+    y.forward();
+    assert_eq!(*y.data(), ndarray::Array::zeros((2, 2)));
+
+    status.set(false);
+    y.forward();
+    assert_eq!(*y.data(), ndarray::Array::from_elem((2, 2), 2.));
+}
+
+#[test]
+fn plan_memory_recomputes_released() {
+    let x = crate::from_ndarray(ndarray::array![[0., 1.], [2., 3.]]);
+    let z = crate::zeros((2, 2));
+    let y = (x * 2.).exp() + z.clone();
+    y.plan_memory();
+
+    y.forward();
+    assert_eq!(y.history.released_mut().len(), 2);
+
+    *z.data_mut() = ndarray::Array::ones((2, 2));
+    y.forward();
+    assert_eq!(
+        *y.data(),
+        ndarray::array![[0., 2.], [4., 6.]].mapv(|el: f32| el.exp() + 1.)
+    );
+}
+
+#[test]
+fn plan_memory() {
+    let x = crate::from_ndarray(ndarray::array![[-1., 2.], [3., -4.]]);
+    let y = ((x.clone() * 2. - 1.).relu()) + 1.;
+    y.plan_memory();
+
+    y.forward();
+    assert_eq!(*y.data(), ndarray::array![[1., 4.], [6., 1.]]);
+    assert_eq!(y.history.released_mut().len(), 3);
+
+    *x.data_mut() = ndarray::array![[1., 1.], [1., 1.]];
+    y.forward();
+    assert_eq!(*y.data(), ndarray::array![[2., 2.], [2., 2.]]);
+    assert_eq!(y.history.released_mut().len(), 3);
+}
+
+#[test]
+fn plan_memory_keeps_owned() {
+    let x = crate::from_ndarray(ndarray::array![[-1., 2.], [3., -4.]]);
+    let h = (x * 2.).relu();
+    let y = h.clone() + 1.;
+    y.plan_memory();
+
+    y.forward();
+    assert_eq!(*h.data(), ndarray::array![[0., 4.], [6., 0.]]);
+    assert_eq!(*y.data(), ndarray::array![[1., 5.], [7., 1.]]);
+    assert!(y.history.released_mut().is_empty());
+}
+
+#[test]
+fn plan_memory_diff() {
+    let x = crate::from_ndarray(ndarray::array![[1., 2.], [3., 4.]]);
+    let w = crate::from_ndarray(ndarray::array![[1., -1.], [2., 0.5]]).requires_grad();
+    let y = (x.mm(w.clone()) + 1.).relu().sum();
+    y.plan_memory();
+
+    y.forward();
+    y.backward(1.);
+    assert_eq!(y.var.item(), 19.);
+    assert_eq!(*w.grad(), ndarray::array![[4., 1.], [6., 2.]]);
+    assert_eq!(y.var.history.released_mut().len(), 2);
+
+    y.forward();
+    assert_eq!(y.var.item(), 19.);
+}
+
+#[test]
+fn in_place() {
+    let x = crate::from_ndarray(ndarray::array![[-1., 2.], [3., -4.]]);
+    let y = (x * 2.).relu_();
+
+    assert_eq!(y.history.len(), 2);
+
+    y.forward();
+    assert_eq!(*y.data(), ndarray::array![[0., 4.], [6., 0.]]);
+
+    let x = crate::zeros((2, 2));
+    let y = (x + 1.).sigmoid_();
+
+    y.forward();
+    assert_eq!(
+        *y.data(),
+        ndarray::Array::from_elem((2, 2), 1. / (1. + (-1f32).exp()))
+    );
+}
+
+#[test]
+fn in_place_diff() {
+    let w = crate::from_ndarray(ndarray::array![[-1., 2.], [3., -4.]]).requires_grad();
+    let y = (w.clone() * 2.).relu_().sum();
+
+    y.forward();
+    y.backward(1.);
+    assert_eq!(y.var.item(), 10.);
+    assert_eq!(*w.grad(), ndarray::array![[0., 2.], [2., 0.]]);
+}
+
+#[test]
+#[should_panic]
+fn in_place_leaf() {
+    crate::zeros((2, 2)).relu_();
+}
+
+#[test]
+#[should_panic]
+fn in_place_used_elsewhere() {
+    let x = crate::zeros((2, 2)) + 1.;
+    let _y = x.clone().exp();
+
+    x.sigmoid_();
+}
+
+#[test]
+#[should_panic]
+fn in_place_needed_by_backward() {
+    let x = crate::zeros((2, 2)).requires_grad().exp();
+
+    x.relu_();
+}
+
+#[test]
+fn checkpoint() {
+    let w = crate::from_ndarray(ndarray::array![[0.5, -1.], [2., 0.25]]).requires_grad();
+    let x = crate::from_ndarray(ndarray::array![[1., 2.], [3., 4.]]).requires_grad();
+    let b = crate::from_ndarray(ndarray::array![[0.1, -0.2], [0.3, 0.4]]).requires_grad();
+    let block = |(x, b): (crate::VarDiff<ndarray::Ix2>, crate::VarDiff<ndarray::Ix2>)| {
+        (x.mm(w.clone()) + b).tanh().mm(w.clone()).sigmoid()
+    };
+
+    let y = block((x.clone() * 2., b.clone())).sum();
+    y.forward();
+    y.backward(1.);
+    let (item, w_grad, x_grad, b_grad) = (
+        y.var.item(),
+        w.grad().clone(),
+        x.grad().clone(),
+        b.grad().clone(),
+    );
+    w.zero_grad();
+    x.zero_grad();
+    b.zero_grad();
+
+    let y = crate::checkpoint(block, (x.clone() * 2., b.clone())).sum();
+
+    assert_eq!(y.var.history.len(), 3);
+    assert_eq!(y.history.len(), 3);
+
+    y.forward();
+    y.backward(1.);
+    assert_eq!(y.var.item(), item);
+    assert_eq!(*w.grad(), w_grad);
+    assert_eq!(*x.grad(), x_grad);
+    assert_eq!(*b.grad(), b_grad);
+}
+
+#[test]
+fn compile() {
+    let w = crate::from_ndarray(ndarray::array![[0.5, -1.], [2., 0.25]]).requires_grad();
+    let x = crate::from_ndarray(ndarray::array![[1., 2.], [3., 4.]]).requires_grad();
+    let a = crate::from_ndarray(ndarray::array![[0.1, -0.2], [0.3, 0.4]]).requires_grad();
+    let b = crate::from_ndarray(ndarray::array![[-1., 0.5], [0.7, 0.]]);
+    let graph = || {
+        (x.clone().mm(w.clone()) * a.clone() + b.clone())
+            .sigmoid()
+            .tanh()
+    };
+
+    let y = graph().sum();
+    y.forward();
+    y.backward(1.);
+    let (item, w_grad, x_grad, a_grad) = (
+        y.var.item(),
+        w.grad().clone(),
+        x.grad().clone(),
+        a.grad().clone(),
+    );
+    w.zero_grad();
+    x.zero_grad();
+    a.zero_grad();
+
+    let y = graph().compile().sum();
+
+    assert_eq!(y.var.history.len(), 3);
+    assert_eq!(y.history.len(), 3);
+
+    y.forward();
+    y.backward(1.);
+    assert!((y.var.item() - item).abs() < 1e-6);
+    crate::utils::are_similar(w.grad(), &w_grad).unwrap();
+    crate::utils::are_similar(x.grad(), &x_grad).unwrap();
+    crate::utils::are_similar(a.grad(), &a_grad).unwrap();
+
+    *a.data_mut() *= 2.;
+    y.forward();
+    let expected = graph().sum();
+    expected.forward();
+    assert!((y.var.item() - expected.var.item()).abs() < 1e-6);
+}
+
+#[test]
+fn compile_keeps_shared() {
+    let x = crate::from_ndarray(ndarray::array![[1., 2.], [3., 4.]]).requires_grad();
+
+    // The result of the exponential is read twice.
+    let exp = x.clone().exp();
+    let y = (exp.clone().sigmoid() * exp).compile();
+    assert_eq!(y.var.history.len(), 2);
+    assert_eq!(y.history.len(), 2);
+
+    // The result of the exponential is owned by the user.
+    let exp = x.exp();
+    let y = exp.clone().sigmoid().compile();
+    assert_eq!(y.var.history.len(), 2);
+    assert_eq!(y.history.len(), 2);
+
+    y.forward();
+    crate::utils::are_similar(
+        exp.data(),
+        &ndarray::array![[1., 2.], [3., 4.]].mapv(f32::exp),
+    )
+    .unwrap();
+}
+
 // #[test]
 // fn convolve() {
 //     use crate::Convolve;
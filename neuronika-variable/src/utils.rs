@@ -1,4 +1,4 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, f64::consts::PI, rc::Rc};
 
 use ndarray::{
     Array, ArrayBase, ArrayViewD, ArrayViewMutD, Axis, Data, DataMut, DimMax, Dimension, Ix1, Ix2,
@@ -62,16 +62,22 @@ impl DotDim<Ix2> for Ix2 {
 ///
 /// * `shape` - shape of the input.
 ///
-/// * `padding` - padding around the input.
-pub(crate) fn padded_shape<D>(shape: D, padding: <D::Smaller as Dimension>::Smaller) -> D
+/// * `before` - padding before each spatial dimension of the input.
+///
+/// * `after` - padding after each spatial dimension of the input.
+pub(crate) fn padded_shape<D>(
+    shape: D,
+    before: <D::Smaller as Dimension>::Smaller,
+    after: <D::Smaller as Dimension>::Smaller,
+) -> D
 where
     D: Dimension,
 {
     let shape = shape.slice();
-    let padding = padding.slice();
+    let (before, after) = (before.slice(), after.slice());
 
     // Checks that the number of spatial dimension and input dimensions is the same.
-    assert!(shape.len() - 2 == padding.len());
+    assert!(shape.len() - 2 == before.len());
 
     let mut padded_input_shape = D::zeros(shape.len());
     padded_input_shape[0] = shape[0]; // Copy batch size.
@@ -81,12 +87,66 @@ where
         .iter_mut()
         .skip(2)
         .zip(shape.iter().skip(2))
-        .zip(padding.iter())
-        .for_each(|((padded_dim, original_dim), padding)| *padded_dim = original_dim + 2 * padding);
+        .zip(before.iter().zip(after))
+        .for_each(|((padded_dim, original_dim), (before, after))| {
+            *padded_dim = before + original_dim + after
+        });
 
     padded_input_shape
 }
 
+/// Computes the shape of a variable whose spatial dimensions are resized to `size`.
+pub(crate) fn interpolated_shape<D>(shape: D, size: <D::Smaller as Dimension>::Smaller) -> D
+where
+    D: Dimension,
+{
+    let size = size.slice();
+
+    // Checks that the number of spatial dimension and input dimensions is the same.
+    assert_eq!(
+        shape.ndim() - 2,
+        size.len(),
+        "Size {:?} doesn't match the spatial dimensions of shape {:?}",
+        size,
+        shape.slice()
+    );
+    assert!(
+        size.iter().all(|len| *len > 0),
+        "Size {:?} must be positive",
+        size
+    );
+
+    let mut interpolated_shape = shape;
+    interpolated_shape
+        .slice_mut()
+        .iter_mut()
+        .skip(2)
+        .zip(size)
+        .for_each(|(interpolated_dim, len)| *interpolated_dim = *len);
+
+    interpolated_shape
+}
+
+/// Computes the spatial size of a variable of shape `shape` upsampled by `scale_factor`.
+pub(crate) fn scaled_size<D>(shape: &D, scale_factor: f32) -> <D::Smaller as Dimension>::Smaller
+where
+    D: Dimension,
+{
+    assert!(
+        scale_factor > 0.,
+        "Scale factor {} must be positive",
+        scale_factor
+    );
+
+    let mut size = <D::Smaller as Dimension>::Smaller::zeros(shape.ndim() - 2);
+    size.slice_mut()
+        .iter_mut()
+        .zip(shape.slice().iter().skip(2))
+        .for_each(|(scaled_len, len)| *scaled_len = (*len as f32 * scale_factor).floor() as usize);
+
+    size
+}
+
 /// Computes the result of broadcasting between `left` and `right`.
 ///
 /// # Arguments
@@ -161,34 +221,98 @@ where
         return;
     }
 
-    // Computes the difference between the number of dimensions
-    let target_dims = target.ndim();
-    let source_shape = source.shape();
-    let k = source_shape.len() - target_dims;
-    let mut reshape = D::Larger::zeros(target_dims + 1);
-    reshape[0] = source_shape[..k].iter().product();
-    reshape
-        .slice_mut()
-        .iter_mut()
-        .skip(1)
-        .zip(source_shape.iter().skip(k))
-        .for_each(|(r, &s)| *r = s);
-
-    // Reshapes such that the sub-views match the dimensionality of target
-    let view = source.view().into_shape(reshape).unwrap();
-    let axis = Axis(target_dims - 1);
-    let mut lanes_v = view.lanes(axis).into_iter();
-    while lanes_v.len() > 0 {
-        for mut lane_t in target.lanes_mut(axis) {
-            if let Some(lane_v) = lanes_v.next() {
-                if lane_t.len() == 1 {
-                    lane_t[0] += lane_v.sum();
-                } else {
-                    lane_t += &lane_v;
-                }
-            }
+    // When the broadcasting only prepended axes, the sub-views of `source` along them have the
+    // same shape as `target` and can be summed into it without allocating.
+    let leading = source.ndim() - target.ndim();
+    if source.shape()[leading..] == *target.shape() {
+        accumulate_leading(target, source.view().into_dyn(), leading);
+        return;
+    }
+
+    // Otherwise sums away the axes prepended by the broadcasting...
+    let mut reduced = source.to_owned().into_dyn();
+    for _ in 0..leading {
+        reduced = reduced.sum_axis(Axis(0));
+    }
+
+    // ...and the ones stretched from length one.
+    for (axis, &len) in target.shape().iter().enumerate() {
+        if len == 1 && reduced.len_of(Axis(axis)) != 1 {
+            reduced = reduced.sum_axis(Axis(axis)).insert_axis(Axis(axis));
         }
     }
+
+    *target += &reduced.into_dimensionality::<D>().unwrap();
+}
+
+/// Accumulates into `target` all the sub-views of `source` obtained by fixing its first
+/// `leading` axes.
+fn accumulate_leading<D>(target: &mut Array<f32, D>, source: ArrayViewD<f32>, leading: usize)
+where
+    D: Dimension,
+{
+    if leading == 0 {
+        *target += &source.into_dimensionality::<D>().unwrap();
+    } else {
+        source
+            .outer_iter()
+            .for_each(|view| accumulate_leading(target, view, leading - 1));
+    }
+}
+
+/// Computes the digamma function, i.e. the logarithmic derivative of the gamma function.
+///
+/// Arguments smaller than one half are handled with the reflection formula, the others are
+/// shifted by recurrence to where the asymptotic expansion is accurate.
+pub(crate) fn digamma(x: f32) -> f32 {
+    let mut x = x as f64;
+    if x <= 0. && x.floor() == x {
+        return f32::NAN;
+    }
+
+    let mut result = 0.;
+    if x < 0.5 {
+        result -= PI / (PI * x).tan();
+        x = 1. - x;
+    }
+    while x < 6. {
+        result -= x.recip();
+        x += 1.;
+    }
+
+    let x2 = (x * x).recip();
+    result += x.ln()
+        - 0.5 / x
+        - x2 * (1. / 12. - x2 * (1. / 120. - x2 * (1. / 252. - x2 * (1. / 240. - x2 / 132.))));
+    result as f32
+}
+
+/// Computes the trigamma function, i.e. the derivative of the digamma function.
+///
+/// See [`digamma`] for the evaluation strategy.
+pub(crate) fn trigamma(x: f32) -> f32 {
+    let mut x = x as f64;
+    if x <= 0. && x.floor() == x {
+        return f32::NAN;
+    }
+
+    let (mut reflection, mut sign) = (0., 1.);
+    if x < 0.5 {
+        reflection = PI * PI / (PI * x).sin().powi(2);
+        sign = -1.;
+        x = 1. - x;
+    }
+
+    let mut series = 0.;
+    while x < 6. {
+        series += (x * x).recip();
+        x += 1.;
+    }
+
+    let x2 = (x * x).recip();
+    series +=
+        x.recip() + x2 / 2. + x2 / x * (1. / 6. - x2 * (1. / 30. - x2 * (1. / 42. - x2 / 30.)));
+    (reflection + sign * series) as f32
 }
 
 /// Computes the shape of the array resulting from the **n**-dimensional convolution
@@ -235,6 +359,54 @@ where
     );
     output_map_shape
 }
+
+/// Computes the shape of the array resulting from the **n**-dimensional transposed convolution
+/// performed with the given parameters.
+///
+/// # Arguments
+///
+/// * `input_shape` - the shape of the input.
+///
+/// * `kernel_shape` - the shape of the kernel.
+///
+/// * `stride` - the stride.
+///
+/// * `dilation` - the dilation.
+///
+/// * `output_padding` - the size added to one side of each spatial dimension of the output.
+///
+/// * `groups` - the number of groups.
+pub(crate) fn conv_transpose_out_shape<D>(
+    input_shape: &[usize],
+    kernel_shape: &[usize],
+    stride: &[usize],
+    dilation: &[usize],
+    output_padding: &[usize],
+    groups: usize,
+) -> D
+where
+    D: Dimension,
+{
+    let mut output_map_shape = D::zeros(input_shape.len());
+    output_map_shape[0] = input_shape[0];
+    // The kernel is laid out as (Cin, Cout / groups, ...).
+    output_map_shape[1] = kernel_shape[1] * groups;
+    itertools::izip!(
+        output_map_shape.slice_mut().iter_mut().skip(2),
+        input_shape.iter().skip(2),
+        kernel_shape.iter().skip(2),
+        stride,
+        dilation,
+        output_padding
+    )
+    .for_each(
+        |(output_map_dim, input_dim, kernel_dim, stride, dilation, output_padding)| {
+            *output_map_dim =
+                (input_dim - 1) * stride + dilation * (kernel_dim - 1) + 1 + output_padding
+        },
+    );
+    output_map_shape
+}
 /// Computes the shape of a rolling window view.
 ///
 /// # Arguments
@@ -496,6 +668,68 @@ pub(crate) fn check_groups_args(input_shape: &[usize], kernel_shape: &[usize], g
     );
 }
 
+/// Checks that the arguments are correct for the given **transposed convolution**. It verifies
+/// that the `stride`, `dilation` and `output_padding` slices are of the right length, that
+/// `kernel` and `input` are of the same dimension and that the kernel has one filter for each
+/// input channel. The output padding must be smaller than the stride.
+pub(crate) fn check_conv_transpose_args(
+    input_shape: &[usize],
+    kernel_shape: &[usize],
+    stride: &[usize],
+    dilation: &[usize],
+    output_padding: &[usize],
+) {
+    let convolution_dimension = input_shape.len() - 2;
+
+    assert_eq!(
+        convolution_dimension,
+        stride.len(),
+        "Invalid stride {:?} for {}d conv.",
+        stride,
+        convolution_dimension
+    );
+
+    assert_eq!(
+        convolution_dimension,
+        dilation.len(),
+        "Invalid dilation {:?} for {}d conv.",
+        dilation,
+        convolution_dimension
+    );
+
+    assert_eq!(
+        convolution_dimension,
+        output_padding.len(),
+        "Invalid output padding {:?} for {}d conv.",
+        output_padding,
+        convolution_dimension
+    );
+
+    assert_eq!(
+        kernel_shape.len(),
+        input_shape.len(),
+        "Invalid kernel shape {:?} for {}d conv",
+        &kernel_shape,
+        convolution_dimension
+    );
+
+    assert_eq!(
+        kernel_shape[0], input_shape[1],
+        "Kernel filters {} don't match in channels {}",
+        kernel_shape[0], input_shape[1]
+    );
+
+    output_padding
+        .iter()
+        .zip(stride)
+        .for_each(|(output_padding_dim, stride_dim)| {
+            assert!(
+                output_padding_dim < stride_dim,
+                "The output padding must be smaller than the stride."
+            )
+        });
+}
+
 #[cfg(test)]
 pub(crate) const F16_EPSILON: f32 = 4.88e-04;
 
@@ -11,16 +11,21 @@ use ndarray::{
 };
 
 use crate::{
-    autograd::Forward,
+    autograd::{Buffer, Forward},
+    fusion,
     gradient::{BufferedGradient, Gradient},
     history::History,
     node::{self, *},
+    onnx::{OnnxError, OnnxExporter},
+    planner,
     utils::{
-        check_conv_args, check_groups_args, cobroadcasted_zeros, conv_out_shape, padded_shape,
+        check_conv_args, check_conv_transpose_args, check_groups_args, cobroadcasted_zeros,
+        conv_out_shape, conv_transpose_out_shape, interpolated_shape, padded_shape, scaled_size,
         DotDim, Shared,
     },
     vardiff::VarDiff,
-    Cat, Convolution, MatMatMul, MatMatMulT, MatVecMul, Reduction, Stack, VecMatMul, VecVecMul,
+    Cat, Convolution, ConvolutionTranspose, MatMatMul, MatMatMulT, MatVecMul, Maximum, Minimum,
+    Reduction, Select, Stack, VecMatMul, VecVecMul,
 };
 
 /// A non-differentiable variable.
@@ -44,10 +49,11 @@ where
     D: Dimension,
 {
     pub(crate) fn leaf(array: Array<f32, D>) -> Self {
-        Self {
-            data: Rc::new(RefCell::new(array)),
-            history: History::default(),
-        }
+        let data = Rc::new(RefCell::new(array));
+        let mut history = History::default();
+        history.track(data.address());
+
+        Self { data, history }
     }
 
     pub(crate) fn node(
@@ -56,6 +62,7 @@ where
         mut history: History<(Rc<dyn Forward>, Cell<bool>)>,
     ) -> Self {
         history.insert(Rc::as_ptr(&op) as *const () as usize, (op, Cell::default()));
+        history.track(data.address());
 
         Self { data, history }
     }
@@ -72,7 +79,11 @@ where
     ///
     /// At the variable's creation the data is filled with zeros. You can populate it with a
     /// call to [`.forward()`](Var::forward()).
+    ///
+    /// Each call marks the data as modified, so that the next [`.forward()`](Var::forward()) of
+    /// any variable depending on `self` recomputes the affected part of the graph.
     pub fn data_mut(&self) -> RefMut<Array<f32, D>> {
+        self.history.bump(self.data.address());
         self.data.borrow_mut()
     }
 
@@ -100,22 +111,40 @@ where
     ///
     /// let x_diff = x.requires_grad();
     ///```
-    pub fn requires_grad(self) -> VarDiff<D> {
+    ///
+    /// The gradient of the new leaf is recorded in the graph's history, where it is type-erased so
+    /// that fused operations can look it up. This is why `D` must be `'static`, which is the case
+    /// for all of ndarray's dimension types.
+    pub fn requires_grad(self) -> VarDiff<D>
+    where
+        D: 'static,
+    {
         let grad = Array::zeros(self.data.borrow().raw_dim());
         VarDiff::leaf(self, grad)
     }
 
     /// Propagates the computations forwards and populates all the variables from the leaves of the
     /// graph to `self`.
+    ///
+    /// Only the first call evaluates the whole graph. The following ones evaluate just the
+    /// operations that depend on variables modified through [`.data_mut()`](Var::data_mut()) in
+    /// the meantime, together with those drawing random numbers, such as dropout.
     pub fn forward(&self) {
         let mut buffer = self.history.buffer_mut(); // Borrows for the scope
+        let modified = self.history.modified();
 
         // If the length of the buffer is greater than 0 it means that forward has already been
-        // called and the path must be recomputed, else the buffer is empty and must be populated.
+        // called and only the operations downstream of the modified variables must be recomputed,
+        // else the buffer is empty and must be populated.
         if buffer.is_empty() {
             *buffer = self.history.to_vec()
         } else {
-            buffer.iter().for_each(|(_, computed)| computed.set(false));
+            planner::invalidate(&buffer, modified, &self.history.released_mut());
+        }
+
+        if self.history.is_planned() {
+            planner::forward(&buffer, &mut self.history.released_mut());
+            return;
         }
 
         buffer
@@ -126,6 +155,60 @@ where
                 computed.set(true)
             });
     }
+
+    /// Enables the memory planner for the computational graph of `self`.
+    ///
+    /// Once enabled, [`.forward()`](Var::forward()) releases the data of each intermediate
+    /// variable as soon as its last consumer has been computed, and recycles the memory for the
+    /// results computed afterwards. The data of the variables that are still owned and the data
+    /// needed by the backward pass are always kept.
+    ///
+    /// The planner is inherited by all the variables built upon `self`.
+    pub fn plan_memory(&self) {
+        self.history.plan()
+    }
+
+    /// Fuses the chains of element-wise operations of the computational graph of `self`, such as
+    /// additions, multiplications and activation functions, so that each of them is evaluated in a
+    /// single loop over the data.
+    ///
+    /// Only the operations whose results are consumed exactly once, by the next operation of the
+    /// chain, and whose operands all have the same shape are fused. Their intermediate results are
+    /// no longer stored, the variables built upon the returned one will compute the same values
+    /// with fewer passes over memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use neuronika_variable as neuronika;
+    /// let x = neuronika::ones(3);
+    /// let y = (x.clone() * 2. + x).exp().tanh().compile();
+    ///
+    /// y.forward();
+    /// assert!((y.data()[0] - 3_f32.exp().tanh()).abs() < 1e-6);
+    /// ```
+    pub fn compile(mut self) -> Self {
+        fusion::compile(&mut self.history, self.data.address(), None);
+        self
+    }
+
+    /// Exports the computational graph of `self` as an ONNX model, encoded in the protocol buffer
+    /// format. See [`OnnxExporter`] for more details.
+    ///
+    /// The graph must be exported before being compiled, as fused operations have no ONNX
+    /// counterpart.
+    pub fn to_onnx(&self, exporter: &OnnxExporter) -> Result<Vec<u8>, OnnxError> {
+        let operations = self.history.to_vec();
+        exporter.export(&self.data, operations.iter().map(|(op, _)| op))
+    }
+
+    /// Panics if the data of `self` cannot be overwritten by an in-place operation.
+    fn assert_overwritable(&self) {
+        assert!(
+            planner::is_overwritable(&self.data, &self.history.to_vec()),
+            "error: cannot operate in-place on a leaf or on a variable whose data is used elsewhere."
+        )
+    }
 }
 
 impl Var<Ix0> {
@@ -227,6 +310,20 @@ where
         Var::node(data, Rc::new(op), self.history)
     }
 
+    /// Takes the power of each element in `self` with the real exponent `exp` and returns a
+    /// variable with the result.
+    ///
+    /// # Arguments
+    ///
+    /// `exp` - exponent.
+    pub fn powf(self, exp: f32) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = PowF::new(self.data, data.clone(), exp);
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
     /// Takes the square root element-wise and returns a variable with the result.
     pub fn sqrt(self) -> Var<D> {
         let shape = self.data.borrow().raw_dim();
@@ -248,14 +345,159 @@ where
         Var::node(data, Rc::new(op), self.history)
     }
 
+    /// Applies the *rectified linear unit* element-wise, overwriting the data of `self`, and
+    /// returns a variable with the result.
+    ///
+    /// *ReLU(x) = max(0, x)*
+    ///
+    /// # Panics
+    ///
+    /// If `self` is a leaf or its data is used elsewhere.
+    pub fn relu_(self) -> Var<D> {
+        self.assert_overwritable();
+        let op = ReLU::new(self.data.clone(), self.data.clone());
+
+        Var::node(self.data, Rc::new(op), self.history)
+    }
+
     /// Applies the *leaky rectified linear unit* element-wise and returns a variable with
     /// the result.
     ///
     /// *LeakyReLU(x) = max(0, x) + 0.01 * min(0, x)*
     pub fn leaky_relu(self) -> Var<D> {
+        self.leaky_relu_with_slope(0.01)
+    }
+
+    /// Applies the *leaky rectified linear unit* with a custom negative slope element-wise and
+    /// returns a variable with the result.
+    ///
+    /// *LeakyReLU(x) = max(0, x) + slope * min(0, x)*
+    ///
+    /// # Arguments
+    ///
+    /// * `slope` - slope of the function for negative inputs.
+    pub fn leaky_relu_with_slope(self, slope: f32) -> Var<D> {
         let shape = self.data.borrow().raw_dim();
         let data = Rc::new(RefCell::new(Array::zeros(shape)));
-        let op = LeakyReLU::new(self.data, data.clone());
+        let op = LeakyReLU::new(self.data, data.clone(), slope);
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *Gaussian error linear unit* element-wise and returns a variable with the
+    /// result.
+    ///
+    /// *GELU(x) = x * Φ(x)*, where *Φ* is the cumulative distribution function of the standard
+    /// normal distribution.
+    ///
+    /// # Arguments
+    ///
+    /// * `approximate` - if `true`, *Φ* is estimated with the tanh approximation *0.5 * (1 +
+    /// tanh(sqrt(2 / π) * (x + 0.044715 * x^3)))*.
+    pub fn gelu(self, approximate: bool) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = GELU::new(self.data, data.clone(), approximate);
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *sigmoid linear unit*, also known as *swish*, element-wise and returns a
+    /// variable with the result.
+    ///
+    /// *SiLU(x) = x * sigmoid(x)*
+    pub fn silu(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = SiLU::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *exponential linear unit* element-wise and returns a variable with the result.
+    ///
+    /// *ELU(x) = max(0, x) + min(0, alpha * (exp(x) - 1))*
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - value the function saturates to for negative inputs.
+    pub fn elu(self, alpha: f32) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = ELU::new(self.data, data.clone(), alpha);
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *continuously differentiable exponential linear unit* element-wise and returns a
+    /// variable with the result.
+    ///
+    /// *CELU(x) = max(0, x) + min(0, alpha * (exp(x / alpha) - 1))*
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - value the function saturates to for negative inputs.
+    pub fn celu(self, alpha: f32) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = CELU::new(self.data, data.clone(), alpha);
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *scaled exponential linear unit* element-wise and returns a variable with the
+    /// result.
+    ///
+    /// *SELU(x) = scale * (max(0, x) + min(0, alpha * (exp(x) - 1)))*, with *alpha ≈ 1.6733* and
+    /// *scale ≈ 1.0507*.
+    pub fn selu(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = SELU::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *mish* element-wise and returns a variable with the result.
+    ///
+    /// *Mish(x) = x * tanh(softplus(x))*
+    pub fn mish(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Mish::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *hard swish* element-wise and returns a variable with the result.
+    ///
+    /// *HardSwish(x) = x * min(max(0, x + 3), 6) / 6*
+    pub fn hardswish(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = HardSwish::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *hard sigmoid* element-wise and returns a variable with the result.
+    ///
+    /// *HardSigmoid(x) = min(max(0, x + 3), 6) / 6*
+    pub fn hardsigmoid(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = HardSigmoid::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *softsign* element-wise and returns a variable with the result.
+    ///
+    /// *SoftSign(x) = x / (1 + |x|)*
+    pub fn softsign(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = SoftSign::new(self.data, data.clone());
 
         Var::node(data, Rc::new(op), self.history)
     }
@@ -280,6 +522,19 @@ where
         Var::node(data, Rc::new(op), self.history)
     }
 
+    /// Applies the *sigmoid* element-wise, overwriting the data of `self`, and returns a variable
+    /// with the result.
+    ///
+    /// # Panics
+    ///
+    /// If `self` is a leaf or its data is used elsewhere.
+    pub fn sigmoid_(self) -> Var<D> {
+        self.assert_overwritable();
+        let op = Sigmoid::new(self.data.clone(), self.data.clone());
+
+        Var::node(self.data, Rc::new(op), self.history)
+    }
+
     /// Applies the *tanh* element-wise and returns a variable with the result.
     pub fn tanh(self) -> Var<D> {
         let shape = self.data.borrow().raw_dim();
@@ -307,6 +562,219 @@ where
         Var::node(data, Rc::new(op), self.history)
     }
 
+    /// Applies the *sine* element-wise and returns a variable with the result.
+    pub fn sin(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Sin::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *cosine* element-wise and returns a variable with the result.
+    pub fn cos(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Cos::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *tangent* element-wise and returns a variable with the result.
+    pub fn tan(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Tan::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *arcsine* element-wise and returns a variable with the result.
+    pub fn asin(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Asin::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *arctangent* element-wise and returns a variable with the result.
+    pub fn atan(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Atan::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *hyperbolic sine* element-wise and returns a variable with the result.
+    pub fn sinh(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = SinH::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *hyperbolic cosine* element-wise and returns a variable with the result.
+    pub fn cosh(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = CosH::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *natural logarithm of one plus the input* element-wise and returns a variable
+    /// with the result.
+    ///
+    /// *log1p(x) = ln(1 + x)*
+    pub fn log1p(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Log1p::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *exponential minus one* element-wise and returns a variable with the result.
+    ///
+    /// *expm1(x) = exp(x) - 1*
+    pub fn expm1(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Expm1::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *base 2 logarithm* element-wise and returns a variable with the result.
+    pub fn log2(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Log2::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *reciprocal of the square root* element-wise and returns a variable with the
+    /// result.
+    ///
+    /// *rsqrt(x) = 1 / sqrt(x)*
+    pub fn rsqrt(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Rsqrt::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *error function* element-wise and returns a variable with the result.
+    pub fn erf(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Erf::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *inverse error function* element-wise and returns a variable with the result.
+    pub fn erfinv(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Erfinv::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *natural logarithm of the absolute value of the gamma function* element-wise and
+    /// returns a variable with the result.
+    pub fn lgamma(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = LGamma::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Applies the *digamma function* element-wise and returns a variable with the result.
+    ///
+    /// *digamma(x) = d/dx ln(gamma(x))*
+    pub fn digamma(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Digamma::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Takes the absolute value element-wise and returns a variable with the result.
+    pub fn abs(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Abs::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Takes the sign element-wise and returns a variable with the result.
+    ///
+    /// The sign of zero is zero.
+    pub fn sign(self) -> Var<D> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Sign::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Clamps all elements in `self` into the range *[min, max]* and returns a variable with the
+    /// result.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - lower bound of the range.
+    ///
+    /// * `max` - upper bound of the range.
+    ///
+    /// # Panics
+    ///
+    /// If `min` is greater than `max`.
+    pub fn clamp(self, min: f32, max: f32) -> Var<D> {
+        assert!(
+            min <= max,
+            "error: invalid clamp range, min {} is greater than max {}.",
+            min,
+            max
+        );
+
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Clamp::new(self.data, data.clone(), min, max);
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Computes the element-wise maximum between `self` and `rhs`.
+    ///
+    /// The two variables are broadcast together.
+    pub fn maximum<Rhs>(self, rhs: Rhs) -> <Self as Maximum<Rhs>>::Output
+    where
+        Self: Maximum<Rhs>,
+    {
+        Maximum::maximum(self, rhs)
+    }
+
+    /// Computes the element-wise minimum between `self` and `rhs`.
+    ///
+    /// The two variables are broadcast together.
+    pub fn minimum<Rhs>(self, rhs: Rhs) -> <Self as Minimum<Rhs>>::Output
+    where
+        Self: Minimum<Rhs>,
+    {
+        Minimum::minimum(self, rhs)
+    }
+
     /// Applies the *softmax* to `self` and returns a variable with the result.
     ///
     /// The *softmax* is applied to all slices along `axis`, and will re-scale them so
@@ -421,11 +889,40 @@ where
     ///
     /// # Arguments
     ///
-    /// `axis` - dimension to insert the new axis at.
-    pub fn unsqueeze(self, axis: usize) -> Var<D::Larger> {
-        let shape = self.data.borrow().raw_dim();
-        let data = Rc::new(RefCell::new(Array::zeros(shape.insert_axis(Axis(axis)))));
-        let op = Unsqueeze::new(self.data, data.clone());
+    /// `axis` - dimension to insert the new axis at.
+    pub fn unsqueeze(self, axis: usize) -> Var<D::Larger> {
+        let shape = self.data.borrow().raw_dim();
+        let data = Rc::new(RefCell::new(Array::zeros(shape.insert_axis(Axis(axis)))));
+        let op = Unsqueeze::new(self.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+
+    /// Returns a new variable holding the elements of `self`, in row-major order, arranged in the
+    /// given shape. The result may have a different number of dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// `shape` - shape of the result.
+    ///
+    /// # Panics
+    ///
+    /// If the number of elements of `shape` differs from that of the variable.
+    pub fn reshape<E>(self, shape: E) -> Var<E::Dim>
+    where
+        E: IntoDimension,
+        E::Dim: 'static,
+    {
+        let shape = shape.into_dimension();
+        assert_eq!(
+            shape.size(),
+            self.data.borrow().len(),
+            "error: cannot reshape a variable of shape {:?} into {:?}.",
+            self.data.borrow().shape(),
+            shape.slice()
+        );
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Reshape::new(self.data, data.clone());
 
         Var::node(data, Rc::new(op), self.history)
     }
@@ -714,6 +1211,64 @@ where
     {
         Convolution::convolution::<T>(self, input, stride, dilation, groups)
     }
+
+    /// Applies a transposed convolution over an input signal composed of several planes, also
+    /// known as fractionally-strided convolution. It is the gradient of
+    /// [`.convolution()`](Var::convolution()) with respect to its input.
+    ///
+    /// ## 1-dimensional transposed convolution
+    ///
+    /// The input must be of shape (N, Cin, L), `self` must be of shape (Cin, Cout / groups, Lk) and
+    /// the resulting output shape will be (N, Cout, Lout).
+    ///
+    /// ## 2-dimensional transposed convolution
+    ///
+    /// The input must be of shape (N, Cin, H, W), `self` must be of shape
+    /// (Cin, Cout / groups, Hk, Wk) and the resulting output shape will be (N, Cout, Hout, Wout).
+    ///
+    /// ## 3-dimensional transposed convolution
+    ///
+    /// The input must be of shape (N, Cin, D, H, W), `self` must be of shape
+    /// (Cin, Cout / groups, Dk, Hk, Wk) and the resulting output shape will be
+    /// (N, Cout, Dout, Hout, Wout).
+    ///
+    /// Each spatial dimension of the output is computed as
+    /// `(input - 1) * stride + dilation * (kernel - 1) + 1 + output_padding`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - input variable.
+    ///
+    /// * `stride` - stride of the corresponding convolution.
+    ///
+    /// * `dilation` - dilation of the corresponding convolution.
+    ///
+    /// * `output_padding` - additional size added to one side of each spatial dimension of the
+    /// output. It must be smaller than the stride.
+    ///
+    /// * `groups` - controls the connection between inputs and outputs. The channels in the input
+    /// must be divisible by `groups`.
+    pub fn convolution_transpose<Rhs, T>(
+        self,
+        input: Rhs,
+        stride: T,
+        dilation: T,
+        output_padding: T,
+        groups: usize,
+    ) -> <Self as ConvolutionTranspose<Rhs, <D::Smaller as Dimension>::Smaller>>::Output
+    where
+        Self: ConvolutionTranspose<Rhs, <D::Smaller as Dimension>::Smaller>,
+        T: Copy + IntoDimension<Dim = <D::Smaller as Dimension>::Smaller>,
+    {
+        ConvolutionTranspose::convolution_transpose::<T>(
+            self,
+            input,
+            stride,
+            dilation,
+            output_padding,
+            groups,
+        )
+    }
 }
 
 impl<D> Var<D>
@@ -729,12 +1284,63 @@ where
         E: IntoDimension<Dim = <D::Smaller as Dimension>::Smaller>,
     {
         let padding = padding.into_dimension();
-        let shape = padded_shape(self.data().raw_dim(), padding);
+        self.pad_asymmetric(padding, padding, mode)
+    }
+
+    /// Applies distinct amounts of padding before and after each spatial dimension of the
+    /// variable.
+    pub fn pad_asymmetric<T, E>(self, before: E, after: E, mode: T) -> Var<D>
+    where
+        T: 'static + PaddingMode<D>,
+        E: IntoDimension<Dim = <D::Smaller as Dimension>::Smaller>,
+    {
+        let before = before.into_dimension();
+        let shape = padded_shape(self.data().raw_dim(), before, after.into_dimension());
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        let op = Rc::new(Pad::new(self.data, data.clone(), mode, before));
+
+        Var::node(data, op, self.history)
+    }
+
+    /// Resizes the spatial dimensions of the variable to `size`, interpolating its values with the
+    /// specified [`Interpolation`] mode.
+    ///
+    /// When `align_corners` is `true` the centers of the corner elements of the variable and of the
+    /// result are aligned, and the values of the corners are preserved. Otherwise their outer edges
+    /// are aligned. This option doesn't affect [`Interpolation::Nearest`].
+    ///
+    /// # Panics
+    ///
+    /// If `size` doesn't match the number of spatial dimensions of the variable or if any of its
+    /// entries is zero.
+    pub fn interpolate<E>(self, size: E, mode: Interpolation, align_corners: bool) -> Var<D>
+    where
+        E: IntoDimension<Dim = <D::Smaller as Dimension>::Smaller>,
+    {
+        let shape = interpolated_shape(self.data().raw_dim(), size.into_dimension());
         let data = Rc::new(RefCell::new(Array::zeros(shape)));
-        let op = Rc::new(Pad::new(self.data, data.clone(), mode, padding));
+        let op = Rc::new(Interpolate::new(
+            self.data,
+            data.clone(),
+            mode,
+            align_corners,
+        ));
 
         Var::node(data, op, self.history)
     }
+
+    /// Upsamples the spatial dimensions of the variable by `scale_factor`. The length of each of
+    /// them is multiplied by `scale_factor` and rounded down.
+    ///
+    /// See [`.interpolate()`](Var::interpolate()) for more details.
+    ///
+    /// # Panics
+    ///
+    /// If `scale_factor` isn't positive or if it shrinks any spatial dimension to zero.
+    pub fn upsample(self, scale_factor: f32, mode: Interpolation, align_corners: bool) -> Var<D> {
+        let size = scaled_size(&self.data().raw_dim(), scale_factor);
+        self.interpolate(size, mode, align_corners)
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -1084,7 +1690,7 @@ impl MatMatMulT<VarDiff<Ix2>> for Var<Ix2> {
     fn mm_t(self, rhs: VarDiff<Ix2>) -> Self::Output {
         let grad = Rc::new(Gradient::ndarray_zeros(DotDim::shape(
             self.data.borrow().raw_dim(),
-            rhs.var.data().raw_dim(),
+            rhs.var.data().t().raw_dim(),
         )));
         let op = MatrixMatrixMulTBackwardRight::new(self.data.clone(), rhs.grad, grad.clone());
         let var = self.mm_t(rhs.var);
@@ -1299,7 +1905,13 @@ where
 {
     type Output = Var<D>;
 
-    fn convolution<T>(self, input: Var<D>, stride: T, dilation: T, groups: usize) -> Self::Output
+    fn convolution<T>(
+        mut self,
+        input: Var<D>,
+        stride: T,
+        dilation: T,
+        groups: usize,
+    ) -> Self::Output
     where
         T: IntoDimension<Dim = <D::Smaller as Dimension>::Smaller>,
     {
@@ -1322,6 +1934,7 @@ where
             conv_out_shape(input_shape, kernel_shape, stride_slice, dilation_slice)
         };
         let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        self.history.merge(input.history);
         let op = node::Convolution::new(
             input.data,
             self.data,
@@ -1370,6 +1983,247 @@ where
     }
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Transposed Convolution ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<D> ConvolutionTranspose<Var<D>, <D::Smaller as Dimension>::Smaller> for Var<D>
+where
+    D: 'static + Dimension + RemoveAxis,
+{
+    type Output = Var<D>;
+
+    fn convolution_transpose<T>(
+        mut self,
+        input: Var<D>,
+        stride: T,
+        dilation: T,
+        output_padding: T,
+        groups: usize,
+    ) -> Self::Output
+    where
+        T: IntoDimension<Dim = <D::Smaller as Dimension>::Smaller>,
+    {
+        let stride = stride.into_dimension();
+        let dilation = dilation.into_dimension();
+        let output_padding = output_padding.into_dimension();
+
+        let shape: D = {
+            let input_data = input.data();
+            let kernel_data = self.data();
+
+            let input_shape = input_data.shape();
+            let kernel_shape = kernel_data.shape();
+
+            check_conv_transpose_args(
+                input_shape,
+                kernel_shape,
+                stride.slice(),
+                dilation.slice(),
+                output_padding.slice(),
+            );
+            check_groups_args(input_shape, kernel_shape, groups);
+
+            conv_transpose_out_shape(
+                input_shape,
+                kernel_shape,
+                stride.slice(),
+                dilation.slice(),
+                output_padding.slice(),
+                groups,
+            )
+        };
+        let data = Rc::new(RefCell::new(Array::zeros(shape)));
+        self.history.merge(input.history);
+        let op = node::ConvolutionTranspose::new(
+            input.data,
+            self.data,
+            stride,
+            dilation,
+            groups,
+            data.clone(),
+        );
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+}
+
+impl<D> ConvolutionTranspose<VarDiff<D>, <D::Smaller as Dimension>::Smaller> for Var<D>
+where
+    D: 'static + Dimension + RemoveAxis,
+{
+    type Output = VarDiff<D>;
+
+    fn convolution_transpose<T>(
+        self,
+        input: VarDiff<D>,
+        stride: T,
+        dilation: T,
+        output_padding: T,
+        groups: usize,
+    ) -> Self::Output
+    where
+        T: IntoDimension<Dim = <D::Smaller as Dimension>::Smaller> + Copy,
+    {
+        let kernel_data = self.data.clone();
+        let var = self.convolution_transpose(input.var, stride, dilation, output_padding, groups);
+        let shape = var.data().raw_dim();
+        let grad = Rc::new(Gradient::ndarray_zeros(shape));
+        let op = ConvolutionTransposeBackwardInput::new(
+            kernel_data,
+            input.grad,
+            grad.clone(),
+            stride.into_dimension(),
+            dilation.into_dimension(),
+            groups,
+        );
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), input.history)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Comparison Operations Implementations ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Maximum ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<D, E> Maximum<Var<E>> for Var<D>
+where
+    D: 'static + Dimension + DimMax<E>,
+    E: 'static + Dimension,
+{
+    type Output = Var<<D as DimMax<E>>::Output>;
+
+    fn maximum(mut self, rhs: Var<E>) -> Self::Output {
+        self.history.merge(rhs.history);
+
+        let data = Rc::new(RefCell::new(cobroadcasted_zeros(
+            &self.data.borrow(),
+            &rhs.data.borrow(),
+        )));
+        let op = node::Maximum::new(self.data, rhs.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+}
+
+impl<D, E> Maximum<VarDiff<E>> for Var<D>
+where
+    D: 'static + Dimension + DimMax<E>,
+    E: 'static + Dimension,
+{
+    type Output = VarDiff<<D as DimMax<E>>::Output>;
+
+    fn maximum(self, rhs: VarDiff<E>) -> Self::Output {
+        let grad = Rc::new(Gradient::from_ndarray(cobroadcasted_zeros(
+            &self.data.borrow(),
+            &rhs.var.data.borrow(),
+        )));
+        let buff = Rc::new(BufferedGradient::from_ndarray(grad.clone()));
+        let op = MaximumBackwardRight::new(
+            self.data.clone(),
+            rhs.var.data.clone(),
+            rhs.grad,
+            buff.clone(),
+        );
+        let var = Maximum::maximum(self, rhs.var);
+
+        VarDiff::node(var, grad, (Rc::new(op), buff), rhs.history)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Minimum ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<D, E> Minimum<Var<E>> for Var<D>
+where
+    D: 'static + Dimension + DimMax<E>,
+    E: 'static + Dimension,
+{
+    type Output = Var<<D as DimMax<E>>::Output>;
+
+    fn minimum(mut self, rhs: Var<E>) -> Self::Output {
+        self.history.merge(rhs.history);
+
+        let data = Rc::new(RefCell::new(cobroadcasted_zeros(
+            &self.data.borrow(),
+            &rhs.data.borrow(),
+        )));
+        let op = node::Minimum::new(self.data, rhs.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+}
+
+impl<D, E> Minimum<VarDiff<E>> for Var<D>
+where
+    D: 'static + Dimension + DimMax<E>,
+    E: 'static + Dimension,
+{
+    type Output = VarDiff<<D as DimMax<E>>::Output>;
+
+    fn minimum(self, rhs: VarDiff<E>) -> Self::Output {
+        let grad = Rc::new(Gradient::from_ndarray(cobroadcasted_zeros(
+            &self.data.borrow(),
+            &rhs.var.data.borrow(),
+        )));
+        let buff = Rc::new(BufferedGradient::from_ndarray(grad.clone()));
+        let op = MinimumBackwardRight::new(
+            self.data.clone(),
+            rhs.var.data.clone(),
+            rhs.grad,
+            buff.clone(),
+        );
+        let var = Minimum::minimum(self, rhs.var);
+
+        VarDiff::node(var, grad, (Rc::new(op), buff), rhs.history)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Select ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<D, E, M> Select<Var<E>, Var<M>> for Var<D>
+where
+    D: 'static + Dimension + DimMax<E>,
+    E: 'static + Dimension,
+    M: 'static + Dimension,
+{
+    type Output = Var<<D as DimMax<E>>::Output>;
+
+    fn select(mut self, rhs: Var<E>, condition: Var<M>) -> Self::Output {
+        self.history.merge(rhs.history);
+        self.history.merge(condition.history);
+
+        let data = Rc::new(RefCell::new(cobroadcasted_zeros(
+            &self.data.borrow(),
+            &rhs.data.borrow(),
+        )));
+        let op = node::Select::new(self.data, rhs.data, condition.data, data.clone());
+
+        Var::node(data, Rc::new(op), self.history)
+    }
+}
+
+impl<D, E, M> Select<VarDiff<E>, Var<M>> for Var<D>
+where
+    D: 'static + Dimension + DimMax<E>,
+    E: 'static + Dimension,
+    M: 'static + Dimension,
+{
+    type Output = VarDiff<<D as DimMax<E>>::Output>;
+
+    fn select(self, rhs: VarDiff<E>, condition: Var<M>) -> Self::Output {
+        let grad = Rc::new(Gradient::from_ndarray(cobroadcasted_zeros(
+            &self.data.borrow(),
+            &rhs.var.data.borrow(),
+        )));
+        let buff = Rc::new(BufferedGradient::from_ndarray(grad.clone()));
+        let op =
+            SelectBackwardRight::<D, E, M>::new(condition.data.clone(), rhs.grad, buff.clone());
+        let var = Select::select(self, rhs.var, condition);
+
+        VarDiff::node(var, grad, (Rc::new(op), buff), rhs.history)
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Debug ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 impl<D> Debug for Var<D>
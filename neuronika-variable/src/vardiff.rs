@@ -11,13 +11,16 @@ use ndarray::{
 };
 
 use crate::{
-    autograd::Backward,
+    autograd::{Backward, Buffer},
+    fusion,
     gradient::{BufferedGradient, Gradient, NoGrad},
     history::History,
     node::*,
-    utils::{cobroadcasted_zeros, DotDim},
+    onnx::{OnnxError, OnnxExporter},
+    utils::{cobroadcasted_zeros, scaled_size, DotDim},
     var::Var,
-    Cat, Convolution, MatMatMul, MatMatMulT, MatVecMul, Reduction, Stack, VecMatMul, VecVecMul,
+    Cat, Checkpointable, Convolution, ConvolutionTranspose, MatMatMul, MatMatMulT, MatVecMul,
+    Maximum, Minimum, Reduction, Select, Stack, VecMatMul, VecVecMul,
 };
 
 /// A differentiable variable.
@@ -45,12 +48,15 @@ impl<D> VarDiff<D>
 where
     D: Dimension,
 {
-    pub(crate) fn leaf(var: Var<D>, array: Array<f32, D>) -> Self {
-        Self {
-            var,
-            grad: Rc::new(Gradient::from_ndarray(array)),
-            history: History::default(),
-        }
+    pub(crate) fn leaf(var: Var<D>, array: Array<f32, D>) -> Self
+    where
+        D: 'static,
+    {
+        let grad = Rc::new(Gradient::from_ndarray(array));
+        let mut history = History::default();
+        history.register(var.data.address(), grad.clone(), None);
+
+        Self { var, grad, history }
     }
 
     pub(crate) fn node(
@@ -58,8 +64,13 @@ where
         grad: Rc<Gradient<Array<f32, D>, D>>,
         op: (Rc<dyn Backward>, Rc<dyn NoGrad>),
         mut history: History<(Rc<dyn Backward>, Rc<dyn NoGrad>)>,
-    ) -> VarDiff<D> {
-        history.insert(Rc::as_ptr(&op.0) as *const () as usize, op);
+    ) -> VarDiff<D>
+    where
+        D: 'static,
+    {
+        let ptr = Rc::as_ptr(&op.0) as *const () as usize;
+        history.insert(ptr, op);
+        history.register(var.data.address(), grad.clone(), Some(ptr));
 
         Self { var, grad, history }
     }
@@ -115,6 +126,26 @@ where
         }
     }
 
+    /// Enables the memory planner for the computational graph of `self`. See
+    /// [`Var::plan_memory()`] for more details.
+    pub fn plan_memory(&self) {
+        self.var.plan_memory()
+    }
+
+    /// Fuses the chains of element-wise operations of the computational graph of `self`, together
+    /// with their backward components. See [`Var::compile()`] for more details.
+    pub fn compile(mut self) -> Self {
+        let data = self.var.data.address();
+        fusion::compile(&mut self.var.history, data, Some(&mut self.history));
+        self
+    }
+
+    /// Exports the computational graph of `self` as an ONNX model. See [`Var::to_onnx()`] for more
+    /// details.
+    pub fn to_onnx(&self, exporter: &OnnxExporter) -> Result<Vec<u8>, OnnxError> {
+        self.var.to_onnx(exporter)
+    }
+
     /// Back-propagates through the computational graph and populates the gradients of the
     /// differentiable leaves that are ancestors of `self`. Before back-propagating the gradient
     /// of `self` is seeded with `seed`, thus, the leaves' gradients will be scaled accordingly.
@@ -266,6 +297,20 @@ where
         VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
     }
 
+    /// Takes the power of each element in `self` with the real exponent `exp` and returns a
+    /// differentiable variable with the result.
+    ///
+    /// # Arguments
+    ///
+    /// `exp` - exponent.
+    pub fn powf(self, exp: f32) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = PowFBackward::new(self.grad, self.var.data.clone(), grad.clone(), exp);
+        let var = self.var.powf(exp);
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
     /// Takes the square root element-wise and returns a differentiable variable with the result.
     pub fn sqrt(self) -> VarDiff<D> {
         let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
@@ -287,14 +332,162 @@ where
         VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
     }
 
+    /// Applies the *rectified linear unit* element-wise, overwriting the data of `self`, and
+    /// returns a differentiable variable with the result.
+    ///
+    /// *ReLU(x) = max(0, x)*
+    ///
+    /// # Panics
+    ///
+    /// If `self` is a leaf or its data is used elsewhere, for instance by the backward pass.
+    pub fn relu_(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let var = self.var.relu_();
+        let op = ReLUBackward::new(self.grad, var.data.clone(), grad.clone());
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
     /// Applies the *leaky rectified linear unit* element-wise and returns a differentiable
     /// variable with the result.
     ///
     /// *LeakyReLU(x) = max(0, x) + 0.01 * min(0, x)*
     pub fn leaky_relu(self) -> VarDiff<D> {
+        self.leaky_relu_with_slope(0.01)
+    }
+
+    /// Applies the *leaky rectified linear unit* with a custom negative slope element-wise and
+    /// returns a differentiable variable with the result.
+    ///
+    /// *LeakyReLU(x) = max(0, x) + slope * min(0, x)*
+    ///
+    /// # Arguments
+    ///
+    /// * `slope` - slope of the function for negative inputs.
+    pub fn leaky_relu_with_slope(self, slope: f32) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = LeakyReLUBackward::new(self.grad, self.var.data.clone(), grad.clone(), slope);
+        let var = self.var.leaky_relu_with_slope(slope);
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *Gaussian error linear unit* element-wise and returns a differentiable variable
+    /// with the result.
+    ///
+    /// *GELU(x) = x * Φ(x)*, where *Φ* is the cumulative distribution function of the standard
+    /// normal distribution.
+    ///
+    /// # Arguments
+    ///
+    /// * `approximate` - if `true`, *Φ* is estimated with the tanh approximation *0.5 * (1 +
+    /// tanh(sqrt(2 / π) * (x + 0.044715 * x^3)))*.
+    pub fn gelu(self, approximate: bool) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = GELUBackward::new(self.grad, self.var.data.clone(), grad.clone(), approximate);
+        let var = self.var.gelu(approximate);
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *sigmoid linear unit*, also known as *swish*, element-wise and returns a
+    /// differentiable variable with the result.
+    ///
+    /// *SiLU(x) = x * sigmoid(x)*
+    pub fn silu(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = SiLUBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.silu();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *exponential linear unit* element-wise and returns a differentiable variable
+    /// with the result.
+    ///
+    /// *ELU(x) = max(0, x) + min(0, alpha * (exp(x) - 1))*
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - value the function saturates to for negative inputs.
+    pub fn elu(self, alpha: f32) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = ELUBackward::new(self.grad, self.var.data.clone(), grad.clone(), alpha);
+        let var = self.var.elu(alpha);
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *continuously differentiable exponential linear unit* element-wise and returns a
+    /// differentiable variable with the result.
+    ///
+    /// *CELU(x) = max(0, x) + min(0, alpha * (exp(x / alpha) - 1))*
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - value the function saturates to for negative inputs.
+    pub fn celu(self, alpha: f32) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = CELUBackward::new(self.grad, self.var.data.clone(), grad.clone(), alpha);
+        let var = self.var.celu(alpha);
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *scaled exponential linear unit* element-wise and returns a differentiable
+    /// variable with the result.
+    ///
+    /// *SELU(x) = scale * (max(0, x) + min(0, alpha * (exp(x) - 1)))*, with *alpha ≈ 1.6733* and
+    /// *scale ≈ 1.0507*.
+    pub fn selu(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = SELUBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.selu();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *mish* element-wise and returns a differentiable variable with the result.
+    ///
+    /// *Mish(x) = x * tanh(softplus(x))*
+    pub fn mish(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = MishBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.mish();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *hard swish* element-wise and returns a differentiable variable with the result.
+    ///
+    /// *HardSwish(x) = x * min(max(0, x + 3), 6) / 6*
+    pub fn hardswish(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = HardSwishBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.hardswish();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *hard sigmoid* element-wise and returns a differentiable variable with the
+    /// result.
+    ///
+    /// *HardSigmoid(x) = min(max(0, x + 3), 6) / 6*
+    pub fn hardsigmoid(self) -> VarDiff<D> {
         let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
-        let op = LeakyReLUBackward::new(self.grad, self.var.data.clone(), grad.clone());
-        let var = self.var.leaky_relu();
+        let op = HardSigmoidBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.hardsigmoid();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *softsign* element-wise and returns a differentiable variable with the result.
+    ///
+    /// *SoftSign(x) = x / (1 + |x|)*
+    pub fn softsign(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = SoftSignBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.softsign();
 
         VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
     }
@@ -319,6 +512,20 @@ where
         VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
     }
 
+    /// Applies the *sigmoid* element-wise, overwriting the data of `self`, and returns a
+    /// differentiable variable with the result.
+    ///
+    /// # Panics
+    ///
+    /// If `self` is a leaf or its data is used elsewhere, for instance by the backward pass.
+    pub fn sigmoid_(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let var = self.var.sigmoid_();
+        let op = SigmoidBackward::new(self.grad, var.data.clone(), grad.clone());
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
     /// Applies the *tanh* element-wise and returns a differentiable variable with the result.
     pub fn tanh(self) -> VarDiff<D> {
         let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
@@ -348,6 +555,223 @@ where
         VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
     }
 
+    /// Applies the *sine* element-wise and returns a differentiable variable with the result.
+    pub fn sin(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = SinBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.sin();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *cosine* element-wise and returns a differentiable variable with the result.
+    pub fn cos(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = CosBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.cos();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *tangent* element-wise and returns a differentiable variable with the result.
+    pub fn tan(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let var = self.var.tan();
+        let op = TanBackward::new(self.grad, var.data.clone(), grad.clone());
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *arcsine* element-wise and returns a differentiable variable with the result.
+    pub fn asin(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = AsinBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.asin();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *arctangent* element-wise and returns a differentiable variable with the result.
+    pub fn atan(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = AtanBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.atan();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *hyperbolic sine* element-wise and returns a differentiable variable with the
+    /// result.
+    pub fn sinh(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = SinHBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.sinh();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *hyperbolic cosine* element-wise and returns a differentiable variable with the
+    /// result.
+    pub fn cosh(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = CosHBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.cosh();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *natural logarithm of one plus the input* element-wise and returns a
+    /// differentiable variable with the result.
+    ///
+    /// *log1p(x) = ln(1 + x)*
+    pub fn log1p(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = Log1pBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.log1p();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *exponential minus one* element-wise and returns a differentiable variable with
+    /// the result.
+    ///
+    /// *expm1(x) = exp(x) - 1*
+    pub fn expm1(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let var = self.var.expm1();
+        let op = Expm1Backward::new(self.grad, var.data.clone(), grad.clone());
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *base 2 logarithm* element-wise and returns a differentiable variable with the
+    /// result.
+    pub fn log2(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = Log2Backward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.log2();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *reciprocal of the square root* element-wise and returns a differentiable
+    /// variable with the result.
+    ///
+    /// *rsqrt(x) = 1 / sqrt(x)*
+    pub fn rsqrt(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let var = self.var.rsqrt();
+        let op = RsqrtBackward::new(self.grad, var.data.clone(), grad.clone());
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *error function* element-wise and returns a differentiable variable with the
+    /// result.
+    pub fn erf(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = ErfBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.erf();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *inverse error function* element-wise and returns a differentiable variable with
+    /// the result.
+    pub fn erfinv(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let var = self.var.erfinv();
+        let op = ErfinvBackward::new(self.grad, var.data.clone(), grad.clone());
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *natural logarithm of the absolute value of the gamma function* element-wise and
+    /// returns a differentiable variable with the result.
+    pub fn lgamma(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = LGammaBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.lgamma();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Applies the *digamma function* element-wise and returns a differentiable variable with the
+    /// result.
+    ///
+    /// *digamma(x) = d/dx ln(gamma(x))*
+    pub fn digamma(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = DigammaBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.digamma();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Takes the absolute value element-wise and returns a differentiable variable with the
+    /// result.
+    ///
+    /// The subgradient at zero is taken to be zero.
+    pub fn abs(self) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = AbsBackward::new(self.grad, self.var.data.clone(), grad.clone());
+        let var = self.var.abs();
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Takes the sign element-wise and returns a variable with the result.
+    ///
+    /// As the sign function has zero derivative almost everywhere, the result is not
+    /// differentiable.
+    pub fn sign(self) -> Var<D> {
+        self.var.sign()
+    }
+
+    /// Clamps all elements in `self` into the range *[min, max]* and returns a differentiable
+    /// variable with the result.
+    ///
+    /// The gradient flows only through the elements lying inside the range, bounds included.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - lower bound of the range.
+    ///
+    /// * `max` - upper bound of the range.
+    ///
+    /// # Panics
+    ///
+    /// If `min` is greater than `max`.
+    pub fn clamp(self, min: f32, max: f32) -> VarDiff<D> {
+        let grad = Rc::new(Gradient::ndarray_zeros(self.grad.shape()));
+        let op = ClampBackward::new(self.grad, self.var.data.clone(), grad.clone(), min, max);
+        let var = self.var.clamp(min, max);
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Computes the element-wise maximum between `self` and `rhs`.
+    ///
+    /// The two variables are broadcast together. Where they are equal the gradient is split
+    /// evenly between them.
+    pub fn maximum<Rhs>(self, rhs: Rhs) -> <Self as Maximum<Rhs>>::Output
+    where
+        Self: Maximum<Rhs>,
+    {
+        Maximum::maximum(self, rhs)
+    }
+
+    /// Computes the element-wise minimum between `self` and `rhs`.
+    ///
+    /// The two variables are broadcast together. Where they are equal the gradient is split
+    /// evenly between them.
+    pub fn minimum<Rhs>(self, rhs: Rhs) -> <Self as Minimum<Rhs>>::Output
+    where
+        Self: Minimum<Rhs>,
+    {
+        Minimum::minimum(self, rhs)
+    }
+
     /// Applies the *softmax* to `self` and returns a differentiable variable with the result.
     ///
     /// The *softmax* is applied to all slices along `axis`, and will re-scale them so
@@ -464,6 +888,28 @@ where
         VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
     }
 
+    /// Returns a new differentiable variable holding the elements of `self`, in row-major order,
+    /// arranged in the given shape. The result may have a different number of dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// `shape` - shape of the result.
+    ///
+    /// # Panics
+    ///
+    /// If the number of elements of `shape` differs from that of the variable.
+    pub fn reshape<E>(self, shape: E) -> VarDiff<E::Dim>
+    where
+        E: IntoDimension,
+        E::Dim: 'static,
+    {
+        let var = self.var.reshape(shape);
+        let grad = Rc::new(Gradient::ndarray_zeros(var.data().raw_dim()));
+        let op = ReshapeBackward::new(self.grad, grad.clone());
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
     /// Computes the mean absolute error between the two variables.
     ///
     /// # Arguments
@@ -749,12 +1195,54 @@ where
         E: IntoDimension<Dim = <D::Smaller as Dimension>::Smaller>,
     {
         let padding = padding.into_dimension();
-        let grad = Rc::new(Gradient::ndarray_zeros(self.var.data().raw_dim()));
-        let var = self.var.pad(padding, mode);
-        let op = PadBackward::new(self.grad, grad.clone(), padding);
+        self.pad_asymmetric(padding, padding, mode)
+    }
+
+    /// Applies distinct amounts of padding before and after each spatial dimension of the
+    /// variable.
+    pub fn pad_asymmetric<T, E>(self, before: E, after: E, mode: T) -> VarDiff<D>
+    where
+        T: 'static + PaddingMode<D>,
+        E: IntoDimension<Dim = <D::Smaller as Dimension>::Smaller>,
+    {
+        let before = before.into_dimension();
+        let var = self
+            .var
+            .pad_asymmetric(before, after.into_dimension(), mode);
+        let grad = Rc::new(Gradient::ndarray_zeros(var.data().raw_dim()));
+        let op = PadBackward::new(self.grad, grad.clone(), mode, before);
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+
+    /// Resizes the spatial dimensions of the variable to `size`, interpolating its values with the
+    /// specified [`Interpolation`] mode. The gradient is scattered back to the source elements with
+    /// the same weights.
+    ///
+    /// See [`Var::interpolate()`] for more details.
+    pub fn interpolate<E>(self, size: E, mode: Interpolation, align_corners: bool) -> VarDiff<D>
+    where
+        E: IntoDimension<Dim = <D::Smaller as Dimension>::Smaller>,
+    {
+        let var = self.var.interpolate(size, mode, align_corners);
+        let grad = Rc::new(Gradient::ndarray_zeros(var.data().raw_dim()));
+        let op = InterpolateBackward::new(self.grad, grad.clone(), mode, align_corners);
 
         VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
     }
+
+    /// Upsamples the spatial dimensions of the variable by `scale_factor`.
+    ///
+    /// See [`Var::upsample()`] for more details.
+    pub fn upsample(
+        self,
+        scale_factor: f32,
+        mode: Interpolation,
+        align_corners: bool,
+    ) -> VarDiff<D> {
+        let size = scaled_size(&self.var.data().raw_dim(), scale_factor);
+        self.interpolate(size, mode, align_corners)
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -1130,7 +1618,7 @@ impl MatMatMulT<VarDiff<Ix2>> for VarDiff<Ix2> {
 
         let grad = Rc::new(Gradient::ndarray_zeros(DotDim::shape(
             self.var.data().raw_dim(),
-            rhs.var.data().raw_dim(),
+            rhs.var.data().t().raw_dim(),
         )));
         let left = MatrixMatrixMulTBackwardLeft::new(self.grad, rhs.var.data.clone(), grad.clone());
         let right =
@@ -1430,6 +1918,297 @@ where
     }
 }
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Transposed Convolution ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<D> ConvolutionTranspose<Var<D>, <D::Smaller as Dimension>::Smaller> for VarDiff<D>
+where
+    D: 'static + Dimension + RemoveAxis,
+{
+    type Output = VarDiff<D>;
+
+    fn convolution_transpose<T>(
+        self,
+        input: Var<D>,
+        stride: T,
+        dilation: T,
+        output_padding: T,
+        groups: usize,
+    ) -> Self::Output
+    where
+        T: IntoDimension<Dim = <D::Smaller as Dimension>::Smaller> + Copy,
+    {
+        let input_data = input.data.clone();
+        let var = self
+            .var
+            .convolution_transpose(input, stride, dilation, output_padding, groups);
+        let shape = var.data().raw_dim();
+        let grad = Rc::new(Gradient::ndarray_zeros(shape));
+        let op = ConvolutionTransposeBackwardKernel::new(
+            input_data,
+            self.grad,
+            grad.clone(),
+            stride.into_dimension(),
+            dilation.into_dimension(),
+            groups,
+        );
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+}
+
+impl<D> ConvolutionTranspose<VarDiff<D>, <D::Smaller as Dimension>::Smaller> for VarDiff<D>
+where
+    D: 'static + Dimension + RemoveAxis,
+{
+    type Output = VarDiff<D>;
+
+    fn convolution_transpose<T>(
+        mut self,
+        input: VarDiff<D>,
+        stride: T,
+        dilation: T,
+        output_padding: T,
+        groups: usize,
+    ) -> Self::Output
+    where
+        T: IntoDimension<Dim = <D::Smaller as Dimension>::Smaller> + Copy,
+    {
+        self.history.merge(input.history);
+        let kernel_data = self.var.data.clone();
+        let kernel_grad = self.grad.clone();
+        let input_data = input.var.data.clone();
+        let input_grad = input.grad.clone();
+        let var =
+            self.var
+                .convolution_transpose(input.var, stride, dilation, output_padding, groups);
+        let shape = var.data().raw_dim();
+        let grad = Rc::new(Gradient::ndarray_zeros(shape));
+        let backward_input = ConvolutionTransposeBackwardInput::new(
+            kernel_data,
+            input_grad,
+            grad.clone(),
+            stride.into_dimension(),
+            dilation.into_dimension(),
+            groups,
+        );
+        let backward_kernel = ConvolutionTransposeBackwardKernel::new(
+            input_data,
+            kernel_grad,
+            grad.clone(),
+            stride.into_dimension(),
+            dilation.into_dimension(),
+            groups,
+        );
+
+        let op = ConvolutionTransposeBackward::new(backward_input, backward_kernel);
+
+        VarDiff::node(var, grad.clone(), (Rc::new(op), grad), self.history)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Comparison Operations Implementations ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Maximum ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<D, E> Maximum<Var<E>> for VarDiff<D>
+where
+    D: 'static + Dimension + DimMax<E>,
+    E: 'static + Dimension,
+{
+    type Output = VarDiff<<D as DimMax<E>>::Output>;
+
+    fn maximum(self, rhs: Var<E>) -> Self::Output {
+        let grad = Rc::new(Gradient::from_ndarray(cobroadcasted_zeros(
+            &self.var.data.borrow(),
+            &rhs.data.borrow(),
+        )));
+        let buff = Rc::new(BufferedGradient::from_ndarray(grad.clone()));
+        let op = MaximumBackwardLeft::new(
+            self.var.data.clone(),
+            rhs.data.clone(),
+            self.grad,
+            buff.clone(),
+        );
+        let var = Maximum::maximum(self.var, rhs);
+
+        VarDiff::node(var, grad, (Rc::new(op), buff), self.history)
+    }
+}
+
+impl<D, E> Maximum<VarDiff<E>> for VarDiff<D>
+where
+    D: 'static + Dimension + DimMax<E>,
+    E: 'static + Dimension,
+{
+    type Output = VarDiff<<D as DimMax<E>>::Output>;
+
+    fn maximum(mut self, rhs: VarDiff<E>) -> Self::Output {
+        self.history.merge(rhs.history);
+
+        let grad = Rc::new(Gradient::from_ndarray(cobroadcasted_zeros(
+            &self.var.data.borrow(),
+            &rhs.var.data.borrow(),
+        )));
+        let buff = Rc::new(BufferedGradient::from_ndarray(grad.clone()));
+        let left = MaximumBackwardLeft::new(
+            self.var.data.clone(),
+            rhs.var.data.clone(),
+            self.grad,
+            buff.clone(),
+        );
+        let right = MaximumBackwardRight::new(
+            self.var.data.clone(),
+            rhs.var.data.clone(),
+            rhs.grad,
+            buff.clone(),
+        );
+        let op = MaximumBackward::new(left, right);
+        let var = Maximum::maximum(self.var, rhs.var);
+
+        VarDiff::node(var, grad, (Rc::new(op), buff), self.history)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Minimum ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<D, E> Minimum<Var<E>> for VarDiff<D>
+where
+    D: 'static + Dimension + DimMax<E>,
+    E: 'static + Dimension,
+{
+    type Output = VarDiff<<D as DimMax<E>>::Output>;
+
+    fn minimum(self, rhs: Var<E>) -> Self::Output {
+        let grad = Rc::new(Gradient::from_ndarray(cobroadcasted_zeros(
+            &self.var.data.borrow(),
+            &rhs.data.borrow(),
+        )));
+        let buff = Rc::new(BufferedGradient::from_ndarray(grad.clone()));
+        let op = MinimumBackwardLeft::new(
+            self.var.data.clone(),
+            rhs.data.clone(),
+            self.grad,
+            buff.clone(),
+        );
+        let var = Minimum::minimum(self.var, rhs);
+
+        VarDiff::node(var, grad, (Rc::new(op), buff), self.history)
+    }
+}
+
+impl<D, E> Minimum<VarDiff<E>> for VarDiff<D>
+where
+    D: 'static + Dimension + DimMax<E>,
+    E: 'static + Dimension,
+{
+    type Output = VarDiff<<D as DimMax<E>>::Output>;
+
+    fn minimum(mut self, rhs: VarDiff<E>) -> Self::Output {
+        self.history.merge(rhs.history);
+
+        let grad = Rc::new(Gradient::from_ndarray(cobroadcasted_zeros(
+            &self.var.data.borrow(),
+            &rhs.var.data.borrow(),
+        )));
+        let buff = Rc::new(BufferedGradient::from_ndarray(grad.clone()));
+        let left = MinimumBackwardLeft::new(
+            self.var.data.clone(),
+            rhs.var.data.clone(),
+            self.grad,
+            buff.clone(),
+        );
+        let right = MinimumBackwardRight::new(
+            self.var.data.clone(),
+            rhs.var.data.clone(),
+            rhs.grad,
+            buff.clone(),
+        );
+        let op = MinimumBackward::new(left, right);
+        let var = Minimum::minimum(self.var, rhs.var);
+
+        VarDiff::node(var, grad, (Rc::new(op), buff), self.history)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Select ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<D, E, M> Select<Var<E>, Var<M>> for VarDiff<D>
+where
+    D: 'static + Dimension + DimMax<E>,
+    E: 'static + Dimension,
+    M: 'static + Dimension,
+{
+    type Output = VarDiff<<D as DimMax<E>>::Output>;
+
+    fn select(self, rhs: Var<E>, condition: Var<M>) -> Self::Output {
+        let grad = Rc::new(Gradient::from_ndarray(cobroadcasted_zeros(
+            &self.var.data.borrow(),
+            &rhs.data.borrow(),
+        )));
+        let buff = Rc::new(BufferedGradient::from_ndarray(grad.clone()));
+        let op =
+            SelectBackwardLeft::<D, E, M>::new(condition.data.clone(), self.grad, buff.clone());
+        let var = Select::select(self.var, rhs, condition);
+
+        VarDiff::node(var, grad, (Rc::new(op), buff), self.history)
+    }
+}
+
+impl<D, E, M> Select<VarDiff<E>, Var<M>> for VarDiff<D>
+where
+    D: 'static + Dimension + DimMax<E>,
+    E: 'static + Dimension,
+    M: 'static + Dimension,
+{
+    type Output = VarDiff<<D as DimMax<E>>::Output>;
+
+    fn select(mut self, rhs: VarDiff<E>, condition: Var<M>) -> Self::Output {
+        self.history.merge(rhs.history);
+
+        let grad = Rc::new(Gradient::from_ndarray(cobroadcasted_zeros(
+            &self.var.data.borrow(),
+            &rhs.var.data.borrow(),
+        )));
+        let buff = Rc::new(BufferedGradient::from_ndarray(grad.clone()));
+        let left = SelectBackwardLeft::new(condition.data.clone(), self.grad, buff.clone());
+        let right = SelectBackwardRight::new(condition.data.clone(), rhs.grad, buff.clone());
+        let op = SelectBackward::new(left, right);
+        let var = Select::select(self.var, rhs.var, condition);
+
+        VarDiff::node(var, grad, (Rc::new(op), buff), self.history)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Checkpointing ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+impl<D> Checkpointable for VarDiff<D>
+where
+    D: Dimension,
+{
+    fn detach(&self) -> Self {
+        let var = Var {
+            data: self.var.data.clone(),
+            history: History::default(),
+        };
+
+        Self {
+            var,
+            grad: self.grad.clone(),
+            history: History::default(),
+        }
+    }
+
+    fn merge_into<E>(self, node: &mut VarDiff<E>)
+    where
+        E: Dimension,
+    {
+        node.var.history.merge(self.var.history);
+        node.history.merge(self.history);
+    }
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Debug ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 impl<D> Debug for VarDiff<D>
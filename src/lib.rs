@@ -795,6 +795,29 @@ pub mod nn {
     // //! * [`nn::GroupedConv3d`](struct@GroupedConv3d) - Applies a grouped volumetric convolution over an
     // //! input signal composed of several input planes.
     // //!
+    // //! * [`nn::DepthwiseSeparableConv1d`](struct@DepthwiseSeparableConv1d) - Applies a depthwise
+    // //! separable temporal convolution over an input signal composed of several input planes.
+    // //!
+    // //! * [`nn::DepthwiseSeparableConv2d`](struct@DepthwiseSeparableConv2d) - Applies a depthwise
+    // //! separable spatial convolution over an input signal composed of several input planes.
+    // //!
+    // //! * [`nn::DepthwiseSeparableConv3d`](struct@DepthwiseSeparableConv3d) - Applies a depthwise
+    // //! separable volumetric convolution over an input signal composed of several input planes.
+    // //!
+    // //! * [`nn::ConvTranspose1d`](struct@ConvTranspose1d) - Applies a temporal transposed convolution
+    // //! over an input signal composed of several input planes.
+    // //!
+    // //! * [`nn::ConvTranspose2d`](struct@ConvTranspose2d) - Applies a spatial transposed convolution
+    // //! over an input signal composed of several input planes.
+    // //!
+    // //! * [`nn::ConvTranspose3d`](struct@ConvTranspose3d) - Applies a volumetric transposed
+    // //! convolution over an input signal composed of several input planes.
+    // //!
+    // //! ## Upsampling Layers
+    // //!
+    // //! * [`nn::Upsample`](struct@Upsample) - Upsamples an input signal composed of several input
+    // //! planes with nearest, linear or cubic interpolation.
+    // //!
     // //! ## Dropout Layers
     // //!
     // //! * [`nn::Dropout`](struct@Dropout) - During training, randomly zeroes some of the elements of